@@ -0,0 +1,143 @@
+//! Declarations reported as public API: items, inherent impl members, struct
+//! fields, and enum variants
+
+use syn::{Attribute, Fields, ImplItem, Item, ItemImpl, Type, Visibility};
+
+use crate::items::PubItem;
+use crate::parse::push;
+use crate::tokens::{signature, text};
+
+/// `(kind, visibility, name, signature)` of one declaration
+pub(crate) type Decl<'a> = (&'static str, &'a Visibility, String, String);
+
+/// Declaration details of a module-level item, if it is one reported as API
+pub(crate) fn declared(item: &Item) -> Option<Decl<'_>> {
+    Some(match item {
+        Item::Fn(i) => ("fn", &i.vis, i.sig.ident.to_string(), signature(&i.sig)),
+        Item::Mod(i) => (
+            "mod",
+            &i.vis,
+            i.ident.to_string(),
+            format!("mod {}", i.ident),
+        ),
+        Item::Use(i) => (
+            "use",
+            &i.vis,
+            text(&i.tree),
+            format!("use {}", text(&i.tree)),
+        ),
+        _ => return named_decl(item),
+    })
+}
+
+/// Declaration details of an item rendered as `kind Name` plus its generics or
+/// type; structs with private fields end in `{ .. }` or `(..)`, so gaining or
+/// losing private fields shows up as a changed signature
+fn named_decl(item: &Item) -> Option<Decl<'_>> {
+    let (kind, vis, ident, rest) = match item {
+        Item::Struct(i) => {
+            let hidden = i
+                .fields
+                .iter()
+                .any(|f| !matches!(f.vis, Visibility::Public(_)));
+            let marker = match &i.fields {
+                Fields::Named(_) if hidden => " { .. }",
+                Fields::Unnamed(_) if hidden => "(..)",
+                _ => "",
+            };
+            let rest = format!("{}{}", text(&i.generics), marker);
+            ("struct", &i.vis, &i.ident, rest)
+        }
+        Item::Enum(i) => ("enum", &i.vis, &i.ident, text(&i.generics)),
+        Item::Trait(i) => ("trait", &i.vis, &i.ident, text(&i.generics)),
+        Item::Type(i) => (
+            "type",
+            &i.vis,
+            &i.ident,
+            format!("{} = {}", text(&i.generics), text(&i.ty)),
+        ),
+        Item::Const(i) => ("const", &i.vis, &i.ident, format!(": {}", text(&i.ty))),
+        Item::Static(i) => ("static", &i.vis, &i.ident, format!(": {}", text(&i.ty))),
+        _ => return None,
+    };
+    Some((
+        kind,
+        vis,
+        ident.to_string(),
+        format!("{} {}{}", kind, ident, rest),
+    ))
+}
+
+/// Public methods and associated consts of an inherent impl, under its type name
+pub(crate) fn impl_items(scope: &str, imp: &ItemImpl, out: &mut Vec<PubItem>) {
+    let Type::Path(target) = &*imp.self_ty else {
+        return;
+    };
+    let Some(owner) = target.path.segments.last() else {
+        return;
+    };
+    let scope = format!("{}::{}", scope, owner.ident);
+    for item in &imp.items {
+        let decl = match item {
+            ImplItem::Fn(f) => ("fn", &f.vis, f.sig.ident.to_string(), signature(&f.sig)),
+            ImplItem::Const(c) => (
+                "const",
+                &c.vis,
+                c.ident.to_string(),
+                format!("const {}: {}", c.ident, text(&c.ty)),
+            ),
+            _ => continue,
+        };
+        push(out, &scope, decl);
+    }
+}
+
+/// Public fields of a public struct and variants of a public enum, under the
+/// type name (`module::Type::field`, `module::Enum::Variant`)
+///
+/// They are marked exhaustive when callers can build or match the type in
+/// full, so adding one breaks them as surely as removing one does.
+pub(crate) fn type_members(scope: &str, item: &Item, out: &mut Vec<PubItem>) {
+    let member = |ty: &syn::Ident, name: String, kind, signature, exhaustive| PubItem {
+        path: format!("{}::{}::{}", scope, ty, name),
+        kind,
+        signature,
+        exhaustive,
+    };
+    match item {
+        Item::Struct(s) if matches!(s.vis, Visibility::Public(_)) => {
+            let all_public = s
+                .fields
+                .iter()
+                .all(|f| matches!(f.vis, Visibility::Public(_)));
+            let exhaustive = all_public && is_exhaustive(&s.attrs);
+            for (i, field) in s.fields.iter().enumerate() {
+                if !matches!(field.vis, Visibility::Public(_)) {
+                    continue;
+                }
+                let name = field
+                    .ident
+                    .as_ref()
+                    .map_or(i.to_string(), ToString::to_string);
+                let signature = format!("pub {}: {}", name, text(&field.ty));
+                out.push(member(&s.ident, name, "field", signature, exhaustive));
+            }
+        }
+        Item::Enum(e) if matches!(e.vis, Visibility::Public(_)) => {
+            for variant in &e.variants {
+                let mut bare = variant.clone();
+                bare.attrs.clear();
+                bare.fields.iter_mut().for_each(|f| f.attrs.clear());
+                let name = variant.ident.to_string();
+                let exhaustive = is_exhaustive(&e.attrs);
+                out.push(member(&e.ident, name, "variant", text(&bare), exhaustive));
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Whether a type lacks `#[non_exhaustive]`
+fn is_exhaustive(attrs: &[Attribute]) -> bool {
+    !attrs.iter().any(|a| a.path().is_ident("non_exhaustive"))
+}
@@ -0,0 +1,114 @@
+//! Comparing public APIs between revisions
+
+use std::collections::BTreeMap;
+
+use crate::items::PubItem;
+
+/// Public items added, removed, or changed between two revisions
+#[derive(Debug, Clone, Default)]
+pub struct ApiDiff {
+    /// Items only in the new revision
+    pub added: Vec<PubItem>,
+    /// Items only in the old revision
+    pub removed: Vec<PubItem>,
+    /// `(old, new)` pairs whose signature changed
+    pub changed: Vec<(PubItem, PubItem)>,
+}
+
+impl ApiDiff {
+    /// Whether the changes break callers: removals, signature changes, or
+    /// additions to an exhaustive struct or enum
+    pub fn is_breaking(&self) -> bool {
+        !self.removed.is_empty()
+            || !self.changed.is_empty()
+            || self.added.iter().any(|i| i.exhaustive)
+    }
+
+    /// Semver bump the changes call for from `version`, following Cargo's
+    /// rules: below 1.0 the minor number is the breaking one (`0.y.z`) and
+    /// every `0.0.z` change is breaking
+    pub fn suggested_bump(&self, version: &str) -> &'static str {
+        let mut parts = version.split('.').map(|p| p.parse::<u64>().unwrap_or(0));
+        let (major, minor) = (parts.next().unwrap_or(0), parts.next().unwrap_or(0));
+        let additions = !self.added.is_empty();
+        match (major, minor, self.is_breaking()) {
+            (0, 0, true) => "patch (breaking changes; every 0.0.x release is breaking)",
+            (0, 0, false) if additions => "patch (additions only)",
+            (0, _, true) => "minor (breaking changes below 1.0)",
+            (0, _, false) if additions => "patch (additions only below 1.0)",
+            (_, _, true) => "major (breaking changes)",
+            (_, _, false) if additions => "minor (additions only)",
+            _ => "none",
+        }
+    }
+
+    /// The part of the diff for items of the crate named `name` (`my_crate`)
+    pub fn for_crate(&self, name: &str) -> ApiDiff {
+        let prefix = format!("{}::", name);
+        let ours = |i: &PubItem| i.path.starts_with(&prefix);
+        ApiDiff {
+            added: self.added.iter().filter(|i| ours(i)).cloned().collect(),
+            removed: self.removed.iter().filter(|i| ours(i)).cloned().collect(),
+            changed: self
+                .changed
+                .iter()
+                .filter(|(_, new)| ours(new))
+                .cloned()
+                .collect(),
+        }
+    }
+}
+
+/// Diff the public items of `base` against `head`, matching items by path and kind
+pub fn diff_api(base: &[PubItem], head: &[PubItem]) -> ApiDiff {
+    let key = |i: &PubItem| (i.path.clone(), i.kind);
+    let base_map: BTreeMap<_, &PubItem> = base.iter().map(|i| (key(i), i)).collect();
+    let head_map: BTreeMap<_, &PubItem> = head.iter().map(|i| (key(i), i)).collect();
+    let mut diff = ApiDiff::default();
+    for (k, new) in &head_map {
+        match base_map.get(k) {
+            None => diff.added.push((*new).clone()),
+            Some(old) if old.signature != new.signature => {
+                diff.changed.push(((*old).clone(), (*new).clone()))
+            }
+            Some(_) => {}
+        }
+    }
+    diff.removed = base_map
+        .iter()
+        .filter(|(k, _)| !head_map.contains_key(*k))
+        .map(|(_, old)| (*old).clone())
+        .collect();
+    diff
+}
+
+/// Render the diff as a plain-text report for changelogs, with a suggested
+/// bump for each crate in `versions` (path name to current version) that changed
+pub fn render_diff(diff: &ApiDiff, against: &str, versions: &BTreeMap<String, String>) -> String {
+    let mut out = format!("Public API changes since {}\n", against);
+    let sections = [("Added", "+", &diff.added), ("Removed", "-", &diff.removed)];
+    for (title, marker, items) in sections {
+        out.push_str(&format!("\n{} ({}):\n", title, items.len()));
+        for item in items {
+            out.push_str(&format!(
+                "  {} {}  [{}]\n",
+                marker, item.signature, item.path
+            ));
+        }
+    }
+    out.push_str(&format!("\nChanged ({}):\n", diff.changed.len()));
+    for (old, new) in &diff.changed {
+        out.push_str(&format!(
+            "  {}\n    - {}\n    + {}\n",
+            new.path, old.signature, new.signature
+        ));
+    }
+    out.push_str("\nSuggested version bumps:\n");
+    for (name, version) in versions {
+        let bump = diff.for_crate(name).suggested_bump(version);
+        if bump != "none" {
+            out.push_str(&format!("  {} {}: {}\n", name, version, bump));
+        }
+    }
+    out
+}
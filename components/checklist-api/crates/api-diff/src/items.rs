@@ -0,0 +1,119 @@
+//! Public item collection across a project's crates
+
+use checklist_config::Config;
+use discovery_cargo::{find_cargo_tomls, is_workspace, resolve_workspace_inheritance};
+use discovery_crate::extract_crate_name;
+use discovery_source::rust_sources;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::parse::parse_file;
+
+/// A public item found by parsing source
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PubItem {
+    /// `crate::module::Type::name` path of the item
+    pub path: String,
+    /// Item keyword (`fn`, `struct`, `use`, ...), or `field` / `variant`
+    pub kind: &'static str,
+    /// Declaration up to its body
+    pub signature: String,
+    /// Adding this item breaks callers: a variant of an enum, or a field of a
+    /// struct whose fields are all public, without `#[non_exhaustive]`
+    pub exhaustive: bool,
+}
+
+/// A library crate: its name as used in paths (`my_crate`), version, and `src/`
+struct Library {
+    name: String,
+    version: String,
+    src: PathBuf,
+}
+
+/// Public items of every library crate under the configured project root,
+/// sorted; only modules reachable from `src/lib.rs` through `pub mod` count
+pub fn public_api(config: &Config) -> Vec<PubItem> {
+    let mut items = Vec::new();
+    for library in libraries(config) {
+        let Ok(sources) = rust_sources(&library.src, config) else {
+            continue;
+        };
+        let sources: BTreeMap<PathBuf, String> =
+            sources.into_iter().map(|s| (s.path, s.content)).collect();
+        module_items(
+            &library.name,
+            &library.src.join("lib.rs"),
+            &sources,
+            &mut items,
+        );
+    }
+    items.sort();
+    items
+}
+
+/// Version of each library crate under the project root, by path name (`my_crate`)
+pub fn crate_versions(config: &Config) -> BTreeMap<String, String> {
+    libraries(config)
+        .into_iter()
+        .map(|library| (library.name, library.version))
+        .collect()
+}
+
+/// Library crates (packages with a `src/lib.rs`) under the project root, with
+/// workspace-inherited versions resolved
+fn libraries(config: &Config) -> Vec<Library> {
+    find_cargo_tomls(config.project_root(), &config.file().exclude)
+        .iter()
+        .filter_map(|path| {
+            let content = fs::read_to_string(path).ok()?;
+            let dir = path.parent()?;
+            if is_workspace(&content) || !dir.join("src/lib.rs").is_file() {
+                return None;
+            }
+            let manifest = resolve_workspace_inheritance(&content, dir)
+                .parse::<toml::Value>()
+                .ok()?;
+            let version = manifest
+                .get("package")
+                .and_then(|p| p.get("version"))
+                .and_then(|v| v.as_str())
+                .unwrap_or("0.0.0");
+            Some(Library {
+                name: extract_crate_name(&manifest).replace('-', "_"),
+                version: version.to_string(),
+                src: dir.join("src"),
+            })
+        })
+        .collect()
+}
+
+/// Items of the module in `file` and, recursively, of the public modules it
+/// declares out of line (`a.rs` or `a/mod.rs` next to or under the file)
+fn module_items(
+    module: &str,
+    file: &Path,
+    sources: &BTreeMap<PathBuf, String>,
+    out: &mut Vec<PubItem>,
+) {
+    let Some(content) = sources.get(file) else {
+        return;
+    };
+    let parsed = parse_file(module, content);
+    out.extend(parsed.items);
+    let dir = match file.file_stem().and_then(|s| s.to_str()) {
+        Some("lib" | "main" | "mod") | None => file.parent().unwrap_or(file).to_path_buf(),
+        Some(stem) => file.with_file_name(stem),
+    };
+    for segments in parsed.modules {
+        let relative: PathBuf = segments.iter().collect();
+        let candidates = [
+            dir.join(&relative).with_extension("rs"),
+            dir.join(&relative).join("mod.rs"),
+        ];
+        if let Some(child) = candidates.iter().find(|p| sources.contains_key(*p)) {
+            let name = format!("{}::{}", module, segments.join("::"));
+            module_items(&name, child, sources, out);
+        }
+    }
+}
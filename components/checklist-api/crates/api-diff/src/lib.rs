@@ -0,0 +1,16 @@
+//! Public API diffing for sw-checklist
+//!
+//! Parses library crate sources for `pub` items reachable through public
+//! modules (including struct fields and enum variants) and reports what was
+//! added, removed, or changed between two revisions, with the semver bump
+//! each crate needs.
+
+mod decl;
+mod diff;
+mod items;
+mod parse;
+mod tokens;
+
+pub use diff::{ApiDiff, diff_api, render_diff};
+pub use items::{PubItem, crate_versions, public_api};
+pub use parse::file_items;
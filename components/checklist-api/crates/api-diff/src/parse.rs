@@ -0,0 +1,82 @@
+//! Public item extraction from a parsed syntax tree
+
+use syn::{Item, ItemMod, Visibility};
+
+use crate::decl::{Decl, declared, impl_items, type_members};
+use crate::items::PubItem;
+use crate::tokens::text;
+
+/// Public items of one file and the public modules it declares out of line
+#[derive(Debug, Default)]
+pub(crate) struct ParsedFile {
+    /// Public items at any inline module depth
+    pub items: Vec<PubItem>,
+    /// `pub mod name;` declarations, as module path segments below the file's
+    /// module (`["a", "b"]` for `pub mod a { pub mod b; }`)
+    pub modules: Vec<Vec<String>>,
+}
+
+/// Public items declared in one file, with public inline modules and impl
+/// blocks at any depth
+///
+/// Methods are placed under their impl type (`module::Type::method`). Files that
+/// fail to parse contribute no items.
+pub fn file_items(module: &str, content: &str) -> Vec<PubItem> {
+    parse_file(module, content).items
+}
+
+/// Parse one file whose items live in `module`
+pub(crate) fn parse_file(module: &str, content: &str) -> ParsedFile {
+    let mut parsed = ParsedFile::default();
+    if let Ok(file) = syn::parse_file(content) {
+        collect(module, &[], &file.items, &mut parsed);
+    }
+    parsed
+}
+
+/// Walk `items` declared in `scope` (`nested` segments below the file's
+/// module), descending into public inline modules only
+fn collect(scope: &str, nested: &[String], items: &[Item], out: &mut ParsedFile) {
+    for item in items {
+        if let Some(decl) = declared(item) {
+            push(&mut out.items, scope, decl);
+        }
+        match item {
+            Item::Impl(imp) if imp.trait_.is_none() => impl_items(scope, imp, &mut out.items),
+            Item::Struct(_) | Item::Enum(_) => type_members(scope, item, &mut out.items),
+            Item::Mod(m) if is_public_module(m) => {
+                let mut path = nested.to_vec();
+                path.push(m.ident.to_string());
+                match &m.content {
+                    Some((_, inner)) => {
+                        collect(&format!("{}::{}", scope, m.ident), &path, inner, out)
+                    }
+                    None => out.modules.push(path),
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Record `decl` if it is `pub`; restricted visibility (`pub(crate)`) is not public API
+pub(crate) fn push(out: &mut Vec<PubItem>, scope: &str, (kind, vis, name, signature): Decl<'_>) {
+    if matches!(vis, Visibility::Public(_)) {
+        out.push(PubItem {
+            path: format!("{}::{}", scope, name),
+            kind,
+            signature: format!("pub {}", signature),
+            exhaustive: false,
+        });
+    }
+}
+
+/// Items in a module are public API only when the module itself is `pub`;
+/// `#[cfg(test)]` modules never are
+fn is_public_module(m: &ItemMod) -> bool {
+    let test = m
+        .attrs
+        .iter()
+        .any(|a| a.path().is_ident("cfg") && text(&a.meta) == "cfg(test)");
+    matches!(m.vis, Visibility::Public(_)) && !test
+}
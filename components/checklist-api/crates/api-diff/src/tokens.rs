@@ -0,0 +1,39 @@
+//! Rendering syntax nodes as compact source text
+
+use quote::ToTokens;
+use syn::Signature;
+
+/// Source text of `node` with token-stream spacing collapsed (`Option < & str >` -> `Option<&str>`)
+pub fn text(node: &impl ToTokens) -> String {
+    tidy(&node.to_token_stream().to_string())
+}
+
+/// Function signature text without a trailing comma after the last parameter
+pub fn signature(sig: &Signature) -> String {
+    let mut sig = sig.clone();
+    sig.inputs = sig.inputs.into_iter().collect();
+    text(&sig)
+}
+
+/// Drop the spaces a token stream puts around punctuation, keeping `{ a: T }`
+/// braces spaced as rustfmt writes them
+fn tidy(tokens: &str) -> String {
+    let tokens = tokens.replace(" :: ", "::").replace(":: ", "::");
+    let mut out = String::with_capacity(tokens.len());
+    let mut chars = tokens.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == ' ' {
+            let prev = out.chars().last().unwrap_or(' ');
+            let next = chars.peek().copied().unwrap_or(' ');
+            let callee =
+                prev.is_alphanumeric() || prev == '_' || (prev == '>' && !out.ends_with("->"));
+            let glue_next = matches!(next, ')' | ']' | ',' | ';' | ':' | '>' | '?')
+                || (callee && matches!(next, '(' | '<'));
+            if glue_next || matches!(prev, '(' | '[' | '<' | '&') {
+                continue;
+            }
+        }
+        out.push(c);
+    }
+    out
+}
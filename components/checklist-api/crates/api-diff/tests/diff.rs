@@ -0,0 +1,100 @@
+//! Breaking-change detection and version bump suggestions
+
+use api_diff::{ApiDiff, crate_versions, diff_api, file_items, public_api, render_diff};
+use checklist_config::ConfigBuilder;
+use checklist_tempdir::TempWorkspace;
+use std::fs;
+
+fn diff(base: &str, head: &str) -> ApiDiff {
+    diff_api(&file_items("demo", base), &file_items("demo", head))
+}
+
+#[test]
+fn new_enum_variant_is_breaking() {
+    let diff = diff("pub enum Mode { Fast }", "pub enum Mode { Fast, Slow }");
+    assert_eq!(diff.added.len(), 1);
+    assert!(diff.is_breaking());
+    assert_eq!(diff.suggested_bump("1.4.2"), "major (breaking changes)");
+}
+
+#[test]
+fn new_field_behind_private_fields_is_an_addition() {
+    let diff = diff(
+        "pub struct Opts { pub a: u8, b: u8 }",
+        "pub struct Opts { pub a: u8, pub c: u8, b: u8 }",
+    );
+    assert!(!diff.is_breaking());
+    assert_eq!(diff.suggested_bump("1.4.2"), "minor (additions only)");
+}
+
+#[test]
+fn removed_field_is_breaking() {
+    let diff = diff(
+        "pub struct Opts { pub a: u8, pub b: u8 }",
+        "pub struct Opts { pub a: u8 }",
+    );
+    assert_eq!(diff.removed.len(), 1);
+    assert!(diff.is_breaking());
+}
+
+#[test]
+fn zero_major_versions_shift_the_bump() {
+    let breaking = diff("pub fn a() {}", "");
+    assert_eq!(
+        breaking.suggested_bump("0.3.1"),
+        "minor (breaking changes below 1.0)"
+    );
+    let additions = diff("", "pub fn a() {}");
+    assert_eq!(
+        additions.suggested_bump("0.3.1"),
+        "patch (additions only below 1.0)"
+    );
+    assert!(breaking.suggested_bump("0.0.4").starts_with("patch"));
+    assert_eq!(
+        diff("pub fn a() {}", "pub fn a() {}").suggested_bump("0.3.1"),
+        "none"
+    );
+}
+
+#[test]
+fn only_public_module_tree_is_api() {
+    let root = TempWorkspace::new("api-diff-test").unwrap();
+    let dir = root.path();
+    fs::create_dir_all(dir.join("src/net")).unwrap();
+    let files = [
+        (
+            "Cargo.toml",
+            "[package]\nname = \"my-lib\"\nversion = \"0.2.0\"\n",
+        ),
+        (
+            "src/lib.rs",
+            "pub mod net;\nmod util;\npub use util::helper;\n",
+        ),
+        ("src/util.rs", "pub fn helper() {}\npub fn hidden() {}\n"),
+        ("src/net/mod.rs", "pub mod tcp;\n"),
+        ("src/net/tcp.rs", "pub fn connect() {}\n"),
+    ];
+    for (path, content) in files {
+        fs::write(dir.join(path), content).unwrap();
+    }
+    let config = ConfigBuilder::new().project_path(dir.to_path_buf()).build();
+    let paths: Vec<String> = public_api(&config).into_iter().map(|i| i.path).collect();
+    assert_eq!(
+        paths,
+        [
+            "my_lib::net",
+            "my_lib::net::tcp",
+            "my_lib::net::tcp::connect",
+            "my_lib::util::helper"
+        ]
+    );
+    let versions = crate_versions(&config);
+    assert_eq!(versions["my_lib"], "0.2.0");
+    let removed = diff_api(&public_api(&config), &[]);
+    let report = render_diff(&removed, "v0.2.0", &versions);
+    assert!(
+        report.contains("my_lib 0.2.0: minor (breaking changes below 1.0)"),
+        "{}",
+        report
+    );
+}
@@ -0,0 +1,117 @@
+//! Public item extraction from source that spans lines and nests modules
+
+use api_diff::{PubItem, file_items};
+
+fn find<'a>(items: &'a [PubItem], path: &str) -> &'a PubItem {
+    items
+        .iter()
+        .find(|i| i.path == path)
+        .unwrap_or_else(|| panic!("{} not found in {:?}", path, items))
+}
+
+#[test]
+fn multi_line_fn() {
+    let source = r#"
+pub fn load(
+    root: &Path,
+    strict: bool,
+) -> Result<Vec<String>> {
+    todo!()
+}
+"#;
+    let items = file_items("demo", source);
+    let load = find(&items, "demo::load");
+    assert_eq!(load.kind, "fn");
+    assert_eq!(
+        load.signature,
+        "pub fn load(root: &Path, strict: bool) -> Result<Vec<String>>"
+    );
+}
+
+#[test]
+fn inline_modules() {
+    let source = r#"
+pub mod outer {
+    pub struct Config;
+    pub mod inner {
+        pub const LIMIT: usize = 7;
+    }
+    pub(crate) fn hidden() {}
+}
+mod private {
+    pub fn internal() {}
+}
+#[cfg(test)]
+mod tests {
+    pub fn helper() {}
+}
+"#;
+    let items = file_items("demo", source);
+    assert_eq!(find(&items, "demo::outer").kind, "mod");
+    assert_eq!(find(&items, "demo::outer::Config").kind, "struct");
+    assert_eq!(
+        find(&items, "demo::outer::inner::LIMIT").signature,
+        "pub const LIMIT: usize"
+    );
+    assert!(items.iter().all(|i| !i.path.ends_with("hidden")));
+    assert!(items.iter().all(|i| !i.path.contains("tests")));
+    assert!(items.iter().all(|i| !i.path.contains("private")));
+}
+
+#[test]
+fn impls_inside_modules() {
+    let source = r#"
+pub mod shapes {
+    pub struct Circle;
+        impl Circle {
+            pub fn area(&self) -> f64 { 0.0 }
+            fn private(&self) {}
+        }
+    impl Default for Circle {
+        fn default() -> Self { Circle }
+    }
+}
+"#;
+    let items = file_items("demo", source);
+    assert_eq!(
+        find(&items, "demo::shapes::Circle::area").signature,
+        "pub fn area(&self) -> f64"
+    );
+    assert!(items.iter().all(|i| !i.path.ends_with("private")));
+    assert!(items.iter().all(|i| !i.path.ends_with("default")));
+}
+
+#[test]
+fn fields_and_variants() {
+    let source = r#"
+pub struct Point {
+    /// Horizontal
+    pub x: i32,
+    pub y: i32,
+}
+pub struct Handle {
+    pub id: u32,
+    inner: usize,
+}
+#[non_exhaustive]
+pub enum Shape {
+    /// A circle
+    Circle(f64),
+    Square { side: f64 },
+}
+"#;
+    let items = file_items("demo", source);
+    let x = find(&items, "demo::Point::x");
+    assert_eq!((x.kind, x.signature.as_str()), ("field", "pub x: i32"));
+    assert!(x.exhaustive);
+    assert!(!find(&items, "demo::Handle::id").exhaustive);
+    assert_eq!(
+        find(&items, "demo::Handle").signature,
+        "pub struct Handle { .. }"
+    );
+    assert!(items.iter().all(|i| !i.path.ends_with("inner")));
+    let square = find(&items, "demo::Shape::Square");
+    assert_eq!(square.signature, "Square { side: f64 }");
+    assert!(!square.exhaustive);
+    assert_eq!(find(&items, "demo::Shape::Circle").signature, "Circle(f64)");
+}
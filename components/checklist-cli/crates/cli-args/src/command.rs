@@ -0,0 +1,171 @@
+//! Subcommand definitions
+
+use clap::{Args, Subcommand};
+use std::path::PathBuf;
+
+/// sw-checklist subcommands (checking a project is the default)
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Render an SVG status badge for embedding in a README
+    Badge(BadgeArgs),
+    /// Post (or update) a sticky PR comment with the Markdown report
+    Comment(CommentArgs),
+    /// Serve an HTTP API for triggering and reading check runs
+    Serve(ServeArgs),
+    /// Record current modularity metrics in .sw-checklist-ratchet.toml
+    Ratchet(RatchetArgs),
+    /// Manage .sw-checklist-baseline.json, whose findings are reported as INFO
+    Baseline(BaselineArgs),
+    /// Run a language server publishing findings as editor diagnostics
+    Lsp,
+    /// Report public API items added, removed, or changed since a git ref
+    ApiDiff(ApiDiffArgs),
+    /// Run binary or Web UI dist checks against a prebuilt artifact
+    Artifact(ArtifactArgs),
+}
+
+/// Handler and path selection, shared by the default check and every
+/// subcommand that checks a project
+#[derive(Args, Debug, Clone, Default)]
+pub struct SelectArgs {
+    /// Run only this handler (repeatable), e.g. --only modularity
+    #[arg(long, value_name = "HANDLER")]
+    pub only: Vec<String>,
+
+    /// Don't run this handler (repeatable), e.g. --skip clap when binaries aren't built
+    #[arg(long, value_name = "HANDLER")]
+    pub skip: Vec<String>,
+
+    /// Leave paths matching this glob (relative to the project) out of crate
+    /// discovery and source walks (repeatable), e.g. --exclude vendor
+    #[arg(long, value_name = "GLOB")]
+    pub exclude: Vec<String>,
+}
+
+/// Arguments for the badge subcommand
+#[derive(Args, Debug)]
+pub struct BadgeArgs {
+    /// Project path to check (defaults to current directory)
+    #[arg(default_value = ".")]
+    pub path: PathBuf,
+
+    /// File to write the SVG badge to
+    #[arg(short, long, default_value = "badge.svg")]
+    pub output: PathBuf,
+
+    /// Show the conformance score instead of pass/fail/warn counts
+    #[arg(long)]
+    pub score: bool,
+
+    #[command(flatten)]
+    pub select: SelectArgs,
+}
+
+/// Arguments for the comment subcommand
+#[derive(Args, Debug)]
+pub struct CommentArgs {
+    /// Project path to check (defaults to current directory)
+    #[arg(default_value = ".")]
+    pub path: PathBuf,
+
+    /// Pull request number to comment on
+    #[arg(long)]
+    pub pr: u64,
+
+    /// Repository as owner/name (defaults to $GITHUB_REPOSITORY)
+    #[arg(long)]
+    pub repo: Option<String>,
+
+    /// GitHub token (defaults to $GITHUB_TOKEN)
+    #[arg(long)]
+    pub token: Option<String>,
+
+    /// Base branch or ref to diff results against (e.g. origin/main)
+    #[arg(long)]
+    pub base: Option<String>,
+
+    #[command(flatten)]
+    pub select: SelectArgs,
+}
+
+/// Arguments for the serve subcommand
+#[derive(Args, Debug)]
+pub struct ServeArgs {
+    /// Address to listen on
+    #[arg(long, default_value = "127.0.0.1")]
+    pub host: String,
+
+    /// Port to listen on
+    #[arg(short, long, default_value_t = 8080)]
+    pub port: u16,
+
+    /// Accept `{"git": url}` targets; checking a clone runs its code (built
+    /// binaries, npm test), so only allow this for trusted callers
+    #[arg(long)]
+    pub allow_remote: bool,
+
+    #[command(flatten)]
+    pub select: SelectArgs,
+}
+
+/// Arguments for the ratchet subcommand
+#[derive(Args, Debug)]
+pub struct RatchetArgs {
+    /// Project path to record (defaults to current directory)
+    #[arg(default_value = ".")]
+    pub path: PathBuf,
+
+    #[command(flatten)]
+    pub select: SelectArgs,
+}
+
+/// Arguments for the baseline subcommand
+#[derive(Args, Debug)]
+pub struct BaselineArgs {
+    #[command(subcommand)]
+    pub action: BaselineAction,
+}
+
+/// baseline subcommand actions
+#[derive(Subcommand, Debug)]
+pub enum BaselineAction {
+    /// Snapshot current failures and warnings into .sw-checklist-baseline.json
+    Write(BaselineWriteArgs),
+}
+
+/// Arguments for baseline write
+#[derive(Args, Debug)]
+pub struct BaselineWriteArgs {
+    /// Project path to record (defaults to current directory)
+    #[arg(default_value = ".")]
+    pub path: PathBuf,
+
+    #[command(flatten)]
+    pub select: SelectArgs,
+}
+
+/// Arguments for the api-diff subcommand
+#[derive(Args, Debug)]
+pub struct ApiDiffArgs {
+    /// Project path to inspect (defaults to current directory)
+    #[arg(default_value = ".")]
+    pub path: PathBuf,
+
+    /// Git ref to compare against (e.g. v0.1.0 or origin/main)
+    #[arg(long)]
+    pub against: String,
+
+    #[command(flatten)]
+    pub select: SelectArgs,
+}
+
+/// Arguments for the artifact subcommand
+#[derive(Args, Debug)]
+pub struct ArtifactArgs {
+    /// Prebuilt binary, built dist/ directory, or release archive (.tar.gz) to check
+    pub path: PathBuf,
+
+    /// Show per-check details (default: summary only)
+    #[arg(short, long)]
+    pub verbose: bool,
+}
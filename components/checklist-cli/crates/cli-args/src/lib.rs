@@ -1,5 +1,10 @@
 //! CLI argument parsing for sw-checklist
 
 mod args;
+mod command;
 
 pub use args::{Cli, parse};
+pub use command::{
+    ApiDiffArgs, ArtifactArgs, BadgeArgs, BaselineAction, BaselineArgs, BaselineWriteArgs, Command,
+    CommentArgs, RatchetArgs, SelectArgs, ServeArgs,
+};
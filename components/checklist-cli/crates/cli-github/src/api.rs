@@ -0,0 +1,137 @@
+//! GitHub REST API access (via curl)
+
+use anyhow::{Context, Result, bail};
+use checklist_exec::Exec;
+use checklist_tempdir::TempWorkspace;
+use std::env;
+use std::fs;
+use std::time::Duration;
+
+use crate::markdown::REPORT_MARKER;
+
+/// Longest time one GitHub API request may take
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Pull request to comment on, with the token used to authenticate
+pub struct PrTarget {
+    pub repo: String,
+    pub pr: u64,
+    pub token: String,
+}
+
+impl PrTarget {
+    /// Fill in the repository and token from GITHUB_REPOSITORY / GITHUB_TOKEN when not given
+    pub fn resolve(repo: Option<String>, pr: u64, token: Option<String>) -> Result<Self> {
+        let repo = repo
+            .or_else(|| env::var("GITHUB_REPOSITORY").ok())
+            .context("No repository given (use --repo owner/name or set GITHUB_REPOSITORY)")?;
+        let token = token
+            .or_else(|| env::var("GITHUB_TOKEN").ok())
+            .context("No token given (use --token or set GITHUB_TOKEN)")?;
+        Ok(Self { repo, pr, token })
+    }
+}
+
+/// Create the sticky report comment on the PR, or update it if it already exists
+pub fn upsert_comment(target: &PrTarget, body: &str) -> Result<()> {
+    let api = env::var("GITHUB_API_URL").unwrap_or_else(|_| "https://api.github.com".into());
+    let repo_url = format!("{}/repos/{}", api, target.repo);
+    let payload = serde_json::json!({ "body": body }).to_string();
+    match find_report_comment(&repo_url, target)? {
+        Some(id) => {
+            let url = format!("{}/issues/comments/{}", repo_url, id);
+            request("PATCH", &url, target, Some(&payload), &[])?;
+        }
+        None => {
+            let url = format!("{}/issues/{}/comments", repo_url, target.pr);
+            request("POST", &url, target, Some(&payload), &[])?;
+        }
+    }
+    Ok(())
+}
+
+/// Id of the existing report comment, following `Link: rel="next"` pages
+fn find_report_comment(repo_url: &str, target: &PrTarget) -> Result<Option<u64>> {
+    let mut url = Some(format!(
+        "{}/issues/{}/comments?per_page=100",
+        repo_url, target.pr
+    ));
+    while let Some(page) = url {
+        let (comments, next) = get_page(&page, target)?;
+        let found = comments
+            .as_array()
+            .into_iter()
+            .flatten()
+            .find(|c| {
+                c.get("body")
+                    .and_then(|b| b.as_str())
+                    .is_some_and(|b| b.contains(REPORT_MARKER))
+            })
+            .and_then(|c| c.get("id"))
+            .and_then(|id| id.as_u64());
+        if found.is_some() {
+            return Ok(found);
+        }
+        url = next;
+    }
+    Ok(None)
+}
+
+/// GET one page of a JSON list, returning it and the next page's URL, if any
+/// (headers are dumped to a separate file so the body is exactly stdout)
+fn get_page(url: &str, target: &PrTarget) -> Result<(serde_json::Value, Option<String>)> {
+    let workspace = TempWorkspace::new("github-headers")?;
+    let headers_file = workspace.path().join("headers");
+    let dump = headers_file.to_string_lossy();
+    let body = request("GET", url, target, None, &["-D", &dump])?;
+    let headers = fs::read_to_string(&headers_file).unwrap_or_default();
+    let page = serde_json::from_str(&body).context("Failed to parse PR comments")?;
+    Ok((page, next_link(&headers)))
+}
+
+/// URL of the `rel="next"` entry in the final response's `Link` header (curl
+/// dumps a header block per interim response or redirect, the final one last)
+pub fn next_link(headers: &str) -> Option<String> {
+    let last = headers
+        .split("\r\n\r\n")
+        .filter(|block| !block.trim().is_empty())
+        .last()?;
+    let link = last.lines().find_map(|line| {
+        let (name, value) = line.split_once(':')?;
+        name.eq_ignore_ascii_case("link").then_some(value)
+    })?;
+    link.split(',')
+        .find(|entry| entry.contains("rel=\"next\""))
+        .and_then(|entry| entry.split_once('<')?.1.split_once('>'))
+        .map(|(url, _)| url.to_string())
+}
+
+/// Run curl for an API request with `extra` arguments; the token is passed on
+/// stdin to keep it out of the process list
+fn request(
+    method: &str,
+    url: &str,
+    target: &PrTarget,
+    payload: Option<&str>,
+    extra: &[&str],
+) -> Result<String> {
+    let output = Exec::new("curl")
+        .args(["-sS", "-f", "-X", method, "-H", "@-"])
+        .args(["-H", "Accept: application/vnd.github+json"])
+        .args(extra)
+        .args([url])
+        .args(payload.map(|p| ["--data-binary", p]).into_iter().flatten())
+        .stdin(format!("Authorization: Bearer {}\n", target.token))
+        .timeout(REQUEST_TIMEOUT)
+        .run()
+        .context("Failed to run curl")?;
+    if !output.success() {
+        bail!(
+            "GitHub API {} {} failed: {}",
+            method,
+            url,
+            output.stderr.trim()
+        );
+    }
+    Ok(output.stdout)
+}
@@ -0,0 +1,95 @@
+//! Base branch checkout and result diffing
+
+use anyhow::{Context, Result};
+use checklist_exec::git;
+use checklist_result::CheckResult;
+use checklist_tempdir::TempWorkspace;
+use engine_baseline::BaselineEntry;
+use std::path::{Path, PathBuf};
+
+/// Issues introduced and resolved relative to the base branch
+pub struct BaseDiff {
+    pub base_ref: String,
+    pub new_issues: Vec<CheckResult>,
+    pub resolved: Vec<CheckResult>,
+}
+
+/// Temporary git worktree of the base branch, removed on drop
+pub struct BaseWorktree {
+    repo_dir: PathBuf,
+    worktree: PathBuf,
+    project_dir: PathBuf,
+    _workspace: TempWorkspace,
+}
+
+impl BaseWorktree {
+    /// Check out `base_ref` into a temporary worktree of the project's repository
+    pub fn checkout(project_root: &Path, base_ref: &str) -> Result<Self> {
+        let repo_dir = PathBuf::from(git(project_root, &["rev-parse", "--show-toplevel"])?);
+        let project_root = project_root.canonicalize()?;
+        let relative = project_root
+            .strip_prefix(&repo_dir)
+            .unwrap_or(Path::new(""));
+        let workspace = TempWorkspace::new("base")?;
+        let worktree = workspace.path().join("base");
+        let worktree_arg = worktree.to_string_lossy().into_owned();
+        git(
+            &repo_dir,
+            &["worktree", "add", "--detach", &worktree_arg, base_ref],
+        )
+        .with_context(|| format!("Failed to check out base ref {}", base_ref))?;
+        Ok(Self {
+            project_dir: worktree.join(relative),
+            repo_dir,
+            worktree,
+            _workspace: workspace,
+        })
+    }
+
+    /// Project directory inside the base worktree
+    pub fn project_dir(&self) -> &Path {
+        &self.project_dir
+    }
+}
+
+impl Drop for BaseWorktree {
+    fn drop(&mut self) {
+        let worktree_arg = self.worktree.to_string_lossy().into_owned();
+        let _ = git(
+            &self.repo_dir,
+            &["worktree", "remove", "--force", &worktree_arg],
+        );
+    }
+}
+
+/// Compare failing/warning checks on the head (under `head_root`) against
+/// the base branch (under `base_root`), matching findings by check name,
+/// message, and root-relative file as the baseline does
+pub fn diff_results(
+    base_ref: &str,
+    head_root: &Path,
+    head: &[CheckResult],
+    base_root: &Path,
+    base: &[CheckResult],
+) -> BaseDiff {
+    let is_issue = |r: &CheckResult| !r.status.passed() || r.status.is_warning();
+    let keys = |root: &Path, results: &[CheckResult]| -> Vec<BaselineEntry> {
+        results
+            .iter()
+            .map(|r| BaselineEntry::new(root, r))
+            .collect()
+    };
+    let (head_keys, base_keys) = (keys(head_root, head), keys(base_root, base));
+    let only_in = |root: &Path, results: &[CheckResult], other: &[BaselineEntry]| {
+        results
+            .iter()
+            .filter(|r| is_issue(r) && !other.contains(&BaselineEntry::new(root, r)))
+            .cloned()
+            .collect()
+    };
+    BaseDiff {
+        base_ref: base_ref.to_string(),
+        new_issues: only_in(head_root, head, &base_keys),
+        resolved: only_in(base_root, base, &head_keys),
+    }
+}
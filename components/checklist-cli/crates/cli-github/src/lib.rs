@@ -0,0 +1,12 @@
+//! GitHub pull request reporting for sw-checklist
+//!
+//! Renders check results as a Markdown report and posts it as a sticky PR
+//! comment, optionally diffed against the results on the base branch.
+
+mod api;
+mod base;
+mod markdown;
+
+pub use api::{PrTarget, next_link, upsert_comment};
+pub use base::{BaseDiff, BaseWorktree, diff_results};
+pub use markdown::render_report;
@@ -0,0 +1,83 @@
+//! Markdown report rendering
+
+use checklist_result::{CheckResult, CheckStatus};
+
+use crate::base::BaseDiff;
+
+/// Hidden marker identifying the sticky report comment
+pub const REPORT_MARKER: &str = "<!-- sw-checklist-report -->";
+
+/// GitHub rejects comment bodies over 65536 characters
+const MAX_BODY_LEN: usize = 65_000;
+
+/// Render check results (and the diff against the base branch) as a Markdown report
+pub fn render_report(results: &[CheckResult], diff: Option<&BaseDiff>) -> String {
+    let count = |s: CheckStatus| results.iter().filter(|r| r.status == s).count();
+    let mut out = format!(
+        "{}\n## sw-checklist report\n\n**Summary:** {} passed, {} failed, {} warnings, {} info\n",
+        REPORT_MARKER,
+        count(CheckStatus::Pass),
+        count(CheckStatus::Fail),
+        count(CheckStatus::Warn),
+        count(CheckStatus::Info)
+    );
+    if let Some(diff) = diff {
+        out.push_str(&diff_section(diff));
+    }
+    let issues: Vec<&CheckResult> = results
+        .iter()
+        .filter(|r| matches!(r.status, CheckStatus::Fail | CheckStatus::Warn))
+        .collect();
+    out.push_str(&issues_table("Issues", &issues));
+    if out.len() > MAX_BODY_LEN {
+        let cut = out.floor_char_boundary(MAX_BODY_LEN);
+        out.truncate(cut);
+        out.push_str("\n\n_Report truncated; run sw-checklist -v locally for all results._\n");
+    }
+    out
+}
+
+fn diff_section(diff: &BaseDiff) -> String {
+    if diff.new_issues.is_empty() && diff.resolved.is_empty() {
+        return format!(
+            "\nNo new or resolved issues compared to `{}`.\n",
+            diff.base_ref
+        );
+    }
+    let new: Vec<&CheckResult> = diff.new_issues.iter().collect();
+    let resolved: Vec<&CheckResult> = diff.resolved.iter().collect();
+    format!(
+        "{}{}",
+        issues_table(&format!("New since `{}`", diff.base_ref), &new),
+        issues_table(&format!("Resolved since `{}`", diff.base_ref), &resolved)
+    )
+}
+
+fn issues_table(title: &str, results: &[&CheckResult]) -> String {
+    if results.is_empty() {
+        return String::new();
+    }
+    let mut out = format!(
+        "\n### {} ({})\n\n| Status | Check | Message |\n|---|---|---|\n",
+        title,
+        results.len()
+    );
+    for r in results {
+        out.push_str(&format!(
+            "| {} | {} | {} |\n",
+            status_label(r.status),
+            r.name.replace('|', "\\|"),
+            r.display_message().replace('|', "\\|").replace('\n', " ")
+        ));
+    }
+    out
+}
+
+fn status_label(status: CheckStatus) -> &'static str {
+    match status {
+        CheckStatus::Pass => "✅ PASS",
+        CheckStatus::Fail => "❌ FAIL",
+        CheckStatus::Warn => "⚠️ WARN",
+        CheckStatus::Info => "ℹ️ INFO",
+    }
+}
@@ -0,0 +1,34 @@
+use cli_github::next_link;
+
+const NEXT: &str = "link: <https://api.github.com/repos/o/r/issues/1/comments?page=2>; rel=\"next\", \
+                    <https://api.github.com/repos/o/r/issues/1/comments?page=3>; rel=\"last\"";
+
+#[test]
+fn finds_the_next_page() {
+    let headers = format!(
+        "HTTP/2 200\r\n{}\r\ncontent-type: application/json\r\n\r\n",
+        NEXT
+    );
+    assert_eq!(
+        next_link(&headers).as_deref(),
+        Some("https://api.github.com/repos/o/r/issues/1/comments?page=2")
+    );
+}
+
+#[test]
+fn reads_the_final_header_block() {
+    let redirect = "HTTP/1.1 301 Moved Permanently\r\nLocation: https://api.github.com/x\r\n\r\n";
+    let interim = "HTTP/1.1 100 Continue\r\n\r\n";
+    let last = "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\n\r\n";
+    let headers = format!("{}{}{}", redirect, interim, last);
+    assert_eq!(next_link(&headers), None);
+
+    let headers = format!("{}HTTP/1.1 200 OK\r\n{}\r\n\r\n", redirect, NEXT);
+    assert!(next_link(&headers).is_some_and(|url| url.ends_with("page=2")));
+}
+
+#[test]
+fn last_page_has_no_next() {
+    let headers = "HTTP/2 200\r\nlink: <https://api.github.com/x?page=1>; rel=\"prev\"\r\n\r\n";
+    assert_eq!(next_link(headers), None);
+}
@@ -0,0 +1,53 @@
+use checklist_result::CheckResult;
+use cli_github::diff_results;
+use std::path::Path;
+
+fn lines(root: &str, file: &str, count: usize) -> CheckResult {
+    let message = format!("Function has {} lines", count);
+    CheckResult::fail("Function LOC [demo]", message).at(Path::new(root).join(file), 3)
+}
+
+#[test]
+fn findings_match_by_name_message_and_relative_file() {
+    let head = [
+        lines("/head", "src/lib.rs", 60),
+        lines("/head", "src/main.rs", 60),
+        lines("/head", "src/cli.rs", 70),
+    ];
+    let base = [
+        lines("/tmp/base", "src/lib.rs", 60),
+        lines("/tmp/base", "src/cli.rs", 65),
+    ];
+    let diff = diff_results(
+        "main",
+        Path::new("/head"),
+        &head,
+        Path::new("/tmp/base"),
+        &base,
+    );
+    let messages = |results: &[CheckResult]| -> Vec<String> {
+        results
+            .iter()
+            .map(|r| format!("{:?}: {}", r.location.as_ref().unwrap().file, r.message))
+            .collect()
+    };
+    assert_eq!(
+        messages(&diff.new_issues),
+        [
+            "\"/head/src/main.rs\": Function has 60 lines",
+            "\"/head/src/cli.rs\": Function has 70 lines"
+        ]
+    );
+    assert_eq!(
+        messages(&diff.resolved),
+        ["\"/tmp/base/src/cli.rs\": Function has 65 lines"]
+    );
+}
+
+#[test]
+fn passing_checks_are_not_diffed() {
+    let head = [CheckResult::pass("Function LOC [demo]", "ok")];
+    let diff = diff_results("main", Path::new("."), &head, Path::new("."), &[]);
+    assert!(diff.new_issues.is_empty());
+    assert!(diff.resolved.is_empty());
+}
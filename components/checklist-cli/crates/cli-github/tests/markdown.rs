@@ -0,0 +1,25 @@
+//! Golden-file snapshots of the Markdown PR report
+
+use checklist_result::CheckStatus;
+use checklist_testkit::sample_results;
+use cli_github::{BaseDiff, render_report};
+
+#[test]
+fn report() {
+    insta::assert_snapshot!(render_report(&sample_results(), None));
+}
+
+#[test]
+fn report_with_diff() {
+    let results = sample_results();
+    let diff = BaseDiff {
+        base_ref: "origin/main".to_string(),
+        new_issues: results
+            .iter()
+            .filter(|r| r.status == CheckStatus::Fail)
+            .cloned()
+            .collect(),
+        resolved: vec![results[3].clone()],
+    };
+    insta::assert_snapshot!(render_report(&results, Some(&diff)));
+}
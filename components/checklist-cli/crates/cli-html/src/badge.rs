@@ -0,0 +1,71 @@
+//! SVG status badge rendering
+
+use checklist_result::{CheckResult, CheckStatus};
+
+/// What the badge message shows
+#[derive(Debug, Clone, Copy)]
+pub enum BadgeContent {
+    /// Pass/fail/warn counts
+    Counts,
+    /// Percentage of passing checks (info results excluded)
+    Score,
+}
+
+/// Render a shields-style SVG badge summarizing check results
+pub fn render_badge(results: &[CheckResult], content: BadgeContent) -> String {
+    let count = |status| results.iter().filter(|r| r.status == status).count();
+    let (passed, failed, warnings) = (
+        count(CheckStatus::Pass),
+        count(CheckStatus::Fail),
+        count(CheckStatus::Warn),
+    );
+    let (message, color) = match content {
+        BadgeContent::Counts => counts_message(passed, failed, warnings),
+        BadgeContent::Score => score_message(passed, failed + warnings),
+    };
+    svg("sw-checklist", &message, color)
+}
+
+fn counts_message(passed: usize, failed: usize, warnings: usize) -> (String, &'static str) {
+    let color = match (failed, warnings) {
+        (0, 0) => "#4c1",
+        (0, _) => "#dfb317",
+        _ => "#e05d44",
+    };
+    (
+        format!("{} passed | {} failed | {} warn", passed, failed, warnings),
+        color,
+    )
+}
+
+fn score_message(passed: usize, issues: usize) -> (String, &'static str) {
+    let total = passed + issues;
+    let score = (passed * 100).checked_div(total).unwrap_or(100);
+    let color = match score {
+        100 => "#4c1",
+        80..=99 => "#dfb317",
+        _ => "#e05d44",
+    };
+    (format!("{}%", score), color)
+}
+
+fn svg(label: &str, message: &str, color: &str) -> String {
+    // Approximate Verdana 11px text width
+    let label_width = label.len() * 7 + 10;
+    let message_width = message.len() * 7 + 10;
+    format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="{total}" height="20" role="img" aria-label="{label}: {message}">
+  <title>{label}: {message}</title>
+  <rect width="{label_width}" height="20" fill="#555"/>
+  <rect x="{label_width}" width="{message_width}" height="20" fill="{color}"/>
+  <g fill="#fff" text-anchor="middle" font-family="Verdana,Geneva,DejaVu Sans,sans-serif" font-size="11">
+    <text x="{label_x}" y="14">{label}</text>
+    <text x="{message_x}" y="14">{message}</text>
+  </g>
+</svg>
+"##,
+        total = label_width + message_width,
+        label_x = label_width / 2,
+        message_x = label_width + message_width / 2,
+    )
+}
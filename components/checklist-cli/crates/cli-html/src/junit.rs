@@ -0,0 +1,65 @@
+//! JUnit XML report rendering
+
+use checklist_result::{CheckResult, CheckStatus};
+
+use crate::page::escape;
+use crate::render::by_crate;
+
+/// Render check results as JUnit XML for CI test report UIs: one
+/// `<testsuite>` per crate and one `<testcase>` per result, with failures as
+/// `<failure>` and warnings as `<skipped>` carrying the message
+pub fn render_junit(results: &[CheckResult]) -> String {
+    let count = |status| results.iter().filter(|r| r.status == status).count();
+    let suites: String = by_crate(results)
+        .iter()
+        .map(|(name, results)| test_suite(name, results))
+        .collect();
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <testsuites name=\"sw-checklist\" tests=\"{}\" failures=\"{}\" skipped=\"{}\">\n{}</testsuites>\n",
+        results.len(),
+        count(CheckStatus::Fail),
+        count(CheckStatus::Warn),
+        suites
+    )
+}
+
+/// A `<testsuite>` for one crate with its failure and warning counts
+fn test_suite(name: &str, results: &[&CheckResult]) -> String {
+    let count = |status| results.iter().filter(|r| r.status == status).count();
+    let cases: String = results.iter().map(|r| test_case(name, r)).collect();
+    format!(
+        "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" skipped=\"{}\">\n{}  </testsuite>\n",
+        escape(name),
+        results.len(),
+        count(CheckStatus::Fail),
+        count(CheckStatus::Warn),
+        cases
+    )
+}
+
+/// A `<testcase>` named after the check (and location, so repeated findings
+/// stay distinct); passes and info keep their message as `<system-out>`
+fn test_case(suite: &str, result: &CheckResult) -> String {
+    let location = result.location.as_ref().map_or(String::new(), |l| {
+        format!(" ({}:{})", l.file.display(), l.line)
+    });
+    let name = escape(&format!("{}{}", result.check_title(), location));
+    let message = escape(&result.display_message());
+    let body = match result.status {
+        CheckStatus::Fail => format!(
+            "<failure message=\"{}\" type=\"{}\">{}</failure>",
+            message,
+            result.check_id(),
+            message
+        ),
+        CheckStatus::Warn => format!("<skipped message=\"{}\"/>", message),
+        CheckStatus::Pass | CheckStatus::Info => format!("<system-out>{}</system-out>", message),
+    };
+    format!(
+        "    <testcase classname=\"{}\" name=\"{}\">{}</testcase>\n",
+        escape(suite),
+        name,
+        body
+    )
+}
@@ -0,0 +1,17 @@
+//! Self-contained HTML, JUnit XML, SARIF, and SVG badge rendering for sw-checklist
+//!
+//! Renders a run as a single HTML file (inline CSS, no scripts) with a status
+//! summary and a collapsible section per crate, for sharing nightly results,
+//! as JUnit XML for CI test report UIs (Jenkins, GitLab), as SARIF for
+//! code scanning (GitHub), or as a shields-style status badge.
+
+mod badge;
+mod junit;
+mod page;
+mod render;
+mod sarif;
+
+pub use badge::{BadgeContent, render_badge};
+pub use junit::render_junit;
+pub use render::render_html;
+pub use sarif::render_sarif;
@@ -0,0 +1,58 @@
+//! Page shell, status badges, and escaping for the HTML report
+
+use checklist_result::{CheckResult, CheckStatus};
+
+const STYLE: &str = "body{font-family:system-ui,sans-serif;margin:2em;color:#222}\
+details{border:1px solid #ddd;border-radius:6px;margin:.5em 0;padding:.3em .8em}\
+summary{cursor:pointer;font-weight:600}\
+table{border-collapse:collapse;width:100%;margin:.5em 0}\
+td{border-top:1px solid #eee;padding:.3em .5em;vertical-align:top}\
+.badge{display:inline-block;min-width:3.5em;padding:.1em .4em;border-radius:4px;\
+color:#fff;font-size:.8em;font-weight:600;text-align:center}\
+.pass{background:#2e7d32}.fail{background:#c62828}.warn{background:#ef6c00}.info{background:#0277bd}";
+
+/// A complete HTML document around `body`, with the report's inline styles
+pub(crate) fn page(body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>sw-checklist report</title>\n<style>{}</style>\n</head>\n<body>\n\
+         <h1>sw-checklist report</h1>\n{}</body>\n</html>\n",
+        STYLE, body
+    )
+}
+
+/// Each status badge with its count across the run
+pub(crate) fn totals(results: &[CheckResult]) -> String {
+    [
+        CheckStatus::Pass,
+        CheckStatus::Fail,
+        CheckStatus::Warn,
+        CheckStatus::Info,
+    ]
+    .into_iter()
+    .map(|status| {
+        let count = results.iter().filter(|r| r.status == status).count();
+        format!("{} {}", badge(status), count)
+    })
+    .collect::<Vec<_>>()
+    .join(" ")
+}
+
+/// A colored status badge
+pub(crate) fn badge(status: CheckStatus) -> String {
+    let (class, word) = match status {
+        CheckStatus::Pass => ("pass", "PASS"),
+        CheckStatus::Fail => ("fail", "FAIL"),
+        CheckStatus::Warn => ("warn", "WARN"),
+        CheckStatus::Info => ("info", "INFO"),
+    };
+    format!("<span class=\"badge {}\">{}</span>", class, word)
+}
+
+/// Escape text for HTML/XML element content and attribute values
+pub(crate) fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
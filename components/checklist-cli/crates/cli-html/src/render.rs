@@ -0,0 +1,60 @@
+//! HTML report rendering
+
+use checklist_result::{CheckResult, CheckStatus};
+use std::collections::BTreeMap;
+
+use crate::page::{badge, escape, page, totals};
+
+/// Render check results as a self-contained HTML document: summary counts,
+/// then one collapsible section per crate (expanded when it has issues) with
+/// a status badge, the check, the message, and a link to the offending file
+pub fn render_html(results: &[CheckResult]) -> String {
+    let mut body = format!("<p>{}</p>\n", totals(results));
+    for (name, results) in &by_crate(results) {
+        body.push_str(&crate_section(name, results));
+    }
+    page(&body)
+}
+
+/// Results grouped by crate in name order (crate-less results under "other")
+pub(crate) fn by_crate(results: &[CheckResult]) -> BTreeMap<&str, Vec<&CheckResult>> {
+    let mut groups: BTreeMap<&str, Vec<&CheckResult>> = BTreeMap::new();
+    for result in results {
+        let subject = result.subject().unwrap_or("other");
+        groups.entry(subject).or_default().push(result);
+    }
+    groups
+}
+
+/// A `<details>` section for one crate, open when it has failures or warnings
+fn crate_section(name: &str, results: &[&CheckResult]) -> String {
+    let issues = results
+        .iter()
+        .filter(|r| matches!(r.status, CheckStatus::Fail | CheckStatus::Warn))
+        .count();
+    let open = if issues > 0 { " open" } else { "" };
+    let rows: String = results.iter().map(|r| result_row(r)).collect();
+    format!(
+        "<details{}>\n<summary>{} ({} checks, {} issues)</summary>\n<table>\n{}</table>\n</details>\n",
+        open,
+        escape(name),
+        results.len(),
+        issues,
+        rows
+    )
+}
+
+/// A table row: status badge, check title, message, and file link
+fn result_row(result: &CheckResult) -> String {
+    let location = result.location.as_ref().map_or(String::new(), |l| {
+        let file = escape(&l.file.display().to_string());
+        format!("<a href=\"{}\">{}:{}</a>", file, file, l.line)
+    });
+    format!(
+        "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+        badge(result.status),
+        escape(result.check_title()),
+        escape(&result.display_message()),
+        location
+    )
+}
@@ -0,0 +1,67 @@
+//! SARIF 2.1.0 report rendering
+
+use checklist_result::{CheckResult, CheckStatus};
+use serde_json::{Value, json};
+use std::path::Path;
+
+/// Where SARIF viewers link the tool
+const INFORMATION_URI: &str = "https://github.com/softwarewrighter/sw-checklist";
+
+/// Render failures and warnings as a SARIF 2.1.0 log for code scanning UIs:
+/// one rule per check ID, file paths relative to `root`
+pub fn render_sarif(results: &[CheckResult], root: &Path) -> String {
+    let issues: Vec<&CheckResult> = results
+        .iter()
+        .filter(|r| matches!(r.status, CheckStatus::Fail | CheckStatus::Warn))
+        .collect();
+    let log = json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": { "driver": {
+                "name": "sw-checklist",
+                "informationUri": INFORMATION_URI,
+                "rules": rules(&issues),
+            }},
+            "results": issues.iter().map(|r| sarif_result(r, root)).collect::<Vec<_>>(),
+        }],
+    });
+    format!("{:#}\n", log)
+}
+
+/// One rule per distinct check, in first-seen order
+fn rules(issues: &[&CheckResult]) -> Vec<Value> {
+    let mut rules: Vec<Value> = Vec::new();
+    for r in issues {
+        let rule = json!({ "id": r.check_id(), "name": r.check_title() });
+        if !rules.contains(&rule) {
+            rules.push(rule);
+        }
+    }
+    rules
+}
+
+/// A failure (level error) or warning with its location, if any
+fn sarif_result(result: &CheckResult, root: &Path) -> Value {
+    let level = match result.status {
+        CheckStatus::Fail => "error",
+        _ => "warning",
+    };
+    let locations: Vec<Value> = result
+        .location
+        .iter()
+        .map(|loc| {
+            let file = loc.file.strip_prefix(root).unwrap_or(&loc.file);
+            json!({ "physicalLocation": {
+                "artifactLocation": { "uri": file.display().to_string() },
+                "region": { "startLine": loc.line },
+            }})
+        })
+        .collect();
+    json!({
+        "ruleId": result.check_id(),
+        "level": level,
+        "message": { "text": format!("{}: {}", result.name, result.message) },
+        "locations": locations,
+    })
+}
@@ -0,0 +1,49 @@
+//! Single-document formats: HTML for sharing, JUnit XML for CI test report
+//! UIs, SARIF for code scanning, and Markdown
+
+use cli_github::render_report;
+use cli_html::{render_html, render_junit, render_sarif};
+use std::path::PathBuf;
+
+use crate::formatter::{Formatter, RunSummary};
+
+/// A self-contained HTML report with collapsible per-crate sections, printed
+/// as the summary like JSON
+pub struct HtmlFormatter;
+
+impl Formatter for HtmlFormatter {
+    fn summary(&self, run: &RunSummary) -> String {
+        render_html(run.results)
+    }
+}
+
+/// JUnit XML with a testcase per result, printed as the summary like JSON
+pub struct JunitFormatter;
+
+impl Formatter for JunitFormatter {
+    fn summary(&self, run: &RunSummary) -> String {
+        render_junit(run.results)
+    }
+}
+
+/// Failures and warnings as a SARIF 2.1.0 log with paths relative to `root`,
+/// printed as the summary like JSON
+pub struct SarifFormatter {
+    pub root: PathBuf,
+}
+
+impl Formatter for SarifFormatter {
+    fn summary(&self, run: &RunSummary) -> String {
+        render_sarif(run.results, &self.root)
+    }
+}
+
+/// The Markdown report `sw-checklist comment` posts, printed as the summary
+/// like JSON
+pub struct MarkdownFormatter;
+
+impl Formatter for MarkdownFormatter {
+    fn summary(&self, run: &RunSummary) -> String {
+        render_report(run.results, None)
+    }
+}
@@ -0,0 +1,103 @@
+//! Editor-oriented formats: quickfix lines and rustc-style code frames
+
+use annotate_snippets::{Level, Renderer, Snippet};
+use checklist_result::{CheckResult, CheckStatus, Location};
+use std::fs;
+use std::io::{self, IsTerminal};
+use std::path::{Path, PathBuf};
+
+use crate::formatter::{Formatter, RunSummary};
+use crate::text::{is_issue, result_line};
+
+/// Issues as `file:line:col: severity: message` for editor quickfix lists (no
+/// summary); the column is where the located line's code starts, or 1 when its
+/// source can't be read (relative paths are read from `root`)
+pub struct QuickfixFormatter {
+    pub root: PathBuf,
+}
+
+impl Formatter for QuickfixFormatter {
+    fn results(&self, run: &RunSummary) -> String {
+        let mut out = String::new();
+        for result in run.results.iter().filter(|r| is_issue(r.status)) {
+            let severity = match result.status {
+                CheckStatus::Fail => "error",
+                _ => "warning",
+            };
+            if let Some(loc) = &result.location {
+                let column = source_line(loc, &self.root)
+                    .map_or(1, |line| line.len() - line.trim_start().len() + 1);
+                out.push_str(&format!("{}:{}:{}: ", loc.file.display(), loc.line, column));
+            }
+            out.push_str(&format!(
+                "{}: {}: {}\n",
+                severity, result.name, result.message
+            ));
+        }
+        out
+    }
+
+    fn summary(&self, _run: &RunSummary) -> String {
+        String::new()
+    }
+}
+
+/// Issues with the offending source line underlined (relative paths are read
+/// from `root`)
+pub struct AnnotatedFormatter {
+    pub root: PathBuf,
+}
+
+impl Formatter for AnnotatedFormatter {
+    fn results(&self, run: &RunSummary) -> String {
+        run.results
+            .iter()
+            .filter(|r| is_issue(r.status))
+            .map(|r| code_frame(r, &self.root))
+            .collect()
+    }
+}
+
+/// An issue as a code frame (plain line if unlocated or unreadable)
+fn code_frame(result: &CheckResult, root: &Path) -> String {
+    let Some(loc) = &result.location else {
+        return result_line(result);
+    };
+    let Some(source) = source_line(loc, root) else {
+        return result_line(result);
+    };
+    let source = source.as_str();
+    let level = match result.status {
+        CheckStatus::Fail => Level::Error,
+        _ => Level::Warning,
+    };
+    let origin = loc.file.display().to_string();
+    let indent = source.len() - source.trim_start().len();
+    let message = level.title(&result.name).snippet(
+        Snippet::source(source)
+            .line_start(loc.line)
+            .origin(&origin)
+            .annotation(level.span(indent..source.len()).label(&result.message)),
+    );
+    format!("{}\n\n", renderer().render(message))
+}
+
+/// The located line, reading the file as given or else relative to `root`
+fn source_line(loc: &Location, root: &Path) -> Option<String> {
+    let content = fs::read_to_string(&loc.file)
+        .or_else(|_| fs::read_to_string(root.join(&loc.file)))
+        .ok()?;
+    content
+        .lines()
+        .nth(loc.line.checked_sub(1)?)
+        .map(str::to_string)
+}
+
+/// Colored output on a terminal, plain when piped or redirected
+fn renderer() -> Renderer {
+    if io::stdout().is_terminal() {
+        Renderer::styled()
+    } else {
+        Renderer::plain()
+    }
+}
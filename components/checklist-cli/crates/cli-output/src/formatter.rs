@@ -0,0 +1,90 @@
+//! Output formatter trait and printing
+
+use checklist_config::{Config, is_suppressed};
+use checklist_result::{CheckResult, CheckStatus, roll_up};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::registry::formatter_for;
+
+/// A check run's results with counts by status
+#[derive(Debug, Clone, Copy)]
+pub struct RunSummary<'a> {
+    pub results: &'a [CheckResult],
+    pub passed: usize,
+    pub failed: usize,
+    pub warnings: usize,
+    pub info: usize,
+    /// Info results that are suppressed issues
+    pub suppressed: usize,
+}
+
+impl<'a> RunSummary<'a> {
+    /// Count the results by status
+    pub fn new(results: &'a [CheckResult]) -> Self {
+        let count = |status| results.iter().filter(|r| r.status == status).count();
+        Self {
+            results,
+            passed: count(CheckStatus::Pass),
+            failed: count(CheckStatus::Fail),
+            warnings: count(CheckStatus::Warn),
+            info: count(CheckStatus::Info),
+            suppressed: results.iter().filter(|r| is_suppressed(r)).count(),
+        }
+    }
+}
+
+/// Renders a check run for output; implement this to add a format
+pub trait Formatter {
+    /// Per-check output for the run (empty when there is nothing to show, and
+    /// by default for formats that render the whole run as the summary)
+    fn results(&self, _run: &RunSummary) -> String {
+        String::new()
+    }
+
+    /// Closing summary for the run
+    fn summary(&self, run: &RunSummary) -> String {
+        let suppressed = match run.suppressed {
+            0 => String::new(),
+            n => format!(" ({} suppressed)", n),
+        };
+        format!(
+            "Summary: {} passed, {} failed, {} warnings, {} info{}\n",
+            run.passed, run.failed, run.warnings, run.info, suppressed
+        )
+    }
+}
+
+/// Print per-check results with the configured formatter (text: all in
+/// verbose mode, issues only otherwise; quickfix/annotated: issues only).
+/// Outside verbose mode, similar findings are rolled up when configured.
+pub fn print_results(results: &[CheckResult], config: &Config) {
+    let rolled = match config.rollup() {
+        Some(max_similar) if !config.verbose() => roll_up(results, max_similar),
+        _ => results.to_vec(),
+    };
+    print!(
+        "{}",
+        formatter_for(config).results(&RunSummary::new(&rolled))
+    );
+}
+
+/// Print the run summary with the configured formatter (omitted for quickfix output)
+pub fn print_summary(results: &[CheckResult], config: &Config) {
+    print!(
+        "{}",
+        formatter_for(config).summary(&RunSummary::new(results))
+    );
+}
+
+/// Write the run summary to `output` instead of stdout (the whole report for
+/// JSON and HTML, which render as one document)
+pub fn write_summary(results: &[CheckResult], config: &Config, output: &Path) -> io::Result<()> {
+    fs::write(
+        output,
+        formatter_for(config).summary(&RunSummary::new(results)),
+    )?;
+    println!("Wrote report to {}", output.display());
+    Ok(())
+}
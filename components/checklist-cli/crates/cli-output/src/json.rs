@@ -0,0 +1,47 @@
+//! Machine-readable JSON format for CI systems and agents
+
+use checklist_result::{CheckResult, CheckStatus, Disposition};
+use serde_json::{Value, json};
+
+use crate::formatter::{Formatter, RunSummary};
+
+/// Every result and the counts as one JSON document, printed as the summary
+/// so a run emits exactly one document
+pub struct JsonFormatter;
+
+impl Formatter for JsonFormatter {
+    fn summary(&self, run: &RunSummary) -> String {
+        let document = json!({
+            "results": run.results.iter().map(result_json).collect::<Vec<_>>(),
+            "summary": {
+                "passed": run.passed,
+                "failed": run.failed,
+                "warnings": run.warnings,
+                "info": run.info,
+                "suppressed": run.suppressed,
+            },
+        });
+        format!("{:#}\n", document)
+    }
+}
+
+/// A result with its stable ID, check title, crate, and location split out
+fn result_json(result: &CheckResult) -> Value {
+    let status = match result.status {
+        CheckStatus::Pass => "pass",
+        CheckStatus::Fail => "fail",
+        CheckStatus::Warn => "warn",
+        CheckStatus::Info => "info",
+    };
+    json!({
+        "id": result.check_id(),
+        "check": result.check_title(),
+        "name": result.name,
+        "status": status,
+        "message": result.message,
+        "disposition": result.disposition.map(Disposition::label),
+        "crate": result.subject(),
+        "file": result.location.as_ref().map(|l| l.file.display().to_string()),
+        "line": result.location.as_ref().map(|l| l.line),
+    })
+}
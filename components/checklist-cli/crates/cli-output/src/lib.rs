@@ -1,6 +1,19 @@
 //! Output formatting for sw-checklist results
+//!
+//! Each output format is a [`Formatter`] rendering a [`RunSummary`], listed by
+//! name in a [`FormatterRegistry`]; [`formatter_for`] picks the one selected
+//! by `Config`.
 
-mod format;
-mod print;
+mod document;
+mod editor;
+mod formatter;
+mod json;
+mod registry;
+mod text;
 
-pub use print::{print_results, print_summary};
+pub use document::{HtmlFormatter, JunitFormatter, MarkdownFormatter, SarifFormatter};
+pub use editor::{AnnotatedFormatter, QuickfixFormatter};
+pub use formatter::{Formatter, RunSummary, print_results, print_summary, write_summary};
+pub use json::JsonFormatter;
+pub use registry::{FormatterRegistry, formatter_for};
+pub use text::TextFormatter;
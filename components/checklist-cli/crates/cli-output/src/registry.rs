@@ -0,0 +1,76 @@
+//! Formatters by `--format` name
+
+use checklist_config::{Config, FORMAT_NAMES};
+use checklist_i18n::Catalog;
+use std::collections::BTreeMap;
+
+use crate::document::{HtmlFormatter, JunitFormatter, MarkdownFormatter, SarifFormatter};
+use crate::editor::{AnnotatedFormatter, QuickfixFormatter};
+use crate::formatter::Formatter;
+use crate::json::JsonFormatter;
+use crate::text::TextFormatter;
+
+/// Builds a formatter from the run's config
+type FormatterFactory = fn(&Config) -> Box<dyn Formatter>;
+
+/// The built-in formatters keyed by `--format` name
+pub struct FormatterRegistry {
+    factories: BTreeMap<String, FormatterFactory>,
+}
+
+impl Default for FormatterRegistry {
+    fn default() -> Self {
+        let mut registry = Self {
+            factories: BTreeMap::new(),
+        };
+        registry.register("text", text);
+        registry.register("quickfix", |config| {
+            let root = config.project_root().to_path_buf();
+            Box::new(QuickfixFormatter { root })
+        });
+        registry.register("annotated", |config| {
+            let root = config.project_root().to_path_buf();
+            Box::new(AnnotatedFormatter { root })
+        });
+        registry.register("json", |_| Box::new(JsonFormatter));
+        registry.register("html", |_| Box::new(HtmlFormatter));
+        registry.register("junit", |_| Box::new(JunitFormatter));
+        registry.register("sarif", |config| {
+            let root = config.project_root().to_path_buf();
+            Box::new(SarifFormatter { root })
+        });
+        registry.register("markdown", |_| Box::new(MarkdownFormatter));
+        registry
+    }
+}
+
+impl FormatterRegistry {
+    fn register(&mut self, name: &str, factory: FormatterFactory) {
+        self.factories.insert(name.to_string(), factory);
+    }
+
+    /// The formatter registered under `name`, built for `config`
+    pub fn formatter(&self, name: &str, config: &Config) -> Option<Box<dyn Formatter>> {
+        self.factories.get(name).map(|factory| factory(config))
+    }
+}
+
+/// The built-in formatter for the configured output format
+pub fn formatter_for(config: &Config) -> Box<dyn Formatter> {
+    let name = FORMAT_NAMES
+        .iter()
+        .find(|(_, format)| *format == config.format())
+        .map_or("text", |(name, _)| *name);
+    FormatterRegistry::default()
+        .formatter(name, config)
+        .unwrap_or_else(|| text(config))
+}
+
+fn text(config: &Config) -> Box<dyn Formatter> {
+    Box::new(TextFormatter {
+        verbose: config.verbose(),
+        max_per_check: config.max_per_check(),
+        catalog: Catalog::load(config.file().lang.as_deref(), config.project_root())
+            .unwrap_or_default(),
+    })
+}
@@ -0,0 +1,115 @@
+//! Human-readable text format
+
+use checklist_i18n::Catalog;
+use checklist_result::{CheckResult, CheckStatus};
+
+use crate::formatter::{Formatter, RunSummary};
+
+/// Every result in verbose mode, otherwise issues grouped by check with at
+/// most `max_per_check` shown per check, in the catalog's language
+pub struct TextFormatter {
+    pub verbose: bool,
+    pub max_per_check: usize,
+    pub catalog: Catalog,
+}
+
+impl Formatter for TextFormatter {
+    fn results(&self, run: &RunSummary) -> String {
+        if self.verbose {
+            let line = |r| localized_line(r, &self.catalog);
+            return run.results.iter().map(line).collect();
+        }
+        let issues: Vec<_> = run.results.iter().filter(|r| is_issue(r.status)).collect();
+        let mut titles: Vec<&str> = Vec::new();
+        for r in &issues {
+            if !titles.contains(&r.check_title()) {
+                titles.push(r.check_title());
+            }
+        }
+        let mut out = String::new();
+        let mut truncated = false;
+        for title in titles {
+            let group: Vec<_> = issues.iter().filter(|r| r.check_title() == title).collect();
+            truncated |= self.check_group(&mut out, &group);
+        }
+        if truncated {
+            let hint = "Run with -v/--verbose for all findings";
+            out += &self.catalog.text("verbose_hint", hint, &[]);
+            out.push('\n');
+        }
+        out
+    }
+
+    fn summary(&self, run: &RunSummary) -> String {
+        let blank = if self.verbose { "\n" } else { "" };
+        let english = "Summary: {passed} passed, {failed} failed, {warnings} warnings, {info} info";
+        let counts = [
+            ("passed", run.passed.to_string()),
+            ("failed", run.failed.to_string()),
+            ("warnings", run.warnings.to_string()),
+            ("info", run.info.to_string()),
+        ];
+        let suppressed = match run.suppressed {
+            0 => String::new(),
+            n => {
+                let count = [("count", n.to_string())];
+                self.catalog
+                    .text("suppressed", " ({count} suppressed)", &count)
+            }
+        };
+        format!(
+            "{}{}{}\n",
+            blank,
+            self.catalog.text("summary", english, &counts),
+            suppressed
+        )
+    }
+}
+
+impl TextFormatter {
+    /// Write up to `max_per_check` issues of one check; returns true if any were cut
+    fn check_group(&self, out: &mut String, group: &[&&CheckResult]) -> bool {
+        let shown = group.iter().take(self.max_per_check);
+        shown.for_each(|r| out.push_str(&localized_line(r, &self.catalog)));
+        if group.len() <= self.max_per_check {
+            return false;
+        }
+        let args = [
+            ("count", (group.len() - self.max_per_check).to_string()),
+            ("check", self.catalog.title(group[0]).to_string()),
+        ];
+        let more = self
+            .catalog
+            .text("more", "... and {count} more {check} findings", &args);
+        out.push_str(&format!("{}\n", more));
+        true
+    }
+}
+
+/// A result as a `[STATUS] name: message` line
+pub(crate) fn result_line(result: &CheckResult) -> String {
+    localized_line(result, &Catalog::default())
+}
+
+/// A result line with the status word, title, and message from `catalog`
+fn localized_line(result: &CheckResult, catalog: &Catalog) -> String {
+    let shown = catalog.localize(result);
+    let color = match result.status {
+        CheckStatus::Pass => "32",
+        CheckStatus::Fail => "31",
+        CheckStatus::Warn => "33",
+        CheckStatus::Info => "36",
+    };
+    let status = catalog.status(result.status);
+    format!(
+        "[\x1b[{}m{}\x1b[0m] {}: {}\n",
+        color,
+        status,
+        shown.name,
+        shown.display_message()
+    )
+}
+
+pub(crate) fn is_issue(status: CheckStatus) -> bool {
+    matches!(status, CheckStatus::Fail | CheckStatus::Warn)
+}
@@ -0,0 +1,30 @@
+use checklist_config::{ConfigBuilder, FORMAT_NAMES, OutputFormat};
+use checklist_testkit::sample_results;
+use cli_output::{FormatterRegistry, RunSummary, formatter_for};
+
+#[test]
+fn every_format_name_is_registered() {
+    let config = ConfigBuilder::new().build();
+    let registry = FormatterRegistry::default();
+    for (name, _) in FORMAT_NAMES {
+        assert!(registry.formatter(name, &config).is_some(), "{}", name);
+    }
+}
+
+#[test]
+fn unknown_names_have_no_formatter() {
+    let config = ConfigBuilder::new().build();
+    assert!(
+        FormatterRegistry::default()
+            .formatter("count", &config)
+            .is_none()
+    );
+}
+
+#[test]
+fn formatter_for_uses_the_configured_format() {
+    let config = ConfigBuilder::new().format(OutputFormat::Json).build();
+    let results = sample_results();
+    let summary = formatter_for(&config).summary(&RunSummary::new(&results));
+    assert!(summary.starts_with("{\n  \"results\""), "{}", summary);
+}
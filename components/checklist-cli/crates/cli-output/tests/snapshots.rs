@@ -0,0 +1,112 @@
+//! Golden-file snapshots of each output format for a fixed run
+//!
+//! Formatting changes show up as snapshot diffs; review and accept them with
+//! `cargo insta review` so CI parsers of these formats never break silently.
+
+use checklist_i18n::Catalog;
+use checklist_tempdir::TempWorkspace;
+use checklist_testkit::sample_results;
+use cli_output::{
+    AnnotatedFormatter, Formatter, HtmlFormatter, JsonFormatter, JunitFormatter, MarkdownFormatter,
+    QuickfixFormatter, RunSummary, SarifFormatter, TextFormatter,
+};
+
+/// Results then summary, as the runner prints them, without ANSI colors
+fn render(formatter: &dyn Formatter) -> String {
+    let results = sample_results();
+    let run = RunSummary::new(&results);
+    let out = format!("{}{}", formatter.results(&run), formatter.summary(&run));
+    strip_ansi(&out)
+}
+
+fn text(verbose: bool, max_per_check: usize) -> TextFormatter {
+    TextFormatter {
+        verbose,
+        max_per_check,
+        catalog: Catalog::default(),
+    }
+}
+
+/// A project root holding the sample results' located source lines
+fn sources() -> TempWorkspace {
+    let workspace = TempWorkspace::new("snapshots").unwrap();
+    let src = workspace.path().join("crates/demo/src");
+    std::fs::create_dir_all(&src).unwrap();
+    let main = format!("{}fn run() {{\n", "\n".repeat(11));
+    let lib = format!("{}    pub fn parse(input: &str) {{\n", "\n".repeat(39));
+    std::fs::write(src.join("main.rs"), main).unwrap();
+    std::fs::write(src.join("lib.rs"), lib).unwrap();
+    workspace
+}
+
+fn strip_ansi(text: &str) -> String {
+    let mut out = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find('\x1b') {
+        out.push_str(&rest[..start]);
+        let end = rest[start..]
+            .find('m')
+            .map_or(rest.len(), |i| start + i + 1);
+        rest = &rest[end..];
+    }
+    out.push_str(rest);
+    out
+}
+
+#[test]
+fn text_issues() {
+    insta::assert_snapshot!(render(&text(false, 5)));
+}
+
+#[test]
+fn text_truncated() {
+    insta::assert_snapshot!(render(&text(false, 1)));
+}
+
+#[test]
+fn text_verbose() {
+    insta::assert_snapshot!(render(&text(true, 5)));
+}
+
+#[test]
+fn quickfix() {
+    let workspace = sources();
+    insta::assert_snapshot!(render(&QuickfixFormatter {
+        root: workspace.path().to_path_buf(),
+    }));
+}
+
+#[test]
+fn annotated() {
+    let workspace = sources();
+    insta::assert_snapshot!(render(&AnnotatedFormatter {
+        root: workspace.path().to_path_buf(),
+    }));
+}
+
+#[test]
+fn json() {
+    insta::assert_snapshot!(render(&JsonFormatter));
+}
+
+#[test]
+fn html() {
+    insta::assert_snapshot!(render(&HtmlFormatter));
+}
+
+#[test]
+fn junit() {
+    insta::assert_snapshot!(render(&JunitFormatter));
+}
+
+#[test]
+fn sarif() {
+    insta::assert_snapshot!(render(&SarifFormatter {
+        root: "crates".into(),
+    }));
+}
+
+#[test]
+fn markdown() {
+    insta::assert_snapshot!(render(&MarkdownFormatter));
+}
@@ -0,0 +1,30 @@
+use checklist_config::{ConfigBuilder, FileConfig, suppress};
+use checklist_result::CheckResult;
+use cli_output::{Formatter, RunSummary};
+
+struct Plain;
+
+impl Formatter for Plain {}
+
+#[test]
+fn suppressed_issues_count_as_info_and_are_noted() {
+    let mut file = FileConfig::default();
+    file.suppress.insert("no-print".into(), vec!["demo".into()]);
+    let config = ConfigBuilder::new().file_config(file).build();
+    let results: Vec<CheckResult> = [
+        CheckResult::pass("Rust Edition [demo]", "2024"),
+        CheckResult::warn("No Print [demo]", "println! in lib.rs"),
+        CheckResult::fail("Function LOC [demo]", "60 lines"),
+        CheckResult::info("Logging Setup [demo]", "No subscriber"),
+    ]
+    .into_iter()
+    .map(|r| suppress(&config, r, None))
+    .collect();
+    let run = RunSummary::new(&results);
+    assert_eq!((run.passed, run.failed, run.warnings), (1, 1, 0));
+    assert_eq!((run.info, run.suppressed), (2, 1));
+    assert_eq!(
+        Plain.summary(&run),
+        "Summary: 1 passed, 1 failed, 0 warnings, 2 info (1 suppressed)\n"
+    );
+}
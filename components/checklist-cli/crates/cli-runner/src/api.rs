@@ -0,0 +1,18 @@
+//! Public API diff subcommand
+
+use anyhow::Result;
+use api_diff::{crate_versions, diff_api, public_api, render_diff};
+use checklist_config::{Config, ConfigBuilder, load_file_config};
+use cli_github::BaseWorktree;
+
+/// Print the public items added, removed, or changed since `against`
+pub fn api_diff(config: &Config, against: &str) -> Result<i32> {
+    let worktree = BaseWorktree::checkout(config.project_root(), against)?;
+    let base_config = ConfigBuilder::new()
+        .project_path(worktree.project_dir().to_path_buf())
+        .file_config(load_file_config(worktree.project_dir())?)
+        .build();
+    let diff = diff_api(&public_api(&base_config), &public_api(config));
+    print!("{}", render_diff(&diff, against, &crate_versions(config)));
+    Ok(0)
+}
@@ -0,0 +1,60 @@
+//! Checks against prebuilt artifacts
+
+use anyhow::{Result, bail};
+use checklist_config::Config;
+use checklist_result::{CheckResult, exit_code};
+use checklist_tempdir::{executables, is_archive, unpack};
+use cli_output::{print_results, print_summary};
+use handler_clap::check_artifact_binary;
+use handler_wasm::check_dist_dir;
+use std::path::Path;
+
+/// Check a prebuilt binary (help/version/metadata), a built Web UI dist
+/// directory, or a release archive holding either, without the source tree,
+/// and return the exit code
+pub fn artifact(config: &Config, path: &Path) -> Result<i32> {
+    let results = if is_archive(path) {
+        unpacked_results(config, path)?
+    } else {
+        artifact_results(config, path)?
+    };
+    print_results(&results, config);
+    print_summary(&results, config);
+
+    let statuses = results.iter().map(|r| r.status);
+    Ok(exit_code(statuses, config.file().strict))
+}
+
+fn artifact_results(config: &Config, path: &Path) -> Result<Vec<CheckResult>> {
+    if path.is_file() {
+        return Ok(check_artifact_binary(path, config.verbose()));
+    }
+    if path.join("index.html").is_file() {
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "dist".to_string());
+        return Ok(check_dist_dir(path, &name));
+    }
+    bail!(
+        "{} is neither a binary nor a dist directory with an index.html",
+        path.display()
+    )
+}
+
+/// Unpack a release archive into a temp workspace and check the dist or the
+/// binaries at its top level
+fn unpacked_results(config: &Config, archive: &Path) -> Result<Vec<CheckResult>> {
+    let (_workspace, root) = unpack(archive)?;
+    if root.join("index.html").is_file() {
+        return artifact_results(config, &root);
+    }
+    let results: Vec<CheckResult> = executables(&root)
+        .iter()
+        .flat_map(|binary| check_artifact_binary(binary, config.verbose()))
+        .collect();
+    if results.is_empty() {
+        bail!("{} contains no binary or dist directory", archive.display());
+    }
+    Ok(results)
+}
@@ -0,0 +1,134 @@
+//! Subcommand runners
+
+use anyhow::{Context, Result, bail};
+use checklist_config::{Config, ConfigBuilder, OutputFormat, load_file_config};
+use checklist_engine::run_checks;
+use checklist_result::{CheckResult, EXIT_ERROR, exit_code};
+use cli_github::{BaseDiff, BaseWorktree, PrTarget, diff_results, render_report, upsert_comment};
+use cli_html::{BadgeContent, render_badge};
+use discovery_cargo::find_projects;
+use engine_baseline::{BASELINE_FILE_NAME, write_baseline};
+use handler_ratchet::{RATCHET_FILE_NAME, record_ratchet};
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+
+/// Run all checks and write an SVG badge summarizing the results
+pub fn badge(config: &Config, output: &Path, content: BadgeContent) -> Result<i32> {
+    let results = run_checks(config)?;
+    fs::write(output, render_badge(&results, content))
+        .with_context(|| format!("Failed to write badge to {}", output.display()))?;
+    println!("Wrote badge to {}", output.display());
+    Ok(0)
+}
+
+/// Run all checks and post the Markdown report as a sticky PR comment
+pub fn comment(config: &Config, target: &PrTarget, base_ref: Option<&str>) -> Result<i32> {
+    let results = run_checks(config)?;
+    let diff = base_ref
+        .map(|base_ref| base_diff(config, base_ref, &results))
+        .transpose()?;
+    upsert_comment(target, &render_report(&results, diff.as_ref()))?;
+    println!(
+        "Posted sw-checklist report to {}#{}",
+        target.repo, target.pr
+    );
+
+    let statuses = results.iter().map(|r| r.status);
+    Ok(exit_code(statuses, config.file().strict))
+}
+
+fn base_diff(config: &Config, base_ref: &str, head: &[CheckResult]) -> Result<BaseDiff> {
+    let worktree = BaseWorktree::checkout(config.project_root(), base_ref)?;
+    let base_config = ConfigBuilder::new()
+        .project_path(worktree.project_dir().to_path_buf())
+        .file_config(load_file_config(worktree.project_dir())?)
+        .build();
+    let base = run_checks(&base_config)?;
+    Ok(diff_results(
+        base_ref,
+        config.project_root(),
+        head,
+        worktree.project_dir(),
+        &base,
+    ))
+}
+
+/// Run all checks and record the current failures and warnings as the baseline
+pub fn baseline(config: &Config) -> Result<i32> {
+    let count = write_baseline(config.project_root(), &run_checks(config)?)?;
+    println!(
+        "Recorded {} finding(s) in {}",
+        count,
+        config.project_root().join(BASELINE_FILE_NAME).display()
+    );
+    Ok(0)
+}
+
+/// Record the project's current modularity metrics as the ratchet file
+pub fn ratchet(config: &Config) -> Result<i32> {
+    let count = record_ratchet(config)?;
+    println!(
+        "Recorded metrics for {} crate(s) in {}",
+        count,
+        config.project_root().join(RATCHET_FILE_NAME).display()
+    );
+    Ok(0)
+}
+
+/// Run `check` on each project directly under `root`, then print an aggregate
+/// summary and return the most severe project exit code
+///
+/// Project headers and the aggregate go to stderr for machine-readable formats.
+/// A project whose check errors is reported on stderr and counted as an error;
+/// the remaining projects are still checked.
+pub fn recursive(
+    root: &Path,
+    format: OutputFormat,
+    check: impl Fn(&Path) -> Result<i32>,
+) -> Result<i32> {
+    let projects = find_projects(root);
+    if projects.is_empty() {
+        bail!("No git repos or Cargo.toml projects under {:?}", root);
+    }
+    let mut out: Box<dyn Write> = match format {
+        OutputFormat::Text | OutputFormat::Annotated => Box::new(io::stdout()),
+        _ => Box::new(io::stderr()),
+    };
+    let mut codes = Vec::new();
+    for project in &projects {
+        let name = project.strip_prefix(root).unwrap_or(project).display();
+        writeln!(out, "\n=== Project: {} ===", name)?;
+        out.flush()?;
+        let code = check(project)
+            .inspect_err(|e| eprintln!("Error: {:?}", e))
+            .unwrap_or(EXIT_ERROR);
+        codes.push((name.to_string(), code));
+    }
+    print_aggregate(&mut out, &codes)
+}
+
+/// Print pass/warn/fail project counts, naming the failures; exit codes rank
+/// 0 (pass) < 2 (warnings) < 1 (failures) < 3 (errors)
+fn print_aggregate(out: &mut dyn Write, codes: &[(String, i32)]) -> Result<i32> {
+    let severity = |code: i32| [0, 2, 1].iter().position(|&c| c == code).unwrap_or(3);
+    let count = |code: i32| codes.iter().filter(|(_, c)| *c == code).count();
+    let failed: Vec<&str> = codes
+        .iter()
+        .filter(|(_, code)| severity(*code) > 1)
+        .map(|(name, _)| name.as_str())
+        .collect();
+    writeln!(out, "\n=== All projects ({}) ===", codes.len())?;
+    let summary = format!("{} passed, {} with warnings", count(0), count(2));
+    if failed.is_empty() {
+        writeln!(out, "{}, 0 failed", summary)?;
+    } else {
+        let names = failed.join(", ");
+        writeln!(out, "{}, {} failed: {}", summary, failed.len(), names)?;
+    }
+    let worst = codes
+        .iter()
+        .map(|(_, code)| *code)
+        .max_by_key(|&code| severity(code));
+    Ok(worst.unwrap_or(0))
+}
@@ -1,6 +1,15 @@
 //! CLI runner for sw-checklist
 
+mod api;
+mod artifact;
+mod commands;
+mod plan;
 mod runner;
-mod setup;
+mod subcommand;
 
+pub use api::api_diff;
+pub use artifact::artifact;
+pub use commands::{badge, baseline, comment, ratchet, recursive};
+pub use plan::dry_run;
 pub use runner::run;
+pub use subcommand::{config_builder, file_config, run_command};
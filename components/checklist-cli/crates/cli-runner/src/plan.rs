@@ -0,0 +1,100 @@
+//! Dry-run check plan
+
+use anyhow::Result;
+use checklist_config::Config;
+use checklist_engine::{
+    CheckContext, Handler, create_handlers, create_project_handlers, resolve_crate, skip_reason,
+    validate_selection,
+};
+use checklist_result::EXIT_ERROR;
+use discovery_cargo::find_cargo_tomls;
+use std::path::Path;
+
+use crate::runner::select_workspaces;
+
+/// Print the crates that would be checked, their detected types, and which
+/// handlers would run or be skipped and why, without running any checks
+pub fn dry_run(config: &Config, workspace: Option<&str>) -> Result<i32> {
+    validate_selection(config)?;
+    let cargo_tomls = find_cargo_tomls(config.project_root(), &config.file().exclude);
+    if cargo_tomls.is_empty() {
+        println!("No Cargo.toml files found in {:?}", config.project_root());
+        return Ok(EXIT_ERROR);
+    }
+    let handlers = create_handlers();
+    let workspaces = select_workspaces(config, &cargo_tomls, workspace)?;
+    for ws in &workspaces {
+        println!("=== Workspace: {} ===", ws.name);
+        for cargo_path in &ws.cargo_tomls {
+            print_crate_plan(config, cargo_path, &handlers)?;
+        }
+    }
+    print_project_plan(config);
+    let crates: usize = workspaces.iter().map(|ws| ws.cargo_tomls.len()).sum();
+    println!(
+        "Dry run: {} manifests in {} workspace(s); no checks were run",
+        crates,
+        workspaces.len()
+    );
+    Ok(0)
+}
+
+fn print_project_plan(config: &Config) {
+    let project: Vec<&str> = create_project_handlers()
+        .iter()
+        .map(|h| h.name())
+        .filter(|name| config.file().selects(name))
+        .collect();
+    println!(
+        "\nProject handlers (once, over all workspaces): {}",
+        project.join(", ")
+    );
+}
+
+fn print_crate_plan(
+    config: &Config,
+    cargo_path: &Path,
+    handlers: &[Box<dyn Handler>],
+) -> Result<()> {
+    let krate = resolve_crate(config, cargo_path)?;
+    let ctx = CheckContext {
+        config,
+        crate_dir: cargo_path.parent().unwrap_or(Path::new(".")),
+        crate_name: &krate.name,
+        crate_type: krate.crate_type,
+        cargo_toml: &krate.cargo_toml,
+    };
+    let (run, skipped) = split_by_skip_reason(&ctx, handlers);
+    println!(
+        "{} ({:?}) {}",
+        krate.name,
+        krate.crate_type,
+        cargo_path.display()
+    );
+    println!("  run:  {}", run.join(", "));
+    for (reason, names) in skipped {
+        println!("  skip: {} ({})", names.join(", "), reason);
+    }
+    Ok(())
+}
+
+/// The handlers that would run for the crate, and those that would be
+/// skipped grouped by reason (in handler order)
+fn split_by_skip_reason<'h>(
+    ctx: &CheckContext,
+    handlers: &'h [Box<dyn Handler>],
+) -> (Vec<&'h str>, Vec<(String, Vec<&'h str>)>) {
+    let mut run = Vec::new();
+    let mut skipped: Vec<(String, Vec<&str>)> = Vec::new();
+    for handler in handlers {
+        let Some(reason) = skip_reason(ctx, handler.as_ref()) else {
+            run.push(handler.name());
+            continue;
+        };
+        match skipped.iter_mut().find(|(r, _)| *r == reason) {
+            Some((_, names)) => names.push(handler.name()),
+            None => skipped.push((reason, vec![handler.name()])),
+        }
+    }
+    (run, skipped)
+}
@@ -1,79 +1,121 @@
 //! Main runner logic
 
-use anyhow::Result;
-use checklist_config::Config;
-use checklist_result::CheckResult;
-use discovery_cargo::find_cargo_tomls;
-use discovery_crate::detect_crate_type;
-use handler_trait::{CheckContext, Handler};
-use std::fs;
-use std::path::Path;
+use anyhow::{Result, bail};
+use checklist_config::{Config, DOCUMENT_FORMATS, OutputFormat};
+use checklist_engine::{
+    Progress, check_crates_with, check_project_level, check_project_with, validate_selection,
+};
+use checklist_result::{CheckResult, EXIT_ERROR, dedup_results, exit_code};
+use discovery_cargo::{Workspace, find_cargo_tomls, group_workspaces};
+use std::path::{Path, PathBuf};
 
-use crate::setup::{create_handlers, extract_crate_name};
-use cli_output::{print_results, print_summary};
+use cli_output::{print_results, print_summary, write_summary};
 
-/// Run all checks and return exit code
-pub fn run(config: &Config) -> Result<i32> {
-    let cargo_tomls = find_cargo_tomls(config.project_root());
+/// Run all checks and return exit code, limited to one workspace when
+/// `workspace` names it (by path relative to the project root or directory
+/// name); with `output`, the summary (or JSON/HTML report) goes to that file
+pub fn run(config: &Config, workspace: Option<&str>, output: Option<&Path>) -> Result<i32> {
+    validate_selection(config)?;
+    let cargo_tomls = find_cargo_tomls(config.project_root(), &config.file().exclude);
 
     if cargo_tomls.is_empty() {
         println!("No Cargo.toml files found in {:?}", config.project_root());
-        return Ok(1);
+        return Ok(EXIT_ERROR);
     }
 
-    let results = check_all_crates(config, &cargo_tomls)?;
-    print_results(&results, config);
-    if config.verbose() {
-        println!();
+    let workspaces = select_workspaces(config, &cargo_tomls, workspace)?;
+    let results = if workspaces.len() > 1 {
+        check_workspaces(config, &workspaces, &cargo_tomls)?
+    } else {
+        let results =
+            check_project_with(config, &workspaces[0].cargo_tomls, &mut reporter(config))?;
+        print_results(&results, config);
+        results
+    };
+    match output {
+        Some(path) => write_summary(&results, config, path)?,
+        None => print_summary(&results, config),
     }
-    print_summary(&results);
-
-    let failed = results.iter().filter(|r| !r.status.passed()).count();
-    Ok(if failed > 0 { 1 } else { 0 })
+    let statuses = results.iter().map(|r| r.status);
+    Ok(exit_code(statuses, config.file().strict))
 }
 
-fn check_all_crates(
+/// Group manifests into workspaces, keeping only the one named by `workspace`
+pub(crate) fn select_workspaces(
     config: &Config,
-    cargo_tomls: &[std::path::PathBuf],
-) -> Result<Vec<CheckResult>> {
-    let handlers = create_handlers();
-    let mut results = Vec::new();
-    for cargo_path in cargo_tomls {
-        results.extend(check_crate(config, cargo_path, &handlers)?);
+    cargo_tomls: &[PathBuf],
+    workspace: Option<&str>,
+) -> Result<Vec<Workspace>> {
+    let mut workspaces = group_workspaces(cargo_tomls, config.project_root());
+    if let Some(name) = workspace {
+        workspaces.retain(|ws| ws.name == name || ws.name.rsplit('/').next() == Some(name));
+        if workspaces.is_empty() {
+            bail!(
+                "No workspace named '{}' found in {:?}",
+                name,
+                config.project_root()
+            );
+        }
     }
-    Ok(results)
+    Ok(workspaces)
 }
 
-fn check_crate(
+/// Check each workspace's crates as their own sub-project with its own
+/// summary, then run the project-level checks once over every manifest,
+/// returning the combined results (findings shared across workspaces are
+/// reported once)
+fn check_workspaces(
     config: &Config,
-    cargo_path: &Path,
-    handlers: &[Box<dyn Handler>],
+    workspaces: &[Workspace],
+    cargo_tomls: &[PathBuf],
 ) -> Result<Vec<CheckResult>> {
-    let cargo_toml = fs::read_to_string(cargo_path)?;
-    let crate_dir = cargo_path.parent().unwrap();
-    let crate_type = detect_crate_type(&cargo_toml, crate_dir);
-    let crate_name = extract_crate_name(&cargo_toml, crate_dir);
+    let mut all = Vec::new();
+    for workspace in workspaces {
+        section(config, &format!("Workspace: {}", workspace.name));
+        let results = check_crates_with(config, &workspace.cargo_tomls, &mut reporter(config))?;
+        show(config, &results);
+        all.extend(results);
+    }
+    section(config, "Project");
+    let project = check_project_level(config, cargo_tomls)?;
+    show(config, &project);
+    all.extend(project);
+    section(config, &format!("All workspaces ({})", workspaces.len()));
+    Ok(dedup_results(all))
+}
 
-    if config.verbose() {
-        println!("Checking {} ({:?})", crate_name, crate_type);
+/// Print a `=== title ===` header in text and annotated output
+fn section(config: &Config, title: &str) {
+    if matches!(
+        config.format(),
+        OutputFormat::Text | OutputFormat::Annotated
+    ) {
+        println!("\n=== {} ===", title);
     }
+}
 
-    let ctx = CheckContext {
-        config,
-        crate_dir,
-        crate_name: &crate_name,
-        crate_type,
-        cargo_toml: &cargo_toml,
-    };
-    run_handlers(&ctx, handlers)
+/// Print one section's results and summary, unless stdout carries a single
+/// document (json, html, junit, sarif, markdown) for the whole run
+fn show(config: &Config, results: &[CheckResult]) {
+    if !DOCUMENT_FORMATS.contains(&config.format()) {
+        print_results(results, config);
+        print_summary(results, config);
+    }
 }
 
-fn run_handlers(ctx: &CheckContext, handlers: &[Box<dyn Handler>]) -> Result<Vec<CheckResult>> {
-    let mut results = Vec::new();
-    for handler in handlers {
-        if handler.handles(ctx.crate_type) {
-            results.extend(handler.check(ctx)?);
+/// Print crates as they are checked (-v) and skipped handlers (--show skipped),
+/// to stderr when stdout carries a single document
+fn reporter(config: &Config) -> impl FnMut(Progress) + '_ {
+    let document = DOCUMENT_FORMATS.contains(&config.format());
+    move |event| {
+        let shown = match event {
+            Progress::Checking { .. } => config.verbose(),
+            Progress::Skipped { .. } => config.show_skipped(),
+        };
+        if shown && document {
+            eprintln!("{}", event);
+        } else if shown {
+            println!("{}", event);
         }
     }
-    Ok(results)
 }
@@ -0,0 +1,82 @@
+//! Subcommand dispatch
+
+use anyhow::Result;
+use checklist_config::{ConfigBuilder, FileConfig, load_file_config};
+use checklist_engine::run_checks;
+use cli_args::{BaselineAction, BaselineArgs, Command, SelectArgs, ServeArgs};
+use cli_github::PrTarget;
+use cli_html::BadgeContent;
+use server_runs::Checker;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::api::api_diff;
+use crate::artifact::artifact;
+use crate::commands::{badge, baseline, comment, ratchet};
+
+/// Run a subcommand and return its exit code
+pub fn run_command(command: Command) -> Result<i32> {
+    match command {
+        Command::Badge(args) => {
+            let content = if args.score {
+                BadgeContent::Score
+            } else {
+                BadgeContent::Counts
+            };
+            let config = config_builder(args.path, &args.select)?.build();
+            badge(&config, &args.output, content)
+        }
+        Command::Comment(args) => {
+            let target = PrTarget::resolve(args.repo, args.pr, args.token)?;
+            let config = config_builder(args.path, &args.select)?.build();
+            comment(&config, &target, args.base.as_deref())
+        }
+        Command::Serve(args) => serve(args),
+        Command::Ratchet(args) => ratchet(&config_builder(args.path, &args.select)?.build()),
+        Command::Baseline(BaselineArgs {
+            action: BaselineAction::Write(args),
+        }) => baseline(&config_builder(args.path, &args.select)?.build()),
+        Command::Lsp => lsp_server::run_stdio().map(|_| 0),
+        Command::ApiDiff(args) => {
+            let config = config_builder(args.path, &args.select)?.build();
+            api_diff(&config, &args.against)
+        }
+        Command::Artifact(args) => {
+            let config = ConfigBuilder::new().verbose(u8::from(args.verbose)).build();
+            artifact(&config, &args.path)
+        }
+    }
+}
+
+/// The project's .sw-checklist.toml with `--only`, `--skip`, and `--exclude`
+/// applied (`--only` replaces the file's list; the others add to it)
+pub fn file_config(path: &Path, select: &SelectArgs) -> Result<FileConfig> {
+    let mut file_config = load_file_config(path)?;
+    if !select.only.is_empty() {
+        file_config.only = select.only.clone();
+    }
+    file_config.skip.extend(select.skip.iter().cloned());
+    file_config.exclude.extend(select.exclude.iter().cloned());
+    Ok(file_config)
+}
+
+/// Config builder for checking the project at `path` with `select` applied
+pub fn config_builder(path: PathBuf, select: &SelectArgs) -> Result<ConfigBuilder> {
+    let file_config = file_config(&path, select)?;
+    Ok(ConfigBuilder::new()
+        .project_path(path)
+        .file_config(file_config))
+}
+
+/// Serve the HTTP API, checking each requested project with `args.select`
+fn serve(args: ServeArgs) -> Result<i32> {
+    let select = args.select;
+    let checker: Arc<Checker> = Arc::new(move |path: &Path| {
+        run_checks(&config_builder(path.to_path_buf(), &select)?.build())
+    });
+    let addr = format!("{}:{}", args.host, args.port);
+    let listener = server_http::bind(&addr)?;
+    println!("sw-checklist serving on http://{}", addr);
+    server_http::serve(listener, checker, args.allow_remote)?;
+    Ok(0)
+}
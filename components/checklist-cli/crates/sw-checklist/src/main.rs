@@ -1,9 +1,12 @@
 //! sw-checklist - CLI tool for validating Software Wrighter LLC project conformance
 
-use anyhow::Result;
-use checklist_config::ConfigBuilder;
-use clap::Parser;
-use std::path::PathBuf;
+use anyhow::{Result, bail};
+use checklist_config::{Config, ConfigBuilder, DOCUMENT_FORMATS, OutputFormat};
+use checklist_i18n::Catalog;
+use checklist_result::EXIT_ERROR;
+use clap::{Parser, ValueEnum};
+use cli_args::{Command, SelectArgs};
+use std::path::{Path, PathBuf};
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 const BUILD_COMMIT: &str = env!("BUILD_COMMIT_SHA");
@@ -29,12 +32,66 @@ USAGE FOR AI AGENTS:
   2. Address each issue reported by the tool
   3. Re-run to verify all checks pass
 
-CHECKS PERFORMED:
+EXAMPLES:
+  $ sw-checklist
+  $ sw-checklist /path/to/project
+  $ sw-checklist -v /path/to/project
+  $ sw-checklist -vv
+  $ sw-checklist --show skipped
+  $ sw-checklist --format quickfix /path/to/project
+  $ sw-checklist --format annotated
+  $ sw-checklist --format json
+  $ sw-checklist --format html --output report.html
+  $ sw-checklist --format junit --output sw-checklist.xml
+  $ sw-checklist --format sarif --output sw-checklist.sarif
+  $ sw-checklist --rollup 3
+  $ sw-checklist --strict
+  $ sw-checklist --workspace components/checklist-cli
+  $ sw-checklist --dry-run
+  $ sw-checklist --recursive ~/src
+  $ sw-checklist --only modularity --only cargo
+  $ sw-checklist --skip clap
+  $ sw-checklist --exclude vendor --exclude 'examples/**'
+  $ sw-checklist --fix
+  $ sw-checklist --lang de
+  $ sw-checklist badge --output badge.svg
+  $ sw-checklist badge --score /path/to/project
+  $ sw-checklist comment --pr 42 --base origin/main
+  $ sw-checklist serve --port 8080
+  $ sw-checklist lsp
+  $ sw-checklist ratchet && sw-checklist --ratchet
+  $ sw-checklist baseline write
+  $ sw-checklist api-diff --against v0.1.0
+  $ sw-checklist artifact ./release/sw-checklist
+  $ sw-checklist artifact ./web/dist
+
+SUBCOMMANDS:
+  badge     Write an SVG badge with pass/fail/warn counts (or --score)
+  comment   Post/update a sticky PR comment with the Markdown report
+            (uses GITHUB_TOKEN and GITHUB_REPOSITORY unless --token/--repo)
+  serve     HTTP API: POST /check {"path"|"git"}, GET /runs/<id>[/report]
+            (git URLs only with --allow-remote: checks run the clone's code)
+  ratchet   Record current metrics; --ratchet then fails only on regressions
+  baseline  write: record current failures/warnings in .sw-checklist-baseline.json;
+            later runs report them as INFO so only new violations fail
+  lsp       Language server (stdio) with modularity diagnostics for open files
+  api-diff  Public items added/removed/changed since --against <ref>,
+            with a suggested semver bump
+  artifact  Help/version checks on a prebuilt binary, or favicon and asset
+            checks on a built dist/ directory, without the source tree
+
+EXIT CODES:
+  0  All checks passed (INFO results don't count)
+  1  At least one check failed (with --strict, warnings too)
+  2  No failures, but at least one warning
+  3  Internal error: bad arguments or config, nothing to check, or a crash
+
+CHECKS PERFORMED (default thresholds, see [modularity] in .sw-checklist.toml):
   - Rust edition must be 2024
   - Functions: warns if >25 LOC, fails if >50 LOC
   - Modules: warns if >4 functions, fails if >7 functions
   - Crates: warns if >4 modules, fails if >7 modules
-  - CLI binaries: help/version output validation
+  - CLI binaries: help/version output validation, EXAMPLES section in --help
   - Web UI: favicon, index.html, footer requirements
 "#;
 
@@ -44,24 +101,132 @@ CHECKS PERFORMED:
 #[command(long_version = LONG_VERSION)]
 #[command(about = "CLI tool for validating Software Wrighter LLC project conformance")]
 #[command(after_long_help = AI_INSTRUCTIONS)]
+#[command(args_conflicts_with_subcommands = true)]
 struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
     /// Project path to check (defaults to current directory)
     #[arg(default_value = ".")]
     path: PathBuf,
 
-    /// Show per-check details (default: summary only)
-    #[arg(short, long)]
-    verbose: bool,
+    /// Show per-check details (default: summary only); -vv also lists skipped handlers
+    #[arg(short, long, action = clap::ArgAction::Count)]
+    verbose: u8,
+
+    /// Extra detail to show: skipped (handlers not run per crate, and why; same as -vv)
+    #[arg(long, value_name = "WHAT")]
+    show: Vec<Show>,
+
+    /// Output format: text, quickfix (file:line:col: severity: message), annotated (code frames), json, html, junit, sarif, or markdown
+    #[arg(long, default_value = "text")]
+    format: OutputFormat,
+
+    /// Write the json, html, junit, sarif, or markdown report to FILE instead of stdout
+    /// (not with --recursive, which would overwrite it per project)
+    #[arg(long, value_name = "FILE", conflicts_with = "recursive")]
+    output: Option<PathBuf>,
+
+    /// Collapse more than N similar findings (same check and status) into one entry
+    #[arg(long, value_name = "N")]
+    rollup: Option<usize>,
+
+    /// Show at most N issues per check without --verbose (default: 5)
+    #[arg(long, value_name = "N")]
+    max_per_check: Option<usize>,
+
+    /// Fail only when metrics regress from .sw-checklist-ratchet.toml
+    #[arg(long)]
+    ratchet: bool,
+
+    /// Exit non-zero on warnings as well as failures (for CI; also `strict = true`)
+    #[arg(long)]
+    strict: bool,
+
+    /// Check only the named workspace (path relative to the project or directory name)
+    #[arg(long, value_name = "NAME")]
+    workspace: Option<String>,
+
+    /// Check each top-level subdirectory with its own git repo or Cargo.toml as a
+    /// separate project, then print an aggregate summary
+    #[arg(long)]
+    recursive: bool,
+
+    /// Print the crates, detected types, and handlers that would run, without running checks
+    #[arg(long)]
+    dry_run: bool,
+
+    /// Repair what supporting checks find (e.g. line endings) and report it as fixed
+    #[arg(long)]
+    fix: bool,
+
+    #[command(flatten)]
+    select: SelectArgs,
+
+    /// Language for text output, e.g. de (check IDs, quickfix, and JSON stay English)
+    #[arg(long, value_name = "LANG")]
+    lang: Option<String>,
 }
 
-fn main() -> Result<()> {
-    let cli = Cli::parse();
+/// Extra detail requested with --show
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Show {
+    /// Handler/crate combinations that were skipped, with the reason
+    Skipped,
+}
+
+fn main() {
+    let cli = Cli::try_parse().unwrap_or_else(|e| {
+        if e.use_stderr() {
+            let _ = e.print();
+            std::process::exit(EXIT_ERROR);
+        }
+        e.exit()
+    });
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
 
-    let config = ConfigBuilder::new()
-        .project_path(cli.path)
-        .verbose(cli.verbose)
-        .build();
+    let outcome = match cli.command {
+        Some(command) => cli_runner::run_command(command),
+        None if cli.recursive => {
+            cli_runner::recursive(&cli.path, cli.format, |path| check(&cli, path))
+        }
+        None => check(&cli, &cli.path),
+    };
+    std::process::exit(outcome.unwrap_or_else(|e| {
+        eprintln!("Error: {:?}", e);
+        EXIT_ERROR
+    }));
+}
+
+/// Check (or dry-run) the project at `path` with the CLI's options
+fn check(cli: &Cli, path: &Path) -> Result<i32> {
+    let config = check_config(cli, path)?;
+    if cli.output.is_some() && !DOCUMENT_FORMATS.contains(&cli.format) {
+        bail!("--output needs --format json, html, junit, sarif, or markdown");
+    }
+    if cli.dry_run {
+        cli_runner::dry_run(&config, cli.workspace.as_deref())
+    } else {
+        cli_runner::run(&config, cli.workspace.as_deref(), cli.output.as_deref())
+    }
+}
 
-    let exit_code = cli_runner::run(&config)?;
-    std::process::exit(exit_code);
+/// Config for checking `path`, with `--lang` overriding the file's `lang`
+fn check_config(cli: &Cli, path: &Path) -> Result<Config> {
+    let mut file_config = cli_runner::file_config(path, &cli.select)?;
+    file_config.lang = cli.lang.clone().or(file_config.lang);
+    file_config.strict |= cli.strict;
+    Catalog::load(file_config.lang.as_deref(), path)?;
+    let show_skipped = u8::from(cli.show.contains(&Show::Skipped));
+    let verbose = cli.verbose.max(2 * show_skipped);
+    Ok(ConfigBuilder::new()
+        .project_path(path.to_path_buf())
+        .file_config(file_config)
+        .verbose(verbose)
+        .format(cli.format)
+        .rollup(cli.rollup)
+        .max_per_check(cli.max_per_check)
+        .ratchet(cli.ratchet)
+        .fix(cli.fix)
+        .build())
 }
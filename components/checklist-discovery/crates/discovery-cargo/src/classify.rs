@@ -18,5 +18,16 @@ pub fn is_wasm_crate(cargo_toml: &str) -> bool {
     if is_workspace(cargo_toml) {
         return false;
     }
-    cargo_toml.contains("wasm-bindgen") || cargo_toml.contains("crate-type = [\"cdylib\"]")
+    cargo_toml.contains("wasm-bindgen")
+}
+
+/// Check if a Cargo.toml builds a C-compatible dynamic library (cdylib)
+pub fn is_cdylib_crate(cargo_toml: &str) -> bool {
+    if is_workspace(cargo_toml) {
+        return false;
+    }
+    cargo_toml.lines().any(|line| {
+        let trimmed = line.trim();
+        trimmed.starts_with("crate-type") && trimmed.contains("\"cdylib\"")
+    })
 }
@@ -0,0 +1,56 @@
+//! Path exclusion by glob (`exclude` in .sw-checklist.toml, `--exclude`)
+
+use std::path::Path;
+
+/// Whether `path` (under `root`) or one of its ancestors matches an exclude glob
+///
+/// Patterns are relative to `root` and support `*`, `?`, and `**` (any number
+/// of directories). A pattern without a `/`, like `examples`, matches a
+/// directory or file of that name at any depth; a trailing `/` is ignored.
+pub fn is_excluded(path: &Path, root: &Path, patterns: &[String]) -> bool {
+    let Ok(relative) = path.strip_prefix(root) else {
+        return false;
+    };
+    let components: Vec<String> = relative
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy().into_owned())
+        .collect();
+    patterns.iter().any(|pattern| {
+        let pattern = pattern.trim_start_matches("./").trim_end_matches('/');
+        let parts: Vec<&str> = pattern.split('/').filter(|p| !p.is_empty()).collect();
+        match parts.as_slice() {
+            [] => false,
+            [name] => components.iter().any(|c| wildcard(name, c)),
+            _ => (1..=components.len()).any(|n| glob_parts(&parts, &components[..n])),
+        }
+    })
+}
+
+/// Match path components against pattern parts, where `**` spans any number of components
+fn glob_parts(parts: &[&str], components: &[String]) -> bool {
+    match parts.split_first() {
+        None => components.is_empty(),
+        Some((&"**", rest)) => (0..=components.len()).any(|i| glob_parts(rest, &components[i..])),
+        Some((part, rest)) => components
+            .split_first()
+            .is_some_and(|(c, tail)| wildcard(part, c) && glob_parts(rest, tail)),
+    }
+}
+
+/// Match one path component against a pattern with `*` and `?` wildcards
+fn wildcard(pattern: &str, text: &str) -> bool {
+    let mut chars = pattern.chars();
+    match chars.next() {
+        None => text.is_empty(),
+        Some('*') => (0..=text.len())
+            .filter(|&i| text.is_char_boundary(i))
+            .any(|i| wildcard(chars.as_str(), &text[i..])),
+        Some('?') => {
+            let mut text_chars = text.chars();
+            text_chars.next().is_some() && wildcard(chars.as_str(), text_chars.as_str())
+        }
+        Some(c) => text
+            .strip_prefix(c)
+            .is_some_and(|rest| wildcard(chars.as_str(), rest)),
+    }
+}
@@ -1,14 +1,84 @@
 //! Find Cargo.toml files in a project
 
+use std::collections::BTreeMap;
+use std::fs;
 use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
 
-/// Find all Cargo.toml files in a directory tree
-pub fn find_cargo_tomls(path: &Path) -> Vec<PathBuf> {
+use crate::exclude::is_excluded;
+
+/// An independent Cargo workspace (or standalone crate) within a project
+#[derive(Debug, Clone)]
+pub struct Workspace {
+    /// Path of the workspace root relative to the project root ("." for the root itself)
+    pub name: String,
+    /// Cargo.toml files belonging to the workspace, including its own manifest
+    pub cargo_tomls: Vec<PathBuf>,
+}
+
+/// Find all Cargo.toml files in a directory tree, skipping paths that match an
+/// `exclude` glob
+pub fn find_cargo_tomls(path: &Path, exclude: &[String]) -> Vec<PathBuf> {
     WalkDir::new(path)
         .into_iter()
+        .filter_entry(|e| !is_excluded(e.path(), path, exclude))
         .filter_map(|e| e.ok())
         .filter(|e| e.file_name() == "Cargo.toml")
         .map(|e| e.path().to_path_buf())
         .collect()
 }
+
+/// Top-level subdirectories of `root` that are projects of their own (they contain
+/// a `.git` entry or a `Cargo.toml`), sorted by path
+pub fn find_projects(root: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(root) else {
+        return Vec::new();
+    };
+    let mut projects: Vec<PathBuf> = entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|path| path.join(".git").exists() || path.join("Cargo.toml").is_file())
+        .collect();
+    projects.sort();
+    projects
+}
+
+/// Group Cargo.toml files by the innermost `[workspace]` containing them,
+/// sorted by name (crates outside any workspace form their own group)
+pub fn group_workspaces(cargo_tomls: &[PathBuf], root: &Path) -> Vec<Workspace> {
+    let workspaces: Vec<&Path> = cargo_tomls
+        .iter()
+        .filter(|path| fs::read_to_string(path).is_ok_and(|c| c.contains("[workspace]")))
+        .filter_map(|path| path.parent())
+        .collect();
+    let mut groups: BTreeMap<String, Vec<PathBuf>> = BTreeMap::new();
+    for path in cargo_tomls {
+        let crate_dir = path.parent().unwrap_or(root);
+        let name = owner_name(crate_dir, &workspaces, root);
+        groups.entry(name).or_default().push(path.clone());
+    }
+    groups
+        .into_iter()
+        .map(|(name, cargo_tomls)| Workspace { name, cargo_tomls })
+        .collect()
+}
+
+/// Path of the innermost workspace containing `crate_dir`, relative to the project root
+fn owner_name(crate_dir: &Path, workspaces: &[&Path], root: &Path) -> String {
+    let owner = workspaces
+        .iter()
+        .filter(|ws| crate_dir.starts_with(ws))
+        .max_by_key(|ws| ws.components().count())
+        .copied()
+        .unwrap_or(crate_dir);
+    let name = owner
+        .strip_prefix(root)
+        .unwrap_or(owner)
+        .display()
+        .to_string();
+    if name.is_empty() {
+        ".".to_string()
+    } else {
+        name
+    }
+}
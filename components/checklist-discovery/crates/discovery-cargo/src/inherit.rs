@@ -0,0 +1,50 @@
+//! Workspace field inheritance resolution
+
+use std::fs;
+use std::path::Path;
+
+/// Replace `field.workspace = true` entries in `[package]` with values from the
+/// enclosing workspace's `[workspace.package]` table
+pub fn resolve_workspace_inheritance(cargo_toml: &str, crate_dir: &Path) -> String {
+    let Some(package) = workspace_package(crate_dir) else {
+        return cargo_toml.to_string();
+    };
+    let mut in_package = false;
+    let mut resolved = Vec::new();
+    for line in cargo_toml.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('[') {
+            in_package = trimmed == "[package]";
+        }
+        match inherited_key(trimmed).and_then(|key| package.get(key).map(|v| (key, v))) {
+            Some((key, value)) if in_package => resolved.push(format!("{} = {}", key, value)),
+            _ => resolved.push(line.to_string()),
+        }
+    }
+    let mut out = resolved.join("\n");
+    if cargo_toml.ends_with('\n') {
+        out.push('\n');
+    }
+    out
+}
+
+/// Find the nearest `[workspace.package]` table at or above the crate directory
+fn workspace_package(crate_dir: &Path) -> Option<toml::Table> {
+    crate_dir.ancestors().find_map(|dir| {
+        let content = fs::read_to_string(dir.join("Cargo.toml")).ok()?;
+        let value = content.parse::<toml::Value>().ok()?;
+        value.get("workspace")?.get("package")?.as_table().cloned()
+    })
+}
+
+/// Parse `key.workspace = true` or `key = { workspace = true }` into the key
+fn inherited_key(line: &str) -> Option<&str> {
+    let (lhs, rhs) = line.split_once('=')?;
+    let (lhs, rhs) = (lhs.trim(), rhs.trim());
+    if let Some(key) = lhs.strip_suffix(".workspace") {
+        return (rhs == "true").then_some(key.trim());
+    }
+    let inline = rhs.strip_prefix('{')?.strip_suffix('}')?;
+    let (k, v) = inline.split_once('=')?;
+    (k.trim() == "workspace" && v.trim() == "true").then_some(lhs)
+}
@@ -1,9 +1,15 @@
 //! Cargo.toml discovery for sw-checklist
 //!
-//! This crate finds and classifies Cargo.toml files in a project.
+//! This crate finds and classifies Cargo.toml files in a project (skipping
+//! `exclude` globs), groups them into independent workspaces, and resolves
+//! fields inherited from `[workspace.package]`.
 
 mod classify;
+mod exclude;
 mod find;
+mod inherit;
 
-pub use classify::{has_clap_dependency, is_wasm_crate, is_workspace};
-pub use find::find_cargo_tomls;
+pub use classify::{has_clap_dependency, is_cdylib_crate, is_wasm_crate, is_workspace};
+pub use exclude::is_excluded;
+pub use find::{Workspace, find_cargo_tomls, find_projects, group_workspaces};
+pub use inherit::resolve_workspace_inheritance;
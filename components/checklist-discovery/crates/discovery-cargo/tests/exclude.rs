@@ -0,0 +1,71 @@
+//! Exclude glob matching
+
+use checklist_tempdir::TempWorkspace;
+use discovery_cargo::{find_cargo_tomls, is_excluded};
+use std::fs;
+use std::path::Path;
+
+fn excluded(path: &str, patterns: &[&str]) -> bool {
+    let patterns: Vec<String> = patterns.iter().map(|p| p.to_string()).collect();
+    is_excluded(
+        &Path::new("/project").join(path),
+        Path::new("/project"),
+        &patterns,
+    )
+}
+
+#[test]
+fn bare_names_match_at_any_depth() {
+    assert!(excluded("vendor", &["vendor"]));
+    assert!(excluded("crates/a/vendor/lib.rs", &["vendor/"]));
+    assert!(!excluded("crates/vendored", &["vendor"]));
+}
+
+#[test]
+fn paths_are_anchored_at_the_root() {
+    assert!(excluded("examples/demo/main.rs", &["examples/*"]));
+    assert!(!excluded("crates/examples/demo", &["examples/*"]));
+    assert!(excluded(
+        "crates/a/tests/fixtures/x.rs",
+        &["crates/**/fixtures"]
+    ));
+    assert!(excluded("crates/fixtures", &["./crates/**/fixtures"]));
+}
+
+#[test]
+fn wildcards_stay_within_a_component() {
+    assert!(excluded("gen_v1/out.rs", &["gen_v?"]));
+    assert!(excluded("crates/legacy-core", &["crates/legacy-*"]));
+    assert!(!excluded("crates/a/legacy-core", &["crates/legacy-*"]));
+    assert!(!excluded("anything", &[""]));
+}
+
+#[test]
+fn paths_outside_the_root_are_never_excluded() {
+    let patterns = ["**".to_string()];
+    assert!(!is_excluded(
+        Path::new("/elsewhere/a"),
+        Path::new("/project"),
+        &patterns
+    ));
+}
+
+#[test]
+fn discovery_skips_excluded_directories() {
+    let workspace = TempWorkspace::new("exclude").unwrap();
+    let root = workspace.path();
+    for dir in [".", "app", "vendor/dep", "examples/demo"] {
+        fs::create_dir_all(root.join(dir)).unwrap();
+        fs::write(root.join(dir).join("Cargo.toml"), "[package]\n").unwrap();
+    }
+    let exclude = ["vendor".to_string(), "examples/**".to_string()];
+    let mut found: Vec<_> = find_cargo_tomls(root, &exclude)
+        .iter()
+        .map(|p| p.strip_prefix(root).unwrap().to_path_buf())
+        .collect();
+    found.sort();
+    assert_eq!(
+        found,
+        [Path::new("Cargo.toml"), Path::new("app/Cargo.toml")]
+    );
+}
@@ -0,0 +1,91 @@
+//! Cargo.toml discovery across crate layouts
+
+use checklist_tempdir::TempWorkspace;
+use discovery_cargo::{find_cargo_tomls, group_workspaces};
+use std::fs;
+use std::path::Path;
+
+/// Write a manifest at `dir` (relative to `root`) with `contents`
+fn manifest(root: &Path, dir: &str, contents: &str) {
+    let dir = root.join(dir);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("Cargo.toml"), contents).unwrap();
+}
+
+#[test]
+fn finds_a_single_crate() {
+    let workspace = TempWorkspace::new("find").unwrap();
+    manifest(workspace.path(), ".", "[package]\nname = \"test\"\n");
+    let found = find_cargo_tomls(workspace.path(), &[]);
+    assert_eq!(found.len(), 1);
+    assert!(found[0].ends_with("Cargo.toml"));
+}
+
+#[test]
+fn finds_nothing_in_an_empty_project() {
+    let workspace = TempWorkspace::new("find").unwrap();
+    assert!(find_cargo_tomls(workspace.path(), &[]).is_empty());
+}
+
+#[test]
+fn finds_workspace_root_and_members() {
+    let workspace = TempWorkspace::new("find").unwrap();
+    let root = workspace.path();
+    manifest(
+        root,
+        ".",
+        "[workspace]\nmembers = [\"crate1\", \"crate2\"]\n",
+    );
+    manifest(root, "crate1", "[package]\nname = \"crate1\"\n");
+    manifest(root, "crate2", "[package]\nname = \"crate2\"\n");
+    assert_eq!(find_cargo_tomls(root, &[]).len(), 3);
+}
+
+#[test]
+fn finds_deeply_nested_crates() {
+    let workspace = TempWorkspace::new("find").unwrap();
+    let root = workspace.path();
+    manifest(root, ".", "[package]\nname = \"root\"\n");
+    manifest(root, "nested/deep", "[package]\nname = \"nested\"\n");
+    assert_eq!(find_cargo_tomls(root, &[]).len(), 2);
+}
+
+#[test]
+fn groups_crates_by_their_innermost_workspace() {
+    let workspace = TempWorkspace::new("find").unwrap();
+    let root = workspace.path();
+    manifest(root, ".", "[workspace]\nmembers = [\"app\"]\n");
+    manifest(root, "app", "[package]\nname = \"app\"\n");
+    manifest(
+        root,
+        "components/core",
+        "[workspace]\nmembers = [\"crates/*\"]\n",
+    );
+    manifest(
+        root,
+        "components/core/crates/a",
+        "[package]\nname = \"a\"\n",
+    );
+    manifest(
+        root,
+        "components/core/crates/b",
+        "[package]\nname = \"b\"\n",
+    );
+    let groups = group_workspaces(&find_cargo_tomls(root, &[]), root);
+    let sizes: Vec<(&str, usize)> = groups
+        .iter()
+        .map(|ws| (ws.name.as_str(), ws.cargo_tomls.len()))
+        .collect();
+    assert_eq!(sizes, [(".", 2), ("components/core", 3)]);
+}
+
+#[test]
+fn standalone_crates_form_their_own_group() {
+    let workspace = TempWorkspace::new("find").unwrap();
+    let root = workspace.path();
+    manifest(root, "tools/lint", "[package]\nname = \"lint\"\n");
+    manifest(root, "tools/fmt", "[package]\nname = \"fmt\"\n");
+    let groups = group_workspaces(&find_cargo_tomls(root, &[]), root);
+    let names: Vec<&str> = groups.iter().map(|ws| ws.name.as_str()).collect();
+    assert_eq!(names, ["tools/fmt", "tools/lint"]);
+}
@@ -1,7 +1,10 @@
 //! Crate type detection
 
-use discovery_cargo::{has_clap_dependency, is_wasm_crate, is_workspace};
+use discovery_cargo::{has_clap_dependency, is_cdylib_crate, is_wasm_crate, is_workspace};
 use std::path::Path;
+use std::str::FromStr;
+
+use crate::metadata::has_dependency;
 
 /// Type of crate detected
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -14,28 +17,89 @@ pub enum CrateType {
     Wasm,
     /// CLI + WASM combined
     CliWasm,
+    /// Build tooling CLI (xtask pattern), not an installable tool
+    Tooling,
+    /// Host plugin (cdylib without wasm-bindgen)
+    Plugin,
+    /// Tauri desktop app backend (`src-tauri/`)
+    Tauri,
+    /// Native desktop GUI app (eframe/egui binary)
+    Desktop,
     /// Library crate
     Library,
 }
 
+impl FromStr for CrateType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "workspace" => Ok(Self::Workspace),
+            "cli" => Ok(Self::Cli),
+            "wasm" => Ok(Self::Wasm),
+            "cli-wasm" => Ok(Self::CliWasm),
+            "tooling" => Ok(Self::Tooling),
+            "plugin" => Ok(Self::Plugin),
+            "tauri" => Ok(Self::Tauri),
+            "desktop" => Ok(Self::Desktop),
+            "library" => Ok(Self::Library),
+            other => Err(format!(
+                "unknown crate type '{}' (expected workspace, cli, wasm, cli-wasm, tooling, plugin, tauri, desktop, or library)",
+                other
+            )),
+        }
+    }
+}
+
 /// Detect the type of crate from Cargo.toml content and crate directory
 pub fn detect_crate_type(cargo_toml: &str, crate_dir: &Path) -> CrateType {
     if is_workspace(cargo_toml) {
         return CrateType::Workspace;
     }
+    if is_tauri_crate(cargo_toml, crate_dir) {
+        return CrateType::Tauri;
+    }
 
     let has_clap = has_clap_dependency(cargo_toml);
     let is_binary = is_binary_crate(cargo_toml, crate_dir);
     let has_wasm = is_wasm_crate(cargo_toml);
+    if is_binary && !has_wasm && has_dependency(cargo_toml, "eframe") {
+        return CrateType::Desktop;
+    }
 
     match (has_clap && is_binary, has_wasm) {
         (true, true) => CrateType::CliWasm,
+        (true, false) if is_xtask_crate(cargo_toml, crate_dir) => CrateType::Tooling,
         (true, false) => CrateType::Cli,
         (false, true) => CrateType::Wasm,
+        (false, false) if is_cdylib_crate(cargo_toml) => CrateType::Plugin,
         (false, false) => CrateType::Library,
     }
 }
 
+/// Check if a crate is a Tauri app backend (tauri dependency plus `src-tauri/`
+/// directory or tauri.conf.json)
+fn is_tauri_crate(cargo_toml: &str, crate_dir: &Path) -> bool {
+    let in_src_tauri = crate_dir.file_name().is_some_and(|n| n == "src-tauri");
+    has_dependency(cargo_toml, "tauri")
+        && (in_src_tauri || crate_dir.join("tauri.conf.json").exists())
+}
+
+/// Check if a crate follows the xtask naming pattern (`xtask` or `xtask-*`)
+fn is_xtask_crate(cargo_toml: &str, crate_dir: &Path) -> bool {
+    let is_xtask = |name: &str| name == "xtask" || name.starts_with("xtask-");
+    let dir_match = crate_dir
+        .file_name()
+        .and_then(|n| n.to_str())
+        .is_some_and(is_xtask);
+    let name_match = cargo_toml
+        .parse::<toml::Value>()
+        .ok()
+        .and_then(|c| c.get("package")?.get("name")?.as_str().map(is_xtask))
+        .unwrap_or(false);
+    dir_match || name_match
+}
+
 /// Check if a crate produces a binary
 fn is_binary_crate(cargo_toml: &str, crate_dir: &Path) -> bool {
     // Explicit [[bin]] section in Cargo.toml
@@ -48,12 +112,12 @@ fn is_binary_crate(cargo_toml: &str, crate_dir: &Path) -> bool {
     }
     // Has src/bin/ directory with .rs files
     let bin_dir = crate_dir.join("src/bin");
-    if bin_dir.is_dir() {
-        if let Ok(entries) = std::fs::read_dir(&bin_dir) {
-            for entry in entries.flatten() {
-                if entry.path().extension().is_some_and(|e| e == "rs") {
-                    return true;
-                }
+    if bin_dir.is_dir()
+        && let Ok(entries) = std::fs::read_dir(&bin_dir)
+    {
+        for entry in entries.flatten() {
+            if entry.path().extension().is_some_and(|e| e == "rs") {
+                return true;
             }
         }
     }
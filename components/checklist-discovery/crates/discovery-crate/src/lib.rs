@@ -4,6 +4,8 @@
 
 mod crate_type;
 mod metadata;
+mod targets;
 
 pub use crate_type::{CrateType, detect_crate_type};
-pub use metadata::extract_crate_name;
+pub use metadata::{extract_crate_name, has_dependency};
+pub use targets::{binary_label, binary_targets};
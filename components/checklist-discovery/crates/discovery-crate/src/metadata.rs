@@ -8,3 +8,25 @@ pub fn extract_crate_name(cargo: &toml::Value) -> &str {
         .and_then(|n| n.as_str())
         .unwrap_or("unknown")
 }
+
+/// Dependency tables searched by `has_dependency`
+const DEPENDENCY_TABLES: &[&str] = &["dependencies", "build-dependencies", "dev-dependencies"];
+
+/// Check if Cargo.toml content depends on a crate (any dependency table,
+/// including target-specific ones)
+pub fn has_dependency(cargo_toml: &str, name: &str) -> bool {
+    let Ok(cargo) = cargo_toml.parse::<toml::Value>() else {
+        return false;
+    };
+    let targets = cargo
+        .get("target")
+        .and_then(|t| t.as_table())
+        .into_iter()
+        .flat_map(|t| t.values());
+    std::iter::once(&cargo).chain(targets).any(|section| {
+        DEPENDENCY_TABLES
+            .iter()
+            .filter_map(|table| section.get(table))
+            .any(|deps| deps.get(name).is_some())
+    })
+}
@@ -0,0 +1,81 @@
+//! Binary target enumeration
+
+use std::path::Path;
+
+/// List binary target names for a crate
+///
+/// Combines `[[bin]]` sections with the targets Cargo discovers automatically
+/// (`src/main.rs`, `src/bin/*.rs`, `src/bin/*/main.rs`) unless `autobins = false`.
+pub fn binary_targets(cargo_toml: &str, crate_name: &str, crate_dir: &Path) -> Vec<String> {
+    let cargo = cargo_toml.parse::<toml::Value>().ok();
+    let declared = cargo.as_ref().map(declared_bins).unwrap_or_default();
+    let autobins = cargo
+        .as_ref()
+        .and_then(|c| c.get("package"))
+        .and_then(|p| p.get("autobins"))
+        .and_then(|a| a.as_bool())
+        .unwrap_or(true);
+
+    let mut names: Vec<String> = declared.iter().map(|(name, _)| name.clone()).collect();
+    if autobins {
+        for (name, path) in inferred_bins(crate_name, crate_dir) {
+            let path_declared = declared.iter().any(|(_, p)| p.as_deref() == Some(&path));
+            if !path_declared && !names.contains(&name) {
+                names.push(name);
+            }
+        }
+    }
+    names
+}
+
+/// Label suffix for checks of one binary: `[crate]` when the binary is named after
+/// its crate, otherwise `[crate/binary]`
+pub fn binary_label(crate_name: &str, binary_name: &str) -> String {
+    if binary_name == crate_name {
+        format!("[{}]", crate_name)
+    } else {
+        format!("[{}/{}]", crate_name, binary_name)
+    }
+}
+
+/// `[[bin]]` entries as (name, path) pairs
+fn declared_bins(cargo: &toml::Value) -> Vec<(String, Option<String>)> {
+    cargo
+        .get("bin")
+        .and_then(|b| b.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|bin| {
+            let name = bin.get("name")?.as_str()?.to_string();
+            let path = bin.get("path").and_then(|p| p.as_str()).map(str::to_string);
+            Some((name, path))
+        })
+        .collect()
+}
+
+/// Auto-discovered binaries as (name, relative path) pairs
+fn inferred_bins(crate_name: &str, crate_dir: &Path) -> Vec<(String, String)> {
+    let mut bins = Vec::new();
+    if crate_dir.join("src/main.rs").exists() {
+        bins.push((crate_name.to_string(), "src/main.rs".to_string()));
+    }
+    let mut found: Vec<(String, String)> = std::fs::read_dir(crate_dir.join("src/bin"))
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.path();
+            let name = path.file_stem()?.to_str()?.to_string();
+            if path.extension().is_some_and(|e| e == "rs") {
+                Some((name.clone(), format!("src/bin/{}.rs", name)))
+            } else if path.join("main.rs").exists() {
+                Some((name.clone(), format!("src/bin/{}/main.rs", name)))
+            } else {
+                None
+            }
+        })
+        .collect();
+    found.sort();
+    bins.extend(found);
+    bins
+}
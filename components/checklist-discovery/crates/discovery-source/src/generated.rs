@@ -0,0 +1,25 @@
+//! Generated-code detection
+
+/// Lines at the top of a file searched for a generator header
+const HEADER_LINES: usize = 10;
+
+/// Header markers written by code generators (prost-build, tonic-build,
+/// bindgen, etc.)
+const HEADER_MARKERS: &[&str] = &[
+    "@generated",
+    "automatically generated",
+    "do not edit",
+    "generated by",
+];
+
+/// Check if Rust source content was produced by a code generator, going by a
+/// marker in a header comment only; code that merely uses generated pieces
+/// (`#[automatically_derived]`, `include!` from `OUT_DIR`) is hand-written
+pub fn is_generated(content: &str) -> bool {
+    content.lines().take(HEADER_LINES).any(|line| {
+        let line = line.trim_start();
+        let lower = line.to_lowercase();
+        let comment = line.starts_with("//") || line.starts_with("/*");
+        comment && HEADER_MARKERS.iter().any(|m| lower.contains(m))
+    })
+}
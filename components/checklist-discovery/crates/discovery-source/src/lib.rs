@@ -0,0 +1,12 @@
+//! Rust source discovery for sw-checklist
+//!
+//! Collects the .rs files of a crate for source-scanning checks, skipping
+//! generated code unless the project opts in.
+
+mod generated;
+mod scan;
+mod sources;
+
+pub use generated::is_generated;
+pub use scan::{is_entry_point, matching_lines};
+pub use sources::{RustSource, all_rust_sources, rust_sources};
@@ -0,0 +1,115 @@
+//! Line scanning for source pattern checks
+
+use std::path::Path;
+
+/// A string literal still open at the end of a line
+#[derive(Debug, Clone, Copy)]
+enum Open {
+    /// `"..."`
+    Plain,
+    /// `r#"..."#` with this many `#`s
+    Raw(usize),
+}
+
+/// 1-based lines whose code matches `is_match`
+///
+/// Comment lines, string and char literal contents (including raw and
+/// multi-line strings), and `#[cfg(test)]` items are ignored, so checks don't
+/// flag tests or their own pattern tables; code after a test module is still
+/// scanned.
+pub fn matching_lines(content: &str, is_match: impl Fn(&str) -> bool) -> Vec<usize> {
+    let mut open = None;
+    // (brace depth, seen an opening brace) while inside a #[cfg(test)] item
+    let mut test: Option<(usize, bool)> = None;
+    let mut lines = Vec::new();
+    for (i, line) in content.lines().enumerate() {
+        let code = strip_strings(line.trim_start(), &mut open);
+        if test.is_none() && code.starts_with("#[cfg(test)]") {
+            test = Some((0, false));
+        }
+        if let Some((depth, entered)) = test.as_mut() {
+            *depth = (*depth + code.matches('{').count()).saturating_sub(code.matches('}').count());
+            *entered |= code.contains('{');
+            let ended = if *entered {
+                *depth == 0
+            } else {
+                code.trim_end().ends_with(';')
+            };
+            if ended {
+                test = None;
+            }
+        } else if !code.starts_with("//") && is_match(&code) {
+            lines.push(i + 1);
+        }
+    }
+    lines
+}
+
+/// Check if a source file is a binary entry point (`src/main.rs`, `src/bin/`)
+pub fn is_entry_point(path: &Path, crate_dir: &Path) -> bool {
+    path == crate_dir.join("src/main.rs") || path.starts_with(crate_dir.join("src/bin"))
+}
+
+/// The line with string and char literal contents removed (quotes kept);
+/// `open` carries a string left open at the end of the previous line
+fn strip_strings(line: &str, open: &mut Option<Open>) -> String {
+    let chars: Vec<char> = line.chars().collect();
+    let mut code = String::with_capacity(line.len());
+    let mut i = 0;
+    while i < chars.len() {
+        if let Some(kind) = *open {
+            let Some(len) = string_end(&chars[i..], kind) else {
+                break;
+            };
+            code.push('"');
+            *open = None;
+            i += len;
+            continue;
+        }
+        let hashes = chars[i + 1..].iter().take_while(|c| **c == '#').count();
+        match chars[i] {
+            '"' => (*open, i) = (Some(Open::Plain), i + 1),
+            'r' if chars.get(i + 1 + hashes) == Some(&'"') => {
+                (*open, i) = (Some(Open::Raw(hashes)), i + 2 + hashes)
+            }
+            '\'' if chars.get(i + 1) == Some(&'\\') => {
+                let close = chars[i + 2..].iter().position(|c| *c == '\'');
+                code.push_str("''");
+                i += close.map_or(chars.len(), |p| p + 3);
+                continue;
+            }
+            '\'' if chars.get(i + 2) == Some(&'\'') => {
+                code.push_str("''");
+                i += 3;
+                continue;
+            }
+            c => {
+                code.push(c);
+                i += 1;
+                continue;
+            }
+        }
+        code.push('"');
+    }
+    code
+}
+
+/// Length of `rest` up to and including the quote closing an open string, if
+/// it closes on this line
+fn string_end(rest: &[char], kind: Open) -> Option<usize> {
+    let mut escaped = false;
+    for (i, c) in rest.iter().enumerate() {
+        match kind {
+            Open::Plain if *c == '"' && !escaped => return Some(i + 1),
+            Open::Raw(hashes)
+                if *c == '"'
+                    && rest[i + 1..].iter().take_while(|h| **h == '#').count() >= hashes =>
+            {
+                return Some(i + 1 + hashes);
+            }
+            _ => {}
+        }
+        escaped = matches!(kind, Open::Plain) && *c == '\\' && !escaped;
+    }
+    None
+}
@@ -0,0 +1,58 @@
+//! Rust source file collection
+
+use checklist_config::Config;
+use discovery_cargo::is_excluded;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+use crate::generated::is_generated;
+
+/// A Rust source file and its content
+#[derive(Debug, Clone)]
+pub struct RustSource {
+    /// Path to the file
+    pub path: PathBuf,
+    /// File content
+    pub content: String,
+}
+
+impl RustSource {
+    /// File name for display in messages
+    pub fn file_name(&self) -> String {
+        self.path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    }
+}
+
+/// Read all .rs files under `dir`, excluding generated code unless configured
+/// and paths matching an `exclude` glob
+pub fn rust_sources(dir: &Path, config: &Config) -> io::Result<Vec<RustSource>> {
+    let generated = &config.file().generated;
+    let mut sources = all_rust_sources(dir, config)?;
+    sources.retain(|s| {
+        generated.check || !(generated.matches_path(&s.path) || is_generated(&s.content))
+    });
+    Ok(sources)
+}
+
+/// Read all .rs files under `dir` outside `exclude` globs, generated code included
+pub fn all_rust_sources(dir: &Path, config: &Config) -> io::Result<Vec<RustSource>> {
+    let exclude = &config.file().exclude;
+    let entries = WalkDir::new(dir)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|e| !is_excluded(e.path(), config.project_root(), exclude))
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().extension().and_then(|s| s.to_str()) == Some("rs"));
+    entries
+        .map(|entry| {
+            let path = entry.into_path();
+            let content = fs::read_to_string(&path)?;
+            Ok(RustSource { path, content })
+        })
+        .collect()
+}
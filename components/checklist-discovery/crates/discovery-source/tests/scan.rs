@@ -0,0 +1,61 @@
+//! Source line scanning and generated-file detection
+
+use discovery_source::{is_generated, matching_lines};
+
+fn prints(content: &str) -> Vec<usize> {
+    matching_lines(content, |code| code.contains("println!"))
+}
+
+#[test]
+fn code_after_a_test_module_is_scanned() {
+    let source = r#"
+fn a() {}
+
+#[cfg(test)]
+mod tests {
+    fn helper() {
+        println!("in a test");
+    }
+}
+
+fn b() {
+    println!("after the tests");
+}
+"#;
+    assert_eq!(prints(source), [12]);
+}
+
+#[test]
+fn single_test_items_are_skipped() {
+    let source = "#[cfg(test)]\nuse std::println;\nfn run() { println!(\"x\"); }\n";
+    assert_eq!(prints(source), [3]);
+}
+
+#[test]
+fn literal_contents_are_ignored() {
+    let source = r###"
+let quote = '"'; println!("a");
+let table = r#"println!("not code")"#;
+let multi = "first line
+println!(still a string)";
+let after = 1; println!("b");
+let escaped = '\''; println!("c");
+"###;
+    assert_eq!(prints(source), [2, 6, 7]);
+}
+
+#[test]
+fn generated_only_by_header() {
+    assert!(is_generated(
+        "// This file is @generated by prost-build.\npub struct A;\n"
+    ));
+    assert!(is_generated(
+        "/* automatically generated by rust-bindgen */\n"
+    ));
+    assert!(!is_generated(
+        "//! Hand-written\n#[automatically_derived]\nimpl A {}\n"
+    ));
+    assert!(!is_generated(
+        "//! Build info\ninclude!(concat!(env!(\"OUT_DIR\"), \"/built.rs\"));\n"
+    ));
+}
@@ -0,0 +1,62 @@
+//! Rust source walking
+
+use checklist_config::{ConfigBuilder, FileConfig};
+use checklist_tempdir::TempWorkspace;
+use discovery_source::{all_rust_sources, rust_sources};
+use std::fs;
+use std::path::Path;
+
+const GENERATED: &str = "// @generated by protoc\npub struct Message;\n";
+
+/// A crate with hand-written, generated, and vendored sources
+fn project() -> TempWorkspace {
+    let workspace = TempWorkspace::new("sources").unwrap();
+    let src = workspace.path().join("src");
+    fs::create_dir_all(src.join("vendor")).unwrap();
+    fs::write(src.join("lib.rs"), "mod proto;\n").unwrap();
+    fs::write(src.join("proto.rs"), GENERATED).unwrap();
+    fs::write(src.join("vendor/dep.rs"), "").unwrap();
+    fs::write(src.join("notes.txt"), "").unwrap();
+    workspace
+}
+
+/// Source file names found under src/ with `exclude` globs
+fn found(root: &Path, exclude: &[&str], all: bool) -> Vec<String> {
+    let file = FileConfig {
+        exclude: exclude.iter().map(|e| e.to_string()).collect(),
+        ..FileConfig::default()
+    };
+    let config = ConfigBuilder::new()
+        .project_path(root.to_path_buf())
+        .file_config(file)
+        .build();
+    let walk = if all { all_rust_sources } else { rust_sources };
+    walk(&root.join("src"), &config)
+        .unwrap()
+        .iter()
+        .map(|s| s.path.strip_prefix(root).unwrap().display().to_string())
+        .collect()
+}
+
+#[test]
+fn generated_files_are_skipped_unless_asked_for() {
+    let workspace = project();
+    let root = workspace.path();
+    assert_eq!(found(root, &[], false), ["src/lib.rs", "src/vendor/dep.rs"]);
+    assert_eq!(
+        found(root, &[], true),
+        ["src/lib.rs", "src/proto.rs", "src/vendor/dep.rs"]
+    );
+}
+
+#[test]
+fn excluded_globs_are_not_walked() {
+    let workspace = project();
+    let root = workspace.path();
+    assert_eq!(found(root, &["vendor"], false), ["src/lib.rs"]);
+    assert_eq!(found(root, &["src/**/*.rs"], true), Vec::<String>::new());
+    assert_eq!(
+        found(root, &["src/proto.rs"], true),
+        ["src/lib.rs", "src/vendor/dep.rs"]
+    );
+}
@@ -0,0 +1,79 @@
+//! Per-crate handler dispatch
+
+use anyhow::Result;
+use checklist_config::Config;
+use checklist_result::CheckResult;
+use handler_trait::{CheckContext, Handler};
+use std::path::{Path, PathBuf};
+
+use crate::engine::settle;
+use crate::progress::Progress;
+use crate::setup::{create_handlers, resolve_crate};
+
+/// Run only the per-crate handlers on the given Cargo.toml files (one
+/// workspace, say), reporting progress; project-level handlers do not run
+pub fn check_crates_with(
+    config: &Config,
+    cargo_tomls: &[PathBuf],
+    report: &mut dyn FnMut(Progress),
+) -> Result<Vec<CheckResult>> {
+    let handlers = create_handlers();
+    let mut results = Vec::new();
+    for cargo_path in cargo_tomls {
+        results.extend(check_crate(config, cargo_path, &handlers, report)?);
+    }
+    settle(config, results)
+}
+
+/// Run every handler that applies to the crate at `cargo_path`
+fn check_crate(
+    config: &Config,
+    cargo_path: &Path,
+    handlers: &[Box<dyn Handler>],
+    report: &mut dyn FnMut(Progress),
+) -> Result<Vec<CheckResult>> {
+    let krate = resolve_crate(config, cargo_path)?;
+    report(Progress::Checking {
+        crate_name: &krate.name,
+        crate_type: krate.crate_type,
+    });
+
+    let ctx = CheckContext {
+        config,
+        crate_dir: cargo_path.parent().unwrap_or(Path::new(".")),
+        crate_name: &krate.name,
+        crate_type: krate.crate_type,
+        cargo_toml: &krate.cargo_toml,
+    };
+    run_handlers(&ctx, handlers, report)
+}
+
+fn run_handlers(
+    ctx: &CheckContext,
+    handlers: &[Box<dyn Handler>],
+    report: &mut dyn FnMut(Progress),
+) -> Result<Vec<CheckResult>> {
+    let mut results = Vec::new();
+    for handler in handlers {
+        match skip_reason(ctx, handler.as_ref()) {
+            Some(reason) => report(Progress::Skipped {
+                handler: handler.name(),
+                crate_name: ctx.crate_name,
+                reason: &reason,
+            }),
+            None => results.extend(handler.check(ctx)?),
+        }
+    }
+    Ok(results)
+}
+
+/// Why `handler` does not run for the crate in `ctx`, if it does not
+pub fn skip_reason(ctx: &CheckContext, handler: &dyn Handler) -> Option<String> {
+    if !ctx.config.file().selects(handler.name()) {
+        return Some("excluded by --only/--skip".to_string());
+    }
+    if !handler.handles(ctx.crate_type) {
+        return Some(format!("not for {:?} crates", ctx.crate_type));
+    }
+    handler.skip_reason(ctx)
+}
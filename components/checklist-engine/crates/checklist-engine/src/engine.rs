@@ -0,0 +1,73 @@
+//! Running every registered check on a project
+
+use anyhow::{Result, bail};
+use checklist_config::{Config, exempt_legacy, suppress};
+use checklist_result::{CheckResult, dedup_results};
+use discovery_cargo::find_cargo_tomls;
+use engine_baseline::{apply_baseline, load_baseline};
+use handler_ratchet::relax_ratcheted;
+use handler_trait::ProjectContext;
+use std::path::PathBuf;
+
+use crate::dispatch::check_crates_with;
+use crate::progress::Progress;
+use crate::setup::create_project_handlers;
+
+/// Run all checks on the configured project root, after exemptions,
+/// suppressions, and the baseline are applied
+pub fn run_checks(config: &Config) -> Result<Vec<CheckResult>> {
+    let cargo_tomls = find_cargo_tomls(config.project_root(), &config.file().exclude);
+    if cargo_tomls.is_empty() {
+        bail!("No Cargo.toml files found in {:?}", config.project_root());
+    }
+    check_project(config, &cargo_tomls)
+}
+
+/// Run all crate and project-level checks on the given Cargo.toml files
+pub fn check_project(config: &Config, cargo_tomls: &[PathBuf]) -> Result<Vec<CheckResult>> {
+    check_project_with(config, cargo_tomls, &mut |_| {})
+}
+
+/// [`check_project`], passing each crate checked and each handler skipped to `report`
+pub fn check_project_with(
+    config: &Config,
+    cargo_tomls: &[PathBuf],
+    report: &mut dyn FnMut(Progress),
+) -> Result<Vec<CheckResult>> {
+    let mut results = check_crates_with(config, cargo_tomls, report)?;
+    results.extend(check_project_level(config, cargo_tomls)?);
+    Ok(dedup_results(results))
+}
+
+/// Run only the project-level handlers, once, over every Cargo.toml in the
+/// project (callers that check workspaces one at a time with
+/// [`check_crates_with`] run this once for the whole project)
+pub fn check_project_level(config: &Config, cargo_tomls: &[PathBuf]) -> Result<Vec<CheckResult>> {
+    let ctx = ProjectContext {
+        config,
+        cargo_tomls,
+    };
+    let selected = create_project_handlers()
+        .into_iter()
+        .filter(|h| config.file().selects(h.name()));
+    let mut results = Vec::new();
+    for handler in selected {
+        results.extend(handler.check(&ctx)?);
+    }
+    settle(config, results)
+}
+
+/// Apply allow_legacy exemptions, suppressions, and the baseline, and drop exact
+/// duplicate findings (ratcheted checks are relaxed in ratchet mode)
+pub(crate) fn settle(config: &Config, mut results: Vec<CheckResult>) -> Result<Vec<CheckResult>> {
+    if config.ratchet() {
+        results = relax_ratcheted(results);
+    }
+    let baseline = load_baseline(config.project_root())?;
+    let results = results
+        .into_iter()
+        .map(|r| suppress(config, exempt_legacy(config, r), None))
+        .map(|r| apply_baseline(baseline.as_ref(), config.project_root(), r))
+        .collect();
+    Ok(dedup_results(results))
+}
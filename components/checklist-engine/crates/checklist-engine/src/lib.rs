@@ -0,0 +1,26 @@
+//! Embeddable check engine for sw-checklist
+//!
+//! Runs every registered handler over a project and returns the settled
+//! results, so editors, bots, and installers can embed the checker instead of
+//! shelling out to `sw-checklist` and parsing its output. Build a [`Config`]
+//! (usually from [`load_file_config`]), call [`run_checks`], and read the
+//! [`CheckResult`]s; [`check_project_with`] also reports [`Progress`] as it
+//! goes, and [`check_crates_with`] plus [`check_project_level`] split a run
+//! into per-workspace crate checks and one project-wide pass. Nothing is
+//! printed. [`Handler`] and [`CheckContext`] are re-exported for tools that
+//! drive individual handlers themselves.
+
+mod dispatch;
+mod engine;
+mod progress;
+mod setup;
+
+pub use checklist_config::{Config, ConfigBuilder, load_file_config};
+pub use checklist_result::{CheckResult, CheckStatus};
+pub use dispatch::{check_crates_with, skip_reason};
+pub use engine::{check_project, check_project_level, check_project_with, run_checks};
+pub use handler_trait::{CheckContext, Handler, ProjectContext, ProjectHandler};
+pub use progress::Progress;
+pub use setup::{
+    ResolvedCrate, create_handlers, create_project_handlers, resolve_crate, validate_selection,
+};
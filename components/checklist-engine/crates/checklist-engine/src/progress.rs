@@ -0,0 +1,37 @@
+//! Progress events reported while checks run
+
+use discovery_crate::CrateType;
+use std::fmt;
+
+/// What the engine is doing, for callers that show progress; the engine itself
+/// never prints. `Display` gives the line the CLI prints for it.
+#[derive(Debug, Clone, Copy)]
+pub enum Progress<'a> {
+    /// Handlers are about to run on a crate
+    Checking {
+        crate_name: &'a str,
+        crate_type: CrateType,
+    },
+    /// A handler does not run on a crate
+    Skipped {
+        handler: &'a str,
+        crate_name: &'a str,
+        reason: &'a str,
+    },
+}
+
+impl fmt::Display for Progress<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Progress::Checking {
+                crate_name,
+                crate_type,
+            } => write!(f, "Checking {} ({:?})", crate_name, crate_type),
+            Progress::Skipped {
+                handler,
+                crate_name,
+                reason,
+            } => write!(f, "  skipped {} for {}: {}", handler, crate_name, reason),
+        }
+    }
+}
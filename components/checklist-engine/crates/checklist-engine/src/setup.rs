@@ -0,0 +1,120 @@
+//! Handler registration and crate resolution
+
+use anyhow::{Result, anyhow, bail};
+use checklist_config::Config;
+use discovery_cargo::resolve_workspace_inheritance;
+use discovery_crate::{CrateType, detect_crate_type};
+use handler_trait::{Handler, ProjectHandler};
+use std::fs;
+use std::path::Path;
+
+/// Create all check handlers
+pub fn create_handlers() -> Vec<Box<dyn Handler>> {
+    vec![
+        Box::new(handler_cargo::CargoHandler),
+        Box::new(cargo_metadata::MetadataHandler),
+        Box::new(deps_version::VersionStyleHandler),
+        Box::new(deps_version::PatchHandler),
+        Box::new(handler_modularity::ModularityHandler),
+        Box::new(handler_clap::ClapHandler),
+        Box::new(handler_wasm::WasmHandler),
+        Box::new(handler_plugin::PluginHandler),
+        Box::new(handler_embedded::EmbeddedHandler),
+        Box::new(handler_grpc::GrpcHandler),
+        Box::new(handler_db::DbHandler),
+        Box::new(handler_tauri::TauriHandler),
+        Box::new(handler_egui::EguiHandler),
+        Box::new(handler_logging::LoggingHandler),
+        Box::new(handler_lint::LintHandler),
+        Box::new(lint_docs::ModuleDocsHandler),
+        Box::new(cargo_semver::SemverHandler),
+        Box::new(lint_deps::UnusedDepsHandler),
+        Box::new(lint_header::HeaderHandler),
+    ]
+}
+
+/// Create all project-level check handlers
+pub fn create_project_handlers() -> Vec<Box<dyn ProjectHandler>> {
+    vec![
+        Box::new(handler_deps::DepsHandler),
+        Box::new(handler_layout::LayoutHandler),
+        Box::new(handler_license::LicenseHandler),
+        Box::new(modularity_budget::LocBudgetHandler),
+        Box::new(modularity_budget::CrateCountHandler),
+        Box::new(modularity_budget::ComponentBudgetHandler),
+        Box::new(cargo_toolchain::ToolchainHandler),
+        Box::new(handler_hygiene::HygieneHandler),
+        Box::new(handler_hygiene::AgentDocsHandler),
+        Box::new(handler_ratchet::RatchetHandler),
+    ]
+}
+
+/// Fail on `only`/`skip` names that aren't registered crate or project handlers
+pub fn validate_selection(config: &Config) -> Result<()> {
+    let mut known: Vec<&str> = create_handlers().iter().map(|h| h.name()).collect();
+    known.extend(create_project_handlers().iter().map(|h| h.name()));
+    let file = config.file();
+    if let Some(unknown) = file
+        .only
+        .iter()
+        .chain(&file.skip)
+        .find(|h| !known.contains(&h.as_str()))
+    {
+        bail!(
+            "Unknown handler '{}' (known: {})",
+            unknown,
+            known.join(", ")
+        );
+    }
+    Ok(())
+}
+
+/// Extract crate name from Cargo.toml content
+pub fn extract_crate_name(cargo_toml: &str, crate_dir: &Path) -> String {
+    for line in cargo_toml.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("name")
+            && trimmed.contains('=')
+            && let Some(start) = trimmed.find('"')
+            && let Some(end) = trimmed[start + 1..].find('"')
+        {
+            return trimmed[start + 1..start + 1 + end].to_string();
+        }
+    }
+    crate_dir
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("unknown")
+        .to_string()
+}
+
+/// A crate's manifest (with workspace inheritance resolved), name, and type
+pub struct ResolvedCrate {
+    pub cargo_toml: String,
+    pub name: String,
+    pub crate_type: CrateType,
+}
+
+/// Read a crate's manifest and classify it, applying `crate_type_overrides`
+/// and reclassifying CLI crates listed as tooling in .sw-checklist.toml
+pub fn resolve_crate(config: &Config, cargo_path: &Path) -> Result<ResolvedCrate> {
+    let crate_dir = cargo_path.parent().unwrap_or(Path::new("."));
+    let cargo_toml = resolve_workspace_inheritance(&fs::read_to_string(cargo_path)?, crate_dir);
+    let name = extract_crate_name(&cargo_toml, crate_dir);
+    let crate_type = match config.file().crate_type_override(&name, crate_dir) {
+        Some(forced) => forced
+            .parse()
+            .map_err(|e| anyhow!("Invalid crate_type_overrides entry for {}: {}", name, e))?,
+        None => match detect_crate_type(&cargo_toml, crate_dir) {
+            CrateType::Cli if config.file().crates.is_tooling(&name, crate_dir) => {
+                CrateType::Tooling
+            }
+            other => other,
+        },
+    };
+    Ok(ResolvedCrate {
+        cargo_toml,
+        name,
+        crate_type,
+    })
+}
@@ -0,0 +1,99 @@
+//! Splitting a multi-workspace run into per-workspace crate checks and one
+//! project-wide pass
+
+use checklist_config::{ConfigBuilder, FileConfig};
+use checklist_engine::{Config, check_crates_with, check_project_level, check_project_with};
+use checklist_tempdir::TempWorkspace;
+use std::fs;
+use std::path::PathBuf;
+
+/// Two workspaces (`ws-a` with two crates, `ws-b` with one) under one root
+fn project() -> (TempWorkspace, Vec<PathBuf>, Vec<PathBuf>) {
+    let root = TempWorkspace::new("engine-test").unwrap();
+    let tomls = |ws: &str, crates: &[&str]| {
+        let dir = root.path().join(ws);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"crates/*\"]\n",
+        )
+        .unwrap();
+        let mut paths = vec![dir.join("Cargo.toml")];
+        for name in crates {
+            let krate = dir.join("crates").join(name);
+            fs::create_dir_all(krate.join("src")).unwrap();
+            fs::write(
+                krate.join("Cargo.toml"),
+                format!("[package]\nname = \"{}\"\nversion = \"0.1.0\"\n", name),
+            )
+            .unwrap();
+            fs::write(krate.join("src/lib.rs"), "pub fn f() {}\n").unwrap();
+            paths.push(krate.join("Cargo.toml"));
+        }
+        paths
+    };
+    let a = tomls("ws-a", &["alpha", "beta"]);
+    let b = tomls("ws-b", &["gamma"]);
+    (root, a, b)
+}
+
+fn config(root: &TempWorkspace) -> Config {
+    ConfigBuilder::new()
+        .project_path(root.path().to_path_buf())
+        .file_config(FileConfig {
+            only: vec!["modularity".to_string(), "crate-count".to_string()],
+            ..FileConfig::default()
+        })
+        .build()
+}
+
+fn names(results: &[checklist_engine::CheckResult]) -> Vec<&str> {
+    results.iter().map(|r| r.name.as_str()).collect()
+}
+
+#[test]
+fn crate_checks_run_no_project_handlers() {
+    let (root, a, _) = project();
+    let results = check_crates_with(&config(&root), &a, &mut |_| {}).unwrap();
+    let names = names(&results);
+    assert!(names.iter().any(|n| n.ends_with("[alpha]")), "{:?}", names);
+    assert!(!names.iter().any(|n| n.contains("[gamma]")), "{:?}", names);
+    assert!(
+        !names.iter().any(|n| n.contains("Crate Count")),
+        "{:?}",
+        names
+    );
+}
+
+#[test]
+fn project_level_sees_every_workspace_once() {
+    let (root, a, b) = project();
+    let all: Vec<PathBuf> = a.into_iter().chain(b).collect();
+    let results = check_project_level(&config(&root), &all).unwrap();
+    let names = names(&results);
+    assert_eq!(
+        names,
+        [
+            "Crate Count [ws-a]",
+            "Crate Count [ws-b]",
+            "Project Crate Count [project]"
+        ]
+    );
+    let total = results.last().unwrap();
+    assert!(
+        total.message.starts_with("3 crates across 2"),
+        "{}",
+        total.message
+    );
+}
+
+#[test]
+fn whole_project_is_crates_plus_project_level() {
+    let (root, a, b) = project();
+    let config = config(&root);
+    let all: Vec<PathBuf> = a.into_iter().chain(b).collect();
+    let whole = check_project_with(&config, &all, &mut |_| {}).unwrap();
+    let crates = check_crates_with(&config, &all, &mut |_| {}).unwrap();
+    let project = check_project_level(&config, &all).unwrap();
+    assert_eq!(whole.len(), crates.len() + project.len());
+}
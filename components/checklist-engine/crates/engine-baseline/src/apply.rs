@@ -0,0 +1,49 @@
+//! Matching results against the baseline
+
+use checklist_result::{CheckResult, Disposition};
+use std::path::Path;
+
+use crate::store::{Baseline, BaselineEntry};
+
+impl BaselineEntry {
+    /// Baseline entry for a result, with its file made relative to `project_root`
+    pub fn new(project_root: &Path, result: &CheckResult) -> Self {
+        let file = result.location.as_ref().map(|loc| {
+            let file = loc.file.strip_prefix(project_root).unwrap_or(&loc.file);
+            file.display().to_string()
+        });
+        Self {
+            name: result.name.clone(),
+            file,
+            message: result.message.clone(),
+        }
+    }
+}
+
+impl Baseline {
+    /// Whether the baseline records this result
+    pub fn contains(&self, project_root: &Path, result: &CheckResult) -> bool {
+        let entry = BaselineEntry::new(project_root, result);
+        self.findings.contains(&entry)
+    }
+}
+
+/// Turn a failure or warning recorded in the baseline into INFO
+pub fn apply_baseline(
+    baseline: Option<&Baseline>,
+    project_root: &Path,
+    result: CheckResult,
+) -> CheckResult {
+    let is_issue = !result.status.passed() || result.status.is_warning();
+    match baseline {
+        Some(baseline) if is_issue && baseline.contains(project_root, &result) => {
+            result.disposed(Disposition::Baselined)
+        }
+        _ => result,
+    }
+}
+
+/// Whether the result was turned into INFO by the baseline
+pub fn is_baselined(result: &CheckResult) -> bool {
+    result.disposition == Some(Disposition::Baselined)
+}
@@ -0,0 +1,10 @@
+//! Baseline of known findings for sw-checklist
+//!
+//! Findings recorded with `sw-checklist baseline write` are reported as INFO
+//! on later runs, so only new violations fail.
+
+mod apply;
+mod store;
+
+pub use apply::{apply_baseline, is_baselined};
+pub use store::{BASELINE_FILE_NAME, Baseline, BaselineEntry, load_baseline, write_baseline};
@@ -0,0 +1,59 @@
+//! Baseline file reading and writing
+
+use anyhow::{Context, Result};
+use checklist_result::CheckResult;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+use crate::apply::is_baselined;
+
+/// Baseline file name, at the project root
+pub const BASELINE_FILE_NAME: &str = ".sw-checklist-baseline.json";
+
+/// Contents of the baseline file
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Baseline {
+    #[serde(default)]
+    pub findings: Vec<BaselineEntry>,
+}
+
+/// A known finding, matched by check name, file, and message (not line number)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    /// Check name including its `[crate]` label
+    pub name: String,
+    /// File relative to the project root, if the finding has a location
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    /// Finding message
+    pub message: String,
+}
+
+/// Load the baseline file, or None if the project has not written one
+pub fn load_baseline(project_root: &Path) -> Result<Option<Baseline>> {
+    let path = project_root.join(BASELINE_FILE_NAME);
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content =
+        fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let baseline = serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse {}", path.display()))?;
+    Ok(Some(baseline))
+}
+
+/// Record every current failure and warning (including already baselined
+/// ones) as the new baseline, returning how many findings were recorded
+pub fn write_baseline(project_root: &Path, results: &[CheckResult]) -> Result<usize> {
+    let findings = results
+        .iter()
+        .filter(|r| !r.status.passed() || r.status.is_warning() || is_baselined(r))
+        .map(|r| BaselineEntry::new(project_root, r))
+        .collect();
+    let baseline = Baseline { findings };
+    let path = project_root.join(BASELINE_FILE_NAME);
+    fs::write(&path, serde_json::to_string_pretty(&baseline)? + "\n")
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(baseline.findings.len())
+}
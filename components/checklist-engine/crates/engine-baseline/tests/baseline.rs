@@ -0,0 +1,59 @@
+//! Writing the baseline and applying it to later runs
+
+use checklist_result::{CheckResult, CheckStatus};
+use checklist_tempdir::TempWorkspace;
+use engine_baseline::{apply_baseline, is_baselined, load_baseline, write_baseline};
+use std::path::Path;
+
+fn long_function(root: &Path, line: usize) -> CheckResult {
+    let message = "Function 'run' has 60 lines (max 50)";
+    CheckResult::fail("Function LOC [demo]", message).at(root.join("src/lib.rs"), line)
+}
+
+fn edition() -> CheckResult {
+    CheckResult::warn("Rust Edition [demo]", "Edition 2021")
+}
+
+#[test]
+fn projects_without_a_baseline_report_everything() {
+    let workspace = TempWorkspace::new("baseline").unwrap();
+    let root = workspace.path();
+    assert!(load_baseline(root).unwrap().is_none());
+    let result = apply_baseline(None, root, edition());
+    assert_eq!(result.status, CheckStatus::Warn);
+}
+
+#[test]
+fn recorded_findings_become_info_even_when_they_move() {
+    let workspace = TempWorkspace::new("baseline").unwrap();
+    let root = workspace.path();
+    let recorded = [
+        long_function(root, 10),
+        edition(),
+        CheckResult::pass("README [demo]", "Found"),
+    ];
+    assert_eq!(write_baseline(root, &recorded).unwrap(), 2);
+    let baseline = load_baseline(root).unwrap().unwrap();
+    assert_eq!(baseline.findings[0].file.as_deref(), Some("src/lib.rs"));
+
+    let moved = apply_baseline(Some(&baseline), root, long_function(root, 25));
+    assert_eq!(moved.status, CheckStatus::Info);
+    assert!(is_baselined(&moved));
+    assert_eq!(moved.message, "Function 'run' has 60 lines (max 50)");
+    let new = CheckResult::fail("Function LOC [demo]", "Function 'parse' has 70 lines");
+    let new = apply_baseline(Some(&baseline), root, new);
+    assert_eq!(new.status, CheckStatus::Fail);
+}
+
+#[test]
+fn rewriting_keeps_baselined_findings() {
+    let workspace = TempWorkspace::new("baseline").unwrap();
+    let root = workspace.path();
+    write_baseline(root, &[edition()]).unwrap();
+    let baseline = load_baseline(root).unwrap().unwrap();
+    let rerun = [apply_baseline(Some(&baseline), root, edition())];
+    assert_eq!(write_baseline(root, &rerun).unwrap(), 1);
+    let rewritten = load_baseline(root).unwrap().unwrap();
+    assert_eq!(rewritten.findings, baseline.findings);
+    assert_eq!(rewritten.findings[0].message, "Edition 2021");
+}
@@ -1,15 +1,93 @@
 //! Rust edition check
 
 use checklist_result::CheckResult;
+use std::fs;
+use std::path::{Path, PathBuf};
 
 use crate::extract::extract_edition;
 
-/// Check that Rust edition is 2024 (required for all new projects)
-pub fn check_rust_edition(cargo_toml: &str, crate_name: &str) -> CheckResult {
+/// Check that Rust edition is 2024 (required for all new projects); with
+/// `fix`, rewrite an older `edition` in the crate's Cargo.toml (or, for
+/// `edition.workspace = true`, in the workspace root's) and report it
+pub fn check_rust_edition(
+    cargo_toml: &str,
+    crate_dir: &Path,
+    crate_name: &str,
+    fix: bool,
+) -> CheckResult {
     let label = format!("Rust Edition [{}]", crate_name);
     match extract_edition(cargo_toml) {
         Some("2024") => CheckResult::pass(label, "Using Rust 2024 edition"),
-        Some(old) => CheckResult::fail(label, format!("Using Rust {} edition (must use 2024)", old)),
+        Some(old) if fix && bump_edition(&crate_dir.join("Cargo.toml"), old) => {
+            CheckResult::info(label, format!("Fixed Cargo.toml: edition {} -> 2024", old))
+        }
+        Some(old) => {
+            CheckResult::fail(label, format!("Using Rust {} edition (must use 2024)", old))
+        }
+        None if inherits_edition(cargo_toml) => inherited_edition(label, crate_dir, fix),
         None => CheckResult::pass(label, "No edition specified (inherits from workspace)"),
     }
 }
+
+/// Whether the manifest sets `edition.workspace = true` (or the inline-table form)
+fn inherits_edition(cargo_toml: &str) -> bool {
+    cargo_toml.lines().any(|line| {
+        let line = line.trim_start();
+        line.starts_with("edition") && line.contains("workspace")
+    })
+}
+
+/// Check the `[workspace.package]` edition a crate inherits from the workspace
+/// root above `crate_dir`, bumping it there with `fix`
+fn inherited_edition(label: String, crate_dir: &Path, fix: bool) -> CheckResult {
+    let Some(root) = workspace_manifest(crate_dir) else {
+        return CheckResult::pass(label, "No edition specified (inherits from workspace)");
+    };
+    let root = root.as_path();
+    let text = fs::read_to_string(root).unwrap_or_default();
+    let shown = root.display();
+    match extract_edition(&text) {
+        Some("2024") => CheckResult::pass(label, "Using Rust 2024 edition (from workspace)"),
+        Some(old) if fix && bump_edition(root, old) => CheckResult::info(
+            label,
+            format!("Fixed {}: workspace edition {} -> 2024", shown, old),
+        ),
+        Some(old) => CheckResult::fail(
+            label,
+            format!("Using Rust {} edition from {} (must use 2024)", old, shown),
+        ),
+        None => CheckResult::pass(label, "No edition specified (inherits from workspace)"),
+    }
+}
+
+/// Nearest Cargo.toml with a `[workspace]` table above `crate_dir`
+fn workspace_manifest(crate_dir: &Path) -> Option<PathBuf> {
+    crate_dir
+        .ancestors()
+        .skip(1)
+        .map(|dir| dir.join("Cargo.toml"))
+        .find(|manifest| {
+            fs::read_to_string(manifest)
+                .is_ok_and(|text| text.lines().any(|l| l.trim() == "[workspace]"))
+        })
+}
+
+/// Replace the first `edition = "<old>"` line with 2024; false if nothing was
+/// written (true if the manifest was already bumped, e.g. via another member)
+fn bump_edition(manifest: &Path, old: &str) -> bool {
+    let Ok(text) = fs::read_to_string(manifest) else {
+        return false;
+    };
+    if extract_edition(&text) == Some("2024") {
+        return true;
+    }
+    let from = format!("\"{}\"", old);
+    let Some(line) = text
+        .lines()
+        .find(|l| l.trim().starts_with("edition") && l.contains(&from))
+    else {
+        return false;
+    };
+    let fixed = text.replacen(line, &line.replace(&from, "\"2024\""), 1);
+    fs::write(manifest, fixed).is_ok()
+}
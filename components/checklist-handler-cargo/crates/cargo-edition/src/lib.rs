@@ -1,6 +1,8 @@
-//! Rust edition checking for Cargo.toml
+//! Rust edition and resolver checking for Cargo.toml
 
 mod check;
 mod extract;
+mod resolver;
 
 pub use check::check_rust_edition;
+pub use resolver::check_workspace_resolver;
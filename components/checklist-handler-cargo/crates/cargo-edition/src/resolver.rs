@@ -0,0 +1,59 @@
+//! Workspace resolver version check
+
+use checklist_result::CheckResult;
+
+use crate::extract::extract_edition;
+
+/// Check that a workspace Cargo.toml opts out of the implicit v1 resolver
+///
+/// Returns `None` for manifests without a `[workspace]` table.
+pub fn check_workspace_resolver(cargo_toml: &str, crate_name: &str) -> Option<CheckResult> {
+    if !cargo_toml.lines().any(|l| l.trim() == "[workspace]") {
+        return None;
+    }
+    let label = format!("Workspace Resolver [{}]", crate_name);
+    Some(resolver_result(label, cargo_toml))
+}
+
+fn resolver_result(label: String, cargo_toml: &str) -> CheckResult {
+    let declared = extract_resolver(cargo_toml);
+    if declared.is_none() && implies_resolver_v2(cargo_toml) {
+        return CheckResult::pass(label, "Resolver implied by the root package edition");
+    }
+    let current = match declared {
+        Some("2" | "3") => {
+            return CheckResult::pass(label, "Workspace declares resolver 2 or later");
+        }
+        Some(v) => format!("resolver {}", v),
+        None => "the implicit v1 resolver".to_string(),
+    };
+    let message = format!(
+        "Workspace uses {}; set resolver = \"2\" (or \"3\" for edition 2024)",
+        current
+    );
+    CheckResult::fail(label, message)
+}
+
+/// A root package on edition 2021+ sets the resolver for the whole workspace
+fn implies_resolver_v2(cargo_toml: &str) -> bool {
+    cargo_toml.lines().any(|l| l.trim() == "[package]")
+        && matches!(extract_edition(cargo_toml), Some("2021" | "2024"))
+}
+
+/// Extract `resolver` from the `[workspace]` table
+fn extract_resolver(cargo_toml: &str) -> Option<&str> {
+    let mut in_workspace = false;
+    for line in cargo_toml.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('[') {
+            in_workspace = trimmed == "[workspace]";
+        } else if in_workspace
+            && trimmed.starts_with("resolver")
+            && let Some(start) = trimmed.find('"')
+            && let Some(end) = trimmed[start + 1..].find('"')
+        {
+            return Some(&trimmed[start + 1..start + 1 + end]);
+        }
+    }
+    None
+}
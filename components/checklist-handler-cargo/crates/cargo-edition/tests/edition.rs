@@ -0,0 +1,51 @@
+use cargo_edition::check_rust_edition;
+use checklist_result::CheckStatus;
+use checklist_tempdir::TempWorkspace;
+use std::fs;
+use std::path::Path;
+
+const MEMBER: &str = "[package]\nname = \"demo\"\nedition.workspace = true\n";
+
+/// A workspace whose root sets `edition`, with one member at crates/demo
+fn workspace(edition: &str) -> TempWorkspace {
+    let workspace = TempWorkspace::new("edition").unwrap();
+    let root = workspace.path();
+    let root_toml = format!(
+        "[workspace]\nmembers = [\"crates/demo\"]\n\n[workspace.package]\nedition = \"{}\"\n",
+        edition
+    );
+    fs::write(root.join("Cargo.toml"), root_toml).unwrap();
+    fs::create_dir_all(root.join("crates/demo")).unwrap();
+    fs::write(root.join("crates/demo/Cargo.toml"), MEMBER).unwrap();
+    workspace
+}
+
+fn member(workspace: &TempWorkspace) -> std::path::PathBuf {
+    workspace.path().join("crates/demo")
+}
+
+fn root_edition(root: &Path) -> String {
+    fs::read_to_string(root.join("Cargo.toml")).unwrap()
+}
+
+#[test]
+fn inherited_edition_is_read_from_the_workspace_root() {
+    let current = workspace("2024");
+    let result = check_rust_edition(MEMBER, &member(&current), "demo", false);
+    assert_eq!(result.status, CheckStatus::Pass, "{}", result.message);
+
+    let old = workspace("2021");
+    let result = check_rust_edition(MEMBER, &member(&old), "demo", false);
+    assert_eq!(result.status, CheckStatus::Fail, "{}", result.message);
+    assert!(result.message.contains("2021"), "{}", result.message);
+}
+
+#[test]
+fn fix_bumps_the_workspace_root_edition() {
+    let old = workspace("2021");
+    let result = check_rust_edition(MEMBER, &member(&old), "demo", true);
+    assert_eq!(result.status, CheckStatus::Info, "{}", result.message);
+    assert!(root_edition(old.path()).contains("edition = \"2024\""));
+    let member_toml = fs::read_to_string(member(&old).join("Cargo.toml")).unwrap();
+    assert_eq!(member_toml, MEMBER);
+}
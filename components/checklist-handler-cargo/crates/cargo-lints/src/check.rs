@@ -0,0 +1,47 @@
+//! Baseline lint configuration check
+
+use checklist_result::CheckResult;
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use crate::declared::declared_lints;
+
+/// Check a crate declares the baseline lints via `[lints]` or crate attributes
+///
+/// Fails when the crate silences everything with `#![allow(warnings)]`; warns
+/// when it declares no lints at all or some baseline lints are missing.
+pub fn check_lint_config(
+    cargo_toml: &str,
+    crate_dir: &Path,
+    crate_name: &str,
+    baseline: &[String],
+) -> CheckResult {
+    let label = format!("Lint Config [{}]", crate_name);
+    grade(label, &declared_lints(cargo_toml, crate_dir), baseline)
+}
+
+fn grade(label: String, declared: &BTreeSet<String>, baseline: &[String]) -> CheckResult {
+    if declared.contains("allow:warnings") {
+        return CheckResult::fail(label, "#![allow(warnings)] silences every lint; remove it");
+    }
+    if declared.is_empty() {
+        let message = format!(
+            "No lints configured; add a [lints] table (or lints.workspace = true) with {}",
+            baseline.join(", ")
+        );
+        return CheckResult::warn(label, message);
+    }
+    let missing: Vec<&str> = baseline
+        .iter()
+        .filter(|lint| !declared.contains(*lint))
+        .map(String::as_str)
+        .collect();
+    if missing.is_empty() {
+        CheckResult::pass(label, "Baseline lints are configured")
+    } else {
+        CheckResult::warn(
+            label,
+            format!("Missing baseline lints: {}", missing.join(", ")),
+        )
+    }
+}
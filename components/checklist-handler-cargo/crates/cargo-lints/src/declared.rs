@@ -0,0 +1,91 @@
+//! Lints a crate declares
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+/// Crate root files whose inner attributes apply to the whole crate
+const CRATE_ROOTS: &[&str] = &["src/lib.rs", "src/main.rs"];
+
+/// Lint levels usable in crate-level attributes
+const LEVELS: &[&str] = &["allow", "warn", "deny", "forbid", "expect"];
+
+/// Lint names declared via `[lints]` or crate-level attributes, as `rust` lints
+/// (`missing_docs`) or tool lints (`clippy::unwrap_used`)
+///
+/// A crate-level `#![allow(warnings)]` is recorded as `allow:warnings`.
+pub fn declared_lints(cargo_toml: &str, crate_dir: &Path) -> BTreeSet<String> {
+    let mut lints = manifest_lints(cargo_toml, crate_dir);
+    for root in CRATE_ROOTS {
+        let content = fs::read_to_string(crate_dir.join(root)).unwrap_or_default();
+        lints.extend(attribute_lints(&content));
+    }
+    lints
+}
+
+/// `[lints]` entries as `lint` or `tool::lint`
+fn manifest_lints(cargo_toml: &str, crate_dir: &Path) -> BTreeSet<String> {
+    let table = lints_table(cargo_toml, crate_dir);
+    let tools = table
+        .as_ref()
+        .and_then(|t| t.as_table())
+        .into_iter()
+        .flatten();
+    tools
+        .flat_map(|(tool, lints)| {
+            let prefix = if tool == "rust" {
+                String::new()
+            } else {
+                format!("{}::", tool)
+            };
+            let names = lints.as_table().into_iter().flat_map(|t| t.keys());
+            names
+                .map(move |name| format!("{}{}", prefix, name))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// The crate's `[lints]` table, following `workspace = true` to the nearest `[workspace.lints]`
+fn lints_table(cargo_toml: &str, crate_dir: &Path) -> Option<toml::Value> {
+    let lints = cargo_toml
+        .parse::<toml::Value>()
+        .ok()?
+        .get("lints")?
+        .clone();
+    if lints.get("workspace").is_none() {
+        return Some(lints);
+    }
+    crate_dir.ancestors().skip(1).find_map(|dir| {
+        let content = fs::read_to_string(dir.join("Cargo.toml")).ok()?;
+        content
+            .parse::<toml::Value>()
+            .ok()?
+            .get("workspace")?
+            .get("lints")
+            .cloned()
+    })
+}
+
+/// Lints named in `#![level(...)]` attributes; `allow(warnings)` is kept as `allow:warnings`
+fn attribute_lints(content: &str) -> BTreeSet<String> {
+    let mut lints = BTreeSet::new();
+    for line in content.lines().map(str::trim) {
+        let Some(attr) = line.strip_prefix("#![").and_then(|l| l.strip_suffix(")]")) else {
+            continue;
+        };
+        let Some((level, names)) = attr.split_once('(') else {
+            continue;
+        };
+        if !LEVELS.contains(&level) {
+            continue;
+        }
+        for name in names.split(',').map(str::trim).filter(|n| !n.is_empty()) {
+            if name == "warnings" && level == "allow" {
+                lints.insert("allow:warnings".to_string());
+            }
+            lints.insert(name.to_string());
+        }
+    }
+    lints
+}
@@ -0,0 +1,10 @@
+//! Lint configuration checking for crates
+//!
+//! Collects lints declared in `[lints]` (including `workspace = true`
+//! inheritance) and crate-level attributes, and compares them with the
+//! org's baseline.
+
+mod check;
+mod declared;
+
+pub use check::check_lint_config;
@@ -0,0 +1,55 @@
+//! `[package]` field presence
+
+use checklist_result::CheckResult;
+use toml::Value;
+
+/// Fields whose absence fails the check (`license-file` stands in for `license`)
+const REQUIRED: &[&str] = &["repository", "license"];
+/// Fields whose absence warns
+const RECOMMENDED: &[&str] = &["description"];
+/// Fields noted in the message when absent
+const OPTIONAL: &[&str] = &["authors", "keywords"];
+
+/// Grade a (workspace-inheritance resolved) Cargo.toml's package metadata
+pub fn check_package_metadata(cargo_toml: &str, crate_name: &str) -> CheckResult {
+    let label = format!("Package Metadata [{}]", crate_name);
+    let cargo = cargo_toml.parse::<Value>().ok();
+    let package = cargo.as_ref().and_then(|c| c.get("package"));
+    let missing = |fields| missing_fields(package, fields);
+    let (required, recommended) = (missing(REQUIRED), missing(RECOMMENDED));
+    let optional = missing(OPTIONAL);
+    let note = if optional.is_empty() {
+        String::new()
+    } else {
+        format!(" (no {})", optional.join(", "))
+    };
+    if !required.is_empty() {
+        CheckResult::fail(label, format!("Missing {}{}", required.join(", "), note))
+    } else if !recommended.is_empty() {
+        CheckResult::warn(label, format!("Missing {}{}", recommended.join(", "), note))
+    } else {
+        CheckResult::pass(
+            label,
+            format!("Has repository, license, and description{}", note),
+        )
+    }
+}
+
+/// Fields not set to a non-empty value (all of them without a `[package]`)
+fn missing_fields(package: Option<&Value>, fields: &[&'static str]) -> Vec<&'static str> {
+    fields
+        .iter()
+        .copied()
+        .filter(|f| !package.is_some_and(|p| has_field(p, f)))
+        .collect()
+}
+
+/// Whether `field` is set to a non-empty value
+fn has_field(package: &Value, field: &str) -> bool {
+    match package.get(field) {
+        Some(Value::String(s)) => !s.trim().is_empty(),
+        Some(Value::Array(items)) => !items.is_empty(),
+        Some(_) => true,
+        None => field == "license" && package.get("license-file").is_some(),
+    }
+}
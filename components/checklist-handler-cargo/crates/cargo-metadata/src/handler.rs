@@ -0,0 +1,25 @@
+//! Metadata handler implementation
+
+use anyhow::Result;
+use checklist_result::CheckResult;
+use discovery_crate::CrateType;
+use handler_trait::{CheckContext, Handler};
+
+use crate::fields::check_package_metadata;
+
+/// Handler for description, repository, license, authors, and keywords in Cargo.toml
+pub struct MetadataHandler;
+
+impl Handler for MetadataHandler {
+    fn name(&self) -> &'static str {
+        "cargo-metadata"
+    }
+
+    fn handles(&self, crate_type: CrateType) -> bool {
+        crate_type != CrateType::Workspace
+    }
+
+    fn check(&self, ctx: &CheckContext) -> Result<Vec<CheckResult>> {
+        Ok(vec![check_package_metadata(ctx.cargo_toml, ctx.crate_name)])
+    }
+}
@@ -0,0 +1,10 @@
+//! Package metadata checks for sw-checklist
+//!
+//! Checks each package's Cargo.toml for the fields a published crate needs:
+//! repository and license are required, a description is expected, and
+//! authors and keywords are noted when missing.
+
+mod fields;
+mod handler;
+
+pub use handler::MetadataHandler;
@@ -0,0 +1,42 @@
+use cargo_metadata::MetadataHandler;
+use checklist_result::CheckStatus;
+use checklist_testkit::{TestCrate, assert_status};
+
+/// A library whose `[package]` has `package` after its name and version
+fn demo(package: &str) -> TestCrate {
+    let manifest = format!(
+        "[package]\nname = \"demo\"\nversion = \"0.1.0\"\n{}",
+        package
+    );
+    TestCrate::with_manifest(&manifest)
+        .unwrap()
+        .file("src/lib.rs", "")
+        .unwrap()
+}
+
+#[test]
+fn missing_license_or_repository_fails() {
+    let results = demo("description = \"Demo\"\nlicense = \"MIT\"\n")
+        .check(&MetadataHandler)
+        .unwrap();
+    assert_status(&results, "Package Metadata", CheckStatus::Fail);
+    assert!(
+        results[0].message.contains("repository"),
+        "{}",
+        results[0].message
+    );
+}
+
+#[test]
+fn missing_description_warns() {
+    let results = demo("license = \"MIT\"\nrepository = \"https://example.com/demo\"\n")
+        .check(&MetadataHandler)
+        .unwrap();
+    assert_status(&results, "Package Metadata", CheckStatus::Warn);
+}
+
+#[test]
+fn complete_metadata_passes() {
+    let results = demo("description = \"Demo\"\nlicense-file = \"LICENSE\"\nrepository = \"https://example.com/demo\"\n").check(&MetadataHandler).unwrap();
+    assert_status(&results, "Package Metadata", CheckStatus::Pass);
+}
@@ -0,0 +1,7 @@
+//! Release profile checking for Cargo.toml
+
+mod policy;
+mod release;
+
+pub use policy::check_release_policy;
+pub use release::{NATIVE_RELEASE, ReleaseSetting, WASM_RELEASE, check_release_profile};
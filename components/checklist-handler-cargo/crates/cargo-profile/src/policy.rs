@@ -0,0 +1,59 @@
+//! `[profile.release]` panic strategy and overflow-check policy
+
+use checklist_config::{PanicStrategy, ReleaseProfileConfig};
+use checklist_result::CheckResult;
+use std::fs;
+use std::path::Path;
+
+use crate::release::profile_manifest;
+
+/// Check the release profile governing a crate against the `[release_profile]`
+/// policy: the required `panic` strategy, and `overflow-checks = true` so
+/// production arithmetic panics instead of silently wrapping
+pub fn check_release_policy(
+    crate_dir: &Path,
+    crate_name: &str,
+    policy: &ReleaseProfileConfig,
+) -> CheckResult {
+    let label = format!("Release Policy [{}]", crate_name);
+    let manifest = profile_manifest(crate_dir);
+    let content = fs::read_to_string(&manifest).unwrap_or_default();
+    let profile = content
+        .parse::<toml::Value>()
+        .ok()
+        .and_then(|v| v.get("profile")?.get("release").cloned());
+    let violations = policy_violations(profile.as_ref(), policy);
+    if violations.is_empty() {
+        return CheckResult::pass(label, "Release profile meets the panic/overflow policy");
+    }
+    let line = content
+        .lines()
+        .position(|l| l.trim() == "[profile.release]");
+    CheckResult::warn(label, violations.join("; ")).at(&manifest, line.map_or(1, |l| l + 1))
+}
+
+/// Policy violations in the profile, each with the setting that fixes it
+fn policy_violations(profile: Option<&toml::Value>, policy: &ReleaseProfileConfig) -> Vec<String> {
+    let setting = |key: &str| profile.and_then(|p| p.get(key));
+    let mut violations = Vec::new();
+    if policy.overflow_checks && setting("overflow-checks").and_then(|v| v.as_bool()) != Some(true)
+    {
+        violations
+            .push("arithmetic silently wraps on overflow (set overflow-checks = true)".to_string());
+    }
+    let panic = setting("panic")
+        .and_then(|v| v.as_str())
+        .unwrap_or("unwind");
+    let required = match policy.panic {
+        Some(PanicStrategy::Abort) => "abort",
+        Some(PanicStrategy::Unwind) => "unwind",
+        None => panic,
+    };
+    if panic != required {
+        violations.push(format!(
+            "panic strategy is {} (set panic = \"{}\")",
+            panic, required
+        ));
+    }
+    violations
+}
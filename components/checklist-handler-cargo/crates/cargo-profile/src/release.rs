@@ -0,0 +1,71 @@
+//! `[profile.release]` convention check
+
+use checklist_result::CheckResult;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A release profile key and the values that satisfy it
+pub type ReleaseSetting = (&'static str, &'static [&'static str]);
+
+/// Settings expected for installable native binaries
+pub const NATIVE_RELEASE: &[ReleaseSetting] = &[
+    ("lto", &["true", "\"fat\"", "\"thin\""]),
+    ("codegen-units", &["1"]),
+    ("strip", &["true", "\"symbols\"", "\"debuginfo\""]),
+];
+
+/// Settings expected for WASM bundles
+pub const WASM_RELEASE: &[ReleaseSetting] = &[("opt-level", &["\"s\"", "\"z\""])];
+
+/// Check the release profile governing a crate has the expected settings
+///
+/// Profiles only take effect in the workspace root, so the nearest manifest
+/// with a `[workspace]` table is checked (or the crate's own manifest).
+pub fn check_release_profile(
+    crate_dir: &Path,
+    crate_name: &str,
+    expected: &[ReleaseSetting],
+) -> CheckResult {
+    let label = format!("Release Profile [{}]", crate_name);
+    let manifest = profile_manifest(crate_dir);
+    let content = fs::read_to_string(&manifest).unwrap_or_default();
+    let profile = content
+        .parse::<toml::Value>()
+        .ok()
+        .and_then(|v| v.get("profile")?.get("release").cloned());
+    let missing = missing_settings(profile.as_ref(), expected);
+    if missing.is_empty() {
+        return CheckResult::pass(label, "Release profile is optimized");
+    }
+    let message = format!(
+        "[profile.release] ships unoptimized defaults; set {}",
+        missing.join(", ")
+    );
+    let line = content
+        .lines()
+        .position(|l| l.trim() == "[profile.release]");
+    CheckResult::warn(label, message).at(&manifest, line.map_or(1, |l| l + 1))
+}
+
+/// Expected settings absent from the profile, rendered as `key = value`
+fn missing_settings(profile: Option<&toml::Value>, expected: &[ReleaseSetting]) -> Vec<String> {
+    expected
+        .iter()
+        .filter(|(key, accepted)| {
+            let value = profile.and_then(|p| p.get(*key));
+            !value.is_some_and(|v| accepted.contains(&v.to_string().as_str()))
+        })
+        .map(|(key, accepted)| format!("{} = {}", key, accepted[0]))
+        .collect()
+}
+
+/// The workspace root manifest for a crate, falling back to its own
+pub(crate) fn profile_manifest(crate_dir: &Path) -> PathBuf {
+    crate_dir
+        .ancestors()
+        .map(|dir| dir.join("Cargo.toml"))
+        .find(|path| {
+            fs::read_to_string(path).is_ok_and(|c| c.lines().any(|l| l.trim() == "[workspace]"))
+        })
+        .unwrap_or_else(|| crate_dir.join("Cargo.toml"))
+}
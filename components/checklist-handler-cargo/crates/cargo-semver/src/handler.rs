@@ -0,0 +1,56 @@
+//! Semver handler implementation
+
+use anyhow::Result;
+use checklist_result::CheckResult;
+use discovery_crate::CrateType;
+use handler_trait::{CheckContext, Handler};
+
+use crate::run::{SemverOutcome, is_installed, latest_tag, semver_checks};
+
+/// Opt-in handler running cargo-semver-checks against the last release
+pub struct SemverHandler;
+
+impl Handler for SemverHandler {
+    fn name(&self) -> &'static str {
+        "semver"
+    }
+
+    fn handles(&self, crate_type: CrateType) -> bool {
+        crate_type == CrateType::Library
+    }
+
+    fn skip_reason(&self, ctx: &CheckContext) -> Option<String> {
+        let enabled = ctx.config.file().semver.check;
+        (!enabled).then(|| "disabled by config ([semver] check = false)".to_string())
+    }
+
+    fn check(&self, ctx: &CheckContext) -> Result<Vec<CheckResult>> {
+        let label = format!("Semver [{}]", ctx.crate_name);
+        if !is_installed() {
+            let message = "cargo-semver-checks not installed (cargo install cargo-semver-checks)";
+            return Ok(vec![CheckResult::info(label, message)]);
+        }
+        let baseline = latest_tag(ctx.config.project_root());
+        let against = baseline.as_deref().unwrap_or("the published version");
+        let outcome = semver_checks(ctx.crate_dir, ctx.crate_name, baseline.as_deref());
+        let result = outcome_result(label, outcome, against);
+        Ok(vec![result])
+    }
+}
+
+fn outcome_result(label: String, outcome: SemverOutcome, against: &str) -> CheckResult {
+    match outcome {
+        SemverOutcome::Compatible => {
+            CheckResult::pass(label, format!("No breaking changes since {}", against))
+        }
+        SemverOutcome::Breaking(failures) => CheckResult::fail(
+            label,
+            format!(
+                "Breaking changes since {} without a matching version bump: {}",
+                against,
+                failures.join(", ")
+            ),
+        ),
+        SemverOutcome::Error(e) => CheckResult::info(label, format!("Not checked: {}", e)),
+    }
+}
@@ -0,0 +1,9 @@
+//! Semver compatibility checks for sw-checklist
+//!
+//! Runs `cargo semver-checks` for library crates when enabled with
+//! `[semver] check = true` in .sw-checklist.toml.
+
+mod handler;
+mod run;
+
+pub use handler::SemverHandler;
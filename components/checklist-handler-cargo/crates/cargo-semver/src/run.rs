@@ -0,0 +1,74 @@
+//! Running cargo-semver-checks
+
+use checklist_exec::{Exec, git};
+use std::path::Path;
+use std::time::Duration;
+
+/// Longest time one crate's semver check may take (it builds rustdoc JSON for
+/// both revisions)
+const SEMVER_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// Result of a cargo-semver-checks run
+#[derive(Debug, Clone)]
+pub enum SemverOutcome {
+    /// The version bump covers every change
+    Compatible,
+    /// Names of the failed lints
+    Breaking(Vec<String>),
+    /// The tool couldn't run (no baseline, build error, ...)
+    Error(String),
+}
+
+/// Check if `cargo semver-checks` is available
+pub fn is_installed() -> bool {
+    Exec::new("cargo")
+        .args(["semver-checks", "--version"])
+        .run()
+        .is_ok_and(|o| o.success())
+}
+
+/// The highest `v*` tag in the repository, if any
+pub fn latest_tag(root: &Path) -> Option<String> {
+    let tags = git(root, &["tag", "--list", "v*", "--sort=-v:refname"]).ok()?;
+    tags.lines().next().map(str::to_string)
+}
+
+/// Run cargo-semver-checks for one crate against `baseline_rev` (or crates.io)
+pub fn semver_checks(
+    crate_dir: &Path,
+    crate_name: &str,
+    baseline_rev: Option<&str>,
+) -> SemverOutcome {
+    let baseline = baseline_rev.map(|rev| ["--baseline-rev", rev]);
+    let output = Exec::new("cargo")
+        .args(["semver-checks", "--package", crate_name, "--manifest-path"])
+        .args([crate_dir.join("Cargo.toml")])
+        .args(baseline.into_iter().flatten())
+        .timeout(SEMVER_TIMEOUT)
+        .run();
+    match output {
+        Ok(output) if output.success() => SemverOutcome::Compatible,
+        Ok(output) => parse_outcome(&format!("{}{}", output.stdout, output.stderr)),
+        Err(e) => SemverOutcome::Error(e.to_string()),
+    }
+}
+
+/// Failed lints are reported as `--- failure <lint>: <description> ---`
+fn parse_outcome(text: &str) -> SemverOutcome {
+    let failures: Vec<String> = text
+        .lines()
+        .filter_map(|l| l.trim().strip_prefix("--- failure "))
+        .filter_map(|l| l.split(':').next())
+        .map(str::to_string)
+        .collect();
+    if failures.is_empty() {
+        let last = text
+            .lines()
+            .rev()
+            .find(|l| !l.trim().is_empty())
+            .unwrap_or("failed");
+        SemverOutcome::Error(last.trim().to_string())
+    } else {
+        SemverOutcome::Breaking(failures)
+    }
+}
@@ -0,0 +1,15 @@
+use cargo_semver::SemverHandler;
+use checklist_config::FileConfig;
+use checklist_testkit::TestCrate;
+
+#[test]
+fn semver_checks_are_opt_in() {
+    let krate = TestCrate::new("demo-lib")
+        .unwrap()
+        .file("src/lib.rs", "pub fn f() {}\n")
+        .unwrap();
+    let results = krate
+        .run(&SemverHandler, &krate.config(FileConfig::default()))
+        .unwrap();
+    assert!(results.is_empty(), "{:?}", results);
+}
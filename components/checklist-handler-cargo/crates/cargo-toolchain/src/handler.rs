@@ -0,0 +1,64 @@
+//! Toolchain handler implementation
+
+use anyhow::Result;
+use checklist_config::ToolchainPolicy;
+use checklist_result::CheckResult;
+use handler_trait::{ProjectContext, ProjectHandler};
+
+use crate::pin::{ToolchainPin, find_pin};
+use crate::requirements::unmet_requirements;
+
+/// Project handler enforcing the rust-toolchain.toml policy
+pub struct ToolchainHandler;
+
+impl ProjectHandler for ToolchainHandler {
+    fn name(&self) -> &'static str {
+        "toolchain"
+    }
+
+    fn check(&self, ctx: &ProjectContext) -> Result<Vec<CheckResult>> {
+        let policy = ctx.config.file().toolchain.policy;
+        let label = "Toolchain Pin [project]";
+        let Some(pin) = find_pin(ctx.config.project_root()) else {
+            let result = match policy {
+                ToolchainPolicy::Required => CheckResult::fail(
+                    label,
+                    "No rust-toolchain.toml; pin the channel (policy: required)",
+                ),
+                _ => CheckResult::pass(label, "No toolchain pinned"),
+            };
+            return Ok(vec![result]);
+        };
+        let found = format!("Pinned to {}", pin.channel);
+        if policy == ToolchainPolicy::Forbidden {
+            let message = format!("{}; remove it to track stable (policy: forbidden)", found);
+            return Ok(vec![CheckResult::fail(label, message).at(&pin.path, 1)]);
+        }
+        let compat = check_compatibility(&pin, ctx);
+        Ok(vec![CheckResult::pass(label, found), compat])
+    }
+}
+
+/// Check a numeric pin is at least every edition/MSRV minimum
+fn check_compatibility(pin: &ToolchainPin, ctx: &ProjectContext) -> CheckResult {
+    let label = "Toolchain Compatibility [project]";
+    let Some(pinned) = pin.version() else {
+        return CheckResult::pass(
+            label,
+            format!("Channel {} tracks a release train", pin.channel),
+        );
+    };
+    let too_new = unmet_requirements(ctx.cargo_tomls, pinned);
+    if too_new.is_empty() {
+        return CheckResult::pass(
+            label,
+            format!("Rust {} supports all editions and MSRVs", pin.channel),
+        );
+    }
+    let message = format!(
+        "Pinned Rust {} is too old: {}",
+        pin.channel,
+        too_new.join("; ")
+    );
+    CheckResult::fail(label, message).at(&pin.path, 1)
+}
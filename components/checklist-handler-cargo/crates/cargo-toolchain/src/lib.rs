@@ -0,0 +1,10 @@
+//! Toolchain pinning checks for sw-checklist
+//!
+//! Applies the `[toolchain]` policy from .sw-checklist.toml and verifies a
+//! pinned toolchain can build the editions and MSRVs the manifests declare.
+
+mod handler;
+mod pin;
+mod requirements;
+
+pub use handler::ToolchainHandler;
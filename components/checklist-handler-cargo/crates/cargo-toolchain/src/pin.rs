@@ -0,0 +1,49 @@
+//! rust-toolchain.toml parsing
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Toolchain files rustup reads, in precedence order
+const TOOLCHAIN_FILES: &[&str] = &["rust-toolchain.toml", "rust-toolchain"];
+
+/// A toolchain pin at the project root
+#[derive(Debug, Clone)]
+pub struct ToolchainPin {
+    /// Path of the toolchain file
+    pub path: PathBuf,
+    /// Pinned channel, e.g. `stable`, `1.85`, or `nightly-2025-01-01`
+    pub channel: String,
+}
+
+impl ToolchainPin {
+    /// The `(major, minor)` version of a numeric channel like `1.85.0`
+    pub fn version(&self) -> Option<(u32, u32)> {
+        parse_version(&self.channel)
+    }
+}
+
+/// Find the toolchain pin in the project root, if any
+pub fn find_pin(root: &Path) -> Option<ToolchainPin> {
+    TOOLCHAIN_FILES.iter().find_map(|name| {
+        let path = root.join(name);
+        let content = fs::read_to_string(&path).ok()?;
+        // Legacy `rust-toolchain` files may hold just the channel name
+        let channel = match content.parse::<toml::Value>() {
+            Ok(value) => value
+                .get("toolchain")?
+                .get("channel")?
+                .as_str()?
+                .to_string(),
+            Err(_) => content.trim().to_string(),
+        };
+        Some(ToolchainPin { path, channel })
+    })
+}
+
+/// Parse `(major, minor)` from a version like `1.85` or `1.85.0`
+pub fn parse_version(version: &str) -> Option<(u32, u32)> {
+    let mut parts = version.trim().split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    Some((major, minor))
+}
@@ -0,0 +1,45 @@
+//! Minimum Rust versions implied by the project's manifests
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::pin::parse_version;
+
+/// First stable Rust release supporting each edition
+const EDITION_MINIMUMS: &[(&str, (u32, u32))] =
+    &[("2018", (1, 31)), ("2021", (1, 56)), ("2024", (1, 85))];
+
+/// Edition and `rust-version` minimums newer than `pinned`, described for the user
+pub fn unmet_requirements(cargo_tomls: &[PathBuf], pinned: (u32, u32)) -> Vec<String> {
+    cargo_tomls
+        .iter()
+        .flat_map(|path| manifest_requirements(path))
+        .filter(|(_, version)| *version > pinned)
+        .map(|(source, version)| format!("{} needs {}.{}", source, version.0, version.1))
+        .collect()
+}
+
+/// Minimum versions from one manifest's (workspace) package edition and rust-version
+fn manifest_requirements(path: &Path) -> Vec<(String, (u32, u32))> {
+    let Some(manifest) = fs::read_to_string(path)
+        .ok()
+        .and_then(|c| c.parse::<toml::Value>().ok())
+    else {
+        return Vec::new();
+    };
+    let package = manifest
+        .get("package")
+        .or_else(|| manifest.get("workspace")?.get("package"));
+    let field = |key: &str| package?.get(key)?.as_str();
+    let edition = field("edition").and_then(|e| {
+        let (_, version) = EDITION_MINIMUMS.iter().find(|(name, _)| *name == e)?;
+        Some((format!("edition {}", e), *version))
+    });
+    let msrv = field("rust-version")
+        .and_then(|v| Some((format!("rust-version {}", v), parse_version(v)?)));
+    [edition, msrv]
+        .into_iter()
+        .flatten()
+        .map(|(what, version)| (format!("{} ({})", what, path.display()), version))
+        .collect()
+}
@@ -0,0 +1,45 @@
+use cargo_toolchain::ToolchainHandler;
+use checklist_config::{FileConfig, ToolchainPolicy};
+use checklist_result::{CheckResult, CheckStatus};
+use checklist_testkit::{TestCrate, assert_status};
+
+fn with_policy(krate: &TestCrate, policy: ToolchainPolicy) -> Vec<CheckResult> {
+    let mut file = FileConfig::default();
+    file.toolchain.policy = policy;
+    krate
+        .run_project(&ToolchainHandler, &krate.config(file))
+        .unwrap()
+}
+
+fn pinned(channel: &str) -> TestCrate {
+    let toolchain = format!("[toolchain]\nchannel = \"{}\"\n", channel);
+    TestCrate::new("demo")
+        .unwrap()
+        .file("rust-toolchain.toml", toolchain)
+        .unwrap()
+}
+
+#[test]
+fn unpinned_projects_fail_only_when_a_pin_is_required() {
+    let krate = TestCrate::new("demo").unwrap();
+    let results = krate.check_project(&ToolchainHandler).unwrap();
+    assert_status(&results, "Toolchain Pin", CheckStatus::Pass);
+    let results = with_policy(&krate, ToolchainPolicy::Required);
+    assert_status(&results, "Toolchain Pin", CheckStatus::Fail);
+}
+
+#[test]
+fn pins_older_than_the_edition_fail() {
+    let results = pinned("1.80").check_project(&ToolchainHandler).unwrap();
+    assert_status(&results, "Toolchain Pin", CheckStatus::Pass);
+    assert_status(&results, "Toolchain Compatibility", CheckStatus::Fail);
+
+    let results = pinned("1.85.0").check_project(&ToolchainHandler).unwrap();
+    assert_status(&results, "Toolchain Compatibility", CheckStatus::Pass);
+}
+
+#[test]
+fn pins_fail_when_forbidden() {
+    let results = with_policy(&pinned("stable"), ToolchainPolicy::Forbidden);
+    assert_status(&results, "Toolchain Pin", CheckStatus::Fail);
+}
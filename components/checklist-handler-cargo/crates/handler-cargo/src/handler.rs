@@ -1,11 +1,15 @@
 //! Cargo handler implementation
 
 use anyhow::Result;
-use cargo_edition::check_rust_edition;
+use cargo_edition::{check_rust_edition, check_workspace_resolver};
+use cargo_lints::check_lint_config;
+use cargo_profile::{NATIVE_RELEASE, WASM_RELEASE, check_release_policy, check_release_profile};
 use checklist_result::CheckResult;
 use discovery_crate::CrateType;
 use handler_trait::{CheckContext, Handler};
 
+use crate::scaffold::scaffold_tests;
+
 /// Handler for Cargo.toml checks
 pub struct CargoHandler;
 
@@ -19,6 +23,40 @@ impl Handler for CargoHandler {
     }
 
     fn check(&self, ctx: &CheckContext) -> Result<Vec<CheckResult>> {
-        Ok(vec![check_rust_edition(ctx.cargo_toml, ctx.crate_name)])
+        let (toml, dir, name) = (ctx.cargo_toml, ctx.crate_dir, ctx.crate_name);
+        let mut results = vec![check_rust_edition(toml, dir, name, ctx.config.fix())];
+        results.extend(check_workspace_resolver(ctx.cargo_toml, ctx.crate_name));
+        if ctx.crate_type != CrateType::Workspace {
+            let baseline = &ctx.config.file().lints.baseline;
+            results.push(check_lint_config(toml, dir, name, baseline));
+            if ctx.config.fix() {
+                results.extend(scaffold_tests(dir, name));
+            }
+        }
+        results.extend(release_results(ctx));
+        Ok(results)
+    }
+}
+
+/// Release profile conventions and panic/overflow policy for shipped binaries
+fn release_results(ctx: &CheckContext) -> Vec<CheckResult> {
+    let (dir, name) = (ctx.crate_dir, ctx.crate_name);
+    let native = matches!(
+        ctx.crate_type,
+        CrateType::Cli | CrateType::Tauri | CrateType::Desktop
+    );
+    let mut results = Vec::new();
+    if native {
+        let policy = &ctx.config.file().release_profile;
+        results.push(check_release_policy(dir, name, policy));
+    }
+    let expected = match ctx.crate_type {
+        _ if native => Some(NATIVE_RELEASE),
+        CrateType::Wasm | CrateType::CliWasm => Some(WASM_RELEASE),
+        _ => None,
+    };
+    if let Some(expected) = expected {
+        results.push(check_release_profile(dir, name, expected));
     }
+    results
 }
@@ -1,5 +1,6 @@
 //! Cargo.toml validation handler for sw-checklist
 
 mod handler;
+mod scaffold;
 
 pub use handler::CargoHandler;
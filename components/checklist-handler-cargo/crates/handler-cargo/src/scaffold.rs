@@ -0,0 +1,59 @@
+//! `--fix` scaffolding of a tests/ directory
+
+use checklist_result::CheckResult;
+use std::fs;
+use std::path::Path;
+
+/// Scaffolded test; `{import}` links the library, for crates that have one
+const SMOKE_TEST: &str = r#"//! Smoke test scaffolded by `sw-checklist --fix`
+
+{import}#[test]
+fn smoke() {
+    // Replace with assertions on the crate's behavior
+{body}}
+"#;
+
+/// Smoke test body for a crate with a binary: its --help must succeed
+const BINARY_BODY: &str = r#"    let status = std::process::Command::new(env!("CARGO_BIN_EXE_{name}"))
+        .arg("--help")
+        .status()
+        .expect("binary should run");
+    assert!(status.success());
+"#;
+
+/// Smoke test body for a library-only crate: the test links the right package
+const LIBRARY_BODY: &str = r#"    assert_eq!(env!("CARGO_PKG_NAME"), "{name}");
+"#;
+
+/// Create `tests/smoke.rs` in a crate that has no tests/ (the `--fix` remediation)
+/// and report it; None when the crate already has one or the write fails
+pub fn scaffold_tests(crate_dir: &Path, crate_name: &str) -> Option<CheckResult> {
+    let tests = crate_dir.join("tests");
+    if tests.exists() {
+        return None;
+    }
+    fs::create_dir(&tests).ok()?;
+    fs::write(tests.join("smoke.rs"), smoke_test(crate_dir, crate_name)).ok()?;
+    Some(CheckResult::info(
+        format!("Tests [{}]", crate_name),
+        "Fixed tests/smoke.rs: scaffolded a smoke test (no tests/ directory)",
+    ))
+}
+
+/// The smoke test for the crate: runs its binary's --help if it has one,
+/// otherwise checks that it links the library
+fn smoke_test(crate_dir: &Path, crate_name: &str) -> String {
+    let import = if crate_dir.join("src/lib.rs").is_file() {
+        format!("use {} as _;\n\n", crate_name.replace('-', "_"))
+    } else {
+        String::new()
+    };
+    let body = if crate_dir.join("src/main.rs").is_file() {
+        BINARY_BODY
+    } else {
+        LIBRARY_BODY
+    };
+    SMOKE_TEST
+        .replace("{import}", &import)
+        .replace("{body}", &body.replace("{name}", crate_name))
+}
@@ -0,0 +1,44 @@
+use checklist_config::{Config, ConfigBuilder, FileConfig};
+use checklist_result::CheckStatus;
+use checklist_testkit::{TestCrate, assert_status};
+use handler_cargo::CargoHandler;
+use std::fs;
+
+const OLD_EDITION: &str = "[package]\nname = \"demo\"\nversion = \"0.1.0\"\nedition = \"2021\"\n";
+
+fn fix_config(krate: &TestCrate) -> Config {
+    ConfigBuilder::new()
+        .project_path(krate.path().to_path_buf())
+        .file_config(FileConfig::default())
+        .fix(true)
+        .build()
+}
+
+fn library(manifest: &str) -> TestCrate {
+    TestCrate::with_manifest(manifest)
+        .unwrap()
+        .file("src/lib.rs", "pub fn f() {}\n")
+        .unwrap()
+}
+
+#[test]
+fn old_editions_fail() {
+    let krate = library(OLD_EDITION);
+    let results = krate
+        .run(&CargoHandler, &krate.config(FileConfig::default()))
+        .unwrap();
+    assert_status(&results, "Rust Edition", CheckStatus::Fail);
+}
+
+#[test]
+fn fix_bumps_the_edition_and_scaffolds_tests() {
+    let krate = library(OLD_EDITION);
+    let results = krate.run(&CargoHandler, &fix_config(&krate)).unwrap();
+    assert_status(&results, "Rust Edition", CheckStatus::Info);
+    assert_status(&results, "Tests", CheckStatus::Info);
+    let manifest = fs::read_to_string(krate.path().join("Cargo.toml")).unwrap();
+    assert!(manifest.contains("edition = \"2024\""));
+    let smoke = fs::read_to_string(krate.path().join("tests/smoke.rs")).unwrap();
+    assert!(smoke.contains("use demo as _;"), "{}", smoke);
+    assert!(smoke.contains("assert_eq!(env!(\"CARGO_PKG_NAME\"), \"demo\")"));
+}
@@ -0,0 +1,43 @@
+//! Invalid-flag behavior checking
+
+use checklist_exec::{Exec, ExecOutput};
+use checklist_result::CheckResult;
+use discovery_crate::binary_label;
+use std::path::Path;
+
+const BOGUS_FLAG: &str = "--sw-checklist-bogus-flag";
+
+/// Check that an unknown flag is rejected cleanly
+///
+/// The binary should exit non-zero, print usage to stderr, and not panic.
+pub fn check_invalid_flag(crate_name: &str, binary_name: &str, binary: &Path) -> CheckResult {
+    let label = format!("Invalid Flag {}", binary_label(crate_name, binary_name));
+    let output = match Exec::new(binary).args([BOGUS_FLAG]).run() {
+        Ok(output) => output,
+        Err(e) => return CheckResult::fail(label, format!("Failed: {e}")),
+    };
+    let problems = behavior_problems(&output);
+    if problems.is_empty() {
+        CheckResult::pass(label, "Unknown flag exits non-zero with usage on stderr")
+    } else {
+        CheckResult::fail(
+            label,
+            format!("Unknown flag {}: {}", BOGUS_FLAG, problems.join(", ")),
+        )
+    }
+}
+
+fn behavior_problems(output: &ExecOutput) -> Vec<&'static str> {
+    let stderr = &output.stderr;
+    let mut problems = Vec::new();
+    if output.success() {
+        problems.push("exits with status 0");
+    }
+    if !stderr.to_lowercase().contains("usage") {
+        problems.push("no usage printed to stderr");
+    }
+    if stderr.contains("panicked at") || stderr.contains("stack backtrace") {
+        problems.push("binary panicked");
+    }
+    problems
+}
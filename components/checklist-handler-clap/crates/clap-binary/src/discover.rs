@@ -1,26 +1,21 @@
 //! Binary discovery utilities
 
+use discovery_crate::binary_targets;
 use std::path::{Path, PathBuf};
 
-/// Get binary names from Cargo.toml
-pub fn get_binary_names(cargo_toml: &str, crate_name: &str) -> Vec<String> {
-    let mut names = Vec::new();
-    if let Ok(cargo) = cargo_toml.parse::<toml::Value>()
-        && let Some(bins) = cargo.get("bin").and_then(|b| b.as_array())
-    {
-        for bin in bins {
-            if let Some(name) = bin.get("name").and_then(|n| n.as_str()) {
-                names.push(name.to_string());
-            }
-        }
-    }
+use crate::target::target_dirs;
+
+/// Get binary names from `[[bin]]` sections and auto-discovered `src/bin` targets
+pub fn get_binary_names(cargo_toml: &str, crate_name: &str, crate_dir: &Path) -> Vec<String> {
+    let mut names = binary_targets(cargo_toml, crate_name, crate_dir);
     if names.is_empty() {
         names.push(crate_name.to_string());
     }
     names
 }
 
-/// Find binary in target directories (root or component)
+/// Find binary in target directories (root or component), including
+/// `target/<triple>/` for cross-compilation targets set in `.cargo/config.toml`
 pub fn find_binary(project_root: &Path, binary_name: &str) -> Option<PathBuf> {
     // Check root target directory
     if let Some(path) = find_in_target(project_root, binary_name) {
@@ -31,15 +26,12 @@ pub fn find_binary(project_root: &Path, binary_name: &str) -> Option<PathBuf> {
 }
 
 fn find_in_target(dir: &Path, binary_name: &str) -> Option<PathBuf> {
-    let release = dir.join("target/release").join(binary_name);
-    if release.exists() {
-        return Some(release);
-    }
-    let debug = dir.join("target/debug").join(binary_name);
-    if debug.exists() {
-        return Some(debug);
-    }
-    None
+    target_dirs(dir).into_iter().find_map(|target| {
+        ["release", "debug"]
+            .iter()
+            .map(|profile| target.join(profile).join(binary_name))
+            .find(|path| path.exists())
+    })
 }
 
 fn find_in_components(project_root: &Path, binary_name: &str) -> Option<PathBuf> {
@@ -1,7 +1,16 @@
-//! Binary discovery and freshness checking for CLI crates
+//! Binary discovery (including cross-compilation targets), freshness, size,
+//! embedded build paths, and behavior checking for CLI crates
 
+mod behavior;
 mod discover;
 mod freshness;
+mod paths;
+mod size;
+mod target;
 
+pub use behavior::check_invalid_flag;
 pub use discover::{find_binary, get_binary_names};
 pub use freshness::check_binary_freshness;
+pub use paths::check_embedded_paths;
+pub use size::check_binary_size;
+pub use target::{binary_target, is_host_target};
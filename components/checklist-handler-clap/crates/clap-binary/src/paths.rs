@@ -0,0 +1,76 @@
+//! Build-path leakage in release binaries
+
+use checklist_result::CheckResult;
+use discovery_crate::binary_label;
+use std::fs;
+use std::path::Path;
+
+/// Prefixes of absolute home directories that reveal the build machine
+const HOME_PREFIXES: &[&str] = &["/home/", "/Users/", "/root/", "C:\\Users\\"];
+
+/// Shortest printable run considered a string, as with `strings`
+const MIN_STRING_LEN: usize = 8;
+
+/// Check that a release binary embeds no absolute home paths, which means it
+/// was built with `--remap-path-prefix` (or `trim-paths`) and is reproducible
+/// across machines (only meaningful for release builds)
+pub fn check_embedded_paths(crate_name: &str, binary_name: &str, path: &Path) -> CheckResult {
+    let label = format!(
+        "Reproducible Paths {}",
+        binary_label(crate_name, binary_name)
+    );
+    let Ok(bytes) = fs::read(path) else {
+        return CheckResult::warn(label, "Could not read binary");
+    };
+    let leaks = home_paths(&bytes);
+    let Some(example) = leaks.first() else {
+        return CheckResult::pass(label, "No absolute home paths embedded");
+    };
+    CheckResult::warn(
+        label,
+        format!(
+            "{} strings embed home paths, e.g. {} (build with RUSTFLAGS=\"--remap-path-prefix=$HOME=~\")",
+            leaks.len(),
+            example
+        ),
+    )
+}
+
+/// Distinct printable strings in `bytes` that contain an absolute home path,
+/// each starting at the path
+fn home_paths(bytes: &[u8]) -> Vec<String> {
+    let mut leaks: Vec<String> = bytes
+        .split(|b| !(b.is_ascii_graphic() || *b == b' '))
+        .filter(|run| run.len() >= MIN_STRING_LEN)
+        .filter_map(|run| {
+            let text = String::from_utf8_lossy(run);
+            let start = home_path_start(&text)?;
+            Some(text[start..].chars().take(80).collect())
+        })
+        .collect();
+    leaks.sort();
+    leaks.dedup();
+    leaks
+}
+
+/// Byte offset of the first home prefix in `text` followed by a path segment
+/// and separator (e.g. `/home/alice/`), so the prefixes alone don't match
+fn home_path_start(text: &str) -> Option<usize> {
+    HOME_PREFIXES
+        .iter()
+        .flat_map(|prefix| {
+            text.match_indices(prefix)
+                .map(move |(i, _)| (i, prefix.len()))
+        })
+        .filter(|&(i, len)| {
+            let rest = &text[i + len..];
+            let segment = rest.find(['/', '\\']).map_or(rest, |end| &rest[..end]);
+            segment.len() < rest.len()
+                && !segment.is_empty()
+                && segment
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || "._-".contains(c))
+        })
+        .map(|(i, _)| i)
+        .min()
+}
@@ -0,0 +1,41 @@
+//! Release binary size budgets
+
+use checklist_config::BudgetsConfig;
+use checklist_result::CheckResult;
+use discovery_crate::binary_label;
+use std::path::Path;
+
+const MIB: u64 = 1024 * 1024;
+
+/// Check a release binary's file size against the `[budgets]` binary limits
+///
+/// Debug builds are reported as INFO: their size says nothing about what ships.
+pub fn check_binary_size(
+    crate_name: &str,
+    binary_name: &str,
+    path: &Path,
+    budgets: &BudgetsConfig,
+) -> CheckResult {
+    let label = format!("Binary Size {}", binary_label(crate_name, binary_name));
+    if !path.parent().is_some_and(|dir| dir.ends_with("release")) {
+        return CheckResult::info(label, "Only a debug build found (build with --release)");
+    }
+    match path.metadata() {
+        Ok(metadata) => size_result(label, metadata.len(), budgets),
+        Err(_) => CheckResult::warn(label, "Could not read binary size"),
+    }
+}
+
+fn size_result(label: String, bytes: u64, budgets: &BudgetsConfig) -> CheckResult {
+    let size = format!("{:.1} MiB", bytes as f64 / MIB as f64);
+    if bytes > budgets.binary_mb_fail * MIB {
+        let limit = budgets.binary_mb_fail;
+        let message = format!("{} exceeds {} MiB (strip symbols, trim deps)", size, limit);
+        CheckResult::fail(label, message)
+    } else if bytes > budgets.binary_mb_warn * MIB {
+        let message = format!("{} is over {} MiB", size, budgets.binary_mb_warn);
+        CheckResult::warn(label, message)
+    } else {
+        CheckResult::pass(label, size)
+    }
+}
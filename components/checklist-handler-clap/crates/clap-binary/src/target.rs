@@ -0,0 +1,50 @@
+//! Cross-compilation target awareness
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Cargo reads either name
+const CARGO_CONFIG_FILES: &[&str] = &[".cargo/config.toml", ".cargo/config"];
+
+/// Target directories holding `release/` and `debug/` under `dir`: `target/`
+/// itself, then `target/<triple>/` for each `[build] target` in `.cargo/config.toml`
+pub fn target_dirs(dir: &Path) -> Vec<PathBuf> {
+    let target = dir.join("target");
+    let mut dirs = vec![target.clone()];
+    dirs.extend(configured_targets(dir).iter().map(|t| target.join(t)));
+    dirs
+}
+
+/// Triples from `[build] target` (a string or an array) in the directory's cargo config
+fn configured_targets(dir: &Path) -> Vec<String> {
+    let Some(config) = CARGO_CONFIG_FILES
+        .iter()
+        .find_map(|name| fs::read_to_string(dir.join(name)).ok())
+        .and_then(|c| c.parse::<toml::Value>().ok())
+    else {
+        return Vec::new();
+    };
+    match config.get("build").and_then(|b| b.get("target")) {
+        Some(toml::Value::String(triple)) => vec![triple.clone()],
+        Some(toml::Value::Array(triples)) => triples
+            .iter()
+            .filter_map(|t| t.as_str().map(str::to_string))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// The target triple a binary was built for, when it sits under `target/<triple>/<profile>/`
+pub fn binary_target(binary: &Path) -> Option<String> {
+    let triple = binary.parent()?.parent()?.file_name()?.to_str()?;
+    (triple != "target").then(|| triple.to_string())
+}
+
+/// Whether binaries built for `triple` can run on this machine
+pub fn is_host_target(triple: &str) -> bool {
+    let os = match std::env::consts::OS {
+        "macos" => "darwin",
+        os => os,
+    };
+    triple.starts_with(std::env::consts::ARCH) && triple.contains(os)
+}
@@ -0,0 +1,45 @@
+//! Built vs installed binary timestamps (one test: it points HOME at a temp
+//! directory for this process)
+
+use checklist_result::CheckStatus;
+use checklist_tempdir::TempWorkspace;
+use clap_binary::check_binary_freshness;
+use std::fs::{self, File};
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+/// Write `path` with its modification time `age` in the past
+fn binary(path: &Path, age: Duration) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    let file = File::create(path).unwrap();
+    file.set_modified(SystemTime::now() - age).unwrap();
+}
+
+#[test]
+fn freshness_compares_built_and_installed_binaries() {
+    let workspace = TempWorkspace::new("freshness").unwrap();
+    let home = workspace.path();
+    // SAFETY: this is the only test in this binary, so nothing reads the
+    // environment concurrently
+    unsafe { std::env::set_var("HOME", home) };
+    let built = home.join("target/release/test-binary");
+    let installed = home.join(".local/softwarewrighter/bin/test-binary");
+    binary(&built, Duration::from_secs(60));
+
+    let result = check_binary_freshness("test-binary", &built);
+    assert_eq!(result.status, CheckStatus::Warn);
+    assert!(
+        result.message.contains("not installed"),
+        "{}",
+        result.message
+    );
+
+    binary(&installed, Duration::from_secs(120));
+    let result = check_binary_freshness("test-binary", &built);
+    assert_eq!(result.status, CheckStatus::Warn);
+    assert!(result.message.contains("newer"), "{}", result.message);
+
+    binary(&installed, Duration::ZERO);
+    let result = check_binary_freshness("test-binary", &built);
+    assert_eq!(result.status, CheckStatus::Pass, "{}", result.message);
+}
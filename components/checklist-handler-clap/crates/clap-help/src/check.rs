@@ -1,24 +1,31 @@
 //! Help flag checking
 
+use checklist_exec::run_command;
 use checklist_result::CheckResult;
+use discovery_crate::binary_label;
 use std::path::Path;
 
-use crate::content::{check_ai_instructions, check_help_length};
-use crate::util::{make_label, run_command};
+use crate::content::{
+    check_ai_instructions, check_examples_section, check_help_length, check_machine_output_flag,
+};
 
 /// Check -h and --help flags
+///
+/// Relaxed mode (build tooling) only checks that --help is longer than -h.
 pub fn check_help_flags(
     binary: &Path,
     binary_name: &str,
     crate_name: &str,
     verbose: bool,
+    relaxed: bool,
 ) -> Vec<CheckResult> {
-    let label = make_label(crate_name, binary_name);
+    let label = binary_label(crate_name, binary_name);
     let short = run_command(binary, &["-h"]);
     let long = run_command(binary, &["--help"]);
 
     match (short, long) {
-        (Ok(short), Ok(long)) => check_help_outputs(&label, &short, &long, verbose),
+        (Ok(short), Ok(long)) if relaxed => vec![check_help_length(&label, &short, &long)],
+        (Ok(short), Ok(long)) => check_help_outputs(&label, binary_name, &short, &long, verbose),
         (Err(e), _) => vec![CheckResult::fail(
             format!("Help -h {label}"),
             format!("Failed: {e}"),
@@ -30,7 +37,13 @@ pub fn check_help_flags(
     }
 }
 
-fn check_help_outputs(label: &str, short: &str, long: &str, verbose: bool) -> Vec<CheckResult> {
+fn check_help_outputs(
+    label: &str,
+    binary_name: &str,
+    short: &str,
+    long: &str,
+    verbose: bool,
+) -> Vec<CheckResult> {
     if verbose {
         println!("  -h output ({} bytes)", short.len());
         println!("  --help output ({} bytes)", long.len());
@@ -38,5 +51,7 @@ fn check_help_outputs(label: &str, short: &str, long: &str, verbose: bool) -> Ve
     vec![
         check_help_length(label, short, long),
         check_ai_instructions(label, long),
+        check_examples_section(label, binary_name, long),
+        check_machine_output_flag(label, long),
     ]
 }
@@ -39,3 +39,53 @@ pub fn check_ai_instructions(label: &str, help_output: &str) -> CheckResult {
         )
     }
 }
+
+/// Check for a machine-readable output flag (--json or --format)
+pub fn check_machine_output_flag(label: &str, help_output: &str) -> CheckResult {
+    let name = format!("Machine Output Flag {}", label);
+    if help_output.contains("--json") || help_output.contains("--format") {
+        CheckResult::pass(
+            name,
+            "Found --json/--format flag for machine-readable output",
+        )
+    } else {
+        CheckResult::warn(
+            name,
+            "--help should list a --json or --format flag for machine-readable output",
+        )
+    }
+}
+
+/// Check for an EXAMPLES section containing at least one runnable command line
+pub fn check_examples_section(label: &str, binary_name: &str, help_output: &str) -> CheckResult {
+    let name = format!("Examples Section {}", label);
+    match count_example_commands(help_output, binary_name) {
+        None => CheckResult::fail(name, "--help should include an 'EXAMPLES:' section"),
+        Some(0) => CheckResult::fail(
+            name,
+            format!(
+                "EXAMPLES section should show at least one command line (e.g. '$ {} ...')",
+                binary_name
+            ),
+        ),
+        Some(n) => CheckResult::pass(
+            name,
+            format!("Found EXAMPLES section with {} command line(s)", n),
+        ),
+    }
+}
+
+/// Count command lines in the EXAMPLES section (None if the section is absent)
+fn count_example_commands(help_output: &str, binary_name: &str) -> Option<usize> {
+    let mut lines = help_output.lines().skip_while(|l| {
+        let t = l.trim();
+        !t.starts_with("EXAMPLES:") && !t.starts_with("Examples:")
+    });
+    lines.next()?;
+    let count = lines
+        .take_while(|l| l.starts_with(char::is_whitespace) || !l.trim_end().ends_with(':'))
+        .map(str::trim)
+        .filter(|l| l.starts_with("$ ") || l.split_whitespace().next() == Some(binary_name))
+        .count();
+    Some(count)
+}
@@ -2,6 +2,5 @@
 
 mod check;
 mod content;
-mod util;
 
 pub use check::check_help_flags;
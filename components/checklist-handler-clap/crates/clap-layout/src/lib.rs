@@ -0,0 +1,9 @@
+//! Naming and layout conventions for CLI crates
+
+mod library;
+mod naming;
+mod thin_main;
+
+pub use library::check_library_split;
+pub use naming::check_binary_naming;
+pub use thin_main::check_thin_main;
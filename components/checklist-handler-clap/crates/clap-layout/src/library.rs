@@ -0,0 +1,62 @@
+//! Library/binary split check for CLI crates
+
+use checklist_result::CheckResult;
+use std::fs;
+use std::path::Path;
+
+/// Check that a CLI crate keeps its reusable logic in a library
+///
+/// Passes when the crate has `src/lib.rs` or depends on an internal (path)
+/// library crate, as sw-checklist does with cli-runner.
+pub fn check_library_split(cargo_toml: &str, crate_dir: &Path, crate_name: &str) -> CheckResult {
+    let label = format!("Library Split [{}]", crate_name);
+    if crate_dir.join("src/lib.rs").exists() {
+        return CheckResult::pass(label, "Reusable logic is in src/lib.rs");
+    }
+    let deps = internal_dependencies(cargo_toml, crate_dir);
+    if deps.is_empty() {
+        CheckResult::warn(
+            label,
+            "No src/lib.rs or internal library dependency; move reusable logic out of \
+             main.rs so the tool is embeddable and testable",
+        )
+    } else {
+        CheckResult::pass(
+            label,
+            format!("Uses internal library crate(s): {}", deps.join(", ")),
+        )
+    }
+}
+
+/// `[dependencies]` entries that are path dependencies, directly or via
+/// `workspace = true`
+fn internal_dependencies(cargo_toml: &str, crate_dir: &Path) -> Vec<String> {
+    let Ok(cargo) = cargo_toml.parse::<toml::Value>() else {
+        return Vec::new();
+    };
+    let workspace_deps = workspace_dependencies(crate_dir);
+    cargo
+        .get("dependencies")
+        .and_then(|d| d.as_table())
+        .into_iter()
+        .flatten()
+        .filter(|(name, dep)| {
+            dep.get("path").is_some()
+                || (dep.get("workspace").and_then(|w| w.as_bool()) == Some(true)
+                    && workspace_deps
+                        .as_ref()
+                        .and_then(|deps| deps.get(name.as_str())?.get("path"))
+                        .is_some())
+        })
+        .map(|(name, _)| name.clone())
+        .collect()
+}
+
+/// The nearest `[workspace.dependencies]` table at or above the crate directory
+fn workspace_dependencies(crate_dir: &Path) -> Option<toml::Value> {
+    crate_dir.ancestors().find_map(|dir| {
+        let content = fs::read_to_string(dir.join("Cargo.toml")).ok()?;
+        let value = content.parse::<toml::Value>().ok()?;
+        value.get("workspace")?.get("dependencies").cloned()
+    })
+}
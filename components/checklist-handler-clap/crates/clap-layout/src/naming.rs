@@ -0,0 +1,67 @@
+//! Binary naming convention checking
+
+use checklist_result::CheckResult;
+
+/// Check binary names against the org convention
+///
+/// Binaries should be kebab-case and carry `prefix` when one is configured
+/// (`[naming] binary_prefix`), and a single binary should be named after its
+/// package.
+pub fn check_binary_naming(
+    binary_names: &[String],
+    crate_name: &str,
+    prefix: Option<&str>,
+) -> Vec<CheckResult> {
+    let single = binary_names.len() == 1;
+    binary_names
+        .iter()
+        .map(|binary| {
+            let label = if binary == crate_name {
+                format!("Binary Naming [{}]", crate_name)
+            } else {
+                format!("Binary Naming [{}/{}]", crate_name, binary)
+            };
+            let issues = naming_issues(binary, crate_name, prefix, single);
+            if issues.is_empty() {
+                CheckResult::pass(label, format!("'{}' follows naming convention", binary))
+            } else {
+                CheckResult::warn(label, issues.join("; "))
+            }
+        })
+        .collect()
+}
+
+fn naming_issues(
+    binary: &str,
+    crate_name: &str,
+    prefix: Option<&str>,
+    single: bool,
+) -> Vec<String> {
+    let mut issues = Vec::new();
+    if !is_kebab_case(binary) {
+        issues.push(format!("'{}' is not kebab-case", binary));
+    }
+    if let Some(prefix) = prefix.filter(|p| !binary.starts_with(p)) {
+        issues.push(format!(
+            "'{}' lacks the '{}' prefix for installable tools",
+            binary, prefix
+        ));
+    }
+    if single && binary != crate_name {
+        issues.push(format!(
+            "'{}' does not match package name '{}'",
+            binary, crate_name
+        ));
+    }
+    issues
+}
+
+fn is_kebab_case(name: &str) -> bool {
+    !name.is_empty()
+        && !name.starts_with('-')
+        && !name.ends_with('-')
+        && !name.contains("--")
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+}
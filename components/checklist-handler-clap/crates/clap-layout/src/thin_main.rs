@@ -0,0 +1,68 @@
+//! Thin binary entry point check
+
+use checklist_result::CheckResult;
+use modularity_loc::find_functions;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Check binary entry points only parse arguments and call into library code
+///
+/// Flags entry points whose functions total more than `max_loc` lines
+/// (constants such as help text don't count) or that define `impl` blocks.
+pub fn check_thin_main(crate_dir: &Path, crate_name: &str, max_loc: usize) -> Vec<CheckResult> {
+    let label = format!("Thin Main [{}]", crate_name);
+    let results: Vec<CheckResult> = entry_points(crate_dir)
+        .into_iter()
+        .filter_map(|path| {
+            let issue = main_issue(&fs::read_to_string(&path).ok()?, max_loc)?;
+            let file = path.strip_prefix(crate_dir).unwrap_or(&path).display();
+            Some(CheckResult::warn(label.clone(), format!("{} {}", file, issue)).at(&path, 1))
+        })
+        .collect();
+    if results.is_empty() {
+        return vec![CheckResult::pass(
+            label,
+            "Binary entry points only parse arguments and call library code",
+        )];
+    }
+    results
+}
+
+/// `src/main.rs`, `src/bin/*.rs`, and `src/bin/*/main.rs`
+fn entry_points(crate_dir: &Path) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = fs::read_dir(crate_dir.join("src/bin"))
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|e| e.path())
+        .map(|p| if p.is_dir() { p.join("main.rs") } else { p })
+        .filter(|p| p.extension().is_some_and(|e| e == "rs") && p.is_file())
+        .collect();
+    paths.sort();
+    let main = crate_dir.join("src/main.rs");
+    if main.is_file() {
+        paths.insert(0, main);
+    }
+    paths
+}
+
+fn main_issue(content: &str, max_loc: usize) -> Option<String> {
+    let fn_loc: usize = find_functions(content).iter().map(|f| f.loc).sum();
+    if fn_loc > max_loc {
+        return Some(format!(
+            "has {} lines of function code (max {}); move logic into the library",
+            fn_loc, max_loc
+        ));
+    }
+    let impls = content
+        .lines()
+        .map(str::trim_start)
+        .filter(|l| l.starts_with("impl ") || l.starts_with("impl<"))
+        .count();
+    (impls > 0).then(|| {
+        format!(
+            "defines {} impl block(s); move types into the library",
+            impls
+        )
+    })
+}
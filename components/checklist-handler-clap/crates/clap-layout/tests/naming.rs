@@ -0,0 +1,42 @@
+use checklist_result::CheckStatus;
+use clap_layout::check_binary_naming;
+
+fn names(names: &[&str]) -> Vec<String> {
+    names.iter().map(|n| n.to_string()).collect()
+}
+
+#[test]
+fn prefix_is_only_required_when_configured() {
+    let results = check_binary_naming(&names(&["demo"]), "demo", None);
+    assert_eq!(results.len(), 1);
+    assert_eq!(
+        results[0].status,
+        CheckStatus::Pass,
+        "{}",
+        results[0].message
+    );
+
+    let results = check_binary_naming(&names(&["demo"]), "demo", Some("sw-"));
+    assert_eq!(results[0].status, CheckStatus::Warn);
+    assert!(
+        results[0].message.contains("'sw-' prefix"),
+        "{}",
+        results[0].message
+    );
+}
+
+#[test]
+fn single_binary_must_match_package_and_be_kebab_case() {
+    let results = check_binary_naming(&names(&["Demo_Tool"]), "demo", None);
+    assert_eq!(results[0].status, CheckStatus::Warn);
+    assert!(
+        results[0].message.contains("not kebab-case"),
+        "{}",
+        results[0].message
+    );
+    assert!(
+        results[0].message.contains("does not match"),
+        "{}",
+        results[0].message
+    );
+}
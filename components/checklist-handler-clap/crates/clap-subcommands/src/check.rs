@@ -0,0 +1,59 @@
+//! Subcommand AI guidance checking
+
+use checklist_exec::run_command;
+use checklist_result::CheckResult;
+use discovery_crate::binary_label;
+use std::path::Path;
+
+use crate::parse::{ai_section, mentions, subcommand_names};
+
+/// Check that every subcommand is covered by AI agent guidance
+///
+/// A subcommand is covered when its own `--help` includes an AI Coding Agent
+/// section, or when the top-level instructions mention it by name.
+/// Single-command CLIs produce no results.
+pub fn check_subcommand_guidance(
+    binary: &Path,
+    binary_name: &str,
+    crate_name: &str,
+) -> Vec<CheckResult> {
+    let Ok(top_help) = run_command(binary, &["--help"]) else {
+        return Vec::new();
+    };
+    let subcommands = subcommand_names(&top_help);
+    if subcommands.is_empty() {
+        return Vec::new();
+    }
+    let top_ai = ai_section(&top_help).unwrap_or("");
+    let missing: Vec<&str> = subcommands
+        .iter()
+        .map(String::as_str)
+        .filter(|sub| !mentions(top_ai, sub) && !has_own_guidance(binary, sub))
+        .collect();
+    let label = binary_label(crate_name, binary_name);
+    vec![guidance_result(&label, &missing, subcommands.len())]
+}
+
+fn has_own_guidance(binary: &Path, subcommand: &str) -> bool {
+    run_command(binary, &[subcommand, "--help"])
+        .map(|help| ai_section(&help).is_some())
+        .unwrap_or(false)
+}
+
+fn guidance_result(label: &str, missing: &[&str], total: usize) -> CheckResult {
+    let name = format!("Subcommand AI Guidance {}", label);
+    if missing.is_empty() {
+        CheckResult::pass(
+            name,
+            format!("AI agent guidance covers all {} subcommand(s)", total),
+        )
+    } else {
+        CheckResult::fail(
+            name,
+            format!(
+                "No AI agent guidance for subcommand(s): {} (add an AI section to their --help or describe them in the top-level instructions)",
+                missing.join(", ")
+            ),
+        )
+    }
+}
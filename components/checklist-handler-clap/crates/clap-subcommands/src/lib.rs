@@ -0,0 +1,6 @@
+//! Subcommand AI guidance checking for multi-command CLI binaries
+
+mod check;
+mod parse;
+
+pub use check::check_subcommand_guidance;
@@ -0,0 +1,29 @@
+//! Help output parsing for subcommands
+
+/// Extract subcommand names from the Commands section of --help output
+pub fn subcommand_names(help_output: &str) -> Vec<String> {
+    help_output
+        .lines()
+        .skip_while(|l| !matches!(l.trim(), "Commands:" | "SUBCOMMANDS:"))
+        .skip(1)
+        .take_while(|l| l.trim().is_empty() || l.starts_with(char::is_whitespace))
+        .filter(|l| l.starts_with("  ") && !l[2..].starts_with(char::is_whitespace))
+        .filter_map(|l| l.split_whitespace().next())
+        .filter(|name| *name != "help")
+        .map(str::to_string)
+        .collect()
+}
+
+/// Get the AI Coding Agent instructions section (to the end of the output)
+pub fn ai_section(help_output: &str) -> Option<&str> {
+    help_output
+        .find("AI CODING AGENT")
+        .or_else(|| help_output.find("AI Coding Agent"))
+        .map(|start| &help_output[start..])
+}
+
+/// Check whether text mentions a word as a standalone token
+pub fn mentions(text: &str, word: &str) -> bool {
+    text.split(|c: char| !c.is_alphanumeric() && c != '-' && c != '_')
+        .any(|w| w == word)
+}
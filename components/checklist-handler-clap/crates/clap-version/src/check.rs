@@ -1,10 +1,11 @@
 //! Version flag checking
 
+use checklist_exec::run_command;
 use checklist_result::CheckResult;
+use discovery_crate::binary_label;
 use std::path::Path;
 
 use crate::fields::check_version_fields;
-use crate::util::{make_label, run_command};
 
 /// Check -V and --version flags
 pub fn check_version_flags(
@@ -13,7 +14,7 @@ pub fn check_version_flags(
     crate_name: &str,
     verbose: bool,
 ) -> Vec<CheckResult> {
-    let label = make_label(crate_name, binary_name);
+    let label = binary_label(crate_name, binary_name);
     let short = run_command(binary, &["-V"]);
     let long = run_command(binary, &["--version"]);
 
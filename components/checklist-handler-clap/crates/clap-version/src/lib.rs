@@ -1,7 +1,8 @@
-//! Version flag checking for CLI binaries
+//! Version flag and startup time checking for CLI binaries
 
 mod check;
 mod fields;
-mod util;
+mod startup;
 
 pub use check::check_version_flags;
+pub use startup::check_startup_time;
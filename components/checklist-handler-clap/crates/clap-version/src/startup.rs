@@ -0,0 +1,47 @@
+//! Startup time measurement via `--version`
+
+use checklist_config::StartupConfig;
+use checklist_exec::Exec;
+use checklist_result::CheckResult;
+use discovery_crate::binary_label;
+use std::path::Path;
+use std::time::Duration;
+
+/// Time `<binary> --version` (median of the configured runs) against the
+/// `[startup]` budget; `None` unless `[startup] check` is enabled
+pub fn check_startup_time(
+    crate_name: &str,
+    binary_name: &str,
+    binary: &Path,
+    startup: &StartupConfig,
+) -> Option<CheckResult> {
+    if !startup.check {
+        return None;
+    }
+    let label = format!("Startup Time {}", binary_label(crate_name, binary_name));
+    let Some(median) = median_startup(binary, startup.runs.max(1)) else {
+        return Some(CheckResult::warn(label, "Could not run --version"));
+    };
+    let message = format!("--version took {}ms (median)", median.as_millis());
+    Some(if median > Duration::from_millis(startup.budget_ms) {
+        CheckResult::warn(
+            label,
+            format!("{}, budget is {}ms", message, startup.budget_ms),
+        )
+    } else {
+        CheckResult::pass(label, message)
+    })
+}
+
+fn median_startup(binary: &Path, runs: usize) -> Option<Duration> {
+    let mut times = Vec::with_capacity(runs);
+    for _ in 0..runs {
+        let output = Exec::new(binary).args(["--version"]).run().ok()?;
+        if !output.success() {
+            return None;
+        }
+        times.push(output.elapsed);
+    }
+    times.sort();
+    times.get(runs / 2).copied()
+}
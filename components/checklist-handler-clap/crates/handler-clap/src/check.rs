@@ -1,18 +1,25 @@
 //! Binary checking orchestration
 
 use checklist_result::CheckResult;
-use clap_binary::{check_binary_freshness, find_binary, get_binary_names};
+use clap_binary::{
+    binary_target, check_binary_freshness, check_binary_size, check_embedded_paths,
+    check_invalid_flag, find_binary, get_binary_names, is_host_target,
+};
 use clap_help::check_help_flags;
-use clap_version::check_version_flags;
+use clap_subcommands::check_subcommand_guidance;
+use clap_version::{check_startup_time, check_version_flags};
+use discovery_crate::CrateType;
 use handler_trait::CheckContext;
 use std::path::Path;
 
+use crate::result::foreign_target_result;
+
 /// Check binaries for a crate
 pub fn check_crate_binaries(ctx: &CheckContext) -> Option<Vec<CheckResult>> {
     let mut results = Vec::new();
     let mut found_any = false;
 
-    for binary_name in get_binary_names(ctx.cargo_toml, ctx.crate_name) {
+    for binary_name in get_binary_names(ctx.cargo_toml, ctx.crate_name, ctx.crate_dir) {
         if let Some(path) = find_binary(ctx.config.project_root(), &binary_name) {
             found_any = true;
             results.extend(check_binary(ctx, &path, &binary_name));
@@ -25,13 +32,52 @@ fn check_binary(ctx: &CheckContext, path: &Path, binary_name: &str) -> Vec<Check
     if ctx.config.verbose() {
         println!("  Checking binary: {}", path.display());
     }
-    let mut results = check_help_flags(path, binary_name, ctx.crate_name, ctx.config.verbose());
-    results.extend(check_version_flags(
-        path,
-        binary_name,
+    if let Some(triple) = binary_target(path)
+        && !is_host_target(&triple)
+    {
+        return vec![foreign_target_result(ctx.crate_name, binary_name, &triple)];
+    }
+    let tooling = ctx.crate_type == CrateType::Tooling;
+    let mut results = flag_results(ctx, path, binary_name, tooling);
+    let budgets = &ctx.config.file().budgets;
+    results.push(check_binary_size(
         ctx.crate_name,
-        ctx.config.verbose(),
+        binary_name,
+        path,
+        budgets,
     ));
-    results.push(check_binary_freshness(binary_name, path));
+    if path.parent().is_some_and(|dir| dir.ends_with("release")) {
+        results.push(check_embedded_paths(ctx.crate_name, binary_name, path));
+    }
+    if !tooling {
+        results.extend(check_subcommand_guidance(path, binary_name, ctx.crate_name));
+        results.push(check_binary_freshness(binary_name, path));
+    }
+    results
+}
+
+/// Help, version, invalid-flag, and startup behavior of a binary
+fn flag_results(ctx: &CheckContext, path: &Path, name: &str, tooling: bool) -> Vec<CheckResult> {
+    let verbose = ctx.config.verbose();
+    let mut results = check_help_flags(path, name, ctx.crate_name, verbose, tooling);
+    results.extend(check_version_flags(path, name, ctx.crate_name, verbose));
+    results.push(check_invalid_flag(ctx.crate_name, name, path));
+    let startup = &ctx.config.file().startup;
+    results.extend(check_startup_time(ctx.crate_name, name, path, startup));
+    results
+}
+
+/// Check a prebuilt binary's help/version behavior without its source tree
+/// (freshness and layout checks need the crate and are skipped)
+pub fn check_artifact_binary(path: &Path, verbose: bool) -> Vec<CheckResult> {
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let mut results = check_help_flags(path, &name, &name, verbose, false);
+    results.extend(check_version_flags(path, &name, &name, verbose));
+    results.push(check_invalid_flag(&name, &name, path));
+    results.extend(check_subcommand_guidance(path, &name, &name));
+    results.push(check_embedded_paths(&name, &name, path));
     results
 }
@@ -2,11 +2,13 @@
 
 use anyhow::Result;
 use checklist_result::CheckResult;
+use clap_binary::get_binary_names;
+use clap_layout::{check_binary_naming, check_library_split, check_thin_main};
 use discovery_crate::CrateType;
 use handler_trait::{CheckContext, Handler};
 
 use crate::check::check_crate_binaries;
-use crate::result::{clap_dependency_result, no_binaries_result};
+use crate::result::{clap_dependency_result, no_binaries_result, tooling_not_built_result};
 
 /// Handler for CLI (clap) crate checks
 pub struct ClapHandler;
@@ -17,13 +19,27 @@ impl Handler for ClapHandler {
     }
 
     fn handles(&self, crate_type: CrateType) -> bool {
-        crate_type == CrateType::Cli
+        matches!(crate_type, CrateType::Cli | CrateType::Tooling)
     }
 
     fn check(&self, ctx: &CheckContext) -> Result<Vec<CheckResult>> {
+        let tooling = ctx.crate_type == CrateType::Tooling;
         let mut results = vec![clap_dependency_result(ctx.crate_name)];
+        if !tooling {
+            let binary_names = get_binary_names(ctx.cargo_toml, ctx.crate_name, ctx.crate_dir);
+            let prefix = ctx.config.file().naming.binary_prefix.as_deref();
+            results.extend(check_binary_naming(&binary_names, ctx.crate_name, prefix));
+            results.push(check_library_split(
+                ctx.cargo_toml,
+                ctx.crate_dir,
+                ctx.crate_name,
+            ));
+            let max_loc = ctx.config.file().budgets.main_loc;
+            results.extend(check_thin_main(ctx.crate_dir, ctx.crate_name, max_loc));
+        }
         match check_crate_binaries(ctx) {
             Some(r) => results.extend(r),
+            None if tooling => results.push(tooling_not_built_result(ctx.crate_name)),
             None => results.push(no_binaries_result(ctx.crate_name)),
         }
         Ok(results)
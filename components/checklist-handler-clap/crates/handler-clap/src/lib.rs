@@ -4,4 +4,5 @@ mod check;
 mod handler;
 mod result;
 
+pub use check::check_artifact_binary;
 pub use handler::ClapHandler;
@@ -1,6 +1,7 @@
 //! Result construction helpers
 
 use checklist_result::CheckResult;
+use discovery_crate::binary_label;
 
 pub fn clap_dependency_result(crate_name: &str) -> CheckResult {
     CheckResult::pass(
@@ -18,3 +19,23 @@ pub fn no_binaries_result(crate_name: &str) -> CheckResult {
         ),
     )
 }
+
+pub fn tooling_not_built_result(crate_name: &str) -> CheckResult {
+    CheckResult::info(
+        format!("Binary Check [{}]", crate_name),
+        format!(
+            "{} is build tooling and has no built binary; skipping binary checks",
+            crate_name
+        ),
+    )
+}
+
+pub fn foreign_target_result(crate_name: &str, binary_name: &str, triple: &str) -> CheckResult {
+    CheckResult::info(
+        format!("Binary Execution {}", binary_label(crate_name, binary_name)),
+        format!(
+            "{} is built for {} (not this host); skipping execution checks",
+            binary_name, triple
+        ),
+    )
+}
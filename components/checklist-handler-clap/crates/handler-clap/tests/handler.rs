@@ -0,0 +1,45 @@
+use checklist_config::FileConfig;
+use checklist_result::CheckStatus;
+use checklist_testkit::{TestCrate, assert_status};
+use handler_clap::ClapHandler;
+
+const CLI_MANIFEST: &str = "[package]\nname = \"demo\"\nversion = \"0.1.0\"\nedition = \"2024\"\n\n[dependencies]\nclap = \"4\"\n";
+
+fn cli() -> TestCrate {
+    TestCrate::with_manifest(CLI_MANIFEST)
+        .unwrap()
+        .file("src/main.rs", "fn main() {}\n")
+        .unwrap()
+}
+
+#[test]
+fn libraries_without_clap_are_not_checked() {
+    let krate = TestCrate::new("my-library")
+        .unwrap()
+        .file("src/lib.rs", "pub fn f() {}\n")
+        .unwrap();
+    let results = krate
+        .run(&ClapHandler, &krate.config(FileConfig::default()))
+        .unwrap();
+    assert!(results.is_empty(), "{:?}", results);
+}
+
+#[test]
+fn unbuilt_cli_binaries_fail() {
+    let krate = cli();
+    let results = krate
+        .run(&ClapHandler, &krate.config(FileConfig::default()))
+        .unwrap();
+    assert_status(&results, "Clap Dependency", CheckStatus::Pass);
+    assert_status(&results, "Binary Naming", CheckStatus::Pass);
+    assert_status(&results, "Binary Check", CheckStatus::Fail);
+}
+
+#[test]
+fn binary_prefix_is_enforced_when_configured() {
+    let krate = cli();
+    let mut file = FileConfig::default();
+    file.naming.binary_prefix = Some("sw-".into());
+    let results = krate.run(&ClapHandler, &krate.config(file)).unwrap();
+    assert_status(&results, "Binary Naming", CheckStatus::Warn);
+}
@@ -0,0 +1,42 @@
+//! Database handler implementation
+
+use anyhow::Result;
+use checklist_result::CheckResult;
+use discovery_crate::{CrateType, has_dependency};
+use handler_trait::{CheckContext, Handler};
+
+use crate::migrations::{check_migration_names, find_migrations_dir};
+use crate::sqlx::check_offline_cache;
+
+/// Handler for crates using sqlx or diesel
+pub struct DbHandler;
+
+impl Handler for DbHandler {
+    fn name(&self) -> &'static str {
+        "db"
+    }
+
+    fn handles(&self, crate_type: CrateType) -> bool {
+        crate_type != CrateType::Workspace
+    }
+
+    fn check(&self, ctx: &CheckContext) -> Result<Vec<CheckResult>> {
+        let uses_sqlx = has_dependency(ctx.cargo_toml, "sqlx");
+        if !uses_sqlx && !has_dependency(ctx.cargo_toml, "diesel") {
+            return Ok(vec![]);
+        }
+        let label = format!("Migrations [{}]", ctx.crate_name);
+        let root = ctx.config.project_root();
+        let mut results = match find_migrations_dir(ctx.crate_dir, root) {
+            Some(dir) => vec![
+                CheckResult::pass(label, format!("Found {}", dir.display())),
+                check_migration_names(&dir, ctx.crate_name),
+            ],
+            None => vec![CheckResult::fail(label, "No migrations/ directory found")],
+        };
+        if uses_sqlx {
+            results.push(check_offline_cache(ctx, root));
+        }
+        Ok(results)
+    }
+}
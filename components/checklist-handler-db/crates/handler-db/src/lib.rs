@@ -0,0 +1,7 @@
+//! Database migration check handler (sqlx, diesel) for sw-checklist
+
+mod handler;
+mod migrations;
+mod sqlx;
+
+pub use handler::DbHandler;
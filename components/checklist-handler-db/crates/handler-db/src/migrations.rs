@@ -0,0 +1,77 @@
+//! Migration directory discovery and naming checks
+
+use checklist_result::CheckResult;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Find `migrations/` in the crate directory or an ancestor up to the project root
+pub fn find_migrations_dir(crate_dir: &Path, project_root: &Path) -> Option<PathBuf> {
+    for dir in crate_dir.ancestors() {
+        let migrations = dir.join("migrations");
+        if migrations.is_dir() {
+            return Some(migrations);
+        }
+        if dir == project_root {
+            break;
+        }
+    }
+    None
+}
+
+/// Check migrations are named `<version>_<name>` with unique versions
+///
+/// Accepts sqlx files (`0001_init.sql`, `20240101120000_init.up.sql`) and
+/// diesel directories (`2024-01-01-120000_init`).
+pub fn check_migration_names(dir: &Path, crate_name: &str) -> CheckResult {
+    let label = format!("Migration Naming [{}]", crate_name);
+    let (keyed, unversioned) = migration_names(dir);
+    if !unversioned.is_empty() {
+        let names = unversioned.join(", ");
+        return CheckResult::fail(label, format!("Not named <version>_<name>: {}", names));
+    }
+    let duplicates: Vec<String> = keyed
+        .windows(2)
+        .filter(|pair| pair[0].0 == pair[1].0)
+        .map(|pair| format!("{} / {}", pair[0].1, pair[1].1))
+        .collect();
+    if duplicates.is_empty() {
+        let count = keyed.len();
+        CheckResult::pass(label, format!("{} migration(s), versions unique", count))
+    } else {
+        let names = duplicates.join(", ");
+        CheckResult::fail(label, format!("Duplicate migration versions: {}", names))
+    }
+}
+
+/// Migration entries as sorted (version key, name) pairs, plus unversioned names
+fn migration_names(dir: &Path) -> (Vec<(String, String)>, Vec<String>) {
+    let mut keyed = Vec::new();
+    let mut unversioned = Vec::new();
+    let entries = fs::read_dir(dir).into_iter().flatten().flatten();
+    for name in entries.map(|e| e.file_name().to_string_lossy().into_owned()) {
+        match version_key(&name) {
+            Some(key) => keyed.push((key, name)),
+            None if !name.starts_with('.') => unversioned.push(name),
+            None => {}
+        }
+    }
+    keyed.sort();
+    unversioned.sort();
+    (keyed, unversioned)
+}
+
+/// The numeric version prefix before the first `_` (dashes allowed, as in
+/// diesel), qualified by direction so sqlx `.up.sql`/`.down.sql` pairs don't collide
+fn version_key(name: &str) -> Option<String> {
+    let (version, rest) = name.split_once('_')?;
+    let numeric = version.chars().all(|c| c.is_ascii_digit() || c == '-');
+    if !numeric || !version.starts_with(|c: char| c.is_ascii_digit()) || rest.is_empty() {
+        return None;
+    }
+    let direction = if rest.ends_with(".down.sql") {
+        "down"
+    } else {
+        "up"
+    };
+    Some(format!("{}-{}", version.replace('-', ""), direction))
+}
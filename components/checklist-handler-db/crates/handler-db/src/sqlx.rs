@@ -0,0 +1,40 @@
+//! sqlx offline query cache check
+
+use checklist_result::CheckResult;
+use discovery_source::rust_sources;
+use handler_trait::CheckContext;
+use std::fs;
+use std::path::Path;
+
+/// Compile-time checked query macros that need the offline cache
+const QUERY_MACROS: &[&str] = &["query!", "query_as!", "query_scalar!", "query_file!"];
+
+/// Check the `.sqlx/` offline query cache exists when query macros are used
+pub fn check_offline_cache(ctx: &CheckContext, project_root: &Path) -> CheckResult {
+    let label = format!("SQLx Offline Cache [{}]", ctx.crate_name);
+    let uses_macros = rust_sources(&ctx.crate_dir.join("src"), ctx.config)
+        .unwrap_or_default()
+        .iter()
+        .any(|source| QUERY_MACROS.iter().any(|m| source.content.contains(m)));
+    if !uses_macros {
+        return CheckResult::pass(label, "No compile-time query macros used");
+    }
+    let cached = [ctx.crate_dir, project_root]
+        .iter()
+        .map(|dir| dir.join(".sqlx"))
+        .find(|dir| has_query_files(dir));
+    match cached {
+        Some(dir) => CheckResult::pass(label, format!("Query cache in {}", dir.display())),
+        None => CheckResult::fail(
+            label,
+            "No .sqlx/ query cache; run `cargo sqlx prepare` and commit .sqlx/",
+        ),
+    }
+}
+
+fn has_query_files(dir: &Path) -> bool {
+    fs::read_dir(dir).into_iter().flatten().flatten().any(|e| {
+        let name = e.file_name().to_string_lossy().into_owned();
+        name.starts_with("query-") && name.ends_with(".json")
+    })
+}
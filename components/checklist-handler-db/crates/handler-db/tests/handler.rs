@@ -0,0 +1,48 @@
+use checklist_result::CheckStatus;
+use checklist_testkit::{TestCrate, assert_status};
+use handler_db::DbHandler;
+
+const DIESEL: &str =
+    "[package]\nname = \"store\"\nversion = \"0.1.0\"\n\n[dependencies]\ndiesel = \"2.2\"\n";
+
+fn store() -> TestCrate {
+    TestCrate::with_manifest(DIESEL)
+        .unwrap()
+        .file("src/lib.rs", "")
+        .unwrap()
+}
+
+#[test]
+fn crates_without_a_database_are_not_checked() {
+    let krate = TestCrate::new("plain")
+        .unwrap()
+        .file("src/lib.rs", "")
+        .unwrap();
+    assert!(krate.check(&DbHandler).unwrap().is_empty());
+}
+
+#[test]
+fn database_crates_need_migrations() {
+    assert_status(
+        &store().check(&DbHandler).unwrap(),
+        "Migrations",
+        CheckStatus::Fail,
+    );
+}
+
+#[test]
+fn migrations_need_unique_versioned_names() {
+    let krate = store()
+        .file("migrations/2024-01-01-120000_init/up.sql", "")
+        .unwrap();
+    let results = krate.check(&DbHandler).unwrap();
+    assert_status(&results, "Migrations", CheckStatus::Pass);
+    assert_status(&results, "Migration Naming", CheckStatus::Pass);
+
+    let krate = krate.file("migrations/init/up.sql", "").unwrap();
+    assert_status(
+        &krate.check(&DbHandler).unwrap(),
+        "Migration Naming",
+        CheckStatus::Fail,
+    );
+}
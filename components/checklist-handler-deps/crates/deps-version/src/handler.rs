@@ -0,0 +1,67 @@
+//! Version style handler implementation
+
+use anyhow::Result;
+use checklist_result::CheckResult;
+use discovery_crate::CrateType;
+use handler_trait::{CheckContext, Handler};
+
+use crate::requirement::{dependency_tables, review};
+
+/// Handler for version-requirement style in every dependency table
+pub struct VersionStyleHandler;
+
+impl Handler for VersionStyleHandler {
+    fn name(&self) -> &'static str {
+        "dep-versions"
+    }
+
+    fn handles(&self, _crate_type: CrateType) -> bool {
+        true
+    }
+
+    fn check(&self, ctx: &CheckContext) -> Result<Vec<CheckResult>> {
+        let label = format!("Dependency Versions [{}]", ctx.crate_name);
+        let Ok(cargo) = ctx.cargo_toml.parse::<toml::Value>() else {
+            return Ok(Vec::new());
+        };
+        let results = style_results(ctx, &cargo, &label);
+        if results.is_empty() {
+            let message = "Dependency requirements follow the style";
+            return Ok(vec![CheckResult::pass(label, message)]);
+        }
+        Ok(results)
+    }
+}
+
+/// A warning or failure, at its Cargo.toml line, for each dependency breaking the style
+fn style_results(ctx: &CheckContext, cargo: &toml::Value, label: &str) -> Vec<CheckResult> {
+    let manifest = ctx.crate_dir.join("Cargo.toml");
+    let mut results = Vec::new();
+    for (table, deps) in dependency_tables(cargo) {
+        for (name, spec) in deps {
+            let Some((status, reason)) = review(spec) else {
+                continue;
+            };
+            let message = format!("{}.{}: {}", table, name, reason);
+            let result = CheckResult {
+                status,
+                ..CheckResult::info(label, message)
+            };
+            results.push(result.at(&manifest, dep_line(ctx.cargo_toml, name)));
+        }
+    }
+    results
+}
+
+/// 1-based line declaring dependency `name` (1 if not found)
+fn dep_line(cargo_toml: &str, name: &str) -> usize {
+    cargo_toml
+        .lines()
+        .position(|line| {
+            let line = line.trim();
+            let key = line.strip_prefix(name).map(str::trim_start);
+            key.is_some_and(|rest| rest.starts_with('=') || rest.starts_with(".workspace"))
+                || line.ends_with(&format!("dependencies.{}]", name))
+        })
+        .map_or(1, |i| i + 1)
+}
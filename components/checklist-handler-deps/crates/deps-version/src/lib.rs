@@ -0,0 +1,13 @@
+//! Dependency version-requirement style checks for sw-checklist
+//!
+//! Enforces the org's requirement style in every dependency table: caret
+//! requirements with at least major.minor, no `*` wildcards, and git
+//! dependencies pinned to a `rev` or `tag`. Also audits `[patch]` and
+//! `[replace]` entries left behind after debugging.
+
+mod handler;
+mod patch;
+mod requirement;
+
+pub use handler::VersionStyleHandler;
+pub use patch::PatchHandler;
@@ -0,0 +1,111 @@
+//! `[patch]` and `[replace]` audit
+
+use anyhow::Result;
+use checklist_result::CheckResult;
+use discovery_crate::CrateType;
+use handler_trait::{CheckContext, Handler};
+use toml::Value;
+
+/// Handler warning about `[patch]` / `[replace]` entries not in `[patches] allow`
+pub struct PatchHandler;
+
+impl Handler for PatchHandler {
+    fn name(&self) -> &'static str {
+        "dep-patches"
+    }
+
+    fn handles(&self, _crate_type: CrateType) -> bool {
+        true
+    }
+
+    fn check(&self, ctx: &CheckContext) -> Result<Vec<CheckResult>> {
+        let cargo = ctx.cargo_toml.parse::<Value>().ok();
+        let entries = cargo.as_ref().map(patch_entries).unwrap_or_default();
+        let label = format!("Dependency Patches [{}]", ctx.crate_name);
+        let allow = &ctx.config.file().patches.allow;
+        let manifest = ctx.crate_dir.join("Cargo.toml");
+        let results: Vec<CheckResult> = entries
+            .iter()
+            .filter(|e| !e.allowed_by(allow))
+            .map(|e| {
+                let message = format!(
+                    "[{}] {} is not allowlisted in [patches] allow",
+                    e.table, e.key
+                );
+                let line = entry_line(ctx.cargo_toml, &e.table, &e.key);
+                CheckResult::warn(label.clone(), message).at(&manifest, line)
+            })
+            .collect();
+        if results.is_empty() && !entries.is_empty() {
+            let message = format!("{} patch entries, all allowlisted", entries.len());
+            return Ok(vec![CheckResult::pass(label, message)]);
+        }
+        Ok(results)
+    }
+}
+
+/// A `[patch.<source>]` or `[replace]` entry
+struct PatchEntry {
+    /// Table the entry is in, e.g. "patch.crates-io" or "replace"
+    table: String,
+    /// Entry key: a package name, or `name:version` for `[replace]`
+    key: String,
+}
+
+impl PatchEntry {
+    /// Whether `allow` names the entry's key, package, or `<source>.<key>`
+    fn allowed_by(&self, allow: &[String]) -> bool {
+        let package = self.key.split(':').next().unwrap_or(&self.key);
+        let source = self.table.strip_prefix("patch.").unwrap_or(&self.table);
+        let qualified = format!("{}.{}", source, self.key);
+        allow
+            .iter()
+            .any(|a| *a == self.key || a == package || *a == qualified)
+    }
+}
+
+/// Every entry of every `[patch.<source>]` table and of `[replace]`
+fn patch_entries(cargo: &Value) -> Vec<PatchEntry> {
+    let table = |key: &str| {
+        cargo
+            .get(key)
+            .and_then(Value::as_table)
+            .into_iter()
+            .flatten()
+    };
+    let patches = table("patch").flat_map(|(source, entries)| {
+        let keys = entries.as_table().into_iter().flatten().map(|(key, _)| key);
+        keys.map(move |key| PatchEntry {
+            table: format!("patch.{}", source),
+            key: key.clone(),
+        })
+    });
+    let replaces = table("replace").map(|(key, _)| PatchEntry {
+        table: "replace".to_string(),
+        key: key.clone(),
+    });
+    patches.chain(replaces).collect()
+}
+
+/// 1-based line of `key` in `[table]` (or of a `[table.key]` header), else 1
+fn entry_line(cargo_toml: &str, table: &str, key: &str) -> usize {
+    let normalize = |s: &str| s.replace(['"', '\'', ' '], "");
+    let (header, dotted) = (
+        normalize(&format!("[{}]", table)),
+        normalize(&format!("[{}.{}]", table, key)),
+    );
+    let entry = format!("{}=", normalize(key));
+    let lines: Vec<String> = cargo_toml.lines().map(normalize).collect();
+    if let Some(i) = lines.iter().position(|l| *l == dotted) {
+        return i + 1;
+    }
+    let start = lines.iter().position(|l| *l == header);
+    start
+        .and_then(|s| {
+            lines[s..]
+                .iter()
+                .position(|l| l.starts_with(&entry))
+                .map(|i| s + i + 1)
+        })
+        .unwrap_or(1)
+}
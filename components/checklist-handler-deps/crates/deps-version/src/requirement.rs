@@ -0,0 +1,73 @@
+//! Dependency tables and requirement review
+
+use checklist_result::CheckStatus;
+use toml::Value;
+
+/// Dependency kinds, as table names
+const KINDS: [&str; 3] = ["dependencies", "dev-dependencies", "build-dependencies"];
+
+/// Dependency tables of a package or workspace manifest, keyed by their TOML path
+pub fn dependency_tables(cargo: &Value) -> Vec<(String, &toml::Table)> {
+    let mut tables = kind_tables(cargo, "");
+    let workspace = cargo.get("workspace").and_then(|w| w.get("dependencies"));
+    if let Some(table) = workspace.and_then(Value::as_table) {
+        tables.push(("workspace.dependencies".to_string(), table));
+    }
+    let targets = cargo.get("target").and_then(Value::as_table);
+    for (target, table) in targets.into_iter().flatten() {
+        tables.extend(kind_tables(table, &format!("target.{}.", target)));
+    }
+    tables
+}
+
+/// The dependency kind tables directly under `parent`, with `prefix` on their names
+fn kind_tables<'a>(parent: &'a Value, prefix: &str) -> Vec<(String, &'a toml::Table)> {
+    KINDS
+        .iter()
+        .filter_map(|kind| Some((format!("{}{}", prefix, kind), parent.get(kind)?.as_table()?)))
+        .collect()
+}
+
+/// Status and reason when a dependency spec breaks the requirement style
+pub fn review(spec: &Value) -> Option<(CheckStatus, String)> {
+    match spec {
+        Value::String(req) => review_requirement(req),
+        Value::Table(table) if table.contains_key("git") => {
+            let pinned = table.contains_key("rev") || table.contains_key("tag");
+            let branch = table
+                .get("branch")
+                .and_then(Value::as_str)
+                .unwrap_or("the default branch");
+            (!pinned).then(|| {
+                let reason = format!("git dependency tracks {} without a `rev` or `tag`", branch);
+                (CheckStatus::Fail, reason)
+            })
+        }
+        Value::Table(table) => table.get("version")?.as_str().and_then(review_requirement),
+        _ => None,
+    }
+}
+
+/// Fail on wildcards; warn on non-caret operators and major-only requirements
+fn review_requirement(req: &str) -> Option<(CheckStatus, String)> {
+    let req = req.trim();
+    if req.contains('*') {
+        return Some((
+            CheckStatus::Fail,
+            format!("wildcard requirement \"{}\"", req),
+        ));
+    }
+    let caret = req.strip_prefix('^').unwrap_or(req).trim();
+    if caret.starts_with(['=', '~', '<', '>']) || caret.contains(',') {
+        let reason = format!("\"{}\" is not a caret requirement (use e.g. \"1.2\")", req);
+        return Some((CheckStatus::Warn, reason));
+    }
+    if !caret.contains('.') {
+        let reason = format!(
+            "\"{}\" gives only the major version (use \"{}.0\")",
+            req, caret
+        );
+        return Some((CheckStatus::Warn, reason));
+    }
+    None
+}
@@ -0,0 +1,50 @@
+use checklist_config::FileConfig;
+use checklist_result::CheckStatus;
+use checklist_testkit::{TestCrate, assert_status, results_for};
+use deps_version::{PatchHandler, VersionStyleHandler};
+
+/// Run `handler` on a crate whose manifest ends with `tables`
+/// A library with `tables` after its `[package]`
+fn demo(tables: &str) -> TestCrate {
+    let manifest = format!(
+        "[package]\nname = \"demo\"\nversion = \"0.1.0\"\n\n{}",
+        tables
+    );
+    TestCrate::with_manifest(&manifest)
+        .unwrap()
+        .file("src/lib.rs", "")
+        .unwrap()
+}
+
+#[test]
+fn caret_requirements_pass() {
+    let results = demo("[dependencies]\nserde = \"1.0\"\n")
+        .check(&VersionStyleHandler)
+        .unwrap();
+    assert_status(&results, "Dependency Versions", CheckStatus::Pass);
+}
+
+#[test]
+fn wildcards_and_unpinned_git_fail_and_major_only_warns() {
+    let tables = "[dependencies]\nanyhow = \"*\"\nlog = \"0\"\n\n[dev-dependencies]\nfoo = { git = \"https://example.com/foo\" }\n";
+    let results = demo(tables).check(&VersionStyleHandler).unwrap();
+    let found = results_for(&results, "Dependency Versions");
+    assert_eq!(found.len(), 3, "{:?}", found);
+    assert_status(&results, "Dependency Versions", CheckStatus::Fail);
+    assert_status(&results, "Dependency Versions", CheckStatus::Warn);
+    let anyhow = found.iter().find(|r| r.message.contains("anyhow")).unwrap();
+    assert_eq!(anyhow.location.as_ref().unwrap().line, 6);
+}
+
+#[test]
+fn patches_warn_unless_allowlisted() {
+    let tables = "[patch.crates-io]\nring = { path = \"../ring\" }\n";
+    let krate = demo(tables);
+    let results = krate.check(&PatchHandler).unwrap();
+    assert_status(&results, "Dependency Patches", CheckStatus::Warn);
+
+    let mut file = FileConfig::default();
+    file.patches.allow = vec!["crates-io.ring".into()];
+    let results = krate.run(&PatchHandler, &krate.config(file)).unwrap();
+    assert_status(&results, "Dependency Patches", CheckStatus::Pass);
+}
@@ -0,0 +1,65 @@
+//! Cycle and depth analysis of the internal dependency graph
+
+use crate::metadata::Graph;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Find a dependency cycle, returned as the crates along it (first repeated at the end)
+pub fn find_cycle(graph: &Graph) -> Option<Vec<String>> {
+    let mut done = BTreeSet::new();
+    let mut stack = Vec::new();
+    graph
+        .keys()
+        .find_map(|node| visit(graph, node, &mut stack, &mut done))
+}
+
+fn visit(
+    graph: &Graph,
+    node: &str,
+    stack: &mut Vec<String>,
+    done: &mut BTreeSet<String>,
+) -> Option<Vec<String>> {
+    if let Some(pos) = stack.iter().position(|n| n == node) {
+        let mut cycle = stack[pos..].to_vec();
+        cycle.push(node.to_string());
+        return Some(cycle);
+    }
+    if done.contains(node) {
+        return None;
+    }
+    stack.push(node.to_string());
+    for dep in graph.get(node).into_iter().flatten() {
+        if let Some(cycle) = visit(graph, dep, stack, done) {
+            return Some(cycle);
+        }
+    }
+    stack.pop();
+    done.insert(node.to_string());
+    None
+}
+
+/// Longest dependency chain in an acyclic graph, from dependent to deepest dependency
+pub fn longest_chain(graph: &Graph) -> Vec<String> {
+    let mut memo: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    graph
+        .keys()
+        .map(|node| chain_from(graph, node, &mut memo))
+        .max_by_key(|chain| chain.len())
+        .unwrap_or_default()
+}
+
+fn chain_from(graph: &Graph, node: &str, memo: &mut BTreeMap<String, Vec<String>>) -> Vec<String> {
+    if let Some(chain) = memo.get(node) {
+        return chain.clone();
+    }
+    let deepest = graph
+        .get(node)
+        .into_iter()
+        .flatten()
+        .map(|dep| chain_from(graph, dep, memo))
+        .max_by_key(|chain| chain.len())
+        .unwrap_or_default();
+    let mut chain = vec![node.to_string()];
+    chain.extend(deepest);
+    memo.insert(node.to_string(), chain.clone());
+    chain
+}
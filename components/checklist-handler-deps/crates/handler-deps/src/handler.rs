@@ -0,0 +1,76 @@
+//! Dependency graph handler implementation
+
+use anyhow::Result;
+use checklist_result::CheckResult;
+use handler_trait::{ProjectContext, ProjectHandler};
+
+use crate::graph::{find_cycle, longest_chain};
+use crate::metadata::{Graph, internal_graph};
+
+/// Crates allowed in an internal dependency chain before warning, unless
+/// `[deps] max_chain_depth` says otherwise
+const DEFAULT_MAX_CHAIN_DEPTH: usize = 7;
+
+/// Project handler for internal dependency cycles and chain depth
+pub struct DepsHandler;
+
+impl ProjectHandler for DepsHandler {
+    fn name(&self) -> &'static str {
+        "deps"
+    }
+
+    fn check(&self, ctx: &ProjectContext) -> Result<Vec<CheckResult>> {
+        match internal_graph(ctx.cargo_tomls) {
+            Ok(graph) => Ok(check_graph(ctx, &graph)),
+            Err(e) => Ok(vec![CheckResult::warn(
+                "Dependency Graph [project]",
+                format!("Could not read cargo metadata: {}", e),
+            )]),
+        }
+    }
+}
+
+fn check_graph(ctx: &ProjectContext, graph: &Graph) -> Vec<CheckResult> {
+    let max_depth = ctx
+        .config
+        .file()
+        .deps
+        .max_chain_depth
+        .unwrap_or(DEFAULT_MAX_CHAIN_DEPTH);
+    if let Some(cycle) = find_cycle(graph) {
+        return vec![CheckResult::fail(
+            "Dependency Cycles [project]",
+            format!("Cycle between internal crates: {}", cycle.join(" -> ")),
+        )];
+    }
+    vec![
+        CheckResult::pass(
+            "Dependency Cycles [project]",
+            format!("No cycles among {} internal crate(s)", graph.len()),
+        ),
+        check_chain_depth(&longest_chain(graph), max_depth),
+    ]
+}
+
+fn check_chain_depth(chain: &[String], max_depth: usize) -> CheckResult {
+    let label = "Dependency Depth [project]";
+    if chain.len() > max_depth {
+        CheckResult::warn(
+            label,
+            format!(
+                "Internal dependency chain of {} crates (max {}): {}",
+                chain.len(),
+                max_depth,
+                chain.join(" -> ")
+            ),
+        )
+    } else {
+        CheckResult::pass(
+            label,
+            format!(
+                "Longest internal dependency chain is {} crate(s)",
+                chain.len()
+            ),
+        )
+    }
+}
@@ -0,0 +1,10 @@
+//! Internal dependency graph checks for sw-checklist
+//!
+//! Builds the graph of path dependencies between project crates from
+//! `cargo metadata` and checks it is a shallow DAG.
+
+mod graph;
+mod handler;
+mod metadata;
+
+pub use handler::DepsHandler;
@@ -0,0 +1,97 @@
+//! Internal dependency graph from cargo metadata
+
+use anyhow::{Context, Result, bail};
+use checklist_exec::Exec;
+use serde::Deserialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Limit for one `cargo metadata --no-deps` run (it may need to update the index)
+const METADATA_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Crate name to the internal (path) dependencies it uses
+pub type Graph = BTreeMap<String, BTreeSet<String>>;
+
+#[derive(Deserialize)]
+struct Metadata {
+    packages: Vec<Package>,
+}
+
+#[derive(Deserialize)]
+struct Package {
+    name: String,
+    manifest_path: PathBuf,
+    dependencies: Vec<Dependency>,
+}
+
+#[derive(Deserialize)]
+struct Dependency {
+    name: String,
+    path: Option<PathBuf>,
+    kind: Option<String>,
+}
+
+/// Build the internal dependency graph across all workspaces in the project
+pub fn internal_graph(cargo_tomls: &[PathBuf]) -> Result<Graph> {
+    let (roots, others): (Vec<&PathBuf>, Vec<&PathBuf>) = cargo_tomls.iter().partition(|p| {
+        fs::read_to_string(p)
+            .map(|c| c.contains("[workspace]"))
+            .unwrap_or(false)
+    });
+    let mut packages = Vec::new();
+    for manifest in roots {
+        packages.extend(load_packages(manifest)?);
+    }
+    // Standalone crates not covered by any workspace
+    for manifest in others {
+        let manifest = manifest.canonicalize().unwrap_or(manifest.clone());
+        if !packages.iter().any(|p| p.manifest_path == manifest) {
+            packages.extend(load_packages(&manifest)?);
+        }
+    }
+    Ok(build_graph(&packages))
+}
+
+fn load_packages(manifest: &Path) -> Result<Vec<Package>> {
+    let output = Exec::new("cargo")
+        .args([
+            "metadata",
+            "--format-version",
+            "1",
+            "--no-deps",
+            "--manifest-path",
+        ])
+        .args([manifest])
+        .timeout(METADATA_TIMEOUT)
+        .run()
+        .context("Failed to run cargo metadata")?;
+    if !output.status.success() {
+        bail!(
+            "cargo metadata failed for {}: {}",
+            manifest.display(),
+            output.stderr.trim()
+        );
+    }
+    let metadata: Metadata = serde_json::from_str(&output.stdout)
+        .with_context(|| format!("Failed to parse cargo metadata for {}", manifest.display()))?;
+    Ok(metadata.packages)
+}
+
+fn build_graph(packages: &[Package]) -> Graph {
+    let names: BTreeSet<&str> = packages.iter().map(|p| p.name.as_str()).collect();
+    packages
+        .iter()
+        .map(|p| {
+            let deps = p
+                .dependencies
+                .iter()
+                .filter(|d| d.path.is_some() && d.kind.as_deref() != Some("dev"))
+                .filter(|d| names.contains(d.name.as_str()))
+                .map(|d| d.name.clone())
+                .collect();
+            (p.name.clone(), deps)
+        })
+        .collect()
+}
@@ -0,0 +1,74 @@
+use checklist_config::FileConfig;
+use checklist_result::CheckStatus;
+use checklist_testkit::{TestCrate, assert_status};
+use handler_deps::DepsHandler;
+
+const WORKSPACE: &str = "[workspace]\nresolver = \"2\"\nmembers = [\"crates/*\"]\n";
+
+/// A workspace of crates under crates/, each with path dependencies on the named siblings
+fn workspace(crates: &[(&str, &[&str])]) -> TestCrate {
+    let root = TestCrate::with_manifest(WORKSPACE).unwrap();
+    crates.iter().fold(root, |krate, (name, deps)| {
+        let deps: String = deps
+            .iter()
+            .map(|d| format!("{} = {{ path = \"../{}\" }}\n", d, d))
+            .collect();
+        let manifest = format!(
+            "[package]\nname = \"{}\"\nversion = \"0.1.0\"\nedition = \"2024\"\n\n[dependencies]\n{}",
+            name, deps
+        );
+        krate
+            .file(&format!("crates/{}/Cargo.toml", name), manifest)
+            .unwrap()
+            .file(&format!("crates/{}/src/lib.rs", name), "")
+            .unwrap()
+    })
+}
+
+#[test]
+fn a_single_crate_is_a_shallow_dag() {
+    let krate = TestCrate::new("demo")
+        .unwrap()
+        .file("src/lib.rs", "")
+        .unwrap();
+    let results = krate
+        .run_project(&DepsHandler, &krate.config(FileConfig::default()))
+        .unwrap();
+    assert_status(&results, "Dependency Cycles", CheckStatus::Pass);
+    assert_status(&results, "Dependency Depth", CheckStatus::Pass);
+}
+
+#[test]
+fn cycles_between_workspace_crates_fail() {
+    let krate = workspace(&[("a", &["b"]), ("b", &["a"])]);
+    let results = krate.check_project(&DepsHandler).unwrap();
+    assert_status(&results, "Dependency Cycles", CheckStatus::Fail);
+    assert!(
+        results[0].message.ends_with("a -> b -> a"),
+        "{}",
+        results[0].message
+    );
+}
+
+#[test]
+fn chains_deeper_than_the_configured_limit_warn() {
+    let krate = workspace(&[("a", &["b"]), ("b", &["c"]), ("c", &[])]);
+    assert_status(
+        &krate.check_project(&DepsHandler).unwrap(),
+        "Dependency Depth",
+        CheckStatus::Pass,
+    );
+
+    let mut file = FileConfig::default();
+    file.deps.max_chain_depth = Some(2);
+    let results = krate
+        .run_project(&DepsHandler, &krate.config(file))
+        .unwrap();
+    assert_status(&results, "Dependency Cycles", CheckStatus::Pass);
+    assert_status(&results, "Dependency Depth", CheckStatus::Warn);
+    assert!(
+        results[1].message.ends_with("a -> b -> c"),
+        "{}",
+        results[1].message
+    );
+}
@@ -0,0 +1,50 @@
+//! About dialog checks, mirroring the CLI --version metadata requirements
+
+use checklist_result::CheckResult;
+
+/// Metadata the about dialog must show, with source patterns (lowercase) that
+/// satisfy each, e.g. the build-time env var or the field's label in UI text
+const ABOUT_FIELDS: &[(&str, &[&str])] = &[
+    (
+        "Version",
+        &["cargo_pkg_version", "\"version:", "\"version {"],
+    ),
+    ("Copyright", &["copyright"]),
+    ("License", &["license"]),
+    ("Repository", &["github.com", "gitlab.com", "repository"]),
+    ("Build Host", &["build_host", "build host"]),
+    (
+        "Build Commit",
+        &["build_commit", "\"build commit", "\"commit:"],
+    ),
+    ("Build Time", &["build_time", "timestamp"]),
+];
+
+/// Check the app has an About window
+pub fn check_about_dialog(source: &str, crate_name: &str) -> CheckResult {
+    let label = format!("About Dialog [{}]", crate_name);
+    let lower = source.to_lowercase();
+    if lower.contains("window::new(\"about") || lower.contains("\"about\"") {
+        CheckResult::pass(label, "Found About window")
+    } else {
+        CheckResult::warn(
+            label,
+            "No About window; add one showing version, license, and build metadata",
+        )
+    }
+}
+
+/// Check the about metadata fields appear in the app source
+pub fn check_about_metadata(lower_source: &str, crate_name: &str) -> Vec<CheckResult> {
+    ABOUT_FIELDS
+        .iter()
+        .map(|(name, patterns)| {
+            let label = format!("About {} [{}]", name, crate_name);
+            if patterns.iter().any(|p| lower_source.contains(p)) {
+                CheckResult::pass(label, format!("Found {}", name))
+            } else {
+                CheckResult::warn(label, format!("No {} found for the About dialog", name))
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,53 @@
+//! egui handler implementation
+
+use anyhow::Result;
+use checklist_result::CheckResult;
+use discovery_crate::CrateType;
+use discovery_source::rust_sources;
+use handler_trait::{CheckContext, Handler};
+
+use crate::about::{check_about_dialog, check_about_metadata};
+
+/// Handler for eframe/egui desktop apps
+pub struct EguiHandler;
+
+impl Handler for EguiHandler {
+    fn name(&self) -> &'static str {
+        "egui"
+    }
+
+    fn handles(&self, crate_type: CrateType) -> bool {
+        crate_type == CrateType::Desktop
+    }
+
+    fn check(&self, ctx: &CheckContext) -> Result<Vec<CheckResult>> {
+        let source: String = rust_sources(&ctx.crate_dir.join("src"), ctx.config)?
+            .into_iter()
+            .map(|s| s.content)
+            .collect::<Vec<_>>()
+            .join("\n");
+        let mut results = vec![
+            check_window_title(&source, ctx.crate_name),
+            check_about_dialog(&source, ctx.crate_name),
+        ];
+        results.extend(check_about_metadata(&source.to_lowercase(), ctx.crate_name));
+        Ok(results)
+    }
+}
+
+/// The native window needs a title (`run_native("Title", ..)` or `with_title`)
+fn check_window_title(source: &str, crate_name: &str) -> CheckResult {
+    let label = format!("Window Title [{}]", crate_name);
+    let titled = source.contains(".with_title(")
+        || source.split("run_native(").skip(1).any(|call| {
+            call.trim_start().starts_with('"') && !call.trim_start().starts_with("\"\"")
+        });
+    if titled {
+        CheckResult::pass(label, "Window title is set")
+    } else {
+        CheckResult::warn(
+            label,
+            "No window title; pass one to eframe::run_native or ViewportBuilder::with_title",
+        )
+    }
+}
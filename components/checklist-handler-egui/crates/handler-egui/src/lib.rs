@@ -0,0 +1,6 @@
+//! egui/eframe desktop app check handler for sw-checklist
+
+mod about;
+mod handler;
+
+pub use handler::EguiHandler;
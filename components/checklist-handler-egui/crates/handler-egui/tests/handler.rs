@@ -0,0 +1,49 @@
+use checklist_config::FileConfig;
+use checklist_result::CheckStatus;
+use checklist_testkit::{TestCrate, assert_status};
+use handler_egui::EguiHandler;
+
+const MANIFEST: &str = "[package]\nname = \"demo-app\"\nversion = \"0.1.0\"\nedition = \"2024\"\n\n[dependencies]\neframe = \"0.31\"\n";
+
+/// A desktop app whose source is `main`
+fn app(main: &str) -> TestCrate {
+    TestCrate::with_manifest(MANIFEST)
+        .unwrap()
+        .file("src/main.rs", main)
+        .unwrap()
+}
+
+#[test]
+fn about_metadata_needs_concrete_fields() {
+    let main = r#"fn main() {
+    let options = eframe::NativeOptions::default();
+    // check the version of the commit before release
+    eframe::run_native("Demo", options, Box::new(|_| Ok(Box::new(App))));
+}
+"#;
+    let app = app(main);
+    let results = app
+        .run(&EguiHandler, &app.config(FileConfig::default()))
+        .unwrap();
+    assert_status(&results, "Window Title", CheckStatus::Pass);
+    assert_status(&results, "About Version", CheckStatus::Warn);
+    assert_status(&results, "About Build Commit", CheckStatus::Warn);
+}
+
+#[test]
+fn about_metadata_found_in_env_vars_and_labels() {
+    let main = r#"fn about(ui: &mut egui::Ui) {
+    egui::Window::new("About").show(ui.ctx(), |ui| {
+        ui.label(format!("Version: {}", env!("CARGO_PKG_VERSION")));
+        ui.label(format!("Commit: {}", env!("BUILD_COMMIT_SHA")));
+    });
+}
+"#;
+    let app = app(main);
+    let results = app
+        .run(&EguiHandler, &app.config(FileConfig::default()))
+        .unwrap();
+    assert_status(&results, "About Dialog", CheckStatus::Pass);
+    assert_status(&results, "About Version", CheckStatus::Pass);
+    assert_status(&results, "About Build Commit", CheckStatus::Pass);
+}
@@ -0,0 +1,50 @@
+//! Detection of no_std crates and embedded build targets
+
+use std::fs;
+use std::path::Path;
+
+/// Check if the crate root declares `#![no_std]` (directly or via cfg_attr)
+pub fn is_no_std(crate_dir: &Path) -> bool {
+    ["src/lib.rs", "src/main.rs"].iter().any(|root| {
+        fs::read_to_string(crate_dir.join(root))
+            .map(|content| {
+                content
+                    .lines()
+                    .map(str::trim)
+                    .any(|l| l.starts_with("#![") && l.contains("no_std"))
+            })
+            .unwrap_or(false)
+    })
+}
+
+/// Find the build target configured in .cargo/config.toml, searching up to the project root
+pub fn configured_target(crate_dir: &Path, project_root: &Path) -> Option<String> {
+    for dir in crate_dir.ancestors() {
+        for name in [".cargo/config.toml", ".cargo/config"] {
+            if let Ok(content) = fs::read_to_string(dir.join(name))
+                && let Some(target) = parse_build_target(&content)
+            {
+                return Some(target);
+            }
+        }
+        if dir == project_root {
+            break;
+        }
+    }
+    None
+}
+
+/// Check if a target triple is a bare-metal (no OS) target
+pub fn is_bare_metal(target: &str) -> bool {
+    target.split('-').any(|part| part == "none")
+}
+
+fn parse_build_target(content: &str) -> Option<String> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|l| l.starts_with("target") && !l.starts_with("target-dir"))
+        .filter_map(|l| l.split_once('='))
+        .map(|(_, value)| value.trim().trim_matches('"').to_string())
+        .find(|value| !value.is_empty() && !value.starts_with('['))
+}
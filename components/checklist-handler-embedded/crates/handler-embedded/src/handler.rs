@@ -0,0 +1,58 @@
+//! Embedded handler implementation
+
+use anyhow::Result;
+use checklist_result::CheckResult;
+use discovery_crate::CrateType;
+use handler_trait::{CheckContext, Handler};
+
+use crate::detect::{configured_target, is_bare_metal, is_no_std};
+use crate::std_usage::check_std_usage;
+
+/// Handler for no_std and embedded crates
+pub struct EmbeddedHandler;
+
+impl Handler for EmbeddedHandler {
+    fn name(&self) -> &'static str {
+        "embedded"
+    }
+
+    fn handles(&self, crate_type: CrateType) -> bool {
+        crate_type != CrateType::Workspace
+    }
+
+    fn check(&self, ctx: &CheckContext) -> Result<Vec<CheckResult>> {
+        if !is_no_std(ctx.crate_dir) {
+            return Ok(vec![]);
+        }
+        let target = configured_target(ctx.crate_dir, ctx.config.project_root());
+        let mut results = vec![CheckResult::info(
+            format!("no_std [{}]", ctx.crate_name),
+            match &target {
+                Some(t) => format!("no_std crate built for {}", t),
+                None => "no_std crate (no build target configured)".to_string(),
+            },
+        )];
+        if ctx.crate_dir.join("src/main.rs").exists() {
+            results.push(check_firmware_config(ctx, target.as_deref()));
+        }
+        results.push(check_std_usage(ctx.crate_dir, ctx.crate_name, ctx.config));
+        Ok(results)
+    }
+}
+
+/// Firmware binaries need a bare-metal target and a memory.x linker script
+fn check_firmware_config(ctx: &CheckContext, target: Option<&str>) -> CheckResult {
+    let label = format!("Firmware Config [{}]", ctx.crate_name);
+    let has_memory_x = ctx.crate_dir.join("memory.x").exists();
+    match target {
+        None => CheckResult::warn(
+            label,
+            "no_std binary has no build target in .cargo/config.toml",
+        ),
+        Some(t) if is_bare_metal(t) && !has_memory_x => CheckResult::warn(
+            label,
+            format!("Bare-metal target {} but no memory.x linker script", t),
+        ),
+        Some(t) => CheckResult::pass(label, format!("Build target {} configured", t)),
+    }
+}
@@ -0,0 +1,7 @@
+//! Embedded and no_std crate check handler for sw-checklist
+
+mod detect;
+mod handler;
+mod std_usage;
+
+pub use handler::EmbeddedHandler;
@@ -0,0 +1,45 @@
+//! Accidental std usage detection for no_std crates
+
+use checklist_config::Config;
+use checklist_result::CheckResult;
+use discovery_source::{matching_lines, rust_sources};
+use std::path::Path;
+
+/// Flag `std::` usage in a no_std crate (test modules and generated code are ignored)
+pub fn check_std_usage(crate_dir: &Path, crate_name: &str, config: &Config) -> CheckResult {
+    let label = format!("No std Usage [{}]", crate_name);
+    let offenders = files_using_std(crate_dir, config);
+    if offenders.is_empty() {
+        CheckResult::pass(label, "No std:: usage outside test code")
+    } else {
+        CheckResult::warn(
+            label,
+            format!(
+                "no_std crate uses std:: in {}; use core::/alloc:: or gate behind a feature",
+                offenders.join(", ")
+            ),
+        )
+    }
+}
+
+fn files_using_std(crate_dir: &Path, config: &Config) -> Vec<String> {
+    rust_sources(&crate_dir.join("src"), config)
+        .unwrap_or_default()
+        .iter()
+        .filter(|source| uses_std(&source.content))
+        .map(|source| {
+            let path = source.path.strip_prefix(crate_dir).unwrap_or(&source.path);
+            path.display().to_string()
+        })
+        .collect()
+}
+
+fn uses_std(content: &str) -> bool {
+    !matching_lines(content, |code| {
+        code.starts_with("use std::")
+            || code.starts_with("extern crate std")
+            || code.contains(" std::")
+            || code.contains("(std::")
+    })
+    .is_empty()
+}
@@ -0,0 +1,54 @@
+use checklist_result::CheckStatus;
+use checklist_testkit::{TestCrate, assert_status};
+use handler_embedded::EmbeddedHandler;
+
+fn firmware(main: &str) -> TestCrate {
+    TestCrate::new("firmware")
+        .unwrap()
+        .file("src/main.rs", main)
+        .unwrap()
+}
+
+#[test]
+fn std_crates_are_not_checked() {
+    assert!(
+        firmware("fn main() {}\n")
+            .check(&EmbeddedHandler)
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[test]
+fn bare_metal_firmware_needs_a_linker_script() {
+    let krate = firmware("#![no_std]\n#![no_main]\n")
+        .file(
+            ".cargo/config.toml",
+            "[build]\ntarget = \"thumbv7em-none-eabihf\"\n",
+        )
+        .unwrap();
+    let results = krate.check(&EmbeddedHandler).unwrap();
+    assert_status(&results, "no_std", CheckStatus::Info);
+    assert_status(&results, "Firmware Config", CheckStatus::Warn);
+    assert_status(&results, "No std Usage", CheckStatus::Pass);
+
+    let krate = krate.file("memory.x", "MEMORY {}\n").unwrap();
+    assert_status(
+        &krate.check(&EmbeddedHandler).unwrap(),
+        "Firmware Config",
+        CheckStatus::Pass,
+    );
+}
+
+#[test]
+fn std_usage_in_no_std_crates_warns() {
+    let krate = TestCrate::new("driver")
+        .unwrap()
+        .file("src/lib.rs", "#![no_std]\nuse std::vec::Vec;\n")
+        .unwrap();
+    assert_status(
+        &krate.check(&EmbeddedHandler).unwrap(),
+        "No std Usage",
+        CheckStatus::Warn,
+    );
+}
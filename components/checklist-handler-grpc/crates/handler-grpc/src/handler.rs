@@ -0,0 +1,65 @@
+//! gRPC handler implementation
+
+use anyhow::Result;
+use checklist_result::CheckResult;
+use discovery_crate::{CrateType, has_dependency};
+use discovery_source::{all_rust_sources, is_generated};
+use handler_trait::{CheckContext, Handler};
+
+use crate::proto::{check_codegen, check_proto_location};
+
+/// Handler for crates using tonic or prost
+pub struct GrpcHandler;
+
+impl Handler for GrpcHandler {
+    fn name(&self) -> &'static str {
+        "grpc"
+    }
+
+    fn handles(&self, crate_type: CrateType) -> bool {
+        crate_type != CrateType::Workspace
+    }
+
+    fn check(&self, ctx: &CheckContext) -> Result<Vec<CheckResult>> {
+        let libs: Vec<&str> = ["tonic", "prost"]
+            .into_iter()
+            .filter(|lib| has_dependency(ctx.cargo_toml, lib))
+            .collect();
+        if libs.is_empty() {
+            return Ok(vec![]);
+        }
+        Ok(vec![
+            CheckResult::info(
+                format!("gRPC [{}]", ctx.crate_name),
+                format!("Uses {}", libs.join(" and ")),
+            ),
+            check_proto_location(ctx.crate_dir, ctx.crate_name, ctx.config.project_root()),
+            check_codegen(ctx.crate_dir, ctx.crate_name),
+            check_generated(ctx),
+        ])
+    }
+}
+
+/// Report checked-in generated modules and whether modularity checks skip them
+fn check_generated(ctx: &CheckContext) -> CheckResult {
+    let label = format!("Generated Code [{}]", ctx.crate_name);
+    let generated = all_rust_sources(&ctx.crate_dir.join("src"), ctx.config)
+        .unwrap_or_default()
+        .iter()
+        .filter(|s| is_generated(&s.content))
+        .count();
+    match generated {
+        0 => CheckResult::pass(label, "No generated modules checked in under src/"),
+        n if ctx.config.file().generated.check => CheckResult::info(
+            label,
+            format!(
+                "{} generated module(s) are checked ([generated] check = true)",
+                n
+            ),
+        ),
+        n => CheckResult::info(
+            label,
+            format!("{} generated module(s) excluded from modularity metrics", n),
+        ),
+    }
+}
@@ -0,0 +1,6 @@
+//! gRPC/protobuf (tonic, prost) crate check handler for sw-checklist
+
+mod handler;
+mod proto;
+
+pub use handler::GrpcHandler;
@@ -0,0 +1,80 @@
+//! .proto file layout and code generation checks
+
+use checklist_result::CheckResult;
+use std::fs;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// Build scripts that regenerate Rust code from .proto files
+const CODEGEN_MARKERS: &[&str] = &["tonic_build", "prost_build", "tonic_prost_build"];
+
+/// Check that the crate's .proto files live under a `proto/` directory
+pub fn check_proto_location(crate_dir: &Path, crate_name: &str, root: &Path) -> CheckResult {
+    let label = format!("Proto Location [{}]", crate_name);
+    let protos = find_proto_files(crate_dir);
+    if protos.is_empty() {
+        return match shared_proto_dir(crate_dir, root) {
+            Some(dir) => CheckResult::pass(label, format!("Shared protos in {}", dir.display())),
+            None => CheckResult::warn(label, "No proto/ directory with .proto files found"),
+        };
+    }
+    let misplaced: Vec<String> = protos
+        .iter()
+        .filter_map(|p| p.strip_prefix(crate_dir).ok())
+        .filter(|p| !p.starts_with("proto"))
+        .map(|p| p.display().to_string())
+        .collect();
+    if misplaced.is_empty() {
+        CheckResult::pass(label, format!("{} .proto file(s) in proto/", protos.len()))
+    } else {
+        CheckResult::warn(
+            label,
+            format!("Move .proto files under proto/: {}", misplaced.join(", ")),
+        )
+    }
+}
+
+/// Check that build.rs regenerates code from the .proto files
+pub fn check_codegen(crate_dir: &Path, crate_name: &str) -> CheckResult {
+    let label = format!("Proto Codegen [{}]", crate_name);
+    match fs::read_to_string(crate_dir.join("build.rs")) {
+        Ok(build) if CODEGEN_MARKERS.iter().any(|m| build.contains(m)) => {
+            CheckResult::pass(label, "build.rs regenerates code from .proto files")
+        }
+        Ok(_) => CheckResult::warn(
+            label,
+            "build.rs does not call tonic_build or prost_build; generated code may be stale",
+        ),
+        Err(_) => CheckResult::warn(
+            label,
+            "No build.rs; regenerate code from .proto files with tonic_build or prost_build",
+        ),
+    }
+}
+
+fn find_proto_files(crate_dir: &Path) -> Vec<PathBuf> {
+    WalkDir::new(crate_dir)
+        .into_iter()
+        .filter_entry(|e| {
+            let name = e.file_name().to_string_lossy();
+            e.depth() == 0 || (name != "target" && !name.starts_with('.'))
+        })
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().extension().and_then(|s| s.to_str()) == Some("proto"))
+        .map(|e| e.into_path())
+        .collect()
+}
+
+/// A `proto/` directory with .proto files in an ancestor, up to the project root
+fn shared_proto_dir(crate_dir: &Path, project_root: &Path) -> Option<PathBuf> {
+    for dir in crate_dir.ancestors().skip(1) {
+        let proto_dir = dir.join("proto");
+        if !find_proto_files(&proto_dir).is_empty() {
+            return Some(proto_dir);
+        }
+        if dir == project_root {
+            break;
+        }
+    }
+    None
+}
@@ -0,0 +1,46 @@
+use checklist_result::CheckStatus;
+use checklist_testkit::{TestCrate, assert_status};
+use handler_grpc::GrpcHandler;
+
+const TONIC: &str = "[package]\nname = \"api\"\nversion = \"0.1.0\"\n\n[dependencies]\ntonic = \"0.12\"\n\n[build-dependencies]\ntonic-build = \"0.12\"\n";
+
+fn api() -> TestCrate {
+    TestCrate::with_manifest(TONIC)
+        .unwrap()
+        .file("src/lib.rs", "")
+        .unwrap()
+}
+
+#[test]
+fn crates_without_grpc_are_not_checked() {
+    let krate = TestCrate::new("plain")
+        .unwrap()
+        .file("src/lib.rs", "")
+        .unwrap();
+    assert!(krate.check(&GrpcHandler).unwrap().is_empty());
+}
+
+#[test]
+fn protos_belong_in_proto_and_need_codegen() {
+    let krate = api()
+        .file("src/api.proto", "syntax = \"proto3\";\n")
+        .unwrap();
+    let results = krate.check(&GrpcHandler).unwrap();
+    assert_status(&results, "gRPC", CheckStatus::Info);
+    assert_status(&results, "Proto Location", CheckStatus::Warn);
+    assert_status(&results, "Proto Codegen", CheckStatus::Warn);
+}
+
+#[test]
+fn conventional_layout_passes() {
+    let build = "fn main() {\n    tonic_build::compile_protos(\"proto/api.proto\").unwrap();\n}\n";
+    let krate = api()
+        .file("proto/api.proto", "syntax = \"proto3\";\n")
+        .unwrap()
+        .file("build.rs", build)
+        .unwrap();
+    let results = krate.check(&GrpcHandler).unwrap();
+    assert_status(&results, "Proto Location", CheckStatus::Pass);
+    assert_status(&results, "Proto Codegen", CheckStatus::Pass);
+    assert_status(&results, "Generated Code", CheckStatus::Pass);
+}
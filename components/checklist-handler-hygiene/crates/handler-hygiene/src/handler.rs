@@ -0,0 +1,68 @@
+//! Hygiene handler implementation
+
+use anyhow::Result;
+use checklist_result::CheckResult;
+use handler_trait::{ProjectContext, ProjectHandler};
+use hygiene_docs::check_agent_docs;
+use hygiene_format::{check_editorconfig, check_rustfmt};
+use hygiene_git::{
+    check_binary_blobs, check_committed_dist, check_default_branch, check_ignored_dirs,
+    check_large_files, check_lockfiles, check_release_tags, check_scripts, is_repo, tracked_files,
+};
+use hygiene_text::check_text_files;
+
+/// Project handler for repository hygiene
+pub struct HygieneHandler;
+
+impl ProjectHandler for HygieneHandler {
+    fn name(&self) -> &'static str {
+        "hygiene"
+    }
+
+    fn check(&self, ctx: &ProjectContext) -> Result<Vec<CheckResult>> {
+        let root = ctx.config.project_root();
+        let mut results = vec![check_editorconfig(root), check_rustfmt(root)];
+        if !is_repo(root) {
+            results.push(CheckResult::info(
+                "Git Hygiene [project]",
+                "Not a git repository",
+            ));
+            return Ok(results);
+        }
+        results.extend(git_results(ctx));
+        Ok(results)
+    }
+}
+
+/// Hygiene checks that need the git index or history
+fn git_results(ctx: &ProjectContext) -> Vec<CheckResult> {
+    let root = ctx.config.project_root();
+    let policy = &ctx.config.file().hygiene;
+    let mut results = vec![
+        check_default_branch(root, &policy.default_branch),
+        check_large_files(root, policy.max_file_kb),
+        check_binary_blobs(root, &policy.asset_dirs),
+        check_ignored_dirs(root),
+        check_release_tags(root, ctx.cargo_tomls, policy.release_stale_days),
+    ];
+    results.extend(check_scripts(root));
+    let files = tracked_files(root).unwrap_or_default();
+    results.extend(check_lockfiles(root, &files));
+    results.extend(check_committed_dist(root, &files));
+    let text = &ctx.config.file().text;
+    results.extend(check_text_files(root, &files, text, ctx.config.fix()));
+    results
+}
+
+/// Project handler requiring agent-facing docs (AGENTS.md / CLAUDE.md)
+pub struct AgentDocsHandler;
+
+impl ProjectHandler for AgentDocsHandler {
+    fn name(&self) -> &'static str {
+        "agent-docs"
+    }
+
+    fn check(&self, ctx: &ProjectContext) -> Result<Vec<CheckResult>> {
+        Ok(vec![check_agent_docs(ctx.config.project_root())])
+    }
+}
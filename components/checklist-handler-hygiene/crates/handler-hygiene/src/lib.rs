@@ -0,0 +1,8 @@
+//! Repository hygiene check handler for sw-checklist
+//!
+//! Checks repo-level files that no per-crate check sees, including the
+//! agent-facing docs.
+
+mod handler;
+
+pub use handler::{AgentDocsHandler, HygieneHandler};
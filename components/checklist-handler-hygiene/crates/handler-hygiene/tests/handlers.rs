@@ -0,0 +1,40 @@
+use checklist_result::CheckStatus;
+use checklist_testkit::{TestCrate, assert_status};
+use handler_hygiene::{AgentDocsHandler, HygieneHandler};
+
+#[test]
+fn outside_git_only_format_configs_are_checked() {
+    let krate = TestCrate::new("demo").unwrap();
+    let results = krate.check_project(&HygieneHandler).unwrap();
+    assert_status(&results, "EditorConfig", CheckStatus::Warn);
+    assert_status(&results, "Rustfmt Config", CheckStatus::Pass);
+    assert_status(&results, "Git Hygiene", CheckStatus::Info);
+    assert_eq!(results.len(), 3, "{:?}", results);
+}
+
+#[test]
+fn agent_docs_must_describe_the_commands() {
+    let krate = TestCrate::new("demo").unwrap();
+    assert_status(
+        &krate.check_project(&AgentDocsHandler).unwrap(),
+        "Agent Docs",
+        CheckStatus::Fail,
+    );
+
+    let krate = krate.file("AGENTS.md", "Run `cargo build`.\n").unwrap();
+    let results = krate.check_project(&AgentDocsHandler).unwrap();
+    assert_status(&results, "Agent Docs", CheckStatus::Fail);
+    assert!(
+        results[0].message.contains("cargo test"),
+        "{}",
+        results[0].message
+    );
+
+    let docs = "`cargo build`, `cargo test`, then `sw-checklist`.\n";
+    let krate = krate.file("AGENTS.md", docs).unwrap();
+    assert_status(
+        &krate.check_project(&AgentDocsHandler).unwrap(),
+        "Agent Docs",
+        CheckStatus::Pass,
+    );
+}
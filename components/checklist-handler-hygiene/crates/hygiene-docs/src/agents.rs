@@ -0,0 +1,53 @@
+//! AGENTS.md / CLAUDE.md check
+
+use checklist_result::CheckResult;
+use std::fs;
+use std::path::Path;
+
+/// Files coding agents read for repo instructions
+const AGENT_DOCS: &[&str] = &["AGENTS.md", "CLAUDE.md"];
+
+/// Build, test, and checklist commands the docs must describe
+const REQUIRED_COMMANDS: &[&str] = &["cargo build", "cargo test", "sw-checklist"];
+
+/// Check the repo root has agent docs describing the build/test/checklist commands
+pub fn check_agent_docs(root: &Path) -> CheckResult {
+    let label = "Agent Docs [project]";
+    let docs = read_docs(root);
+    let Some((first, _)) = docs.first() else {
+        return CheckResult::fail(
+            label,
+            format!("No agent docs found (add {})", AGENT_DOCS.join(" or ")),
+        );
+    };
+    let missing = missing_commands(&docs);
+    if missing.is_empty() {
+        CheckResult::pass(
+            label,
+            format!("{} describes build, test, and checklist commands", first),
+        )
+    } else {
+        CheckResult::fail(
+            label,
+            format!("{} does not mention: {}", first, missing.join(", ")),
+        )
+        .at(root.join(first), 1)
+    }
+}
+
+/// Agent docs present at the project root, with their contents
+fn read_docs(root: &Path) -> Vec<(&'static str, String)> {
+    AGENT_DOCS
+        .iter()
+        .filter_map(|name| Some((*name, fs::read_to_string(root.join(name)).ok()?)))
+        .collect()
+}
+
+/// Required commands mentioned in none of the docs
+fn missing_commands(docs: &[(&str, String)]) -> Vec<&'static str> {
+    REQUIRED_COMMANDS
+        .iter()
+        .filter(|command| !docs.iter().any(|(_, content)| content.contains(*command)))
+        .copied()
+        .collect()
+}
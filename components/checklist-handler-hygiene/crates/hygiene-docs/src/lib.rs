@@ -0,0 +1,8 @@
+//! Agent-facing documentation checks
+//!
+//! Extends the AI-agent instructions required in `--help` to the repo level:
+//! agents opening the repo need the build, test, and checklist commands too.
+
+mod agents;
+
+pub use agents::check_agent_docs;
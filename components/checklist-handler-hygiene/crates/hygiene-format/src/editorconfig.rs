@@ -0,0 +1,64 @@
+//! .editorconfig check
+
+use checklist_result::CheckResult;
+use std::fs;
+use std::path::Path;
+
+/// Canonical `(section, key, value)` settings every repo's .editorconfig needs
+const CANONICAL: &[(&str, &str, &str)] = &[
+    ("", "root", "true"),
+    ("*", "charset", "utf-8"),
+    ("*", "end_of_line", "lf"),
+    ("*", "insert_final_newline", "true"),
+    ("*", "trim_trailing_whitespace", "true"),
+    ("*.rs", "indent_style", "space"),
+    ("*.rs", "indent_size", "4"),
+];
+
+/// Check the project root's .editorconfig matches the canonical settings
+pub fn check_editorconfig(root: &Path) -> CheckResult {
+    let label = "EditorConfig [project]";
+    let path = root.join(".editorconfig");
+    let Ok(content) = fs::read_to_string(&path) else {
+        return CheckResult::warn(label, "No .editorconfig in the project root");
+    };
+    let mismatches: Vec<String> = CANONICAL
+        .iter()
+        .filter_map(|&(section, key, expected)| mismatch(&content, section, key, expected))
+        .collect();
+    if mismatches.is_empty() {
+        CheckResult::pass(label, ".editorconfig matches the canonical settings")
+    } else {
+        CheckResult::warn(label, mismatches.join("; ")).at(&path, 1)
+    }
+}
+
+/// Describe how `key` in `[section]` differs from `expected`, if it does
+fn mismatch(content: &str, section: &str, key: &str, expected: &str) -> Option<String> {
+    let name = if section.is_empty() {
+        key.to_string()
+    } else {
+        format!("[{}] {}", section, key)
+    };
+    match lookup(content, section, key) {
+        Some(v) if v.eq_ignore_ascii_case(expected) => None,
+        Some(v) => Some(format!("{} = {} (expected {})", name, v, expected)),
+        None => Some(format!("{} missing (expected {})", name, expected)),
+    }
+}
+
+/// Value of `key` in `[section]` (empty section = preamble before any header)
+fn lookup<'a>(content: &'a str, section: &str, key: &str) -> Option<&'a str> {
+    let mut current = "";
+    for line in content.lines().map(str::trim) {
+        if let Some(header) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            current = header;
+        } else if current == section
+            && let Some((k, v)) = line.split_once('=')
+            && k.trim() == key
+        {
+            return Some(v.trim());
+        }
+    }
+    None
+}
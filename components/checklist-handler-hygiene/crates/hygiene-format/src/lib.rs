@@ -0,0 +1,10 @@
+//! Editor and formatter configuration checks
+//!
+//! Compares `.editorconfig` and `rustfmt.toml` against the org's canonical
+//! settings key by key, not just by file existence.
+
+mod editorconfig;
+mod rustfmt;
+
+pub use editorconfig::check_editorconfig;
+pub use rustfmt::check_rustfmt;
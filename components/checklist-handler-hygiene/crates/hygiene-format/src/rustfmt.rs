@@ -0,0 +1,37 @@
+//! rustfmt.toml check
+
+use checklist_result::CheckResult;
+use std::fs;
+use std::path::Path;
+
+/// rustfmt reads either name
+const RUSTFMT_FILES: &[&str] = &["rustfmt.toml", ".rustfmt.toml"];
+
+/// Canonical rustfmt settings; anything else should stay at rustfmt defaults
+const CANONICAL: &[(&str, &str)] = &[("edition", "2024")];
+
+/// Check an optional rustfmt.toml agrees with the canonical settings
+pub fn check_rustfmt(root: &Path) -> CheckResult {
+    let label = "Rustfmt Config [project]";
+    let Some((path, content)) = RUSTFMT_FILES.iter().find_map(|name| {
+        let path = root.join(name);
+        fs::read_to_string(&path).ok().map(|c| (path, c))
+    }) else {
+        return CheckResult::pass(label, "No rustfmt.toml (rustfmt defaults)");
+    };
+    let Ok(config) = content.parse::<toml::Value>() else {
+        return CheckResult::fail(label, "rustfmt.toml is not valid TOML").at(&path, 1);
+    };
+    let mismatches: Vec<String> = CANONICAL
+        .iter()
+        .filter_map(|&(key, expected)| {
+            let actual = config.get(key)?.as_str()?;
+            (actual != expected).then(|| format!("{} = {} (expected {})", key, actual, expected))
+        })
+        .collect();
+    if mismatches.is_empty() {
+        CheckResult::pass(label, "rustfmt.toml matches the canonical settings")
+    } else {
+        CheckResult::warn(label, mismatches.join("; ")).at(&path, 1)
+    }
+}
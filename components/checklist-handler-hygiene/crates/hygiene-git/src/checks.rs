@@ -0,0 +1,45 @@
+//! Repository-level git checks
+
+use checklist_result::CheckResult;
+use std::path::Path;
+
+use crate::git::{default_branch, git};
+
+/// Build output directories that must never be committed
+const IGNORED_DIRS: &[&str] = &["target/", "dist/", "node_modules/"];
+
+/// Check the default branch matches the configured name
+pub fn check_default_branch(root: &Path, expected: &str) -> CheckResult {
+    let label = "Default Branch [project]";
+    match default_branch(root) {
+        Ok(branch) if branch == expected => {
+            CheckResult::pass(label, format!("Default branch is {}", branch))
+        }
+        Ok(branch) => CheckResult::warn(
+            label,
+            format!("Default branch is {} (policy: {})", branch, expected),
+        ),
+        Err(e) => CheckResult::info(label, format!("Could not determine default branch: {}", e)),
+    }
+}
+
+/// Check build output directories are git-ignored
+pub fn check_ignored_dirs(root: &Path) -> CheckResult {
+    let label = "Git Ignore [project]";
+    let missing: Vec<&str> = IGNORED_DIRS
+        .iter()
+        .copied()
+        .filter(|dir| git(root, &["check-ignore", "-q", "--no-index", dir]).is_err())
+        .collect();
+    if missing.is_empty() {
+        CheckResult::pass(label, "target/, dist/, and node_modules/ are ignored")
+    } else {
+        CheckResult::warn(
+            label,
+            format!(
+                "Not git-ignored: {}; add them to .gitignore",
+                missing.join(", ")
+            ),
+        )
+    }
+}
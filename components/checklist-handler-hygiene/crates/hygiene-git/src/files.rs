@@ -0,0 +1,74 @@
+//! Tracked file size and binary blob checks
+
+use checklist_result::CheckResult;
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use crate::git::tracked_files;
+
+/// Bytes sniffed for a NUL to classify a file as binary (as git does)
+const SNIFF_BYTES: u64 = 8000;
+
+/// Check no tracked file exceeds `max_kb` KiB
+pub fn check_large_files(root: &Path, max_kb: u64) -> CheckResult {
+    let label = "Large Files [project]";
+    let files = match tracked_files(root) {
+        Ok(files) => files,
+        Err(e) => return CheckResult::info(label, format!("Could not list tracked files: {}", e)),
+    };
+    let large = with_sizes(root, files.iter().filter(|f| kb(root, f) > max_kb));
+    if large.is_empty() {
+        return CheckResult::pass(label, format!("No tracked file over {} KiB", max_kb));
+    }
+    let message = format!("Tracked files over {} KiB: {}", max_kb, large);
+    CheckResult::warn(label, message)
+}
+
+/// Check tracked binary files only live under an approved asset directory
+pub fn check_binary_blobs(root: &Path, asset_dirs: &[String]) -> CheckResult {
+    let label = "Binary Files [project]";
+    let files = match tracked_files(root) {
+        Ok(files) => files,
+        Err(e) => return CheckResult::info(label, format!("Could not list tracked files: {}", e)),
+    };
+    let in_assets = |f: &PathBuf| {
+        f.components()
+            .any(|c| asset_dirs.iter().any(|d| c.as_os_str() == d.as_str()))
+    };
+    let blobs = with_sizes(
+        root,
+        files
+            .iter()
+            .filter(|f| !in_assets(f) && is_binary(&root.join(f))),
+    );
+    let dirs = asset_dirs.join("/, ");
+    if blobs.is_empty() {
+        return CheckResult::pass(label, format!("Binary files only under {}/", dirs));
+    }
+    CheckResult::warn(label, format!("Binary files outside {}/: {}", dirs, blobs))
+}
+
+/// "file (N KiB)" entries, largest first
+fn with_sizes<'a>(root: &Path, files: impl Iterator<Item = &'a PathBuf>) -> String {
+    let mut sized: Vec<(u64, String)> = files
+        .map(|f| (kb(root, f), f.display().to_string()))
+        .collect();
+    sized.sort_by(|a, b| b.cmp(a));
+    let listed: Vec<String> = sized
+        .iter()
+        .map(|(kb, f)| format!("{} ({} KiB)", f, kb))
+        .collect();
+    listed.join(", ")
+}
+
+fn kb(root: &Path, file: &Path) -> u64 {
+    root.join(file).metadata().map_or(0, |m| m.len() / 1024)
+}
+
+fn is_binary(path: &Path) -> bool {
+    let mut head = Vec::new();
+    File::open(path)
+        .and_then(|f| f.take(SNIFF_BYTES).read_to_end(&mut head))
+        .is_ok_and(|_| head.contains(&0))
+}
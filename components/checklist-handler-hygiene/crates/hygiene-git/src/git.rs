@@ -0,0 +1,32 @@
+//! git CLI helpers
+
+use anyhow::Result;
+use std::path::{Path, PathBuf};
+
+pub(crate) use checklist_exec::git;
+
+/// Check if `dir` is inside a git work tree
+pub fn is_repo(dir: &Path) -> bool {
+    git(dir, &["rev-parse", "--is-inside-work-tree"]).is_ok_and(|out| out == "true")
+}
+
+/// The default branch: `origin/HEAD` when a remote is set, else the current branch
+pub fn default_branch(dir: &Path) -> Result<String> {
+    git(
+        dir,
+        &["symbolic-ref", "--short", "refs/remotes/origin/HEAD"],
+    )
+    .map(|b| b.trim_start_matches("origin/").to_string())
+    .or_else(|_| git(dir, &["symbolic-ref", "--short", "HEAD"]))
+    .map_err(Into::into)
+}
+
+/// Tracked files, relative to `dir`
+pub fn tracked_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    let out = git(dir, &["ls-files", "-z"])?;
+    Ok(out
+        .split('\0')
+        .filter(|p| !p.is_empty())
+        .map(PathBuf::from)
+        .collect())
+}
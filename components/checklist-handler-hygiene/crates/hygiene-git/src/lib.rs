@@ -0,0 +1,20 @@
+//! Git repository hygiene checks
+//!
+//! Uses the `git` CLI to check the default branch, tracked file sizes and
+//! binary blobs, ignore rules for build output directories, release tags,
+//! helper scripts, and Web UI reproducibility (JS lockfiles, no committed
+//! Trunk dist/).
+
+mod checks;
+mod files;
+mod git;
+mod release;
+mod scripts;
+mod web;
+
+pub use checks::{check_default_branch, check_ignored_dirs};
+pub use files::{check_binary_blobs, check_large_files};
+pub use git::{is_repo, tracked_files};
+pub use release::check_release_tags;
+pub use scripts::check_scripts;
+pub use web::{check_committed_dist, check_lockfiles};
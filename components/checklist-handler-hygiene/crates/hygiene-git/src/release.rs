@@ -0,0 +1,84 @@
+//! Release tag consistency check
+
+use checklist_result::CheckResult;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::git::git;
+
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// Compare the highest `v*` tag with the project version
+///
+/// Fails when a tag is ahead of Cargo.toml; warns when Cargo.toml is ahead and
+/// the oldest unreleased commit is more than `stale_days` old.
+pub fn check_release_tags(root: &Path, cargo_tomls: &[PathBuf], stale_days: u64) -> CheckResult {
+    let label = "Release Tags [project]";
+    let Some(version) = project_version(cargo_tomls) else {
+        return CheckResult::info(label, "No package version found");
+    };
+    let tags = git(root, &["tag", "--list", "v*", "--sort=-v:refname"]).unwrap_or_default();
+    let Some(tag) = tags.lines().find(|t| parse_semver(t).is_some()) else {
+        return CheckResult::info(
+            label,
+            format!("Version {} has no v* release tag yet", version),
+        );
+    };
+    let (current, released) = (parse_semver(&version), parse_semver(tag));
+    if current < released {
+        let message = format!(
+            "Tag {} has no matching version (Cargo.toml is {})",
+            tag, version
+        );
+        return CheckResult::fail(label, message);
+    }
+    if current == released {
+        return CheckResult::pass(label, format!("Version {} is tagged {}", version, tag));
+    }
+    check_unreleased(root, &version, tag, stale_days)
+}
+
+/// Warn when commits after `tag` have waited more than `stale_days` for a release
+fn check_unreleased(root: &Path, version: &str, tag: &str, stale_days: u64) -> CheckResult {
+    let label = "Release Tags [project]";
+    let range = format!("{}..HEAD", tag);
+    let oldest = git(root, &["log", "--reverse", "--format=%ct", &range])
+        .ok()
+        .and_then(|times| times.lines().next()?.parse::<u64>().ok());
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    match oldest.map(|t| now.saturating_sub(t) / SECONDS_PER_DAY) {
+        Some(days) if days > stale_days => CheckResult::warn(
+            label,
+            format!(
+                "Version {} is unreleased; changes since {} are {} days old (max {})",
+                version, tag, days, stale_days
+            ),
+        ),
+        _ => CheckResult::pass(label, format!("Version {} is ahead of {}", version, tag)),
+    }
+}
+
+/// Version of the shallowest manifest declaring one (workspace or package)
+fn project_version(cargo_tomls: &[PathBuf]) -> Option<String> {
+    let mut manifests: Vec<&PathBuf> = cargo_tomls.iter().collect();
+    manifests.sort_by_key(|p| p.components().count());
+    manifests.into_iter().find_map(|path| {
+        let manifest: toml::Value = fs::read_to_string(path).ok()?.parse().ok()?;
+        let package = manifest
+            .get("workspace")
+            .and_then(|w| w.get("package"))
+            .or_else(|| manifest.get("package"))?;
+        Some(package.get("version")?.as_str()?.to_string())
+    })
+}
+
+/// Parse `major.minor.patch` from a version or `v`-prefixed tag
+fn parse_semver(version: &str) -> Option<(u64, u64, u64)> {
+    let core = version.trim_start_matches('v').split(['-', '+']).next()?;
+    let mut parts = core.split('.').map(|p| p.parse().ok());
+    Some((parts.next()??, parts.next()??, parts.next()??))
+}
@@ -0,0 +1,76 @@
+//! Helper script checks: executable bit, shebang, and line endings
+
+use checklist_result::CheckResult;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::git::git;
+
+/// Extensions that are always scripts
+const SCRIPT_EXTENSIONS: &[&str] = &["sh", "bash", "zsh"];
+
+/// Check every tracked script is committed executable, starts with a shebang,
+/// and has no CRLF line endings
+///
+/// Scripts are `.sh`/`.bash`/`.zsh` files anywhere plus `.py` and
+/// extensionless files under a `scripts/` directory. The executable bit is
+/// read from the git index, so it is checked the same way on every platform.
+pub fn check_scripts(root: &Path) -> Vec<CheckResult> {
+    let label = "Scripts [project]";
+    let Ok(scripts) = tracked_scripts(root) else {
+        return vec![CheckResult::info(label, "Could not list tracked files")];
+    };
+    let mut results: Vec<CheckResult> = scripts
+        .iter()
+        .filter_map(|(path, executable)| {
+            let problems = script_problems(&root.join(path), *executable);
+            let message = format!("{}: {}", path.display(), problems.join(", "));
+            (!problems.is_empty()).then(|| CheckResult::warn(label, message).at(root.join(path), 1))
+        })
+        .collect();
+    if results.is_empty() && !scripts.is_empty() {
+        let message = format!(
+            "{} scripts executable with shebang and LF endings",
+            scripts.len()
+        );
+        results.push(CheckResult::pass(label, message));
+    }
+    results
+}
+
+/// Tracked scripts with whether the index records them as executable
+fn tracked_scripts(root: &Path) -> anyhow::Result<Vec<(PathBuf, bool)>> {
+    let out = git(root, &["ls-files", "-s", "-z"])?;
+    Ok(out
+        .split('\0')
+        .filter_map(|entry| {
+            let (meta, path) = entry.split_once('\t')?;
+            let path = PathBuf::from(path);
+            is_script(&path).then(|| (path, meta.starts_with("100755")))
+        })
+        .collect())
+}
+
+fn is_script(path: &Path) -> bool {
+    let extension = path.extension().and_then(|e| e.to_str());
+    let in_scripts = path
+        .parent()
+        .is_some_and(|p| p.components().any(|c| c.as_os_str() == "scripts"));
+    extension.is_some_and(|e| SCRIPT_EXTENSIONS.contains(&e))
+        || (in_scripts && matches!(extension, None | Some("py")))
+}
+
+fn script_problems(path: &Path, executable: bool) -> Vec<&'static str> {
+    let content = fs::read(path).unwrap_or_default();
+    let mut problems = Vec::new();
+    if !executable {
+        problems.push("not executable (git update-index --chmod=+x)");
+    }
+    if !content.starts_with(b"#!") {
+        problems.push("no shebang");
+    }
+    if content.windows(2).any(|pair| pair == b"\r\n") {
+        problems.push("CRLF line endings");
+    }
+    problems
+}
@@ -0,0 +1,82 @@
+//! Web UI reproducibility checks: committed JS lockfiles and no committed dist/
+
+use checklist_result::CheckResult;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Lockfiles written by npm, pnpm, yarn, and bun
+const LOCKFILES: &[&str] = &[
+    "package-lock.json",
+    "npm-shrinkwrap.json",
+    "pnpm-lock.yaml",
+    "yarn.lock",
+    "bun.lock",
+    "bun.lockb",
+];
+
+/// Check every tracked package.json has a tracked lockfile beside it or in an
+/// ancestor directory (a JS workspace root); nothing without npm tooling
+pub fn check_lockfiles(root: &Path, files: &[PathBuf]) -> Vec<CheckResult> {
+    let label = "JS Lockfile [project]";
+    let packages: Vec<&PathBuf> = files
+        .iter()
+        .filter(|f| f.file_name().is_some_and(|n| n == "package.json"))
+        .filter(|f| !f.components().any(|c| c.as_os_str() == "node_modules"))
+        .collect();
+    let mut results: Vec<CheckResult> = packages
+        .iter()
+        .filter(|package| !has_lockfile(package, files))
+        .map(|package| {
+            let message = format!(
+                "{} has no committed package-lock.json, pnpm-lock.yaml, or yarn.lock",
+                package.display()
+            );
+            CheckResult::warn(label, message).at(root.join(package), 1)
+        })
+        .collect();
+    if results.is_empty() && !packages.is_empty() {
+        let message = format!("{} package.json files have lockfiles", packages.len());
+        results.push(CheckResult::pass(label, message));
+    }
+    results
+}
+
+fn has_lockfile(package: &Path, files: &[PathBuf]) -> bool {
+    package
+        .ancestors()
+        .skip(1)
+        .any(|dir| LOCKFILES.iter().any(|lock| files.contains(&dir.join(lock))))
+}
+
+/// Check no tracked file lives in the `dist/` output of a Trunk project (a
+/// directory with `Trunk.toml` or a `data-trunk` index.html)
+pub fn check_committed_dist(root: &Path, files: &[PathBuf]) -> Vec<CheckResult> {
+    let label = "Committed dist [project]";
+    let mut counts: BTreeMap<&Path, usize> = BTreeMap::new();
+    for file in files {
+        let dist = file
+            .ancestors()
+            .find(|dir| dir.file_name().is_some_and(|n| n == "dist"));
+        if let Some(dist) = dist {
+            *counts.entry(dist).or_default() += 1;
+        }
+    }
+    counts
+        .into_iter()
+        .filter(|(dist, _)| is_trunk_project(&root.join(dist.parent().unwrap_or(dist))))
+        .map(|(dist, count)| {
+            let message = format!(
+                "{}/ has {} tracked files; Trunk regenerates it, so git rm -r --cached it",
+                dist.display(),
+                count
+            );
+            CheckResult::warn(label, message)
+        })
+        .collect()
+}
+
+fn is_trunk_project(dir: &Path) -> bool {
+    dir.join("Trunk.toml").is_file()
+        || fs::read_to_string(dir.join("index.html")).is_ok_and(|html| html.contains("data-trunk"))
+}
@@ -0,0 +1,92 @@
+//! Per-file text policy checking and fixing
+
+use checklist_config::TextConfig;
+use checklist_result::CheckResult;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::normalize::{TextIssues, normalize};
+
+const LABEL: &str = "Text Files [project]";
+
+/// Extensions checked when `[text] extensions` is empty (Markdown is left
+/// out: trailing double spaces are line breaks there)
+const DEFAULT_EXTENSIONS: &[&str] = &[
+    "rs", "toml", "sh", "py", "html", "css", "js", "ts", "json", "yml", "yaml",
+];
+
+/// Check text files (relative to `root`) for encoding, line endings, and
+/// trailing whitespace; with `fix`, rewrite each valid UTF-8 file that has
+/// problems and report it as fixed
+pub fn check_text_files(
+    root: &Path,
+    files: &[PathBuf],
+    policy: &TextConfig,
+    fix: bool,
+) -> Vec<CheckResult> {
+    let checked: Vec<&PathBuf> = files.iter().filter(|f| is_checked(f, policy)).collect();
+    let results: Vec<CheckResult> = checked
+        .iter()
+        .filter_map(|file| file_result(root, file, policy, fix))
+        .collect();
+    if results.is_empty() {
+        let message = format!(
+            "{} text files are UTF-8 with consistent line endings",
+            checked.len()
+        );
+        return vec![CheckResult::pass(LABEL, message)];
+    }
+    results
+}
+
+fn is_checked(file: &Path, policy: &TextConfig) -> bool {
+    let Some(ext) = file.extension().and_then(|e| e.to_str()) else {
+        return false;
+    };
+    let listed = |list: &[String]| list.iter().any(|e| e == ext);
+    listed(&policy.crlf_extensions)
+        || if policy.extensions.is_empty() {
+            DEFAULT_EXTENSIONS.contains(&ext)
+        } else {
+            listed(&policy.extensions)
+        }
+}
+
+fn file_result(root: &Path, file: &Path, policy: &TextConfig, fix: bool) -> Option<CheckResult> {
+    let path = root.join(file);
+    let bytes = fs::read(&path).ok()?;
+    let crlf = file
+        .extension()
+        .is_some_and(|ext| policy.crlf_extensions.iter().any(|e| ext == e.as_str()));
+    let issues = TextIssues::analyze(&bytes, crlf);
+    let problems = issues.problems(crlf, policy.max_trailing_whitespace);
+    if problems.is_empty() {
+        return None;
+    }
+    let message = format!("{}: {}", file.display(), problems.join(", "));
+    if fix && let Some(fixed) = fix_file(&path, &bytes, crlf) {
+        let left = fixed.problems(crlf, policy.max_trailing_whitespace);
+        if left.is_empty() {
+            return Some(CheckResult::info(LABEL, format!("Fixed {}", message)));
+        }
+        let message = format!("{}: {}", file.display(), left.join(", "));
+        return Some(warn_at(message, &path, &fixed));
+    }
+    Some(warn_at(message, &path, &issues))
+}
+
+/// Rewrite a UTF-8 file normalized, returning what's left to report. Rust
+/// sources keep their trailing whitespace: a line may end inside a
+/// multi-line or raw string literal, where the spaces are part of the value.
+fn fix_file(path: &Path, bytes: &[u8], crlf: bool) -> Option<TextIssues> {
+    let text = std::str::from_utf8(bytes).ok()?;
+    let rust = path.extension().is_some_and(|ext| ext == "rs");
+    let fixed = normalize(text, crlf, !rust);
+    fs::write(path, &fixed).ok()?;
+    Some(TextIssues::analyze(fixed.as_bytes(), crlf))
+}
+
+fn warn_at(message: String, path: &Path, issues: &TextIssues) -> CheckResult {
+    let line = issues.trailing_lines.first().copied().unwrap_or(1);
+    CheckResult::warn(LABEL, message).at(path, line)
+}
@@ -0,0 +1,12 @@
+//! Text file encoding and line ending checks
+//!
+//! Requires UTF-8 without a BOM, the configured line endings, and at most a
+//! threshold of lines with trailing whitespace; fix mode normalizes files,
+//! leaving trailing whitespace in Rust sources, where it may sit inside a
+//! multi-line string literal.
+
+mod check;
+mod normalize;
+
+pub use check::check_text_files;
+pub use normalize::{TextIssues, normalize};
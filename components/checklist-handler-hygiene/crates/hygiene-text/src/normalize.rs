@@ -0,0 +1,84 @@
+//! Text analysis and normalization
+
+/// Encoding and whitespace problems in one file
+#[derive(Default)]
+pub struct TextIssues {
+    pub not_utf8: bool,
+    bom: bool,
+    wrong_endings: bool,
+    /// 1-based lines ending in spaces or tabs
+    pub trailing_lines: Vec<usize>,
+}
+
+impl TextIssues {
+    /// Analyze file content against LF (or, with `crlf`, CRLF) line endings
+    pub fn analyze(bytes: &[u8], crlf: bool) -> Self {
+        let Ok(text) = std::str::from_utf8(bytes) else {
+            return Self {
+                not_utf8: true,
+                ..Self::default()
+            };
+        };
+        let crlf_count = text.matches("\r\n").count();
+        Self {
+            not_utf8: false,
+            bom: text.starts_with('\u{feff}'),
+            wrong_endings: if crlf {
+                crlf_count != text.matches('\n').count()
+            } else {
+                crlf_count > 0
+            },
+            trailing_lines: text
+                .lines()
+                .enumerate()
+                .filter(|(_, line)| line.ends_with([' ', '\t']))
+                .map(|(index, _)| index + 1)
+                .collect(),
+        }
+    }
+
+    /// Human-readable problems, allowing `max_trailing` trailing-whitespace lines
+    pub fn problems(&self, crlf: bool, max_trailing: usize) -> Vec<String> {
+        let endings = if crlf {
+            "LF line endings (expected CRLF)"
+        } else {
+            "CRLF line endings"
+        };
+        let mut problems: Vec<String> = [
+            (self.not_utf8, "not UTF-8"),
+            (self.bom, "UTF-8 BOM"),
+            (self.wrong_endings, endings),
+        ]
+        .into_iter()
+        .filter(|(found, _)| *found)
+        .map(|(_, problem)| problem.to_string())
+        .collect();
+        let trailing = self.trailing_lines.len();
+        if trailing > max_trailing {
+            problems.push(format!("{} lines with trailing whitespace", trailing));
+        }
+        problems
+    }
+}
+
+/// Strip the BOM (and, with `trim`, trailing whitespace) and use one line
+/// ending throughout
+pub fn normalize(text: &str, crlf: bool, trim: bool) -> String {
+    let text = text.trim_start_matches('\u{feff}');
+    let ending = if crlf { "\r\n" } else { "\n" };
+    let lines: Vec<&str> = text
+        .lines()
+        .map(|line| {
+            if trim {
+                line.trim_end_matches([' ', '\t'])
+            } else {
+                line
+            }
+        })
+        .collect();
+    let mut normalized = lines.join(ending);
+    if text.ends_with('\n') {
+        normalized.push_str(ending);
+    }
+    normalized
+}
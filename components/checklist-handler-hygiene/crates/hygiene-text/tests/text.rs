@@ -0,0 +1,81 @@
+//! Text analysis, normalization, and fix mode
+
+use checklist_config::TextConfig;
+use checklist_result::{CheckResult, CheckStatus};
+use checklist_tempdir::TempWorkspace;
+use hygiene_text::{TextIssues, check_text_files, normalize};
+use std::fs;
+use std::path::PathBuf;
+
+const RUST: &str = "const S: &str = \"line one  \nline two\";  \n";
+
+#[test]
+fn analyze_finds_encoding_endings_and_trailing_lines() {
+    let issues = TextIssues::analyze(b"\xef\xbb\xbfa \r\nb\r\nc\t\r\n", false);
+    assert!(!issues.not_utf8);
+    assert_eq!(issues.trailing_lines, [1, 3]);
+    assert_eq!(
+        issues.problems(false, 0),
+        [
+            "UTF-8 BOM",
+            "CRLF line endings",
+            "2 lines with trailing whitespace"
+        ]
+    );
+    assert_eq!(
+        issues.problems(false, 2),
+        ["UTF-8 BOM", "CRLF line endings"]
+    );
+
+    let crlf = TextIssues::analyze(b"a\r\nb\n", true);
+    assert_eq!(crlf.problems(true, 0), ["LF line endings (expected CRLF)"]);
+
+    let binary = TextIssues::analyze(&[0xff, 0xfe], false);
+    assert!(binary.not_utf8);
+    assert_eq!(binary.problems(false, 0), ["not UTF-8"]);
+}
+
+#[test]
+fn normalize_strips_the_bom_and_unifies_endings() {
+    let text = "\u{feff}a \r\nb\nc\t";
+    assert_eq!(normalize(text, false, true), "a\nb\nc");
+    assert_eq!(normalize(text, true, true), "a\r\nb\r\nc");
+    assert_eq!(normalize("a \nb\n", false, false), "a \nb\n");
+    assert_eq!(normalize("a\nb\n", true, true), "a\r\nb\r\n");
+}
+
+fn fixed(name: &str, contents: &str) -> (Vec<CheckResult>, String) {
+    let dir = TempWorkspace::new("hygiene-text").unwrap();
+    fs::write(dir.path().join(name), contents).unwrap();
+    let files = [PathBuf::from(name)];
+    let results = check_text_files(dir.path(), &files, &TextConfig::default(), true);
+    (results, fs::read_to_string(dir.path().join(name)).unwrap())
+}
+
+#[test]
+fn fix_mode_rewrites_files_and_reports_them_fixed() {
+    let (results, contents) = fixed("run.sh", "echo hi  \r\n");
+    assert_eq!(contents, "echo hi\n");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].status, CheckStatus::Info);
+    assert!(
+        results[0].message.starts_with("Fixed run.sh"),
+        "{:?}",
+        results
+    );
+}
+
+#[test]
+fn fix_mode_keeps_trailing_whitespace_in_rust_sources() {
+    let (results, contents) = fixed("lib.rs", RUST);
+    assert_eq!(contents, RUST);
+    assert_eq!(results[0].status, CheckStatus::Warn);
+    assert_eq!(
+        results[0].message,
+        "lib.rs: 2 lines with trailing whitespace"
+    );
+
+    let (results, contents) = fixed("lib.rs", &RUST.replace('\n', "\r\n"));
+    assert_eq!(contents, RUST);
+    assert_eq!(results[0].status, CheckStatus::Warn);
+}
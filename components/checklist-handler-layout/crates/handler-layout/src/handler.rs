@@ -0,0 +1,51 @@
+//! Layout handler implementation
+
+use anyhow::Result;
+use checklist_result::CheckResult;
+use discovery_crate::extract_crate_name;
+use handler_trait::{ProjectContext, ProjectHandler};
+use std::collections::BTreeMap;
+
+use crate::manifest::{Manifest, load_manifests};
+use crate::membership::check_membership;
+
+/// Project handler for duplicate package names and workspace membership
+pub struct LayoutHandler;
+
+impl ProjectHandler for LayoutHandler {
+    fn name(&self) -> &'static str {
+        "layout"
+    }
+
+    fn check(&self, ctx: &ProjectContext) -> Result<Vec<CheckResult>> {
+        let manifests = load_manifests(ctx.cargo_tomls);
+        let mut results = vec![check_duplicate_names(&manifests)];
+        results.extend(check_membership(&manifests));
+        Ok(results)
+    }
+}
+
+/// Fail when two packages in the tree share a name
+fn check_duplicate_names(manifests: &[Manifest]) -> CheckResult {
+    let label = "Duplicate Package Names [project]";
+    let mut by_name: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+    for manifest in manifests.iter().filter(|m| m.is_package()) {
+        let paths = by_name
+            .entry(extract_crate_name(&manifest.value))
+            .or_default();
+        paths.push(manifest.path.display().to_string());
+    }
+    let duplicates: Vec<String> = by_name
+        .iter()
+        .filter(|(_, paths)| paths.len() > 1)
+        .map(|(name, paths)| format!("{} ({})", name, paths.join(", ")))
+        .collect();
+    if duplicates.is_empty() {
+        CheckResult::pass(label, "Package names are unique")
+    } else {
+        CheckResult::fail(
+            label,
+            format!("Packages share a name: {}", duplicates.join("; ")),
+        )
+    }
+}
@@ -0,0 +1,11 @@
+//! Manifest layout checks for sw-checklist
+//!
+//! Catches structural problems across the project's Cargo.toml files that
+//! per-crate checks pass silently: duplicate package names and packages left
+//! out of the workspace they sit in.
+
+mod handler;
+mod manifest;
+mod membership;
+
+pub use handler::LayoutHandler;
@@ -0,0 +1,64 @@
+//! Parsed project manifests
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A parsed Cargo.toml
+pub struct Manifest {
+    /// Path to the Cargo.toml
+    pub path: PathBuf,
+    /// Canonical directory containing it
+    pub dir: PathBuf,
+    /// Parsed contents
+    pub value: toml::Value,
+}
+
+impl Manifest {
+    /// Whether the manifest declares a `[package]`
+    pub fn is_package(&self) -> bool {
+        self.value.get("package").is_some()
+    }
+
+    /// Entries of a `[workspace]` list such as `members` (empty unless a workspace root)
+    pub fn workspace_patterns(&self, key: &str) -> Vec<&str> {
+        let list = self
+            .value
+            .get("workspace")
+            .and_then(|w| w.get(key)?.as_array());
+        list.into_iter()
+            .flatten()
+            .filter_map(|p| Some(p.as_str()?.trim_end_matches('/')))
+            .collect()
+    }
+
+    /// Canonical directories of the manifest's path dependencies
+    pub fn path_deps(&self) -> Vec<PathBuf> {
+        ["dependencies", "dev-dependencies", "build-dependencies"]
+            .iter()
+            .filter_map(|table| self.value.get(table)?.as_table())
+            .flat_map(|deps| deps.values())
+            .filter_map(|dep| dep.get("path")?.as_str())
+            .filter_map(|path| self.dir.join(path).canonicalize().ok())
+            .collect()
+    }
+}
+
+/// Parse every readable, valid manifest
+pub fn load_manifests(cargo_tomls: &[PathBuf]) -> Vec<Manifest> {
+    cargo_tomls
+        .iter()
+        .filter_map(|path| {
+            let value = fs::read_to_string(path).ok()?.parse().ok()?;
+            let dir = path
+                .parent()
+                .unwrap_or(Path::new("."))
+                .canonicalize()
+                .ok()?;
+            Some(Manifest {
+                path: path.clone(),
+                dir,
+                value,
+            })
+        })
+        .collect()
+}
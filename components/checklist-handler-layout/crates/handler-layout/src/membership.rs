@@ -0,0 +1,97 @@
+//! Workspace membership of nested packages
+
+use checklist_result::CheckResult;
+use discovery_crate::extract_crate_name;
+use std::path::Path;
+
+use crate::manifest::Manifest;
+
+const LABEL: &str = "Workspace Membership [project]";
+
+/// Check every package belongs to the innermost workspace it sits in (and,
+/// when the project has workspaces, that no package sits outside all of them)
+pub fn check_membership(manifests: &[Manifest]) -> Vec<CheckResult> {
+    let roots: Vec<&Manifest> = manifests
+        .iter()
+        .filter(|m| m.value.get("workspace").is_some())
+        .collect();
+    let problems: Vec<CheckResult> = manifests
+        .iter()
+        .filter(|m| m.is_package() && m.value.get("workspace").is_none())
+        .filter_map(|m| problem(m, &roots, manifests))
+        .collect();
+    if problems.is_empty() {
+        vec![CheckResult::pass(
+            LABEL,
+            "Every package belongs to its workspace",
+        )]
+    } else {
+        problems
+    }
+}
+
+/// The membership finding for a package, if it isn't a member of its workspace
+fn problem(package: &Manifest, roots: &[&Manifest], all: &[Manifest]) -> Option<CheckResult> {
+    let name = extract_crate_name(&package.value);
+    let Some(owner) = roots
+        .iter()
+        .filter(|r| package.dir.starts_with(&r.dir))
+        .max_by_key(|r| r.dir.components().count())
+    else {
+        let message = format!("{} is not part of any workspace", name);
+        return (!roots.is_empty()).then(|| CheckResult::warn(LABEL, message).at(&package.path, 1));
+    };
+    let relative = package.dir.strip_prefix(&owner.dir).ok()?;
+    let workspace = owner.path.display();
+    let excluded = owner.workspace_patterns("exclude");
+    let result = if excluded.iter().any(|e| relative.starts_with(e)) {
+        let message = format!("{} is excluded from {} (built separately)", name, workspace);
+        CheckResult::warn(LABEL, message)
+    } else if is_member(package, owner, relative, all) {
+        return None;
+    } else {
+        let message = format!("{} is inside {} but not a member", name, workspace);
+        CheckResult::fail(LABEL, message)
+    };
+    Some(result.at(&package.path, 1))
+}
+
+/// Listed in `members`, or pulled in as a path dependency of a crate in the
+/// workspace (cargo adds those automatically)
+fn is_member(
+    package: &Manifest,
+    owner: &Manifest,
+    relative: &Path,
+    manifests: &[Manifest],
+) -> bool {
+    owner
+        .workspace_patterns("members")
+        .iter()
+        .any(|p| glob_match(p, relative))
+        || manifests
+            .iter()
+            .filter(|m| m.dir.starts_with(&owner.dir))
+            .any(|m| m.path_deps().contains(&package.dir))
+}
+
+/// Match a `members` pattern (`*` wildcards within path components) against a relative path
+fn glob_match(pattern: &str, relative: &Path) -> bool {
+    let parts: Vec<&str> = pattern
+        .split('/')
+        .filter(|p| !p.is_empty() && *p != ".")
+        .collect();
+    let components: Vec<String> = relative
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy().into_owned())
+        .collect();
+    parts.len() == components.len()
+        && parts
+            .iter()
+            .zip(&components)
+            .all(|(part, component)| match part.split_once('*') {
+                Some((prefix, suffix)) => {
+                    component.starts_with(prefix) && component[prefix.len()..].ends_with(suffix)
+                }
+                None => part == component,
+            })
+}
@@ -0,0 +1,60 @@
+use checklist_config::FileConfig;
+use checklist_result::{CheckResult, CheckStatus};
+use checklist_testkit::{TestCrate, assert_status};
+use handler_layout::LayoutHandler;
+use handler_trait::{ProjectContext, ProjectHandler};
+
+const ROOT: &str = "[workspace]\nmembers = [\"crates/a\"]\n";
+
+/// Run the handler over the fixture's root manifest and the manifests at `members`
+fn check(krate: &TestCrate, members: &[&str]) -> Vec<CheckResult> {
+    let mut cargo_tomls = vec![krate.path().join("Cargo.toml")];
+    cargo_tomls.extend(members.iter().map(|m| krate.path().join(m)));
+    let config = krate.config(FileConfig::default());
+    LayoutHandler
+        .check(&ProjectContext {
+            config: &config,
+            cargo_tomls: &cargo_tomls,
+        })
+        .unwrap()
+}
+
+fn package(name: &str) -> String {
+    format!("[package]\nname = \"{}\"\nversion = \"0.1.0\"\n", name)
+}
+
+#[test]
+fn a_single_crate_passes() {
+    let krate = TestCrate::new("demo").unwrap();
+    let results = check(&krate, &[]);
+    assert_status(&results, "Duplicate Package Names", CheckStatus::Pass);
+    assert_status(&results, "Workspace Membership", CheckStatus::Pass);
+}
+
+#[test]
+fn duplicate_names_and_stray_packages_are_reported() {
+    let krate = TestCrate::with_manifest(ROOT)
+        .unwrap()
+        .file("crates/a/Cargo.toml", package("shared"))
+        .unwrap()
+        .file("crates/b/Cargo.toml", package("shared"))
+        .unwrap();
+    let results = check(&krate, &["crates/a/Cargo.toml", "crates/b/Cargo.toml"]);
+    assert_status(&results, "Duplicate Package Names", CheckStatus::Fail);
+    assert_status(&results, "Workspace Membership", CheckStatus::Fail);
+}
+
+#[test]
+fn path_dependencies_of_members_count_as_members() {
+    let a =
+        "[package]\nname = \"a\"\nversion = \"0.1.0\"\n\n[dependencies]\nb = { path = \"../b\" }\n";
+    let krate = TestCrate::with_manifest(ROOT)
+        .unwrap()
+        .file("crates/a/Cargo.toml", a)
+        .unwrap()
+        .file("crates/b/Cargo.toml", package("b"))
+        .unwrap();
+    let results = check(&krate, &["crates/a/Cargo.toml", "crates/b/Cargo.toml"]);
+    assert_status(&results, "Duplicate Package Names", CheckStatus::Pass);
+    assert_status(&results, "Workspace Membership", CheckStatus::Pass);
+}
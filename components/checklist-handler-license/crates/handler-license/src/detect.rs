@@ -0,0 +1,110 @@
+//! License file discovery and identification
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// File names (case-insensitive, any extension) treated as license files
+const LICENSE_NAMES: &[&str] = &["license", "licence", "copying"];
+
+/// SPDX IDs with phrases that identify their license text (all must match)
+const KNOWN_TEXTS: &[(&str, &[&str])] = &[
+    ("Apache-2.0", &["apache license", "version 2.0"]),
+    ("MPL-2.0", &["mozilla public license", "2.0"]),
+    (
+        "LGPL-3.0",
+        &["gnu lesser general public license", "version 3"],
+    ),
+    (
+        "LGPL-2.1",
+        &["gnu lesser general public license", "version 2.1"],
+    ),
+    (
+        "AGPL-3.0",
+        &["gnu affero general public license", "version 3"],
+    ),
+    ("GPL-3.0", &["gnu general public license", "version 3"]),
+    ("GPL-2.0", &["gnu general public license", "version 2"]),
+    ("Unlicense", &["free and unencumbered software"]),
+    (
+        "BSD-3-Clause",
+        &["redistribution and use", "neither the name"],
+    ),
+    (
+        "BSD-2-Clause",
+        &["redistribution and use in source and binary forms"],
+    ),
+    (
+        "ISC",
+        &["permission to use, copy, modify, and/or distribute"],
+    ),
+    ("MIT", &["permission is hereby granted, free of charge"]),
+];
+
+/// A license file at the project root and the license its text identifies
+pub struct LicenseFile {
+    /// Path to the file
+    pub path: PathBuf,
+    /// SPDX ID recognized from the text, if any
+    pub spdx: Option<&'static str>,
+}
+
+/// License files directly in `root` (LICENSE, LICENSE-MIT, COPYING.md, ...)
+pub fn license_files(root: &Path) -> Vec<LicenseFile> {
+    let mut files: Vec<LicenseFile> = fs::read_dir(root)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file() && is_license_name(path))
+        .map(|path| LicenseFile {
+            spdx: fs::read_to_string(&path).ok().and_then(|t| identify(&t)),
+            path,
+        })
+        .collect();
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+    files
+}
+
+fn is_license_name(path: &Path) -> bool {
+    let Some(stem) = path.file_stem().and_then(|s| s.to_str()) else {
+        return false;
+    };
+    let stem = stem.to_ascii_lowercase();
+    LICENSE_NAMES
+        .iter()
+        .any(|name| stem == *name || stem.starts_with(&format!("{}-", name)))
+}
+
+/// SPDX ID of a license text, from its distinctive phrases
+pub fn identify(text: &str) -> Option<&'static str> {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    let text = text.to_lowercase();
+    KNOWN_TEXTS
+        .iter()
+        .find(|(_, phrases)| phrases.iter().all(|p| text.contains(p)))
+        .map(|(id, _)| *id)
+}
+
+/// Whether an SPDX expression names at least one of the `known` license file
+/// IDs (always true when no license file was recognized)
+pub fn agrees(expression: &str, known: &[&str]) -> bool {
+    known.is_empty()
+        || expression_ids(expression)
+            .iter()
+            .any(|id| known.contains(&id.as_str()))
+}
+
+/// License IDs in an SPDX expression such as "MIT OR Apache-2.0", without
+/// `-only` / `-or-later` / `+` suffixes
+fn expression_ids(expression: &str) -> Vec<String> {
+    expression
+        .split(|c: char| c.is_whitespace() || c == '(' || c == ')' || c == '/')
+        .filter(|t| !t.is_empty() && !["OR", "AND", "WITH"].contains(t))
+        .filter(|t| !t.ends_with("-exception"))
+        .map(|t| {
+            let t = t.trim_end_matches('+');
+            let t = t.strip_suffix("-only").unwrap_or(t);
+            t.strip_suffix("-or-later").unwrap_or(t).to_string()
+        })
+        .collect()
+}
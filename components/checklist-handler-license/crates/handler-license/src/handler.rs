@@ -0,0 +1,76 @@
+//! License handler implementation
+
+use anyhow::Result;
+use checklist_result::CheckResult;
+use discovery_cargo::resolve_workspace_inheritance;
+use discovery_crate::extract_crate_name;
+use handler_trait::{ProjectContext, ProjectHandler};
+use std::fs;
+use std::path::Path;
+
+use crate::detect::{LicenseFile, agrees, license_files};
+
+/// Project handler for the root LICENSE file and each package's `license` field
+pub struct LicenseHandler;
+
+impl ProjectHandler for LicenseHandler {
+    fn name(&self) -> &'static str {
+        "license"
+    }
+
+    fn check(&self, ctx: &ProjectContext) -> Result<Vec<CheckResult>> {
+        let files = license_files(ctx.config.project_root());
+        let known: Vec<&str> = files.iter().filter_map(|f| f.spdx).collect();
+        let mut results = vec![check_license_file(&files)];
+        results.extend(
+            ctx.cargo_tomls
+                .iter()
+                .filter_map(|path| check_license_field(path, &known)),
+        );
+        Ok(results)
+    }
+}
+
+/// Fail unless the project root has a license file
+fn check_license_file(files: &[LicenseFile]) -> CheckResult {
+    let label = "License File [project]";
+    if files.is_empty() {
+        return CheckResult::fail(label, "No LICENSE file at the project root");
+    }
+    let found: Vec<String> = files
+        .iter()
+        .map(|f| {
+            let name = f.path.file_name().unwrap_or_default().to_string_lossy();
+            format!("{} ({})", name, f.spdx.unwrap_or("unrecognized text"))
+        })
+        .collect();
+    CheckResult::pass(label, format!("Found {}", found.join(", ")))
+}
+
+/// Check a package declares a license that agrees with the license files
+/// (None for virtual workspace manifests)
+fn check_license_field(cargo_path: &Path, known: &[&str]) -> Option<CheckResult> {
+    let content = fs::read_to_string(cargo_path).ok()?;
+    let resolved = resolve_workspace_inheritance(&content, cargo_path.parent()?);
+    let cargo: toml::Value = resolved.parse().ok()?;
+    let package = cargo.get("package")?;
+    let label = format!("License Field [{}]", extract_crate_name(&cargo));
+    let Some(license) = package.get("license").and_then(|l| l.as_str()) else {
+        return Some(match package.get("license-file") {
+            Some(_) => CheckResult::pass(label, "Uses license-file"),
+            None => CheckResult::fail(label, "No `license` field in Cargo.toml"),
+        });
+    };
+    if !agrees(license, known) {
+        let message = format!(
+            "license = \"{}\" conflicts with the LICENSE file ({})",
+            license,
+            known.join(", ")
+        );
+        return Some(CheckResult::fail(label, message));
+    }
+    Some(CheckResult::pass(
+        label,
+        format!("license = \"{}\"", license),
+    ))
+}
@@ -0,0 +1,9 @@
+//! License checks for sw-checklist
+//!
+//! Verifies the project root has a LICENSE file, that every package declares
+//! a `license` in Cargo.toml, and that the two name the same license.
+
+mod detect;
+mod handler;
+
+pub use handler::LicenseHandler;
@@ -0,0 +1,38 @@
+use checklist_result::CheckStatus;
+use checklist_testkit::{TestCrate, assert_status};
+use handler_license::LicenseHandler;
+
+const MIT: &str = "MIT License\n\nPermission is hereby granted, free of charge, to any person\n";
+
+fn licensed(license: &str) -> TestCrate {
+    let manifest = format!(
+        "[package]\nname = \"demo\"\nversion = \"0.1.0\"\nlicense = \"{}\"\n",
+        license
+    );
+    TestCrate::with_manifest(&manifest)
+        .unwrap()
+        .file("LICENSE", MIT)
+        .unwrap()
+}
+
+#[test]
+fn projects_need_a_license_file_and_field() {
+    let results = TestCrate::new("demo")
+        .unwrap()
+        .check_project(&LicenseHandler)
+        .unwrap();
+    assert_status(&results, "License File", CheckStatus::Fail);
+    assert_status(&results, "License Field", CheckStatus::Fail);
+}
+
+#[test]
+fn the_field_must_agree_with_the_file() {
+    let results = licensed("MIT").check_project(&LicenseHandler).unwrap();
+    assert_status(&results, "License File", CheckStatus::Pass);
+    assert_status(&results, "License Field", CheckStatus::Pass);
+
+    let results = licensed("Apache-2.0")
+        .check_project(&LicenseHandler)
+        .unwrap();
+    assert_status(&results, "License Field", CheckStatus::Fail);
+}
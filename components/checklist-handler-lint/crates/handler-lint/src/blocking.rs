@@ -0,0 +1,63 @@
+//! Blocking calls inside `async fn` bodies
+
+use checklist_result::CheckResult;
+use discovery_source::{RustSource, matching_lines};
+use std::ops::RangeInclusive;
+
+/// Calls that block the executor thread, with the suggested replacement
+const BLOCKING_CALLS: &[(&str, &str)] = &[
+    ("thread::sleep(", "tokio::time::sleep(..).await"),
+    ("std::fs::", "tokio::fs or spawn_blocking"),
+    (".blocking_lock()", ".lock().await"),
+];
+
+/// Check async fn bodies in a tokio crate don't make blocking calls
+pub fn check_blocking_in_async(sources: &[RustSource], crate_name: &str) -> Vec<CheckResult> {
+    let label = format!("Blocking In Async [{}]", crate_name);
+    let mut results = Vec::new();
+    for source in sources {
+        let bodies = async_bodies(&source.content);
+        for (call, instead) in BLOCKING_CALLS {
+            let lines = matching_lines(&source.content, |l| l.contains(call));
+            results.extend(
+                lines
+                    .into_iter()
+                    .filter(|line| bodies.iter().any(|b| b.contains(line)))
+                    .map(|line| {
+                        let message = format!("Blocking {} in async fn; use {}", call, instead);
+                        CheckResult::warn(label.clone(), message).at(&source.path, line)
+                    }),
+            );
+        }
+    }
+    if results.is_empty() {
+        results.push(CheckResult::pass(label, "No blocking calls in async fns"));
+    }
+    results
+}
+
+/// 1-based line ranges of `async fn` bodies (brace matched; trait declarations skipped)
+fn async_bodies(content: &str) -> Vec<RangeInclusive<usize>> {
+    let mut bodies = Vec::new();
+    let mut depth = 0usize;
+    // (start line, depth outside the fn, whether its body has opened)
+    let mut current: Option<(usize, usize, bool)> = None;
+    for (i, line) in content.lines().enumerate() {
+        let code = line.split("//").next().unwrap_or_default();
+        if current.is_none() && code.contains("async fn ") {
+            current = Some((i + 1, depth, false));
+        }
+        depth += code.matches('{').count();
+        depth = depth.saturating_sub(code.matches('}').count());
+        if let Some((start, outer, opened)) = current {
+            let opened = opened || depth > outer;
+            if (opened && depth <= outer) || (!opened && code.trim_end().ends_with(';')) {
+                bodies.extend(opened.then_some(start..=i + 1));
+                current = None;
+            } else {
+                current = Some((start, outer, opened));
+            }
+        }
+    }
+    bodies
+}
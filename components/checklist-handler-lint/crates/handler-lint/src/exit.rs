@@ -0,0 +1,29 @@
+//! std::process::exit outside binary entry points
+
+use checklist_result::CheckResult;
+use discovery_source::{RustSource, is_entry_point, matching_lines};
+use std::path::Path;
+
+/// Check `process::exit` is only called from main.rs or src/bin
+pub fn check_process_exit(
+    sources: &[RustSource],
+    crate_dir: &Path,
+    crate_name: &str,
+) -> Vec<CheckResult> {
+    let label = format!("Process Exit [{}]", crate_name);
+    let message = "process::exit skips destructors and makes this code untestable; \
+                   return an exit code to main instead";
+    let results: Vec<CheckResult> = sources
+        .iter()
+        .filter(|s| !is_entry_point(&s.path, crate_dir))
+        .flat_map(|s| {
+            matching_lines(&s.content, |l| l.contains("process::exit("))
+                .into_iter()
+                .map(|line| CheckResult::warn(label.clone(), message).at(&s.path, line))
+        })
+        .collect();
+    if results.is_empty() {
+        return vec![CheckResult::pass(label, "process::exit only used in main")];
+    }
+    results
+}
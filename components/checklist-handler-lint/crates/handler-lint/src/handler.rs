@@ -0,0 +1,39 @@
+//! Lint handler implementation
+
+use anyhow::Result;
+use checklist_result::CheckResult;
+use discovery_crate::{CrateType, has_dependency};
+use discovery_source::rust_sources;
+use handler_trait::{CheckContext, Handler};
+
+use crate::blocking::check_blocking_in_async;
+use crate::exit::check_process_exit;
+use crate::orphans::check_orphaned_modules;
+
+/// Handler for source pattern lints
+pub struct LintHandler;
+
+impl Handler for LintHandler {
+    fn name(&self) -> &'static str {
+        "lint"
+    }
+
+    fn handles(&self, crate_type: CrateType) -> bool {
+        crate_type != CrateType::Workspace
+    }
+
+    fn check(&self, ctx: &CheckContext) -> Result<Vec<CheckResult>> {
+        let sources = rust_sources(&ctx.crate_dir.join("src"), ctx.config)?;
+        let mut results = check_process_exit(&sources, ctx.crate_dir, ctx.crate_name);
+        results.extend(check_orphaned_modules(
+            &sources,
+            ctx.crate_dir,
+            ctx.cargo_toml,
+            ctx.crate_name,
+        ));
+        if has_dependency(ctx.cargo_toml, "tokio") {
+            results.extend(check_blocking_in_async(&sources, ctx.crate_name));
+        }
+        Ok(results)
+    }
+}
@@ -0,0 +1,14 @@
+//! Source pattern lint handler for sw-checklist
+//!
+//! Flags code patterns the org has repeatedly been bitten by, with the file
+//! and line of each occurrence.
+
+mod blocking;
+mod exit;
+mod handler;
+mod mods;
+mod orphans;
+
+pub use blocking::check_blocking_in_async;
+pub use exit::check_process_exit;
+pub use handler::LintHandler;
@@ -0,0 +1,68 @@
+//! `mod` declaration parsing for module reachability
+
+use std::path::PathBuf;
+
+/// A file-backed module declaration (`mod name;`) or `include!` in a source file
+pub struct ModDecl {
+    /// Module path below the declaring file's module directory, with any
+    /// enclosing inline modules prepended (e.g. `inner/name`)
+    pub path: PathBuf,
+    /// `#[path = "..."]` or `include!("...")` file, relative to the declaring file's directory
+    pub explicit: Option<String>,
+}
+
+/// File-backed module declarations and includes in `content`
+///
+/// Inline modules (`mod name { ... }`) are tracked by brace depth so the
+/// declarations inside them resolve below the inline module's directory.
+pub fn module_decls(content: &str) -> Vec<ModDecl> {
+    let mut decls = Vec::new();
+    let (mut inline, mut depth, mut path_attr) = (Vec::<(String, usize)>::new(), 0, None);
+    for line in content.lines().map(str::trim) {
+        if line.starts_with("//") {
+            continue;
+        }
+        let prefix: PathBuf = inline.iter().map(|(name, _)| name.as_str()).collect();
+        if line.starts_with("#[path") {
+            path_attr = quoted(line);
+        } else if let Some(name) = mod_name(line) {
+            if line.ends_with('{') {
+                inline.push((name.to_string(), depth));
+            } else if line.ends_with(';') {
+                let explicit = path_attr.take();
+                decls.push(ModDecl {
+                    path: prefix.join(name),
+                    explicit,
+                });
+            }
+        } else if line.contains("include!(") && !line.contains("concat!(") {
+            let explicit = quoted(line);
+            decls.extend(explicit.map(|e| ModDecl {
+                path: prefix,
+                explicit: Some(e),
+            }));
+        }
+        depth = (depth + line.matches('{').count()).saturating_sub(line.matches('}').count());
+        inline.retain(|(_, opened)| *opened < depth);
+    }
+    decls
+}
+
+/// Module name from a `mod` item line, skipping visibility
+fn mod_name(line: &str) -> Option<&str> {
+    let item = match line.strip_prefix("pub") {
+        Some(rest) if rest.starts_with('(') => rest.split_once(')')?.1.trim_start(),
+        Some(rest) => rest.trim_start(),
+        None => line,
+    };
+    let name = item.strip_prefix("mod ")?;
+    let end = name.find(|c: char| !(c.is_alphanumeric() || c == '_'))?;
+    Some(name[..end].trim_start_matches("r#")).filter(|n| !n.is_empty())
+}
+
+/// First string literal on the line
+fn quoted(line: &str) -> Option<String> {
+    let start = line.find('"')? + 1;
+    let end = line[start..].find('"')? + start;
+    Some(line[start..end].to_string())
+}
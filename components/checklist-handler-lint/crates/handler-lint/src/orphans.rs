@@ -0,0 +1,116 @@
+//! Source files never reached from a crate root by `mod` declarations
+
+use checklist_result::CheckResult;
+use discovery_source::RustSource;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::mods::{ModDecl, module_decls};
+
+/// Warn about .rs files under src/ that no `mod` declaration reaches from a
+/// crate root, so they are never compiled
+pub fn check_orphaned_modules(
+    sources: &[RustSource],
+    crate_dir: &Path,
+    cargo_toml: &str,
+    crate_name: &str,
+) -> Vec<CheckResult> {
+    let label = format!("Orphaned Module [{}]", crate_name);
+    let reachable = reachable_files(crate_roots(crate_dir, cargo_toml));
+    if reachable.is_empty() {
+        return Vec::new();
+    }
+    let message = "Not declared with `mod` from any crate root, so never compiled";
+    let results: Vec<CheckResult> = sources
+        .iter()
+        .filter(|s| !reachable.contains(&s.path))
+        .map(|s| CheckResult::warn(label.clone(), message).at(&s.path, 1))
+        .collect();
+    if results.is_empty() {
+        return vec![CheckResult::pass(
+            label,
+            "All source files reached via `mod`",
+        )];
+    }
+    results
+}
+
+/// Existing crate root files: lib.rs, main.rs, src/bin targets, and any
+/// `[lib]` / `[[bin]]` paths from Cargo.toml
+fn crate_roots(crate_dir: &Path, cargo_toml: &str) -> Vec<PathBuf> {
+    let mut roots: Vec<PathBuf> = ["src/lib.rs", "src/main.rs"]
+        .into_iter()
+        .map(str::to_string)
+        .chain(manifest_roots(cargo_toml))
+        .map(|p| crate_dir.join(p))
+        .collect();
+    let bin_dir = fs::read_dir(crate_dir.join("src/bin"))
+        .into_iter()
+        .flatten()
+        .flatten();
+    for entry in bin_dir {
+        let path = entry.path();
+        roots.push(if path.is_dir() {
+            path.join("main.rs")
+        } else {
+            path
+        });
+    }
+    roots.retain(|p| p.is_file());
+    roots
+}
+
+/// `[lib]` and `[[bin]]` paths declared in Cargo.toml
+fn manifest_roots(cargo_toml: &str) -> Vec<String> {
+    let Ok(cargo) = cargo_toml.parse::<toml::Value>() else {
+        return Vec::new();
+    };
+    let lib = cargo.get("lib").and_then(|l| l.get("path")?.as_str());
+    let bins = cargo.get("bin").and_then(|b| b.as_array());
+    let declared = bins
+        .into_iter()
+        .flatten()
+        .filter_map(|b| b.get("path")?.as_str());
+    lib.into_iter()
+        .chain(declared)
+        .map(str::to_string)
+        .collect()
+}
+
+/// Every file reachable from `roots` through `mod` declarations and includes
+fn reachable_files(roots: Vec<PathBuf>) -> HashSet<PathBuf> {
+    let mut reachable = HashSet::new();
+    let mut pending: Vec<(PathBuf, bool)> = roots.into_iter().map(|r| (r, true)).collect();
+    while let Some((file, owns_dir)) = pending.pop() {
+        if !reachable.insert(file.clone()) {
+            continue;
+        }
+        let Ok(content) = fs::read_to_string(&file) else {
+            continue;
+        };
+        for decl in module_decls(&content) {
+            pending.extend(resolve(&file, owns_dir, &decl));
+        }
+    }
+    reachable
+}
+
+/// The file a declaration refers to, with whether that file owns its
+/// directory for further declarations (crate roots and mod.rs do)
+fn resolve(file: &Path, owns_dir: bool, decl: &ModDecl) -> Option<(PathBuf, bool)> {
+    let parent = file.parent()?;
+    if let Some(explicit) = &decl.explicit {
+        return Some((parent.join(explicit), false));
+    }
+    let dir = if owns_dir || file.ends_with("mod.rs") {
+        parent.to_path_buf()
+    } else {
+        parent.join(file.file_stem()?)
+    };
+    let flat = dir.join(&decl.path).with_extension("rs");
+    if flat.is_file() {
+        return Some((flat, false));
+    }
+    Some((dir.join(&decl.path).join("mod.rs"), true))
+}
@@ -0,0 +1,63 @@
+use checklist_result::CheckStatus;
+use checklist_testkit::{TestCrate, assert_no_issues, assert_status, results_for};
+use handler_lint::LintHandler;
+
+const TOKIO: &str =
+    "[package]\nname = \"svc\"\nversion = \"0.1.0\"\n\n[dependencies]\ntokio = \"1\"\n";
+
+fn library(files: &[(&str, &str)]) -> TestCrate {
+    files.iter().fold(
+        TestCrate::new("demo").unwrap(),
+        |krate, (path, contents)| krate.file(path, contents).unwrap(),
+    )
+}
+
+#[test]
+fn a_clean_crate_has_no_issues() {
+    let krate = library(&[("src/lib.rs", "mod util;\n"), ("src/util.rs", "")]);
+    let results = krate.check(&LintHandler).unwrap();
+    assert_no_issues(&results);
+    assert_status(&results, "Orphaned Module", CheckStatus::Pass);
+}
+
+#[test]
+fn process_exit_belongs_in_main() {
+    let krate = library(&[(
+        "src/lib.rs",
+        "pub fn f() {\n    std::process::exit(1);\n}\n",
+    )]);
+    let results = krate.check(&LintHandler).unwrap();
+    assert_status(&results, "Process Exit", CheckStatus::Warn);
+    let exit = results_for(&results, "Process Exit");
+    assert_eq!(exit[0].location.as_ref().unwrap().line, 2);
+}
+
+#[test]
+fn undeclared_files_are_orphaned() {
+    let krate = library(&[("src/lib.rs", ""), ("src/stale.rs", "")]);
+    let orphans = krate.check(&LintHandler).unwrap();
+    let orphans = results_for(&orphans, "Orphaned Module");
+    assert_eq!(orphans.len(), 1);
+    assert_eq!(orphans[0].status, CheckStatus::Warn);
+    assert!(
+        orphans[0]
+            .location
+            .as_ref()
+            .unwrap()
+            .file
+            .ends_with("stale.rs")
+    );
+}
+
+#[test]
+fn tokio_crates_must_not_block_in_async_fns() {
+    let lib = "pub async fn f() {\n    std::thread::sleep(d);\n}\n\npub fn g() {\n    std::thread::sleep(d);\n}\n";
+    let krate = library(&[("src/lib.rs", lib)]).manifest(TOKIO).unwrap();
+    let results = krate.check(&LintHandler).unwrap();
+    let blocking = results_for(&results, "Blocking In Async");
+    assert_eq!(blocking.len(), 1, "{:?}", blocking);
+    assert_eq!(blocking[0].location.as_ref().unwrap().line, 2);
+
+    let krate = library(&[("src/lib.rs", lib)]);
+    assert!(results_for(&krate.check(&LintHandler).unwrap(), "Blocking In Async").is_empty());
+}
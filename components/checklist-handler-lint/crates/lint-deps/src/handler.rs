@@ -0,0 +1,48 @@
+//! Unused dependency handler implementation
+
+use anyhow::Result;
+use checklist_result::CheckResult;
+use discovery_crate::CrateType;
+use handler_trait::{CheckContext, Handler};
+
+use crate::unused::{declaration_line, unused_dependencies};
+
+/// Opt-in handler warning on dependencies never referenced in source
+pub struct UnusedDepsHandler;
+
+impl Handler for UnusedDepsHandler {
+    fn name(&self) -> &'static str {
+        "unused-deps"
+    }
+
+    fn handles(&self, crate_type: CrateType) -> bool {
+        crate_type != CrateType::Workspace
+    }
+
+    fn skip_reason(&self, ctx: &CheckContext) -> Option<String> {
+        let enabled = ctx.config.file().unused_deps.check;
+        (!enabled).then(|| "disabled by config ([unused_deps] check = false)".to_string())
+    }
+
+    fn check(&self, ctx: &CheckContext) -> Result<Vec<CheckResult>> {
+        let policy = &ctx.config.file().unused_deps;
+        let label = format!("Unused Dependencies [{}]", ctx.crate_name);
+        let unused = unused_dependencies(ctx.cargo_toml, ctx.crate_dir, &policy.ignore);
+        if unused.is_empty() {
+            return Ok(vec![CheckResult::pass(
+                label,
+                "All dependencies referenced",
+            )]);
+        }
+        let manifest = ctx.crate_dir.join("Cargo.toml");
+        let text = std::fs::read_to_string(&manifest).unwrap_or_default();
+        Ok(unused
+            .iter()
+            .map(|dep| {
+                let message = format!("{} in [{}] is never referenced", dep.name, dep.table);
+                let line = declaration_line(&text, &dep.name);
+                CheckResult::warn(label.clone(), message).at(&manifest, line)
+            })
+            .collect())
+    }
+}
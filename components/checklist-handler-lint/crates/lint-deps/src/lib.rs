@@ -0,0 +1,10 @@
+//! Unused dependency detection for sw-checklist
+//!
+//! A heuristic alternative to `cargo +nightly udeps` that needs no build:
+//! a dependency is unused when its crate name never appears as a path
+//! (`name::`), in a `use`, or in `extern crate` anywhere in the crate's code.
+
+mod handler;
+mod unused;
+
+pub use handler::UnusedDepsHandler;
@@ -0,0 +1,82 @@
+//! Declared dependencies never referenced in source
+
+use std::fs;
+use std::path::Path;
+use walkdir::WalkDir;
+
+/// Dependency tables checked, with the code that may use them
+const TABLES: &[(&str, &str)] = &[("dependencies", "src/"), ("build-dependencies", "build.rs")];
+
+/// An unused dependency and the manifest table declaring it
+pub struct UnusedDep {
+    pub name: String,
+    pub table: &'static str,
+}
+
+/// Dependencies (including `[target.*]` ones) whose crate name never appears in the code
+/// that can use them: `src/` for normal dependencies, `build.rs` for build dependencies
+pub fn unused_dependencies(
+    cargo_toml: &str,
+    crate_dir: &Path,
+    ignore: &[String],
+) -> Vec<UnusedDep> {
+    let Ok(manifest) = cargo_toml.parse::<toml::Value>() else {
+        return Vec::new();
+    };
+    let mut unused = Vec::new();
+    for (table, code) in TABLES {
+        let code = read_code(&crate_dir.join(code));
+        for name in declared(&manifest, table) {
+            if !ignore.contains(&name) && !is_referenced(&name.replace('-', "_"), &code) {
+                unused.push(UnusedDep { name, table });
+            }
+        }
+    }
+    unused
+}
+
+/// 1-based line declaring a dependency in manifest text (1 if not found)
+pub fn declaration_line(manifest: &str, name: &str) -> usize {
+    manifest
+        .lines()
+        .position(|line| {
+            let rest = line.trim_start().strip_prefix(name).unwrap_or_default();
+            line.trim_start().starts_with(name) && rest.trim_start().starts_with(['=', '.'])
+        })
+        .map_or(1, |index| index + 1)
+}
+
+/// Dependency names in a table, at top level and under every `[target.*]`
+fn declared(manifest: &toml::Value, table: &str) -> Vec<String> {
+    let targets = manifest.get("target").and_then(|t| t.as_table());
+    std::iter::once(manifest)
+        .chain(targets.into_iter().flat_map(|t| t.values()))
+        .filter_map(|scope| scope.get(table)?.as_table())
+        .flat_map(|deps| deps.keys().cloned())
+        .collect()
+}
+
+/// All Rust code under a file or directory, concatenated
+fn read_code(path: &Path) -> String {
+    WalkDir::new(path)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().extension().is_some_and(|ext| ext == "rs"))
+        .filter_map(|e| fs::read_to_string(e.path()).ok())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Whether `ident` appears as a path root, in a `use`, or in `extern crate`
+fn is_referenced(ident: &str, code: &str) -> bool {
+    code.match_indices(ident).any(|(start, _)| {
+        let before = code[..start].chars().next_back();
+        let after = &code[start + ident.len()..];
+        let bounded = !before.is_some_and(|c| c.is_alphanumeric() || c == '_');
+        let prefix = code[..start].trim_end();
+        bounded
+            && (after.starts_with("::")
+                || prefix.ends_with("use")
+                || prefix.ends_with("extern crate"))
+    })
+}
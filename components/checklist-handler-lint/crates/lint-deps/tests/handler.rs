@@ -0,0 +1,52 @@
+use checklist_config::FileConfig;
+use checklist_result::CheckStatus;
+use checklist_testkit::{TestCrate, assert_status};
+use lint_deps::UnusedDepsHandler;
+
+const MANIFEST: &str = "[package]\nname = \"demo\"\nversion = \"0.1.0\"\n\n[dependencies]\nserde-json = \"1\"\nregex = \"1\"\n";
+
+fn demo() -> TestCrate {
+    TestCrate::with_manifest(MANIFEST)
+        .unwrap()
+        .file(
+            "src/lib.rs",
+            "pub fn f() {\n    serde_json::json!({});\n}\n",
+        )
+        .unwrap()
+}
+
+fn enabled() -> FileConfig {
+    let mut file = FileConfig::default();
+    file.unused_deps.check = true;
+    file
+}
+
+#[test]
+fn the_check_is_opt_in() {
+    assert!(demo().check(&UnusedDepsHandler).unwrap().is_empty());
+}
+
+#[test]
+fn unreferenced_dependencies_are_reported_at_their_line() {
+    let krate = demo();
+    let results = krate
+        .run(&UnusedDepsHandler, &krate.config(enabled()))
+        .unwrap();
+    assert_eq!(results.len(), 1, "{:?}", results);
+    assert_eq!(results[0].status, CheckStatus::Warn);
+    assert!(
+        results[0].message.starts_with("regex"),
+        "{}",
+        results[0].message
+    );
+    assert_eq!(results[0].location.as_ref().unwrap().line, 7);
+}
+
+#[test]
+fn ignored_dependencies_are_not_reported() {
+    let mut file = enabled();
+    file.unused_deps.ignore = vec!["regex".into()];
+    let krate = demo();
+    let results = krate.run(&UnusedDepsHandler, &krate.config(file)).unwrap();
+    assert_status(&results, "Unused Dependencies", CheckStatus::Pass);
+}
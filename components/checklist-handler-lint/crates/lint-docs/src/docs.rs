@@ -0,0 +1,16 @@
+//! Module doc comment detection
+
+/// Whether the first item in `content` is an inner doc comment (`//!` or `/*!`)
+///
+/// Blank lines, plain `//` comments (such as a copyright header), and inner
+/// attributes like `#![no_std]` may come before it.
+pub fn has_module_doc(content: &str) -> bool {
+    content
+        .lines()
+        .map(str::trim)
+        .find(|line| {
+            let plain_comment = line.starts_with("//") && !line.starts_with("//!");
+            !(line.is_empty() || plain_comment || line.starts_with("#!["))
+        })
+        .is_some_and(|line| line.starts_with("//!") || line.starts_with("/*!"))
+}
@@ -0,0 +1,38 @@
+//! Module docs handler implementation
+
+use anyhow::Result;
+use checklist_result::CheckResult;
+use discovery_crate::CrateType;
+use discovery_source::rust_sources;
+use handler_trait::{CheckContext, Handler};
+
+use crate::docs::has_module_doc;
+
+/// Handler warning about module files under src/ without a `//!` doc comment
+pub struct ModuleDocsHandler;
+
+impl Handler for ModuleDocsHandler {
+    fn name(&self) -> &'static str {
+        "module-docs"
+    }
+
+    fn handles(&self, crate_type: CrateType) -> bool {
+        crate_type != CrateType::Workspace
+    }
+
+    fn check(&self, ctx: &CheckContext) -> Result<Vec<CheckResult>> {
+        let label = format!("Module Docs [{}]", ctx.crate_name);
+        let sources = rust_sources(&ctx.crate_dir.join("src"), ctx.config)?;
+        let message = "Does not start with a `//!` module doc comment";
+        let results: Vec<CheckResult> = sources
+            .iter()
+            .filter(|s| !has_module_doc(&s.content))
+            .map(|s| CheckResult::warn(label.clone(), message).at(&s.path, 1))
+            .collect();
+        if results.is_empty() {
+            let message = format!("{} files start with a `//!` doc comment", sources.len());
+            return Ok(vec![CheckResult::pass(label, message)]);
+        }
+        Ok(results)
+    }
+}
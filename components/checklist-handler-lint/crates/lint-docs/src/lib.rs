@@ -0,0 +1,9 @@
+//! Module doc comment checks for sw-checklist
+//!
+//! Every module file should open with a `//!` comment saying what it is for,
+//! as the files in this repository do.
+
+mod docs;
+mod handler;
+
+pub use handler::ModuleDocsHandler;
@@ -0,0 +1,29 @@
+use checklist_result::CheckStatus;
+use checklist_testkit::{TestCrate, assert_status};
+use lint_docs::ModuleDocsHandler;
+
+fn library(lib: &str) -> TestCrate {
+    TestCrate::new("demo")
+        .unwrap()
+        .file("src/lib.rs", lib)
+        .unwrap()
+}
+
+#[test]
+fn module_docs_may_follow_a_copyright_header() {
+    let lib = "// Copyright (c) 2025 Michael A Wright\n\n#![no_std]\n//! Demo crate\n";
+    assert_status(
+        &library(lib).check(&ModuleDocsHandler).unwrap(),
+        "Module Docs",
+        CheckStatus::Pass,
+    );
+}
+
+#[test]
+fn files_without_module_docs_warn() {
+    let results = library("/// Item docs are not module docs\npub fn f() {}\n")
+        .check(&ModuleDocsHandler)
+        .unwrap();
+    assert_status(&results, "Module Docs", CheckStatus::Warn);
+    assert_eq!(results[0].location.as_ref().unwrap().line, 1);
+}
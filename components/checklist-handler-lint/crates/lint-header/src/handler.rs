@@ -0,0 +1,72 @@
+//! Copyright header handler implementation
+
+use anyhow::Result;
+use checklist_result::CheckResult;
+use discovery_crate::CrateType;
+use discovery_source::{RustSource, rust_sources};
+use handler_trait::{CheckContext, Handler};
+use std::fs;
+
+use crate::header::{DEFAULT_TEMPLATE, has_header, with_header};
+
+/// Source locations whose `.rs` files need the header
+const SOURCE_PATHS: &[&str] = &["src", "tests", "examples", "benches", "build.rs"];
+
+/// Opt-in handler requiring a copyright header in every source file
+pub struct HeaderHandler;
+
+impl Handler for HeaderHandler {
+    fn name(&self) -> &'static str {
+        "copyright-header"
+    }
+
+    fn handles(&self, crate_type: CrateType) -> bool {
+        crate_type != CrateType::Workspace
+    }
+
+    fn skip_reason(&self, ctx: &CheckContext) -> Option<String> {
+        let enabled = ctx.config.file().header.check;
+        (!enabled).then(|| "disabled by config ([header] check = false)".to_string())
+    }
+
+    fn check(&self, ctx: &CheckContext) -> Result<Vec<CheckResult>> {
+        let policy = &ctx.config.file().header;
+        let template = policy.template.as_deref().unwrap_or(DEFAULT_TEMPLATE);
+        let label = format!("Copyright Header [{}]", ctx.crate_name);
+        let mut sources = Vec::new();
+        for path in SOURCE_PATHS {
+            sources.extend(rust_sources(&ctx.crate_dir.join(path), ctx.config)?);
+        }
+        let missing: Vec<&RustSource> = sources
+            .iter()
+            .filter(|s| !has_header(&s.content, template))
+            .collect();
+        if missing.is_empty() {
+            let message = format!("{} files start with the copyright header", sources.len());
+            return Ok(vec![CheckResult::pass(label, message)]);
+        }
+        missing
+            .iter()
+            .map(|source| missing_result(&label, source, template, ctx.config.fix()))
+            .collect()
+    }
+}
+
+/// A warning for a file without the header, or (in fix mode) an INFO after inserting it
+fn missing_result(
+    label: &str,
+    source: &RustSource,
+    template: &str,
+    fix: bool,
+) -> Result<CheckResult> {
+    let file = source.path.display();
+    if fix {
+        fs::write(&source.path, with_header(&source.content, template))?;
+        return Ok(CheckResult::info(
+            label,
+            format!("Fixed {}: inserted copyright header", file),
+        ));
+    }
+    let message = format!("{} does not start with the copyright header", file);
+    Ok(CheckResult::warn(label, message).at(&source.path, 1))
+}
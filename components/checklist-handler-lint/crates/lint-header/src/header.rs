@@ -0,0 +1,54 @@
+//! Header template matching and insertion
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Header used when `[header] template` is not set
+pub const DEFAULT_TEMPLATE: &str =
+    "// Copyright (c) {year} Michael A Wright\n// SPDX-License-Identifier: MIT";
+
+const YEAR: &str = "{year}";
+
+/// Whether `content` begins with `template`, where `{year}` matches a year
+/// or a year range like `2024-2026`
+pub fn has_header(content: &str, template: &str) -> bool {
+    let mut lines = content.lines();
+    template.lines().all(|expected| {
+        lines
+            .next()
+            .is_some_and(|line| match expected.split_once(YEAR) {
+                Some((prefix, suffix)) => line
+                    .strip_prefix(prefix)
+                    .and_then(|rest| rest.strip_suffix(suffix))
+                    .is_some_and(is_year_or_range),
+                None => line.trim_end() == expected.trim_end(),
+            })
+    })
+}
+
+/// `content` with the template (for the current year) and a blank line prepended
+pub fn with_header(content: &str, template: &str) -> String {
+    let header = template.replace(YEAR, &current_year().to_string());
+    format!("{}\n\n{}", header.trim_end(), content)
+}
+
+fn is_year_or_range(text: &str) -> bool {
+    let is_year = |y: &str| y.len() == 4 && y.bytes().all(|b| b.is_ascii_digit());
+    match text.split_once('-') {
+        Some((from, to)) => is_year(from) && is_year(to),
+        None => is_year(text),
+    }
+}
+
+/// The current UTC year (civil-from-days, proleptic Gregorian)
+fn current_year() -> i64 {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    let z = (secs / 86_400) as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let month_index = (5 * doy + 2) / 153;
+    yoe + era * 400 + i64::from(month_index >= 10)
+}
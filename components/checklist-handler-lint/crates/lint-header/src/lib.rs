@@ -0,0 +1,9 @@
+//! Copyright header checks for sw-checklist
+//!
+//! Extends the copyright requirement on `--version` output to source files:
+//! every `.rs` file must begin with the org's header template.
+
+mod handler;
+mod header;
+
+pub use handler::HeaderHandler;
@@ -0,0 +1,68 @@
+use checklist_config::{ConfigBuilder, FileConfig};
+use checklist_result::CheckStatus;
+use checklist_testkit::{TestCrate, assert_status};
+use lint_header::HeaderHandler;
+use std::fs;
+
+const HEADER: &str =
+    "// Copyright (c) 2024-2026 Michael A Wright\n// SPDX-License-Identifier: MIT\n";
+
+fn enabled() -> FileConfig {
+    let mut file = FileConfig::default();
+    file.header.check = true;
+    file
+}
+
+fn demo(lib: &str) -> TestCrate {
+    TestCrate::new("demo")
+        .unwrap()
+        .file("src/lib.rs", lib)
+        .unwrap()
+}
+
+#[test]
+fn the_check_is_opt_in() {
+    assert!(demo("").check(&HeaderHandler).unwrap().is_empty());
+}
+
+#[test]
+fn every_source_file_needs_the_header() {
+    let krate = demo(HEADER).file("tests/it.rs", "").unwrap();
+    let results = krate.run(&HeaderHandler, &krate.config(enabled())).unwrap();
+    assert_eq!(results.len(), 1, "{:?}", results);
+    assert_status(&results, "Copyright Header", CheckStatus::Warn);
+    assert!(
+        results[0].message.contains("it.rs"),
+        "{}",
+        results[0].message
+    );
+}
+
+#[test]
+fn custom_templates_replace_the_default() {
+    let mut file = enabled();
+    file.header.template = Some("// Part of demo ({year})".into());
+    let krate = demo("// Part of demo (2026)\n");
+    let results = krate.run(&HeaderHandler, &krate.config(file)).unwrap();
+    assert_status(&results, "Copyright Header", CheckStatus::Pass);
+}
+
+#[test]
+fn fix_mode_inserts_the_header() {
+    let krate = demo("pub fn f() {}\n");
+    let config = ConfigBuilder::new()
+        .project_path(krate.path().to_path_buf())
+        .file_config(enabled())
+        .fix(true)
+        .build();
+    let results = krate.run(&HeaderHandler, &config).unwrap();
+    assert_status(&results, "Copyright Header", CheckStatus::Info);
+    let lib = fs::read_to_string(krate.path().join("src/lib.rs")).unwrap();
+    assert!(lib.starts_with("// Copyright (c) "), "{}", lib);
+    assert!(lib.ends_with("\n\npub fn f() {}\n"), "{}", lib);
+    assert_status(
+        &krate.run(&HeaderHandler, &krate.config(enabled())).unwrap(),
+        "Copyright Header",
+        CheckStatus::Pass,
+    );
+}
@@ -0,0 +1,51 @@
+//! Logging handler implementation
+
+use anyhow::Result;
+use checklist_result::CheckResult;
+use discovery_crate::CrateType;
+use discovery_source::rust_sources;
+use handler_trait::{CheckContext, Handler};
+
+use crate::print::check_no_print;
+use crate::setup::check_logging_setup;
+
+/// Handler for logging conventions
+pub struct LoggingHandler;
+
+impl Handler for LoggingHandler {
+    fn name(&self) -> &'static str {
+        "logging"
+    }
+
+    fn handles(&self, crate_type: CrateType) -> bool {
+        matches!(
+            crate_type,
+            CrateType::Library
+                | CrateType::Cli
+                | CrateType::CliWasm
+                | CrateType::Tauri
+                | CrateType::Desktop
+        )
+    }
+
+    fn check(&self, ctx: &CheckContext) -> Result<Vec<CheckResult>> {
+        let sources = rust_sources(&ctx.crate_dir.join("src"), ctx.config)?;
+        if ctx.crate_type != CrateType::Library {
+            let source: Vec<&str> = sources.iter().map(|s| s.content.as_str()).collect();
+            let source = source.join("\n");
+            return Ok(check_logging_setup(&source, ctx.cargo_toml, ctx.crate_name));
+        }
+        if ctx
+            .config
+            .file()
+            .crates
+            .is_output(ctx.crate_name, ctx.crate_dir)
+        {
+            return Ok(vec![CheckResult::info(
+                format!("No Print [{}]", ctx.crate_name),
+                "Terminal output crate; println!/eprintln! allowed",
+            )]);
+        }
+        Ok(check_no_print(&sources, ctx.crate_dir, ctx.crate_name))
+    }
+}
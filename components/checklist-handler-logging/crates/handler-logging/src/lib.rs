@@ -0,0 +1,8 @@
+//! Logging convention check handler for sw-checklist
+
+mod handler;
+mod print;
+mod setup;
+
+pub use handler::LoggingHandler;
+pub use print::check_no_print;
@@ -0,0 +1,44 @@
+//! println!/eprintln! scan for library crates
+
+use checklist_result::CheckResult;
+use discovery_source::{RustSource, is_entry_point, matching_lines};
+use std::path::Path;
+
+/// Macros that write straight to the terminal
+const PRINT_MACROS: &[&str] = &["println!", "eprintln!"];
+
+/// Check library sources don't print to stdout/stderr outside tests
+pub fn check_no_print(
+    sources: &[RustSource],
+    crate_dir: &Path,
+    crate_name: &str,
+) -> Vec<CheckResult> {
+    let label = format!("No Print [{}]", crate_name);
+    let results: Vec<CheckResult> = sources
+        .iter()
+        .filter(|s| !is_entry_point(&s.path, crate_dir))
+        .filter_map(|s| file_result(&label, s, crate_dir))
+        .collect();
+    if results.is_empty() {
+        return vec![CheckResult::pass(
+            label,
+            "No println!/eprintln! in library code",
+        )];
+    }
+    results
+}
+
+/// A warning at the first print macro in a source file, if any
+fn file_result(label: &str, source: &RustSource, crate_dir: &Path) -> Option<CheckResult> {
+    let lines = matching_lines(&source.content, |l| {
+        PRINT_MACROS.iter().any(|m| l.contains(m))
+    });
+    let first = *lines.first()?;
+    let file = source.path.strip_prefix(crate_dir).unwrap_or(&source.path);
+    let message = format!(
+        "{} has {} println!/eprintln! call(s); libraries should log via tracing or log",
+        file.display(),
+        lines.len()
+    );
+    Some(CheckResult::warn(label, message).at(&source.path, first))
+}
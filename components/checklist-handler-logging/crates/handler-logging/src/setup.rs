@@ -0,0 +1,68 @@
+//! Logging subscriber setup check for binaries and servers
+
+use checklist_result::CheckResult;
+use discovery_crate::has_dependency;
+
+/// Source patterns that initialize a logging/tracing subscriber
+const INIT_PATTERNS: &[&str] = &[
+    "tracing_subscriber",
+    "env_logger",
+    "simple_logger",
+    "log4rs",
+    "fern::",
+];
+
+/// Source patterns showing a `RUST_LOG`-style filter is honored
+const FILTER_PATTERNS: &[&str] = &["RUST_LOG", "EnvFilter", "from_default_env", "env_logger"];
+
+/// Dependencies that mark a long-running server or service
+const SERVER_DEPS: &[&str] = &[
+    "tokio",
+    "axum",
+    "actix-web",
+    "hyper",
+    "warp",
+    "rocket",
+    "tonic",
+    "tiny_http",
+];
+
+/// Check a binary initializes logging and honors a `RUST_LOG`-style filter
+pub fn check_logging_setup(source: &str, cargo_toml: &str, crate_name: &str) -> Vec<CheckResult> {
+    let label = format!("Logging Setup [{}]", crate_name);
+    if !INIT_PATTERNS.iter().any(|p| source.contains(p)) {
+        let result = if is_long_running(source, cargo_toml) {
+            CheckResult::warn(
+                label,
+                "Long-running tool has no logging; initialize tracing_subscriber or env_logger in main",
+            )
+        } else {
+            CheckResult::info(label, "No logging subscriber initialized")
+        };
+        return vec![result];
+    }
+    vec![
+        CheckResult::pass(label, "Logging subscriber initialized"),
+        check_log_filter(source, crate_name),
+    ]
+}
+
+/// Check the log level can be set through `RUST_LOG`
+fn check_log_filter(source: &str, crate_name: &str) -> CheckResult {
+    let label = format!("Log Filter [{}]", crate_name);
+    if FILTER_PATTERNS.iter().any(|p| source.contains(p)) {
+        CheckResult::pass(label, "Log level is controlled by RUST_LOG")
+    } else {
+        CheckResult::warn(
+            label,
+            "Logging ignores RUST_LOG; use EnvFilter::from_default_env() or env_logger",
+        )
+    }
+}
+
+/// Servers, async services, and listen loops run long enough to need logs
+fn is_long_running(source: &str, cargo_toml: &str) -> bool {
+    SERVER_DEPS.iter().any(|d| has_dependency(cargo_toml, d))
+        || source.contains("TcpListener")
+        || source.contains(".incoming()")
+}
@@ -0,0 +1,71 @@
+use checklist_config::FileConfig;
+use checklist_result::CheckStatus;
+use checklist_testkit::{TestCrate, assert_status};
+use handler_logging::LoggingHandler;
+
+const CLI: &str =
+    "[package]\nname = \"tool\"\nversion = \"0.1.0\"\n\n[dependencies]\nclap = \"4\"\n";
+const SERVER: &str = "[package]\nname = \"tool\"\nversion = \"0.1.0\"\n\n[dependencies]\nclap = \"4\"\ntokio = \"1\"\n";
+const PRINTS: &str = "pub fn f() {\n    println!(\"hi\");\n}\n";
+
+fn library(lib: &str) -> TestCrate {
+    TestCrate::new("core-lib")
+        .unwrap()
+        .file("src/lib.rs", lib)
+        .unwrap()
+}
+
+fn binary(manifest: &str, main: &str) -> TestCrate {
+    TestCrate::with_manifest(manifest)
+        .unwrap()
+        .file("src/main.rs", main)
+        .unwrap()
+}
+
+#[test]
+fn libraries_must_not_print() {
+    let results = library(PRINTS).check(&LoggingHandler).unwrap();
+    assert_status(&results, "No Print", CheckStatus::Warn);
+    assert_eq!(results[0].location.as_ref().unwrap().line, 2);
+
+    let krate = library("pub fn f() {\n    log::info!(\"hi\");\n}\n");
+    let results = krate.check(&LoggingHandler).unwrap();
+    assert_status(&results, "No Print", CheckStatus::Pass);
+}
+
+#[test]
+fn output_crates_may_print() {
+    let mut file = FileConfig::default();
+    file.crates.output = vec!["core-lib".into()];
+    let krate = library(PRINTS);
+    assert_status(
+        &krate.run(&LoggingHandler, &krate.config(file)).unwrap(),
+        "No Print",
+        CheckStatus::Info,
+    );
+}
+
+#[test]
+fn binaries_initialize_logging_with_a_filter() {
+    let krate = binary(CLI, "fn main() {\n    env_logger::init();\n}\n");
+    let results = krate.check(&LoggingHandler).unwrap();
+    assert_status(&results, "Logging Setup", CheckStatus::Pass);
+    assert_status(&results, "Log Filter", CheckStatus::Pass);
+
+    let krate = binary(
+        CLI,
+        "fn main() {\n    tracing_subscriber::fmt().init();\n}\n",
+    );
+    let results = krate.check(&LoggingHandler).unwrap();
+    assert_status(&results, "Log Filter", CheckStatus::Warn);
+}
+
+#[test]
+fn only_servers_must_log() {
+    let main = "fn main() {}\n";
+    let results = binary(CLI, main).check(&LoggingHandler).unwrap();
+    assert_status(&results, "Logging Setup", CheckStatus::Info);
+
+    let results = binary(SERVER, main).check(&LoggingHandler).unwrap();
+    assert_status(&results, "Logging Setup", CheckStatus::Warn);
+}
@@ -1,37 +1,29 @@
 //! Crate module count checking
 
-use anyhow::Result;
+use checklist_config::Limit;
 use checklist_result::CheckResult;
-use std::path::Path;
-use walkdir::WalkDir;
+use discovery_source::RustSource;
 
-/// Check crate module count
-pub fn check_crate_module_count(src_dir: &Path, crate_name: &str) -> Result<Vec<CheckResult>> {
-    let module_count = walk_rs_files(src_dir).count();
+/// Check crate module count against `limit`
+pub fn check_crate_module_count(
+    sources: &[RustSource],
+    crate_name: &str,
+    limit: Limit,
+) -> Vec<CheckResult> {
+    let module_count = sources.len();
     let label = format!("Crate Module Count [{}]", crate_name);
+    let subject = format!("Crate {} has {} modules", crate_name, module_count);
 
-    let result = if module_count > 7 {
-        CheckResult::fail(
-            label,
-            format!("Crate {} has {} modules (max 7)", crate_name, module_count),
-        )
-    } else if module_count > 4 {
-        CheckResult::warn(
-            label,
-            format!(
-                "Crate {} has {} modules (warning at >4, max 7)",
-                crate_name, module_count
-            ),
-        )
+    let result = if module_count > limit.fail {
+        CheckResult::fail(label, format!("{} (max {})", subject, limit.fail))
+    } else if module_count > limit.warn {
+        let message = format!(
+            "{} (warning at >{}, max {})",
+            subject, limit.warn, limit.fail
+        );
+        CheckResult::warn(label, message)
     } else {
-        CheckResult::pass(label, "Crate has 4 or fewer modules")
+        CheckResult::pass(label, format!("Crate has {} or fewer modules", limit.warn))
     };
-    Ok(vec![result])
-}
-
-fn walk_rs_files(dir: &Path) -> impl Iterator<Item = walkdir::DirEntry> {
-    WalkDir::new(dir)
-        .into_iter()
-        .filter_map(|e| e.ok())
-        .filter(|e| e.path().extension().and_then(|s| s.to_str()) == Some("rs"))
+    vec![result]
 }
@@ -1,10 +1,12 @@
 //! Modularity handler implementation
 
 use anyhow::Result;
+use checklist_config::ModularityConfig;
 use checklist_result::CheckResult;
 use discovery_crate::CrateType;
-use modularity_loc::{check_file_locs, check_function_locs};
+use discovery_source::{RustSource, rust_sources};
 use handler_trait::{CheckContext, Handler};
+use modularity_loc::{check_file_locs, check_function_locs};
 
 use crate::crate_count::check_crate_module_count;
 use crate::module_count::check_module_function_counts;
@@ -23,9 +25,7 @@ impl Handler for ModularityHandler {
     }
 
     fn check(&self, ctx: &CheckContext) -> Result<Vec<CheckResult>> {
-        let mut results = Vec::new();
         let src_dir = ctx.crate_dir.join("src");
-
         if !src_dir.exists() {
             return Ok(vec![CheckResult::pass(
                 format!("Modularity [{}]", ctx.crate_name),
@@ -33,18 +33,32 @@ impl Handler for ModularityHandler {
             )]);
         }
 
-        // Check function LOC
-        results.extend(check_function_locs(&src_dir, ctx.crate_name)?);
-
-        // Check file LOC
-        results.extend(check_file_locs(&src_dir, ctx.crate_name)?);
-
-        // Check module function counts
-        results.extend(check_module_function_counts(&src_dir, ctx.crate_name)?);
-
-        // Check crate module count
-        results.extend(check_crate_module_count(&src_dir, ctx.crate_name)?);
-
-        Ok(results)
+        let sources = rust_sources(&src_dir, ctx.config)?;
+        Ok(modularity_results(
+            &sources,
+            ctx.crate_name,
+            &ctx.config.file().modularity,
+        ))
     }
 }
+
+/// Run each modularity check against its configured limit
+fn modularity_results(
+    sources: &[RustSource],
+    crate_name: &str,
+    limits: &ModularityConfig,
+) -> Vec<CheckResult> {
+    let mut results = check_function_locs(sources, crate_name, limits.function_loc);
+    results.extend(check_file_locs(sources, crate_name, limits.file_loc));
+    results.extend(check_module_function_counts(
+        sources,
+        crate_name,
+        limits.module_functions,
+    ));
+    results.extend(check_crate_module_count(
+        sources,
+        crate_name,
+        limits.crate_modules,
+    ));
+    results
+}
@@ -7,3 +7,4 @@ mod handler;
 mod module_count;
 
 pub use handler::ModularityHandler;
+pub use module_count::check_module_function_count;
@@ -1,66 +1,70 @@
 //! Module function count checking
 
-use anyhow::Result;
+use checklist_config::Limit;
 use checklist_result::CheckResult;
-use std::fs;
-use std::path::Path;
-use walkdir::WalkDir;
+use discovery_source::RustSource;
 
-/// Check module function counts
-pub fn check_module_function_counts(src_dir: &Path, crate_name: &str) -> Result<Vec<CheckResult>> {
+/// Check module function counts against `limit`
+pub fn check_module_function_counts(
+    sources: &[RustSource],
+    crate_name: &str,
+    limit: Limit,
+) -> Vec<CheckResult> {
     let mut results = Vec::new();
     let mut any_issues = false;
 
-    for entry in walk_rs_files(src_dir) {
-        let path = entry.path();
-        let content = fs::read_to_string(path)?;
-        let file_name = path.file_name().unwrap().to_string_lossy();
-        if let Some(r) = check_module_fn_count(crate_name, &file_name, count_functions(&content)) {
+    for source in sources {
+        if let Some(result) = check_module_function_count(source, crate_name, limit) {
             any_issues = true;
-            results.push(r);
+            results.push(result);
         }
     }
 
     if !any_issues {
         results.push(CheckResult::pass(
             format!("Module Function Count [{}]", crate_name),
-            "All modules have 4 or fewer functions",
+            format!("All modules have {} or fewer functions", limit.warn),
         ));
     }
-    Ok(results)
+    results
+}
+
+/// The finding for one module with more functions than `limit` (nested
+/// functions included), located at its first line
+pub fn check_module_function_count(
+    source: &RustSource,
+    crate_name: &str,
+    limit: Limit,
+) -> Option<CheckResult> {
+    let fn_count = count_functions(&source.content);
+    let result = check_module_fn_count(crate_name, &source.file_name(), fn_count, limit)?;
+    Some(result.at(&source.path, 1))
 }
 
 fn check_module_fn_count(
     crate_name: &str,
     file_name: &str,
     fn_count: usize,
+    limit: Limit,
 ) -> Option<CheckResult> {
     let label = format!("Module Function Count [{}]", crate_name);
-    if fn_count > 7 {
+    let subject = format!("Module {} has {} functions", file_name, fn_count);
+    if fn_count > limit.fail {
         Some(CheckResult::fail(
             label,
-            format!("Module {} has {} functions (max 7)", file_name, fn_count),
-        ))
-    } else if fn_count > 4 {
-        Some(CheckResult::warn(
-            label,
-            format!(
-                "Module {} has {} functions (warning at >4, max 7)",
-                file_name, fn_count
-            ),
+            format!("{} (max {})", subject, limit.fail),
         ))
+    } else if fn_count > limit.warn {
+        let message = format!(
+            "{} (warning at >{}, max {})",
+            subject, limit.warn, limit.fail
+        );
+        Some(CheckResult::warn(label, message))
     } else {
         None
     }
 }
 
-fn walk_rs_files(dir: &Path) -> impl Iterator<Item = walkdir::DirEntry> {
-    WalkDir::new(dir)
-        .into_iter()
-        .filter_map(|e| e.ok())
-        .filter(|e| e.path().extension().and_then(|s| s.to_str()) == Some("rs"))
-}
-
 fn count_functions(content: &str) -> usize {
     content
         .lines()
@@ -0,0 +1,179 @@
+use checklist_config::{FileConfig, load_file_config};
+use checklist_result::{CheckResult, CheckStatus};
+use checklist_testkit::{TestCrate, assert_status, results_for};
+use handler_modularity::ModularityHandler;
+
+/// Run the handler on a library crate with the given source files
+fn check(files: &[(String, String)]) -> Vec<CheckResult> {
+    let mut krate = TestCrate::new("test-crate").unwrap();
+    for (path, contents) in files {
+        krate = krate.file(path, contents).unwrap();
+    }
+    let file = load_file_config(krate.path()).unwrap();
+    krate.run(&ModularityHandler, &krate.config(file)).unwrap()
+}
+
+/// `files` with a .sw-checklist.toml holding `config`
+fn configured(config: &str, mut files: Vec<(String, String)>) -> Vec<(String, String)> {
+    files.push((".sw-checklist.toml".to_string(), config.to_string()));
+    files
+}
+
+/// A lib.rs with one function of `body_lines` statements
+fn function_with(body_lines: usize) -> Vec<(String, String)> {
+    let body: String = (0..body_lines)
+        .map(|i| format!("    let x{} = {};\n", i, i))
+        .collect();
+    vec![(
+        "src/lib.rs".to_string(),
+        format!("pub fn big() {{\n{}}}\n", body),
+    )]
+}
+
+/// A lib.rs with `count` empty functions
+fn functions(count: usize) -> Vec<(String, String)> {
+    let content: String = (1..=count)
+        .map(|i| format!("pub fn func{}() {{}}\n", i))
+        .collect();
+    vec![("src/lib.rs".to_string(), content)]
+}
+
+/// A lib.rs declaring `count` modules, each in its own file
+fn modules(count: usize) -> Vec<(String, String)> {
+    let lib: String = (1..=count)
+        .map(|i| format!("pub mod module{};\n", i))
+        .collect();
+    let mut files = vec![("src/lib.rs".to_string(), lib)];
+    files.extend((1..=count).map(|i| (format!("src/module{}.rs", i), "pub fn func() {}\n".into())));
+    files
+}
+
+/// A lib.rs of `lines` comment lines
+fn lines(lines: usize) -> Vec<(String, String)> {
+    let content: String = (0..lines).map(|i| format!("// Line {}\n", i)).collect();
+    vec![("src/lib.rs".to_string(), content)]
+}
+
+fn all_pass(results: &[CheckResult], check: &str) {
+    let found = results_for(results, check);
+    assert!(!found.is_empty(), "no '{}' results", check);
+    assert!(
+        found.iter().all(|r| r.status == CheckStatus::Pass),
+        "{:?}",
+        found
+    );
+}
+
+#[test]
+fn short_functions_pass() {
+    all_pass(&check(&function_with(4)), "Function LOC");
+}
+
+#[test]
+fn functions_over_25_lines_warn() {
+    assert_status(
+        &check(&function_with(28)),
+        "Function LOC",
+        CheckStatus::Warn,
+    );
+}
+
+#[test]
+fn functions_over_50_lines_fail() {
+    assert_status(
+        &check(&function_with(58)),
+        "Function LOC",
+        CheckStatus::Fail,
+    );
+}
+
+#[test]
+fn modules_with_few_functions_pass() {
+    all_pass(&check(&functions(3)), "Module Function Count");
+}
+
+#[test]
+fn modules_with_over_4_functions_warn() {
+    let results = check(&functions(5));
+    assert_status(&results, "Module Function Count", CheckStatus::Warn);
+}
+
+#[test]
+fn modules_with_over_7_functions_fail() {
+    let results = check(&functions(8));
+    assert_status(&results, "Module Function Count", CheckStatus::Fail);
+}
+
+#[test]
+fn crates_with_few_modules_pass() {
+    all_pass(&check(&modules(2)), "Crate Module Count");
+}
+
+#[test]
+fn crates_with_over_7_modules_fail() {
+    let results = check(&modules(8));
+    assert_status(&results, "Crate Module Count", CheckStatus::Fail);
+}
+
+#[test]
+fn short_files_pass() {
+    all_pass(&check(&lines(100)), "File LOC");
+}
+
+#[test]
+fn files_over_350_lines_warn() {
+    let results = check(&lines(400));
+    assert_status(&results, "File LOC", CheckStatus::Warn);
+    assert!(
+        results_for(&results, "File LOC")[0]
+            .message
+            .contains("400 lines")
+    );
+}
+
+#[test]
+fn files_over_500_lines_fail() {
+    let results = check(&lines(600));
+    assert_status(&results, "File LOC", CheckStatus::Fail);
+    assert!(
+        results_for(&results, "File LOC")[0]
+            .message
+            .contains("600 lines")
+    );
+}
+
+#[test]
+fn crates_without_src_pass() {
+    let krate = TestCrate::new("no-src").unwrap();
+    let results = krate
+        .run(&ModularityHandler, &krate.config(FileConfig::default()))
+        .unwrap();
+    assert_status(&results, "Modularity", CheckStatus::Pass);
+}
+
+#[test]
+fn function_limits_come_from_the_config_file() {
+    let config = "[modularity]\nfunction_loc = { warn = 5, fail = 10 }\n";
+    let results = check(&configured(config, function_with(8)));
+    assert_status(&results, "Function LOC", CheckStatus::Warn);
+    let results = check(&configured(config, function_with(12)));
+    assert_status(&results, "Function LOC", CheckStatus::Fail);
+    assert!(
+        results_for(&results, "Function LOC")[0]
+            .message
+            .contains("max 10")
+    );
+}
+
+#[test]
+fn raised_limits_pass_what_the_defaults_fail() {
+    let config = "[modularity]\nmodule_functions = { warn = 10, fail = 20 }\ncrate_modules = { warn = 10, fail = 20 }\n";
+    all_pass(
+        &check(&configured(config, functions(8))),
+        "Module Function Count",
+    );
+    all_pass(
+        &check(&configured(config, modules(8))),
+        "Crate Module Count",
+    );
+}
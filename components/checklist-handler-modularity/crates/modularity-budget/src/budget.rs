@@ -0,0 +1,32 @@
+//! Warn/fail grading of a LOC total against a budget
+
+use checklist_result::CheckResult;
+
+/// Warn and fail thresholds for a LOC total
+#[derive(Debug, Clone, Copy)]
+pub struct Budget {
+    /// LOC above which the check warns
+    pub warn: usize,
+    /// LOC above which the check fails
+    pub fail: usize,
+}
+
+/// Grade `loc` lines of `subject` against `budget`
+pub fn check_budget(label: String, subject: &str, loc: usize, budget: Budget) -> CheckResult {
+    if loc > budget.fail {
+        CheckResult::fail(
+            label,
+            format!("{} has {} Rust LOC (max {})", subject, loc, budget.fail),
+        )
+    } else if loc > budget.warn {
+        CheckResult::warn(
+            label,
+            format!(
+                "{} has {} Rust LOC (warning >{}, max {})",
+                subject, loc, budget.warn, budget.fail
+            ),
+        )
+    } else {
+        CheckResult::pass(label, format!("{} has {} Rust LOC", subject, loc))
+    }
+}
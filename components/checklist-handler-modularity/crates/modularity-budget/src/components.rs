@@ -0,0 +1,44 @@
+//! Crate and LOC totals per component (components/<name>/crates/*)
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::loc::CrateLoc;
+
+/// Crates and Rust LOC of one component
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ComponentTotals {
+    /// Crates under the component's crates/ directory
+    pub crates: usize,
+    /// Rust LOC across those crates
+    pub loc: usize,
+}
+
+/// Totals per component, keyed by the component's path relative to the
+/// project root (crates not laid out as <component>/crates/<crate> are left out)
+pub fn component_totals(crates: &[CrateLoc], root: &Path) -> BTreeMap<String, ComponentTotals> {
+    let mut totals: BTreeMap<String, ComponentTotals> = BTreeMap::new();
+    for krate in crates {
+        if let Some(component) = component_of(&krate.dir, root) {
+            let entry = totals.entry(component).or_default();
+            entry.crates += 1;
+            entry.loc += krate.loc;
+        }
+    }
+    totals
+}
+
+/// Component owning a crate directory: the parent of its `crates/` directory
+fn component_of(crate_dir: &Path, root: &Path) -> Option<String> {
+    let crates_dir = crate_dir.parent()?;
+    if crates_dir.file_name()? != "crates" {
+        return None;
+    }
+    let component = crates_dir.parent()?;
+    let relative = component.strip_prefix(root).unwrap_or(component);
+    if relative.as_os_str().is_empty() {
+        let name = component.canonicalize().ok()?.file_name()?.to_os_string();
+        return Some(name.to_string_lossy().into_owned());
+    }
+    Some(relative.display().to_string())
+}
@@ -0,0 +1,98 @@
+//! LOC budget handler implementation
+
+use anyhow::Result;
+use checklist_result::CheckResult;
+use handler_trait::{ProjectContext, ProjectHandler};
+
+use crate::budget::{Budget, check_budget};
+use crate::components::component_totals;
+use crate::loc::project_loc;
+use crate::workspaces::{check_crate_count, crates_by_workspace};
+
+/// Project handler comparing per-crate and total Rust LOC against the configured budgets
+pub struct LocBudgetHandler;
+
+impl ProjectHandler for LocBudgetHandler {
+    fn name(&self) -> &'static str {
+        "loc-budget"
+    }
+
+    fn check(&self, ctx: &ProjectContext) -> Result<Vec<CheckResult>> {
+        let budgets = &ctx.config.file().budgets;
+        let crate_budget = Budget {
+            warn: budgets.crate_loc_warn,
+            fail: budgets.crate_loc_fail,
+        };
+        let project_budget = Budget {
+            warn: budgets.project_loc_warn,
+            fail: budgets.project_loc_fail,
+        };
+        let crates = project_loc(ctx.cargo_tomls, ctx.config);
+        let mut results: Vec<CheckResult> = crates
+            .iter()
+            .map(|c| {
+                let label = format!("Crate LOC [{}]", c.name);
+                check_budget(label, &format!("Crate {}", c.name), c.loc, crate_budget)
+            })
+            .collect();
+        let total = crates.iter().map(|c| c.loc).sum();
+        let subject = format!("Project ({} crates)", crates.len());
+        let label = "Project LOC [project]".to_string();
+        results.push(check_budget(label, &subject, total, project_budget));
+        Ok(results)
+    }
+}
+
+/// Project handler counting crates per workspace, so independent workspaces
+/// in one repo (like components/) are each held to the limit
+pub struct CrateCountHandler;
+
+impl ProjectHandler for CrateCountHandler {
+    fn name(&self) -> &'static str {
+        "crate-count"
+    }
+
+    fn check(&self, ctx: &ProjectContext) -> Result<Vec<CheckResult>> {
+        let root = ctx.config.project_root();
+        let modularity = &ctx.config.file().modularity;
+        let groups = crates_by_workspace(ctx.cargo_tomls, root, &modularity.crate_count_exclude);
+        let limit = modularity.workspace_crates;
+        let mut results: Vec<CheckResult> = groups
+            .iter()
+            .map(|(workspace, count)| check_crate_count(workspace, *count, limit))
+            .collect();
+        let total: usize = groups.values().sum();
+        results.push(CheckResult::info(
+            "Project Crate Count [project]",
+            format!("{} crates across {} workspace(s)", total, groups.len()),
+        ));
+        Ok(results)
+    }
+}
+
+/// Project handler totaling Rust LOC per component for the
+/// components/<name>/crates/* layout (each component's crate count is already
+/// graded per workspace by [`CrateCountHandler`])
+pub struct ComponentBudgetHandler;
+
+impl ProjectHandler for ComponentBudgetHandler {
+    fn name(&self) -> &'static str {
+        "component-budget"
+    }
+
+    fn check(&self, ctx: &ProjectContext) -> Result<Vec<CheckResult>> {
+        let file = ctx.config.file();
+        let budget = Budget {
+            warn: file.budgets.component_loc_warn,
+            fail: file.budgets.component_loc_fail,
+        };
+        let crates = project_loc(ctx.cargo_tomls, ctx.config);
+        let mut results = Vec::new();
+        for (name, totals) in component_totals(&crates, ctx.config.project_root()) {
+            let label = format!("Component LOC [{}]", name);
+            let subject = format!("Component {} ({} crates)", name, totals.crates);
+            results.push(check_budget(label, &subject, totals.loc, budget));
+        }
+        Ok(results)
+    }
+}
@@ -0,0 +1,13 @@
+//! LOC budget checks for sw-checklist
+//!
+//! Totals Rust source lines per crate and across the project and compares
+//! them against the budgets in .sw-checklist.toml, counts crates per
+//! workspace, and aggregates both per component.
+
+mod budget;
+mod components;
+mod handler;
+mod loc;
+mod workspaces;
+
+pub use handler::{ComponentBudgetHandler, CrateCountHandler, LocBudgetHandler};
@@ -0,0 +1,47 @@
+//! Rust LOC totals per crate
+
+use checklist_config::Config;
+use discovery_cargo::is_workspace;
+use discovery_crate::extract_crate_name;
+use discovery_source::rust_sources;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Total Rust source lines for one crate
+#[derive(Debug, Clone)]
+pub struct CrateLoc {
+    /// Crate name from Cargo.toml
+    pub name: String,
+    /// Crate directory (containing Cargo.toml)
+    pub dir: PathBuf,
+    /// Lines across all non-generated .rs files under src/
+    pub loc: usize,
+}
+
+/// Count Rust LOC under src/ for every (non-workspace) crate
+pub fn project_loc(cargo_tomls: &[PathBuf], config: &Config) -> Vec<CrateLoc> {
+    cargo_tomls
+        .iter()
+        .filter_map(|path| {
+            let content = fs::read_to_string(path).ok()?;
+            if is_workspace(&content) {
+                return None;
+            }
+            let cargo = content.parse::<toml::Value>().ok()?;
+            let dir = path.parent()?.to_path_buf();
+            Some(CrateLoc {
+                name: extract_crate_name(&cargo).to_string(),
+                loc: src_loc(&dir.join("src"), config),
+                dir,
+            })
+        })
+        .collect()
+}
+
+fn src_loc(src_dir: &Path, config: &Config) -> usize {
+    rust_sources(src_dir, config)
+        .unwrap_or_default()
+        .iter()
+        .map(|source| source.content.lines().count())
+        .sum()
+}
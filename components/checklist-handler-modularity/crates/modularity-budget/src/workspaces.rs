@@ -0,0 +1,61 @@
+//! Crate counts per workspace
+
+use checklist_config::Limit;
+use checklist_result::CheckResult;
+use discovery_cargo::{group_workspaces, is_workspace};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Number of crates per owning workspace, keyed by the workspace's path
+/// relative to the project root (standalone crates count as their own workspace),
+/// leaving out crates under an `exclude` directory
+pub fn crates_by_workspace(
+    cargo_tomls: &[PathBuf],
+    root: &Path,
+    exclude: &[String],
+) -> BTreeMap<String, usize> {
+    group_workspaces(cargo_tomls, root)
+        .into_iter()
+        .map(|ws| {
+            let crates = ws
+                .cargo_tomls
+                .iter()
+                .filter(|path| !is_excluded(path, root, exclude))
+                .filter(|path| fs::read_to_string(path).is_ok_and(|c| !is_workspace(&c)))
+                .count();
+            (ws.name, crates)
+        })
+        .filter(|(_, crates)| *crates > 0)
+        .collect()
+}
+
+/// Whether the manifest sits under one of the `exclude` directories
+fn is_excluded(cargo_toml: &Path, root: &Path, exclude: &[String]) -> bool {
+    let dir = cargo_toml.parent().unwrap_or(cargo_toml);
+    let relative = dir.strip_prefix(root).unwrap_or(dir);
+    exclude
+        .iter()
+        .map(|e| e.trim_end_matches('/'))
+        .any(|e| relative.starts_with(e) || relative.components().any(|c| c.as_os_str() == e))
+}
+
+/// Grade a workspace's crate count against `limit`
+pub fn check_crate_count(workspace: &str, count: usize, limit: Limit) -> CheckResult {
+    let label = format!("Crate Count [{}]", workspace);
+    if count > limit.fail {
+        CheckResult::fail(
+            label,
+            format!("Workspace has {} crates (max {})", count, limit.fail),
+        )
+    } else if count > limit.warn {
+        let message = format!(
+            "Workspace has {} crates (warning at >{}, max {})",
+            count, limit.warn, limit.fail
+        );
+        CheckResult::warn(label, message)
+    } else {
+        let message = format!("Workspace has {} crates ({} or fewer)", count, limit.warn);
+        CheckResult::pass(label, message)
+    }
+}
@@ -0,0 +1,112 @@
+use checklist_config::{FileConfig, Limit};
+use checklist_result::{CheckResult, CheckStatus};
+use checklist_testkit::{TestCrate, assert_status, results_for};
+use handler_trait::{ProjectContext, ProjectHandler};
+use modularity_budget::{ComponentBudgetHandler, CrateCountHandler, LocBudgetHandler};
+
+const WORKSPACE: &str = "[workspace]\nmembers = [\"crates/*\"]\n";
+
+/// Run `handler` with `file` over the fixture's manifests at `manifests`
+fn check(
+    handler: &dyn ProjectHandler,
+    krate: &TestCrate,
+    file: FileConfig,
+    manifests: &[&str],
+) -> Vec<CheckResult> {
+    let cargo_tomls: Vec<_> = manifests.iter().map(|m| krate.path().join(m)).collect();
+    let config = krate.config(file);
+    handler
+        .check(&ProjectContext {
+            config: &config,
+            cargo_tomls: &cargo_tomls,
+        })
+        .unwrap()
+}
+
+/// `krate` with a package of `loc` source lines at `dir`
+fn with_package(krate: TestCrate, dir: &str, loc: usize) -> TestCrate {
+    let name = dir.rsplit('/').next().unwrap();
+    let manifest = format!("[package]\nname = \"{}\"\nversion = \"0.1.0\"\n", name);
+    krate
+        .file(&format!("{}/Cargo.toml", dir), manifest)
+        .unwrap()
+        .file(&format!("{}/src/lib.rs", dir), "// line\n".repeat(loc))
+        .unwrap()
+}
+
+fn workspace(crates: &[(&str, usize)]) -> TestCrate {
+    let root = TestCrate::with_manifest(WORKSPACE).unwrap();
+    crates
+        .iter()
+        .fold(root, |krate, (dir, loc)| with_package(krate, dir, *loc))
+}
+
+#[test]
+fn crate_and_project_loc_are_graded_against_the_budgets() {
+    let krate = workspace(&[("crates/a", 30), ("crates/b", 5)]);
+    let mut file = FileConfig::default();
+    file.budgets.crate_loc_warn = 10;
+    file.budgets.crate_loc_fail = 20;
+    file.budgets.project_loc_warn = 30;
+    file.budgets.project_loc_fail = 40;
+    let manifests = ["Cargo.toml", "crates/a/Cargo.toml", "crates/b/Cargo.toml"];
+    let results = check(&LocBudgetHandler, &krate, file, &manifests);
+    let crates = results_for(&results, "Crate LOC");
+    let statuses: Vec<CheckStatus> = crates.iter().map(|r| r.status).collect();
+    assert_eq!(statuses, [CheckStatus::Fail, CheckStatus::Pass]);
+    assert_status(&results, "Project LOC", CheckStatus::Warn);
+}
+
+#[test]
+fn crates_are_counted_per_workspace() {
+    let krate = workspace(&[("crates/a", 1), ("crates/b", 1), ("crates/xtask", 1)]);
+    let manifests = [
+        "Cargo.toml",
+        "crates/a/Cargo.toml",
+        "crates/b/Cargo.toml",
+        "crates/xtask/Cargo.toml",
+    ];
+    let mut file = FileConfig::default();
+    file.modularity.workspace_crates = Limit { warn: 1, fail: 2 };
+    let results = check(&CrateCountHandler, &krate, file.clone(), &manifests);
+    assert_status(&results, "Crate Count", CheckStatus::Fail);
+    assert_status(&results, "Project Crate Count", CheckStatus::Info);
+
+    file.modularity.crate_count_exclude = vec!["xtask".into()];
+    let results = check(&CrateCountHandler, &krate, file, &manifests);
+    assert_status(&results, "Crate Count", CheckStatus::Warn);
+}
+
+#[test]
+fn loc_is_totaled_per_component() {
+    let krate = [
+        ("components/small/crates/a", 5),
+        ("components/large/crates/b", 20),
+        ("components/large/crates/c", 20),
+    ]
+    .iter()
+    .fold(TestCrate::new("root").unwrap(), |krate, (dir, loc)| {
+        with_package(krate, dir, *loc)
+    });
+    let mut file = FileConfig::default();
+    file.budgets.component_loc_warn = 10;
+    file.budgets.component_loc_fail = 30;
+    let manifests = [
+        "Cargo.toml",
+        "components/small/crates/a/Cargo.toml",
+        "components/large/crates/b/Cargo.toml",
+        "components/large/crates/c/Cargo.toml",
+    ];
+    let results = check(&ComponentBudgetHandler, &krate, file, &manifests);
+    let found: Vec<(&str, CheckStatus)> = results
+        .iter()
+        .map(|r| (r.name.as_str(), r.status))
+        .collect();
+    assert_eq!(
+        found,
+        [
+            ("Component LOC [components/large]", CheckStatus::Fail),
+            ("Component LOC [components/small]", CheckStatus::Pass),
+        ]
+    );
+}
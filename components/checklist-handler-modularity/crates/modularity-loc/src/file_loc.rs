@@ -1,20 +1,16 @@
 //! File LOC checking
 
-use anyhow::Result;
+use checklist_config::Limit;
 use checklist_result::CheckResult;
-use std::fs;
-use std::path::Path;
-use walkdir::WalkDir;
+use discovery_source::RustSource;
 
-/// Check file LOC for all Rust files in src/
-pub fn check_file_locs(src_dir: &Path, crate_name: &str) -> Result<Vec<CheckResult>> {
+/// Check file LOC for all Rust source files against `limit`
+pub fn check_file_locs(sources: &[RustSource], crate_name: &str, limit: Limit) -> Vec<CheckResult> {
     let mut results = Vec::new();
     let mut any_issues = false;
 
-    for entry in walk_rs_files(src_dir) {
-        let path = entry.path();
-        let content = fs::read_to_string(path)?;
-        if let Some(result) = check_file(path, &content, crate_name) {
+    for source in sources {
+        if let Some(result) = check_file_loc(source, crate_name, limit) {
             any_issues = true;
             results.push(result);
         }
@@ -23,34 +19,23 @@ pub fn check_file_locs(src_dir: &Path, crate_name: &str) -> Result<Vec<CheckResu
     if !any_issues {
         results.push(CheckResult::pass(
             format!("File LOC [{}]", crate_name),
-            "All files are 350 or fewer lines",
+            format!("All files are {} or fewer lines", limit.warn),
         ));
     }
-    Ok(results)
+    results
 }
 
-fn walk_rs_files(dir: &Path) -> impl Iterator<Item = walkdir::DirEntry> {
-    WalkDir::new(dir)
-        .into_iter()
-        .filter_map(|e| e.ok())
-        .filter(|e| e.path().extension().and_then(|s| s.to_str()) == Some("rs"))
-}
-
-fn check_file(path: &Path, content: &str, crate_name: &str) -> Option<CheckResult> {
-    let file_name = path.file_name().unwrap().to_string_lossy();
-    let loc = content.lines().count();
-
-    if loc > 500 {
-        Some(CheckResult::fail(
-            format!("File LOC [{}]", crate_name),
-            format!("{} has {} lines (max 500)", file_name, loc),
-        ))
-    } else if loc > 350 {
-        Some(CheckResult::warn(
-            format!("File LOC [{}]", crate_name),
-            format!("{} has {} lines (warning >350)", file_name, loc),
-        ))
+/// The finding for one file over `limit`, located at its first line
+pub fn check_file_loc(source: &RustSource, crate_name: &str, limit: Limit) -> Option<CheckResult> {
+    let label = format!("File LOC [{}]", crate_name);
+    let loc = source.content.lines().count();
+    let subject = format!("{} has {} lines", source.file_name(), loc);
+    let result = if loc > limit.fail {
+        CheckResult::fail(label, format!("{} (max {})", subject, limit.fail))
+    } else if loc > limit.warn {
+        CheckResult::warn(label, format!("{} (warning >{})", subject, limit.warn))
     } else {
-        None
-    }
+        return None;
+    };
+    Some(result.at(&source.path, 1))
 }
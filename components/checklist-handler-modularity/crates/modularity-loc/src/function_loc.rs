@@ -1,55 +1,66 @@
 //! Function LOC checking
 
-use anyhow::Result;
+use checklist_config::Limit;
 use checklist_result::CheckResult;
-use std::fs;
-use std::path::Path;
-use walkdir::WalkDir;
+use discovery_source::RustSource;
 
 use crate::parse::find_functions;
 
-/// Check function LOC for all Rust files in src/
-pub fn check_function_locs(src_dir: &Path, crate_name: &str) -> Result<Vec<CheckResult>> {
-    let mut results = Vec::new();
-    let mut any_issues = false;
+/// Check function LOC for all Rust source files against `limit`
+pub fn check_function_locs(
+    sources: &[RustSource],
+    crate_name: &str,
+    limit: Limit,
+) -> Vec<CheckResult> {
+    let mut results: Vec<CheckResult> = sources
+        .iter()
+        .flat_map(|source| check_source_function_locs(source, crate_name, limit))
+        .collect();
 
-    for entry in walk_rs_files(src_dir) {
-        let content = fs::read_to_string(entry.path())?;
-        let file_name = entry.path().file_name().unwrap().to_string_lossy();
-        for (fn_name, loc) in find_functions(&content) {
-            if let Some(r) = check_fn_loc(crate_name, &file_name, &fn_name, loc) {
-                any_issues = true;
-                results.push(r);
-            }
-        }
-    }
-
-    if !any_issues {
+    if results.is_empty() {
         results.push(CheckResult::pass(
             format!("Function LOC [{}]", crate_name),
-            "All functions are under 25 lines",
+            format!("All functions are {} or fewer lines", limit.warn),
         ));
     }
-    Ok(results)
+    results
 }
 
-fn walk_rs_files(dir: &Path) -> impl Iterator<Item = walkdir::DirEntry> {
-    WalkDir::new(dir)
+/// Findings for the functions in one file over `limit`, each located at
+/// the function's first line
+pub fn check_source_function_locs(
+    source: &RustSource,
+    crate_name: &str,
+    limit: Limit,
+) -> Vec<CheckResult> {
+    let file_name = source.file_name();
+    find_functions(&source.content)
         .into_iter()
-        .filter_map(|e| e.ok())
-        .filter(|e| e.path().extension().and_then(|s| s.to_str()) == Some("rs"))
+        .filter_map(|span| {
+            let result = check_fn_loc(crate_name, &file_name, &span.name, span.loc, limit)?;
+            Some(result.at(&source.path, span.line + 1))
+        })
+        .collect()
 }
 
-fn check_fn_loc(crate_name: &str, file: &str, fn_name: &str, loc: usize) -> Option<CheckResult> {
-    if loc > 50 {
+fn check_fn_loc(
+    crate_name: &str,
+    file: &str,
+    fn_name: &str,
+    loc: usize,
+    limit: Limit,
+) -> Option<CheckResult> {
+    let label = format!("Function LOC [{}]", crate_name);
+    let subject = format!("'{}' in {} has {} lines", fn_name, file, loc);
+    if loc > limit.fail {
         Some(CheckResult::fail(
-            format!("Function LOC [{}]", crate_name),
-            format!("'{}' in {} has {} lines (max 50)", fn_name, file, loc),
+            label,
+            format!("{} (max {})", subject, limit.fail),
         ))
-    } else if loc > 25 {
+    } else if loc > limit.warn {
         Some(CheckResult::warn(
-            format!("Function LOC [{}]", crate_name),
-            format!("'{}' in {} has {} lines (warning >25)", fn_name, file, loc),
+            label,
+            format!("{} (warning >{})", subject, limit.warn),
         ))
     } else {
         None
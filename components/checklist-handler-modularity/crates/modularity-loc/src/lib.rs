@@ -4,5 +4,6 @@ mod file_loc;
 mod function_loc;
 mod parse;
 
-pub use file_loc::check_file_locs;
-pub use function_loc::check_function_locs;
+pub use file_loc::{check_file_loc, check_file_locs};
+pub use function_loc::{check_function_locs, check_source_function_locs};
+pub use parse::{FunctionSpan, find_functions};
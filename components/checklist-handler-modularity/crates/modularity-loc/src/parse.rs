@@ -1,7 +1,18 @@
 //! Function parsing utilities
 
-/// Find all functions and their line counts in source code
-pub fn find_functions(content: &str) -> Vec<(String, usize)> {
+/// A function definition found in source code
+#[derive(Debug, Clone)]
+pub struct FunctionSpan {
+    /// Function name
+    pub name: String,
+    /// Zero-based line of the `fn` keyword
+    pub line: usize,
+    /// Lines from definition to closing brace
+    pub loc: usize,
+}
+
+/// Find all functions, their start lines, and their line counts in source code
+pub fn find_functions(content: &str) -> Vec<FunctionSpan> {
     let lines: Vec<&str> = content.lines().collect();
     let mut results = Vec::new();
     let mut i = 0;
@@ -10,7 +21,7 @@ pub fn find_functions(content: &str) -> Vec<(String, usize)> {
         if is_fn_def(lines[i].trim()) {
             let name = extract_fn_name(lines[i].trim());
             if let Some(loc) = count_fn_lines(&lines, i) {
-                results.push((name, loc));
+                results.push(FunctionSpan { name, line: i, loc });
                 i += loc;
                 continue;
             }
@@ -0,0 +1,50 @@
+//! Exported symbol checking for plugin crates
+
+use checklist_config::Config;
+use checklist_result::CheckResult;
+use discovery_source::rust_sources;
+use std::path::Path;
+
+/// Attributes that export the item below them under its own name
+const NO_MANGLE: &[&str] = &["#[no_mangle]", "#[unsafe(no_mangle)]"];
+
+/// Check that the plugin exports C ABI entry points the host can load
+pub fn check_plugin_exports(crate_dir: &Path, crate_name: &str, config: &Config) -> CheckResult {
+    let label = format!("Plugin Exports [{}]", crate_name);
+    let count: usize = rust_sources(&crate_dir.join("src"), config)
+        .unwrap_or_default()
+        .iter()
+        .map(|source| count_exports(&source.content))
+        .sum();
+    if count > 0 {
+        CheckResult::pass(label, format!("Found {} exported C ABI symbol(s)", count))
+    } else {
+        CheckResult::warn(
+            label,
+            "No #[no_mangle] or extern \"C\" exports found for the host to load",
+        )
+    }
+}
+
+/// Exported items in one file: each `extern "C" fn` and each other
+/// `#[no_mangle]` item, counted once even when both apply
+fn count_exports(content: &str) -> usize {
+    let mut count = 0;
+    let mut no_mangle = false;
+    for line in content.lines().map(str::trim) {
+        if line.contains("extern \"C\" fn") {
+            count += 1;
+            no_mangle = false;
+        } else if NO_MANGLE.iter().any(|attr| line.starts_with(attr)) {
+            no_mangle = true;
+        } else if no_mangle
+            && !line.is_empty()
+            && !line.starts_with("#[")
+            && !line.starts_with("//")
+        {
+            count += 1;
+            no_mangle = false;
+        }
+    }
+    count
+}
@@ -0,0 +1,34 @@
+//! Plugin handler implementation
+
+use anyhow::Result;
+use checklist_result::CheckResult;
+use discovery_crate::CrateType;
+use handler_trait::{CheckContext, Handler};
+
+use crate::exports::check_plugin_exports;
+
+/// Handler for host plugin crates (cdylib without wasm-bindgen)
+pub struct PluginHandler;
+
+impl Handler for PluginHandler {
+    fn name(&self) -> &'static str {
+        "plugin"
+    }
+
+    fn handles(&self, crate_type: CrateType) -> bool {
+        crate_type == CrateType::Plugin
+    }
+
+    fn check(&self, ctx: &CheckContext) -> Result<Vec<CheckResult>> {
+        Ok(vec![
+            CheckResult::info(
+                format!("Plugin [{}]", ctx.crate_name),
+                format!(
+                    "{} is a host plugin (cdylib without wasm-bindgen)",
+                    ctx.crate_name
+                ),
+            ),
+            check_plugin_exports(ctx.crate_dir, ctx.crate_name, ctx.config),
+        ])
+    }
+}
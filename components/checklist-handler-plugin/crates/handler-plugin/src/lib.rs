@@ -0,0 +1,6 @@
+//! Host plugin (cdylib) check handler for sw-checklist
+
+mod exports;
+mod handler;
+
+pub use handler::PluginHandler;
@@ -0,0 +1,38 @@
+use checklist_result::CheckStatus;
+use checklist_testkit::{TestCrate, assert_status};
+use handler_plugin::PluginHandler;
+
+const CDYLIB: &str =
+    "[package]\nname = \"hook\"\nversion = \"0.1.0\"\n\n[lib]\ncrate-type = [\"cdylib\"]\n";
+
+fn plugin(lib: &str) -> TestCrate {
+    TestCrate::with_manifest(CDYLIB)
+        .unwrap()
+        .file("src/lib.rs", lib)
+        .unwrap()
+}
+
+#[test]
+fn only_cdylib_crates_are_plugins() {
+    let krate = TestCrate::new("demo")
+        .unwrap()
+        .file("src/lib.rs", "")
+        .unwrap();
+    assert!(krate.check(&PluginHandler).unwrap().is_empty());
+}
+
+#[test]
+fn plugins_export_c_abi_symbols() {
+    let lib = "#[unsafe(no_mangle)]\n/// Entry point\npub static VERSION: u32 = 1;\n\npub extern \"C\" fn init() {}\n";
+    let results = plugin(lib).check(&PluginHandler).unwrap();
+    assert_status(&results, "Plugin", CheckStatus::Info);
+    assert_status(&results, "Plugin Exports", CheckStatus::Pass);
+    assert!(
+        results[1].message.contains("2 exported"),
+        "{}",
+        results[1].message
+    );
+
+    let results = plugin("pub fn init() {}\n").check(&PluginHandler).unwrap();
+    assert_status(&results, "Plugin Exports", CheckStatus::Warn);
+}
@@ -0,0 +1,88 @@
+//! Ratchet handler implementation
+
+use anyhow::Result;
+use checklist_result::{CheckResult, CheckStatus};
+use handler_trait::{ProjectContext, ProjectHandler};
+
+use crate::metrics::{CrateMetrics, project_metrics};
+use crate::store::{RATCHET_FILE_NAME, load_ratchet};
+
+/// Checks whose fixed thresholds are replaced by the ratchet in ratchet mode
+const RATCHETED_CHECKS: &[&str] = &[
+    "Function LOC",
+    "File LOC",
+    "Module Function Count",
+    "Crate Module Count",
+];
+
+/// Project handler comparing current metrics against the ratchet file
+pub struct RatchetHandler;
+
+impl ProjectHandler for RatchetHandler {
+    fn name(&self) -> &'static str {
+        "ratchet"
+    }
+
+    fn check(&self, ctx: &ProjectContext) -> Result<Vec<CheckResult>> {
+        if !ctx.config.ratchet() {
+            return Ok(Vec::new());
+        }
+        let Some(recorded) = load_ratchet(ctx.config.project_root())? else {
+            return Ok(vec![CheckResult::fail(
+                "Ratchet [project]",
+                format!(
+                    "No {} found; run `sw-checklist ratchet` to record metrics",
+                    RATCHET_FILE_NAME
+                ),
+            )]);
+        };
+        Ok(project_metrics(ctx.cargo_tomls, ctx.config)
+            .iter()
+            .map(|(name, current)| match recorded.crates.get(name) {
+                Some(before) => compare(name, before, current),
+                None => CheckResult::warn(
+                    format!("Ratchet [{}]", name),
+                    "No recorded metrics; run `sw-checklist ratchet` to record",
+                ),
+            })
+            .collect())
+    }
+}
+
+fn compare(crate_name: &str, before: &CrateMetrics, now: &CrateMetrics) -> CheckResult {
+    let label = format!("Ratchet [{}]", crate_name);
+    let pairs: Vec<_> = before.named().into_iter().zip(now.named()).collect();
+    let regressions: Vec<String> = pairs
+        .iter()
+        .filter(|((_, before), (_, now))| now > before)
+        .map(|((metric, before), (_, now))| format!("{} rose from {} to {}", metric, before, now))
+        .collect();
+    if !regressions.is_empty() {
+        CheckResult::fail(label, regressions.join("; "))
+    } else if pairs.iter().any(|((_, before), (_, now))| now < before) {
+        CheckResult::pass(
+            label,
+            "Metrics improved; run `sw-checklist ratchet` to lock in",
+        )
+    } else {
+        CheckResult::pass(label, "No regressions from recorded metrics")
+    }
+}
+
+/// In ratchet mode, downgrade fixed-threshold modularity failures to warnings
+pub fn relax_ratcheted(results: Vec<CheckResult>) -> Vec<CheckResult> {
+    results
+        .into_iter()
+        .map(|r| {
+            if r.status == CheckStatus::Fail && RATCHETED_CHECKS.contains(&r.check_title()) {
+                CheckResult {
+                    status: CheckStatus::Warn,
+                    message: format!("{} (ratchet mode)", r.message),
+                    ..r
+                }
+            } else {
+                r
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,12 @@
+//! Ratchet mode for sw-checklist
+//!
+//! Records per-crate modularity metrics in .sw-checklist-ratchet.toml and,
+//! in ratchet mode, fails only when a metric regresses from its recorded value.
+
+mod handler;
+mod metrics;
+mod store;
+
+pub use handler::{RatchetHandler, relax_ratcheted};
+pub use metrics::CrateMetrics;
+pub use store::{RATCHET_FILE_NAME, record_ratchet};
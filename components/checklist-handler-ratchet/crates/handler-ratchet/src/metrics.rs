@@ -0,0 +1,66 @@
+//! Per-crate modularity metrics
+
+use checklist_config::Config;
+use discovery_cargo::is_workspace;
+use discovery_crate::extract_crate_name;
+use discovery_source::rust_sources;
+use modularity_loc::find_functions;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Modularity metrics recorded for one crate
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CrateMetrics {
+    /// Longest function, in lines
+    pub max_function_loc: usize,
+    /// Longest file, in lines
+    pub max_file_loc: usize,
+    /// Most functions in a single module
+    pub max_module_functions: usize,
+    /// Number of modules (.rs files)
+    pub module_count: usize,
+}
+
+impl CrateMetrics {
+    /// Metric values with display names
+    pub fn named(&self) -> [(&'static str, usize); 4] {
+        [
+            ("max function LOC", self.max_function_loc),
+            ("max file LOC", self.max_file_loc),
+            ("max module functions", self.max_module_functions),
+            ("module count", self.module_count),
+        ]
+    }
+}
+
+/// Compute metrics for every (non-workspace) crate, keyed by crate name
+pub fn project_metrics(cargo_tomls: &[PathBuf], config: &Config) -> BTreeMap<String, CrateMetrics> {
+    cargo_tomls
+        .iter()
+        .filter_map(|path| {
+            let content = fs::read_to_string(path).ok()?;
+            if is_workspace(&content) {
+                return None;
+            }
+            let cargo = content.parse::<toml::Value>().ok()?;
+            let name = extract_crate_name(&cargo).to_string();
+            Some((name, crate_metrics(&path.parent()?.join("src"), config)))
+        })
+        .collect()
+}
+
+fn crate_metrics(src_dir: &Path, config: &Config) -> CrateMetrics {
+    let mut metrics = CrateMetrics::default();
+    for source in rust_sources(src_dir, config).unwrap_or_default() {
+        let content = source.content;
+        let functions = find_functions(&content);
+        let longest = functions.iter().map(|f| f.loc).max().unwrap_or(0);
+        metrics.max_function_loc = metrics.max_function_loc.max(longest);
+        metrics.max_file_loc = metrics.max_file_loc.max(content.lines().count());
+        metrics.max_module_functions = metrics.max_module_functions.max(functions.len());
+        metrics.module_count += 1;
+    }
+    metrics
+}
@@ -0,0 +1,50 @@
+//! Ratchet file reading and recording
+
+use anyhow::{Context, Result};
+use checklist_config::Config;
+use discovery_cargo::find_cargo_tomls;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use crate::metrics::{CrateMetrics, project_metrics};
+
+/// Ratchet file name, at the project root
+pub const RATCHET_FILE_NAME: &str = ".sw-checklist-ratchet.toml";
+
+/// Contents of the ratchet file
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct RatchetFile {
+    #[serde(default)]
+    pub crates: BTreeMap<String, CrateMetrics>,
+}
+
+/// Load the ratchet file, or None if the project has not recorded one
+pub fn load_ratchet(project_root: &Path) -> Result<Option<RatchetFile>> {
+    let path = project_root.join(RATCHET_FILE_NAME);
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content =
+        fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let file =
+        toml::from_str(&content).with_context(|| format!("Failed to parse {}", path.display()))?;
+    Ok(Some(file))
+}
+
+/// Record the project's current metrics as the new ratchet values, returning
+/// how many crates were recorded
+pub fn record_ratchet(config: &Config) -> Result<usize> {
+    let project_root = config.project_root();
+    let file = RatchetFile {
+        crates: project_metrics(
+            &find_cargo_tomls(project_root, &config.file().exclude),
+            config,
+        ),
+    };
+    let path = project_root.join(RATCHET_FILE_NAME);
+    fs::write(&path, toml::to_string_pretty(&file)?)
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(file.crates.len())
+}
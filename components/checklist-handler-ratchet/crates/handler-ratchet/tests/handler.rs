@@ -0,0 +1,77 @@
+use checklist_config::{Config, ConfigBuilder, FileConfig};
+use checklist_result::{CheckResult, CheckStatus};
+use checklist_testkit::{TestCrate, assert_status};
+use handler_ratchet::{RatchetHandler, record_ratchet, relax_ratcheted};
+
+fn ratchet_config(krate: &TestCrate) -> Config {
+    ConfigBuilder::new()
+        .project_path(krate.path().to_path_buf())
+        .file_config(FileConfig::default())
+        .ratchet(true)
+        .build()
+}
+
+fn demo(functions: usize) -> TestCrate {
+    let lib = (0..functions)
+        .map(|i| format!("fn f{}() {{}}\n", i))
+        .collect::<String>();
+    TestCrate::new("demo")
+        .unwrap()
+        .file("src/lib.rs", lib)
+        .unwrap()
+}
+
+fn check(krate: &TestCrate) -> Vec<CheckResult> {
+    krate
+        .run_project(&RatchetHandler, &ratchet_config(krate))
+        .unwrap()
+}
+
+#[test]
+fn the_ratchet_only_runs_in_ratchet_mode() {
+    let krate = demo(1);
+    let config = krate.config(FileConfig::default());
+    assert!(
+        krate
+            .run_project(&RatchetHandler, &config)
+            .unwrap()
+            .is_empty()
+    );
+    assert_status(&check(&krate), "Ratchet", CheckStatus::Fail);
+}
+
+#[test]
+fn only_regressions_from_the_recorded_metrics_fail() {
+    let krate = demo(3);
+    assert_eq!(record_ratchet(&ratchet_config(&krate)).unwrap(), 1);
+    assert_status(&check(&krate), "Ratchet", CheckStatus::Pass);
+
+    let krate = krate.file("src/lib.rs", "fn f() {}\n").unwrap();
+    let results = check(&krate);
+    assert_status(&results, "Ratchet", CheckStatus::Pass);
+    assert!(
+        results[0].message.contains("improved"),
+        "{}",
+        results[0].message
+    );
+
+    let krate = krate.file("src/extra.rs", "").unwrap();
+    let results = check(&krate);
+    assert_status(&results, "Ratchet", CheckStatus::Fail);
+    assert!(
+        results[0].message.contains("module count rose from 1 to 2"),
+        "{}",
+        results[0].message
+    );
+}
+
+#[test]
+fn fixed_threshold_failures_relax_to_warnings() {
+    let results = relax_ratcheted(vec![
+        CheckResult::fail("Function LOC [demo]", "too long"),
+        CheckResult::fail("Rust Edition [demo]", "2021"),
+    ]);
+    assert_eq!(results[0].status, CheckStatus::Warn);
+    assert_eq!(results[0].message, "too long (ratchet mode)");
+    assert_eq!(results[1].status, CheckStatus::Fail);
+}
@@ -0,0 +1,45 @@
+//! tauri.conf.json loading and validation
+
+use checklist_result::CheckResult;
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+
+/// Tauri configuration file, in the src-tauri/ crate directory
+pub const CONF_FILE_NAME: &str = "tauri.conf.json";
+
+/// Placeholder identifier from the Tauri templates, rejected when bundling
+const DEFAULT_IDENTIFIER: &str = "com.tauri.dev";
+
+/// Read and parse tauri.conf.json
+pub fn load_conf(crate_dir: &Path) -> Result<Value, String> {
+    let content = fs::read_to_string(crate_dir.join(CONF_FILE_NAME))
+        .map_err(|_| format!("No {} found", CONF_FILE_NAME))?;
+    serde_json::from_str(&content).map_err(|e| format!("Invalid JSON: {}", e))
+}
+
+/// Look up a setting by its Tauri v2 pointer, falling back to the v1 location
+pub fn setting<'a>(conf: &'a Value, v2: &str, v1: &str) -> Option<&'a Value> {
+    conf.pointer(v2).or_else(|| conf.pointer(v1))
+}
+
+/// Check the bundle identifier and product name are set
+pub fn check_conf(conf: &Value, crate_name: &str) -> CheckResult {
+    let label = format!("{} [{}]", CONF_FILE_NAME, crate_name);
+    let identifier = setting(conf, "/identifier", "/tauri/bundle/identifier")
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+    let product = setting(conf, "/productName", "/package/productName").and_then(Value::as_str);
+    match (identifier, product) {
+        ("", _) => CheckResult::fail(label, "No bundle identifier set"),
+        (DEFAULT_IDENTIFIER, _) => CheckResult::fail(
+            label,
+            format!(
+                "Bundle identifier is the template default {}",
+                DEFAULT_IDENTIFIER
+            ),
+        ),
+        (_, None) => CheckResult::warn(label, "No productName set"),
+        (id, Some(name)) => CheckResult::pass(label, format!("{} ({})", name, id)),
+    }
+}
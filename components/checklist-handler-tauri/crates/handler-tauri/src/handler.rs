@@ -0,0 +1,41 @@
+//! Tauri handler implementation
+
+use anyhow::Result;
+use checklist_result::CheckResult;
+use discovery_crate::CrateType;
+use handler_trait::{CheckContext, Handler};
+use handler_wasm::check_web_ui_metadata;
+
+use crate::conf::{CONF_FILE_NAME, check_conf, load_conf};
+use crate::icons::check_icons;
+
+/// Handler for Tauri app backends (`src-tauri/`) and their frontends
+pub struct TauriHandler;
+
+impl Handler for TauriHandler {
+    fn name(&self) -> &'static str {
+        "tauri"
+    }
+
+    fn handles(&self, crate_type: CrateType) -> bool {
+        crate_type == CrateType::Tauri
+    }
+
+    fn check(&self, ctx: &CheckContext) -> Result<Vec<CheckResult>> {
+        let mut results = match load_conf(ctx.crate_dir) {
+            Ok(conf) => vec![
+                check_conf(&conf, ctx.crate_name),
+                check_icons(&conf, ctx.crate_dir, ctx.crate_name),
+            ],
+            Err(e) => vec![CheckResult::fail(
+                format!("{} [{}]", CONF_FILE_NAME, ctx.crate_name),
+                e,
+            )],
+        };
+        // The frontend lives next to src-tauri/ and must show the footer metadata
+        if let Some(frontend_dir) = ctx.crate_dir.parent() {
+            results.extend(check_web_ui_metadata(frontend_dir, ctx.crate_name));
+        }
+        Ok(results)
+    }
+}
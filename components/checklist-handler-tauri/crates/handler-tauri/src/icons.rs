@@ -0,0 +1,31 @@
+//! Bundle icon asset checks
+
+use checklist_result::CheckResult;
+use serde_json::Value;
+use std::path::Path;
+
+use crate::conf::setting;
+
+/// Check every bundle icon listed in tauri.conf.json exists
+pub fn check_icons(conf: &Value, crate_dir: &Path, crate_name: &str) -> CheckResult {
+    let label = format!("Tauri Icons [{}]", crate_name);
+    let icons: Vec<&str> = setting(conf, "/bundle/icon", "/tauri/bundle/icon")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .collect();
+    if icons.is_empty() {
+        return CheckResult::fail(label, "No bundle icons listed (run `cargo tauri icon`)");
+    }
+    let missing: Vec<&str> = icons
+        .iter()
+        .copied()
+        .filter(|icon| !crate_dir.join(icon).exists())
+        .collect();
+    if missing.is_empty() {
+        CheckResult::pass(label, format!("Found {} bundle icon(s)", icons.len()))
+    } else {
+        CheckResult::fail(label, format!("Missing icon files: {}", missing.join(", ")))
+    }
+}
@@ -0,0 +1,7 @@
+//! Tauri desktop app check handler for sw-checklist
+
+mod conf;
+mod handler;
+mod icons;
+
+pub use handler::TauriHandler;
@@ -0,0 +1,44 @@
+use checklist_result::CheckStatus;
+use checklist_testkit::{TestCrate, assert_status};
+use handler_tauri::TauriHandler;
+
+const TAURI: &str =
+    "[package]\nname = \"app\"\nversion = \"0.1.0\"\n\n[dependencies]\ntauri = \"2\"\n";
+
+fn app(conf: &str) -> TestCrate {
+    TestCrate::with_manifest(TAURI)
+        .unwrap()
+        .file("tauri.conf.json", conf)
+        .unwrap()
+        .file("icons/icon.png", "")
+        .unwrap()
+}
+
+#[test]
+fn a_complete_v2_config_passes() {
+    let conf = r#"{"identifier": "com.example.app", "productName": "App",
+        "bundle": {"icon": ["icons/icon.png"]}}"#;
+    let results = app(conf).check(&TauriHandler).unwrap();
+    assert_status(&results, "tauri.conf.json", CheckStatus::Pass);
+    assert_status(&results, "Tauri Icons", CheckStatus::Pass);
+}
+
+#[test]
+fn v1_settings_are_read_from_their_old_location() {
+    let conf = r#"{"package": {"productName": "App"},
+        "tauri": {"bundle": {"identifier": "com.tauri.dev", "icon": ["icons/missing.png"]}}}"#;
+    let results = app(conf).check(&TauriHandler).unwrap();
+    assert_status(&results, "tauri.conf.json", CheckStatus::Fail);
+    assert_status(&results, "Tauri Icons", CheckStatus::Fail);
+}
+
+#[test]
+fn invalid_config_fails() {
+    let results = app("{").check(&TauriHandler).unwrap();
+    assert_status(&results, "tauri.conf.json", CheckStatus::Fail);
+    assert!(
+        results[0].message.starts_with("Invalid JSON"),
+        "{}",
+        results[0].message
+    );
+}
@@ -0,0 +1,43 @@
+//! Assertions on handler results
+
+use checklist_result::{CheckResult, CheckStatus, slugify};
+
+/// Results for one check, by ID or title (e.g. "function-loc" or "Function LOC")
+pub fn results_for<'a>(results: &'a [CheckResult], check: &str) -> Vec<&'a CheckResult> {
+    let id = slugify(check);
+    results.iter().filter(|r| r.check_id() == id).collect()
+}
+
+/// Panic unless some result for `check` has `status`, listing all results
+pub fn assert_status(results: &[CheckResult], check: &str, status: CheckStatus) {
+    if !results_for(results, check)
+        .iter()
+        .any(|r| r.status == status)
+    {
+        panic!(
+            "expected a {:?} result for '{}', got:\n{}",
+            status,
+            check,
+            listing(results)
+        );
+    }
+}
+
+/// Panic if any result is a failure or warning, listing them
+pub fn assert_no_issues(results: &[CheckResult]) {
+    let issues: Vec<CheckResult> = results
+        .iter()
+        .filter(|r| matches!(r.status, CheckStatus::Fail | CheckStatus::Warn))
+        .cloned()
+        .collect();
+    if !issues.is_empty() {
+        panic!("expected no issues, got:\n{}", listing(&issues));
+    }
+}
+
+fn listing(results: &[CheckResult]) -> String {
+    results
+        .iter()
+        .map(|r| format!("  {:?} {}: {}\n", r.status, r.name, r.message))
+        .collect()
+}
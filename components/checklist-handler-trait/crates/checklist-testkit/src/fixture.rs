@@ -0,0 +1,63 @@
+//! Temporary crate fixtures
+
+use anyhow::{Context, Result};
+use checklist_tempdir::TempWorkspace;
+use discovery_crate::extract_crate_name;
+use std::fs;
+use std::path::Path;
+
+/// A crate in its own temp directory, removed on drop
+#[derive(Debug)]
+pub struct TestCrate {
+    pub(crate) workspace: TempWorkspace,
+    pub(crate) cargo_toml: String,
+    pub(crate) name: String,
+}
+
+impl TestCrate {
+    /// A crate named `name` with a minimal edition 2024 Cargo.toml
+    pub fn new(name: &str) -> Result<Self> {
+        Self::with_manifest(&format!(
+            "[package]\nname = \"{}\"\nversion = \"0.1.0\"\nedition = \"2024\"\n",
+            name
+        ))
+    }
+
+    /// A crate with `contents` as its Cargo.toml (dependencies, `[[bin]]`
+    /// targets, a `[workspace]`, ...)
+    pub fn with_manifest(contents: &str) -> Result<Self> {
+        Self {
+            workspace: TempWorkspace::new("testkit")?,
+            cargo_toml: String::new(),
+            name: String::new(),
+        }
+        .manifest(contents)
+    }
+
+    /// Replace Cargo.toml (e.g. to add dependencies or `[[bin]]` targets)
+    pub fn manifest(mut self, contents: &str) -> Result<Self> {
+        let value = contents
+            .parse::<toml::Value>()
+            .unwrap_or(toml::Value::Boolean(false));
+        self.name = extract_crate_name(&value).to_string();
+        self.cargo_toml = contents.to_string();
+        self.file("Cargo.toml", contents)
+    }
+
+    /// Write a file relative to the crate root, creating parent directories
+    /// (`src/main.rs`, `index.html`, `assets/favicon.ico`, ...)
+    pub fn file(self, path: &str, contents: impl AsRef<[u8]>) -> Result<Self> {
+        let full = self.path().join(path);
+        if let Some(parent) = full.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&full, contents)
+            .with_context(|| format!("Failed to write {}", full.display()))?;
+        Ok(self)
+    }
+
+    /// Root directory of the crate
+    pub fn path(&self) -> &Path {
+        self.workspace.path()
+    }
+}
@@ -0,0 +1,17 @@
+//! Test harness for sw-checklist handlers
+//!
+//! [`TestCrate`] builds a throwaway crate (Cargo.toml, sources, web assets)
+//! in a temp directory and runs a [`Handler`](handler_trait::Handler) or
+//! [`ProjectHandler`](handler_trait::ProjectHandler) against it the way the
+//! runner would; the `assert_*` helpers check the results with readable
+//! failure messages. Built-in and third-party handlers share this harness;
+//! [`sample_results`] is a fixed run for formatter snapshot tests.
+
+mod assert;
+mod fixture;
+mod run;
+mod samples;
+
+pub use assert::{assert_no_issues, assert_status, results_for};
+pub use fixture::TestCrate;
+pub use samples::sample_results;
@@ -0,0 +1,63 @@
+//! Running handlers against a fixture
+
+use anyhow::Result;
+use checklist_config::{Config, ConfigBuilder, FileConfig};
+use checklist_result::CheckResult;
+use discovery_crate::detect_crate_type;
+use handler_trait::{CheckContext, Handler, ProjectContext, ProjectHandler};
+
+use crate::fixture::TestCrate;
+
+impl TestCrate {
+    /// Config rooted at the crate with `file` as its .sw-checklist.toml settings
+    pub fn config(&self, file: FileConfig) -> Config {
+        ConfigBuilder::new()
+            .project_path(self.path().to_path_buf())
+            .file_config(file)
+            .build()
+    }
+
+    /// Run `handler` with the default config, as [`TestCrate::run`] does
+    pub fn check(&self, handler: &dyn Handler) -> Result<Vec<CheckResult>> {
+        self.run(handler, &self.config(FileConfig::default()))
+    }
+
+    /// Run a project-level `handler` with the default config, as
+    /// [`TestCrate::run_project`] does
+    pub fn check_project(&self, handler: &dyn ProjectHandler) -> Result<Vec<CheckResult>> {
+        self.run_project(handler, &self.config(FileConfig::default()))
+    }
+
+    /// Run `handler` with the detected crate type, as the runner would
+    /// (an empty result when the handler does not handle that type)
+    pub fn run(&self, handler: &dyn Handler, config: &Config) -> Result<Vec<CheckResult>> {
+        let crate_type = detect_crate_type(&self.cargo_toml, self.path());
+        if !handler.handles(crate_type) {
+            return Ok(Vec::new());
+        }
+        let ctx = CheckContext {
+            config,
+            crate_dir: self.path(),
+            crate_name: &self.name,
+            crate_type,
+            cargo_toml: &self.cargo_toml,
+        };
+        match handler.skip_reason(&ctx) {
+            Some(_) => Ok(Vec::new()),
+            None => handler.check(&ctx),
+        }
+    }
+
+    /// Run a project-level `handler` with this crate as the whole project
+    pub fn run_project(
+        &self,
+        handler: &dyn ProjectHandler,
+        config: &Config,
+    ) -> Result<Vec<CheckResult>> {
+        let cargo_tomls = [self.path().join("Cargo.toml")];
+        handler.check(&ProjectContext {
+            config,
+            cargo_tomls: &cargo_tomls,
+        })
+    }
+}
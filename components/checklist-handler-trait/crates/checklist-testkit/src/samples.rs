@@ -0,0 +1,46 @@
+//! Fixed result sets for output and formatter tests
+
+use checklist_result::{CheckResult, Disposition};
+
+/// A small run covering every status, located and unlocated findings, two
+/// issues for one check, a suppressed finding, and a project-level result
+pub fn sample_results() -> Vec<CheckResult> {
+    let mut results = vec![CheckResult::pass(
+        "Rust Edition [demo]",
+        "Using Rust 2024 edition",
+    )];
+    results.extend(sample_issues());
+    results.push(
+        CheckResult::warn("File LOC [demo]", "tables.rs has 412 lines (warning >350)")
+            .at("crates/demo/src/tables.rs", 1)
+            .disposed(Disposition::Suppressed),
+    );
+    results.push(CheckResult::pass(
+        "Toolchain [project]",
+        "rust-toolchain.toml pins 1.85.0",
+    ));
+    results
+}
+
+fn sample_issues() -> Vec<CheckResult> {
+    vec![
+        CheckResult::fail(
+            "Function LOC [demo]",
+            "'run' in main.rs has 62 lines (max 50)",
+        )
+        .at("crates/demo/src/main.rs", 12),
+        CheckResult::warn(
+            "Function LOC [demo]",
+            "'parse' in lib.rs has 31 lines (warning >25)",
+        )
+        .at("crates/demo/src/lib.rs", 40),
+        CheckResult::warn(
+            "Module Function Count [demo]",
+            "Module lib.rs has 6 functions (warning at >4, max 7)",
+        ),
+        CheckResult::fail(
+            "Help --help [demo/demo-cli]",
+            "Missing EXAMPLES section | run `demo-cli --help`",
+        ),
+    ]
+}
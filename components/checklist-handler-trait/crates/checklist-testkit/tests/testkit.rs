@@ -0,0 +1,145 @@
+//! The harness itself: fixtures, handler runs, and assertions
+
+use anyhow::Result;
+use checklist_result::{CheckResult, CheckStatus};
+use checklist_testkit::{TestCrate, assert_no_issues, assert_status, results_for, sample_results};
+use discovery_crate::CrateType;
+use handler_trait::{CheckContext, Handler, ProjectContext, ProjectHandler};
+
+/// Reports the crate it was run on; warns when there is no src/lib.rs
+struct Probe {
+    skip: bool,
+}
+
+impl Handler for Probe {
+    fn name(&self) -> &'static str {
+        "probe"
+    }
+
+    fn handles(&self, crate_type: CrateType) -> bool {
+        crate_type == CrateType::Library
+    }
+
+    fn skip_reason(&self, _ctx: &CheckContext) -> Option<String> {
+        self.skip.then(|| "disabled".to_string())
+    }
+
+    fn check(&self, ctx: &CheckContext) -> Result<Vec<CheckResult>> {
+        let label = format!("Probe Lib [{}]", ctx.crate_name);
+        Ok(vec![if ctx.crate_dir.join("src/lib.rs").is_file() {
+            CheckResult::pass(label, ctx.cargo_toml)
+        } else {
+            CheckResult::warn(label, "no src/lib.rs")
+        }])
+    }
+}
+
+/// Counts the manifests of the project
+struct Manifests;
+
+impl ProjectHandler for Manifests {
+    fn name(&self) -> &'static str {
+        "manifests"
+    }
+
+    fn check(&self, ctx: &ProjectContext) -> Result<Vec<CheckResult>> {
+        let count = ctx.cargo_tomls.iter().filter(|t| t.is_file()).count();
+        Ok(vec![CheckResult::info(
+            "Manifests [project]",
+            count.to_string(),
+        )])
+    }
+}
+
+fn library() -> TestCrate {
+    TestCrate::new("demo-lib")
+        .unwrap()
+        .file("src/lib.rs", "pub fn f() {}\n")
+        .unwrap()
+}
+
+#[test]
+fn fixtures_write_manifest_and_nested_files() {
+    let krate = library().file("assets/img/favicon.ico", [0u8; 4]).unwrap();
+    let manifest = std::fs::read_to_string(krate.path().join("Cargo.toml")).unwrap();
+    assert!(manifest.contains("name = \"demo-lib\""));
+    assert!(manifest.contains("edition = \"2024\""));
+    assert!(krate.path().join("assets/img/favicon.ico").is_file());
+}
+
+#[test]
+fn runs_handlers_with_the_fixture_as_context() {
+    let krate = library();
+    let results = krate.check(&Probe { skip: false }).unwrap();
+    assert_status(&results, "Probe Lib", CheckStatus::Pass);
+    assert!(results[0].message.contains("name = \"demo-lib\""));
+    assert_eq!(results[0].subject(), Some("demo-lib"));
+}
+
+#[test]
+fn unhandled_types_and_skipped_handlers_give_no_results() {
+    let cli = TestCrate::with_manifest(
+        "[package]\nname = \"demo-cli\"\n\n[dependencies]\nclap = \"4\"\n",
+    )
+    .unwrap()
+    .file("src/main.rs", "fn main() {}\n")
+    .unwrap();
+    let results = cli.check(&Probe { skip: false }).unwrap();
+    assert!(results.is_empty(), "{:?}", results);
+
+    let krate = library();
+    assert!(krate.check(&Probe { skip: true }).unwrap().is_empty());
+}
+
+#[test]
+fn manifest_replaces_name_and_cargo_toml() {
+    let krate = TestCrate::new("old")
+        .unwrap()
+        .manifest("[package]\nname = \"renamed\"\nversion = \"0.1.0\"\n")
+        .unwrap()
+        .file("src/lib.rs", "")
+        .unwrap();
+    let results = krate.check(&Probe { skip: false }).unwrap();
+    assert_eq!(results[0].name, "Probe Lib [renamed]");
+}
+
+#[test]
+fn runs_project_handlers_on_the_fixture_manifest() {
+    let krate = library();
+    let results = krate.check_project(&Manifests).unwrap();
+    assert_eq!(results[0].message, "1");
+}
+
+#[test]
+fn results_are_found_by_id_or_title() {
+    let results = sample_results();
+    let by_title = results_for(&results, "Function LOC");
+    assert!(!by_title.is_empty());
+    assert_eq!(by_title.len(), results_for(&results, "function-loc").len());
+}
+
+#[test]
+fn sample_results_cover_every_status() {
+    let results = sample_results();
+    for status in [
+        CheckStatus::Pass,
+        CheckStatus::Fail,
+        CheckStatus::Warn,
+        CheckStatus::Info,
+    ] {
+        assert!(results.iter().any(|r| r.status == status), "{:?}", status);
+    }
+}
+
+#[test]
+#[should_panic(expected = "expected a Fail result for 'Probe Lib'")]
+fn assert_status_panics_with_the_results() {
+    let results = vec![CheckResult::pass("Probe Lib [demo]", "ok")];
+    assert_status(&results, "Probe Lib", CheckStatus::Fail);
+}
+
+#[test]
+#[should_panic(expected = "expected no issues")]
+fn assert_no_issues_panics_on_warnings() {
+    assert_no_issues(&[CheckResult::warn("Probe Lib [demo]", "no src/lib.rs")]);
+}
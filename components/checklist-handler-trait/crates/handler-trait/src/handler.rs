@@ -13,6 +13,12 @@ pub trait Handler {
     /// Check if this handler should run for the given crate type
     fn handles(&self, crate_type: CrateType) -> bool;
 
+    /// Why this handler is skipped for a crate it handles (e.g. disabled by
+    /// config), or `None` when it should run
+    fn skip_reason(&self, _ctx: &CheckContext) -> Option<String> {
+        None
+    }
+
     /// Run the checks and return results
     fn check(&self, ctx: &CheckContext) -> Result<Vec<CheckResult>>;
 }
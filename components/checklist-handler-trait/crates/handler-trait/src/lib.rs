@@ -1,9 +1,12 @@
 //! Handler trait for sw-checklist checks
 //!
-//! This crate defines the Handler trait used by all check handlers.
+//! This crate defines the Handler trait used by all check handlers, and the
+//! ProjectHandler trait for checks that span the whole project.
 
 mod context;
 mod handler;
+mod project;
 
 pub use context::CheckContext;
 pub use handler::Handler;
+pub use project::{ProjectContext, ProjectHandler};
@@ -0,0 +1,23 @@
+//! Project-level handler trait and context
+
+use anyhow::Result;
+use checklist_config::Config;
+use checklist_result::CheckResult;
+use std::path::PathBuf;
+
+/// Context for a project-wide check operation
+pub struct ProjectContext<'a> {
+    /// Global configuration
+    pub config: &'a Config,
+    /// All Cargo.toml files discovered in the project
+    pub cargo_tomls: &'a [PathBuf],
+}
+
+/// Trait for handlers that check the project as a whole rather than one crate
+pub trait ProjectHandler {
+    /// Name of the handler
+    fn name(&self) -> &'static str;
+
+    /// Run the checks and return results
+    fn check(&self, ctx: &ProjectContext) -> Result<Vec<CheckResult>>;
+}
@@ -4,10 +4,16 @@ use anyhow::Result;
 use checklist_result::CheckResult;
 use discovery_crate::CrateType;
 use handler_trait::{CheckContext, Handler};
-use wasm_html::{check_favicon, check_html_files};
+use wasm_a11y::check_a11y;
+use wasm_build::{check_build_injection, check_dist, check_wasm_opt};
+use wasm_html::{check_asset_refs, check_favicon, check_html_files};
+use wasm_i18n::check_i18n;
+use wasm_jstest::check_js_tests;
+use wasm_smoke::check_http_smoke;
 
 use crate::detect::is_web_ui_crate;
 use crate::metadata::check_web_ui_metadata;
+use crate::package::check_package_version;
 
 /// Handler for Web UI / WASM crate checks
 pub struct WasmHandler;
@@ -36,8 +42,48 @@ fn run_checks(ctx: &CheckContext) -> Vec<CheckResult> {
         format!("Web UI [{}]", ctx.crate_name),
         "Found Web UI crate",
     )];
-    r.extend(check_html_files(ctx.crate_dir, ctx.crate_name));
-    r.extend(check_favicon(ctx.crate_dir, ctx.crate_name));
+    let fix = ctx.config.fix();
+    r.extend(check_html_files(ctx.crate_dir, ctx.crate_name, fix));
+    r.extend(check_favicon(ctx.crate_dir, ctx.crate_name, fix));
+    r.extend(check_asset_refs(ctx.crate_dir, ctx.crate_name));
     r.extend(check_web_ui_metadata(ctx.crate_dir, ctx.crate_name));
+    r.extend(check_package_version(
+        ctx.crate_dir,
+        ctx.crate_name,
+        ctx.cargo_toml,
+    ));
+    r.extend(build_checks(ctx));
+    r.extend(content_checks(ctx));
+    r
+}
+
+/// Checks of the UI's content: localization and (opt-in) accessibility
+fn content_checks(ctx: &CheckContext) -> Vec<CheckResult> {
+    let mut r = check_i18n(ctx.crate_dir, ctx.crate_name, ctx.cargo_toml, ctx.config);
+    if ctx.config.file().a11y.check {
+        r.extend(check_a11y(ctx.crate_dir, ctx.crate_name, ctx.config));
+    }
+    r
+}
+
+/// Checks of the build, its output, and test suites (HTTP smoke and JS tests
+/// opt-in)
+fn build_checks(ctx: &CheckContext) -> Vec<CheckResult> {
+    let file = ctx.config.file();
+    let mut r = check_build_injection(ctx.crate_dir, ctx.crate_name);
+    r.extend(check_dist(ctx.crate_dir, ctx.crate_name));
+    r.extend(check_wasm_opt(ctx.crate_dir, ctx.crate_name));
+    if file.http_smoke.check {
+        r.extend(check_http_smoke(
+            ctx.crate_dir,
+            ctx.crate_name,
+            &file.http_smoke,
+        ));
+    }
+    r.extend(check_js_tests(
+        ctx.crate_dir,
+        ctx.crate_name,
+        &file.js_tests,
+    ));
     r
 }
@@ -3,5 +3,9 @@
 mod detect;
 mod handler;
 mod metadata;
+mod package;
 
 pub use handler::WasmHandler;
+pub use metadata::check_web_ui_metadata;
+pub use package::check_package_version;
+pub use wasm_build::check_dist_dir;
@@ -1,8 +1,8 @@
 //! Build metadata checking for Web UI crates
 
 use checklist_result::CheckResult;
-use wasm_html::collect_source_content;
 use std::path::Path;
+use wasm_html::collect_source_content;
 
 /// Check for footer presence and build metadata
 pub fn check_web_ui_metadata(crate_dir: &Path, crate_name: &str) -> Vec<CheckResult> {
@@ -0,0 +1,54 @@
+//! package.json / Cargo.toml version sync
+
+use checklist_result::CheckResult;
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+
+/// Compare a Web UI's package.json version with its (inheritance-resolved)
+/// Cargo.toml version, warning on drift; nothing when either has no version
+pub fn check_package_version(
+    crate_dir: &Path,
+    crate_name: &str,
+    cargo_toml: &str,
+) -> Vec<CheckResult> {
+    let path = crate_dir.join("package.json");
+    let Ok(content) = fs::read_to_string(&path) else {
+        return Vec::new();
+    };
+    let (Some(npm), Some(cargo)) = (npm_version(&content), cargo_version(cargo_toml)) else {
+        return Vec::new();
+    };
+    let label = format!("Package Version [{}]", crate_name);
+    let result = if npm == cargo {
+        CheckResult::pass(label, format!("Both are version {}", cargo))
+    } else {
+        let message = format!("package.json is {} but Cargo.toml is {}", npm, cargo);
+        CheckResult::warn(label, message)
+    };
+    vec![result.at(&path, version_line(&content))]
+}
+
+/// 1-based line of the `"version"` key (1 if not found on its own line)
+fn version_line(package_json: &str) -> usize {
+    package_json
+        .lines()
+        .position(|l| l.trim_start().starts_with("\"version\""))
+        .map_or(1, |i| i + 1)
+}
+
+fn npm_version(package_json: &str) -> Option<String> {
+    let package: Value = serde_json::from_str(package_json).ok()?;
+    Some(package.get("version")?.as_str()?.to_string())
+}
+
+fn cargo_version(cargo_toml: &str) -> Option<String> {
+    let manifest: toml::Value = cargo_toml.parse().ok()?;
+    Some(
+        manifest
+            .get("package")?
+            .get("version")?
+            .as_str()?
+            .to_string(),
+    )
+}
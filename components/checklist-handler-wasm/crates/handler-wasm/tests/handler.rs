@@ -0,0 +1,90 @@
+use checklist_config::{ConfigBuilder, FileConfig};
+use checklist_result::CheckStatus;
+use checklist_testkit::{TestCrate, assert_status, results_for};
+use handler_wasm::WasmHandler;
+use std::fs;
+
+const WASM: &str =
+    "[package]\nname = \"web\"\nversion = \"0.1.0\"\n\n[dependencies]\nwasm-bindgen = \"0.2\"\n";
+const INDEX: &str = "<html><head><title>Web</title></head><body></body></html>\n";
+
+fn web(index: &str) -> TestCrate {
+    TestCrate::with_manifest(WASM)
+        .unwrap()
+        .file("src/lib.rs", "")
+        .unwrap()
+        .file("index.html", index)
+        .unwrap()
+}
+
+#[test]
+fn server_side_wasm_is_not_a_web_ui() {
+    let krate = TestCrate::with_manifest(WASM)
+        .unwrap()
+        .file("src/lib.rs", "")
+        .unwrap();
+    let results = krate.check(&WasmHandler).unwrap();
+    assert_eq!(results.len(), 1, "{:?}", results);
+    assert_status(&results, "WASM Dependency", CheckStatus::Pass);
+}
+
+#[test]
+fn web_uis_need_a_referenced_favicon() {
+    let results = web(INDEX).check(&WasmHandler).unwrap();
+    assert_status(&results, "Web UI", CheckStatus::Pass);
+    assert_status(&results, "index.html", CheckStatus::Pass);
+    assert_status(&results, "favicon.ico", CheckStatus::Fail);
+    assert_status(&results, "Favicon Reference", CheckStatus::Fail);
+}
+
+#[test]
+fn fix_mode_adds_the_favicon_and_its_link() {
+    let krate = web(INDEX);
+    let config = ConfigBuilder::new()
+        .project_path(krate.path().to_path_buf())
+        .file_config(FileConfig::default())
+        .fix(true)
+        .build();
+    let results = krate.run(&WasmHandler, &config).unwrap();
+    assert_status(&results, "favicon.ico", CheckStatus::Info);
+    assert_status(&results, "Favicon Reference", CheckStatus::Info);
+    assert!(krate.path().join("favicon.ico").exists());
+    let index = fs::read_to_string(krate.path().join("index.html")).unwrap();
+    assert!(index.contains("favicon.ico"), "{}", index);
+
+    let results = krate.check(&WasmHandler).unwrap();
+    assert_status(&results, "favicon.ico", CheckStatus::Pass);
+    assert_status(&results, "Favicon Reference", CheckStatus::Pass);
+}
+
+#[test]
+fn fix_mode_keeps_non_ascii_heads_intact() {
+    let krate = web("<html><head><title>İstanbul \u{212A}</title>\n</head><body></body></html>\n");
+    let config = ConfigBuilder::new()
+        .project_path(krate.path().to_path_buf())
+        .file_config(FileConfig::default())
+        .fix(true)
+        .build();
+    let results = krate.run(&WasmHandler, &config).unwrap();
+    assert_status(&results, "Favicon Reference", CheckStatus::Info);
+    let index = fs::read_to_string(krate.path().join("index.html")).unwrap();
+    assert_eq!(
+        index,
+        "<html><head><title>İstanbul \u{212A}</title>\n  <link data-trunk rel=\"icon\" href=\"favicon.ico\">\n</head><body></body></html>\n"
+    );
+}
+
+#[test]
+fn accessibility_checks_are_opt_in() {
+    let krate = web(INDEX);
+    let results = krate.check(&WasmHandler).unwrap();
+    assert!(results_for(&results, "Contrast").is_empty());
+
+    let mut file = FileConfig::default();
+    file.a11y.check = true;
+    assert_status(
+        &krate.run(&WasmHandler, &krate.config(file)).unwrap(),
+        "Contrast",
+        CheckStatus::Pass,
+    );
+}
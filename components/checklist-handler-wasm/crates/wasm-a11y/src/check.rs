@@ -0,0 +1,61 @@
+//! Accessibility check entry point
+
+use checklist_config::Config;
+use checklist_result::CheckResult;
+use discovery_cargo::is_excluded;
+use std::fs;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+use crate::contrast::low_contrast;
+use crate::headings::check_headings;
+
+/// Directories holding build output or dependencies, never scanned
+const SKIP_DIRS: &[&str] = &["target", "dist", "node_modules"];
+
+/// Check heading structure across index.html and src/ templates, and color
+/// contrast in their inline styles and the crate's stylesheets
+pub fn check_a11y(crate_dir: &Path, crate_name: &str, config: &Config) -> Vec<CheckResult> {
+    let files = source_files(crate_dir, config);
+    let mut results = check_headings(&format!("Headings [{}]", crate_name), &files);
+    let label = format!("Contrast [{}]", crate_name);
+    let mut poor = 0;
+    for (path, content) in &files {
+        let css = path.extension().is_some_and(|ext| ext == "css");
+        for (line, message) in low_contrast(content, css) {
+            results.push(CheckResult::warn(&label, message).at(path, line));
+            poor += 1;
+        }
+    }
+    if poor == 0 {
+        results.push(CheckResult::pass(
+            label,
+            "No low-contrast color pairs found",
+        ));
+    }
+    results
+}
+
+/// index.html, `.rs` files under src/, and `.css` files outside build output
+/// and `exclude` globs, with their contents
+fn source_files(crate_dir: &Path, config: &Config) -> Vec<(PathBuf, String)> {
+    let excluded = |p: &Path| is_excluded(p, config.project_root(), &config.file().exclude);
+    let index = crate_dir.join("index.html");
+    let walked = WalkDir::new(crate_dir)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|e| !SKIP_DIRS.iter().any(|d| e.file_name() == *d) && !excluded(e.path()))
+        .filter_map(|e| e.ok())
+        .map(|e| e.into_path())
+        .filter(|p| match p.extension().and_then(|ext| ext.to_str()) {
+            Some("rs") => p.starts_with(crate_dir.join("src")),
+            Some("css") => true,
+            _ => false,
+        });
+    std::iter::once(index)
+        .filter(|p| !excluded(p))
+        .chain(walked)
+        .filter_map(|p| Some((fs::read_to_string(&p).ok()?, p)))
+        .map(|(content, p)| (p, content))
+        .collect()
+}
@@ -0,0 +1,73 @@
+//! CSS color parsing and WCAG contrast ratios
+
+/// Common named colors (anything else is skipped rather than guessed)
+const NAMED: &[(&str, [u8; 3])] = &[
+    ("black", [0, 0, 0]),
+    ("white", [255, 255, 255]),
+    ("gray", [128, 128, 128]),
+    ("grey", [128, 128, 128]),
+    ("silver", [192, 192, 192]),
+    ("darkgray", [169, 169, 169]),
+    ("lightgray", [211, 211, 211]),
+    ("gainsboro", [220, 220, 220]),
+    ("whitesmoke", [245, 245, 245]),
+    ("red", [255, 0, 0]),
+    ("green", [0, 128, 0]),
+    ("blue", [0, 0, 255]),
+    ("navy", [0, 0, 128]),
+    ("yellow", [255, 255, 0]),
+    ("orange", [255, 165, 0]),
+];
+
+/// An opaque color from `#rgb`, `#rrggbb`, `rgb(r, g, b)`, or a common name;
+/// `None` for anything with transparency or that needs context to resolve
+pub fn parse_color(value: &str) -> Option<[u8; 3]> {
+    let value = value.trim().trim_end_matches("!important").trim();
+    let value = value.to_ascii_lowercase();
+    if let Some(hex) = value.strip_prefix('#') {
+        return parse_hex(hex);
+    }
+    if let Some(args) = value.strip_prefix("rgb(").and_then(|v| v.strip_suffix(')')) {
+        let parts: Vec<u8> = args
+            .split([',', ' '])
+            .filter(|p| !p.is_empty())
+            .map(|p| p.parse().ok())
+            .collect::<Option<_>>()?;
+        return <[u8; 3]>::try_from(parts).ok();
+    }
+    NAMED
+        .iter()
+        .find(|(name, _)| *name == value)
+        .map(|(_, rgb)| *rgb)
+}
+
+fn parse_hex(hex: &str) -> Option<[u8; 3]> {
+    let digits: Vec<u8> = hex
+        .chars()
+        .map(|c| c.to_digit(16).map(|d| d as u8))
+        .collect::<Option<_>>()?;
+    match digits[..] {
+        [r, g, b] => Some([r * 17, g * 17, b * 17]),
+        [r1, r2, g1, g2, b1, b2] => Some([r1 * 16 + r2, g1 * 16 + g2, b1 * 16 + b2]),
+        _ => None,
+    }
+}
+
+/// WCAG contrast ratio between two colors, from 1 (same) to 21 (black/white)
+pub fn contrast_ratio(a: [u8; 3], b: [u8; 3]) -> f64 {
+    let (la, lb) = (luminance(a), luminance(b));
+    (la.max(lb) + 0.05) / (la.min(lb) + 0.05)
+}
+
+/// WCAG relative luminance
+fn luminance(rgb: [u8; 3]) -> f64 {
+    let channel = |c: u8| {
+        let c = f64::from(c) / 255.0;
+        if c <= 0.03928 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    0.2126 * channel(rgb[0]) + 0.7152 * channel(rgb[1]) + 0.0722 * channel(rgb[2])
+}
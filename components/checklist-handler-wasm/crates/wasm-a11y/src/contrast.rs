@@ -0,0 +1,53 @@
+//! Low-contrast color pairs in inline styles and stylesheet rules
+
+use crate::color::{contrast_ratio, parse_color};
+
+/// Ratio below which text is unreadable for many users (WCAG AA asks 3:1 even
+/// for large text), so only obviously insufficient pairs are reported
+const MIN_RATIO: f64 = 3.0;
+
+/// Declaration blocks (`style="..."` attributes, or `{...}` rules in a
+/// stylesheet) whose `color` and `background(-color)` both parse and contrast
+/// below the minimum, as (1-based line, message)
+pub(crate) fn low_contrast(content: &str, css: bool) -> Vec<(usize, String)> {
+    blocks(content, css)
+        .into_iter()
+        .filter_map(|(offset, block)| {
+            let declaration = |names: &[&str]| {
+                block.split(';').find_map(|decl| {
+                    let (name, value) = decl.split_once(':')?;
+                    let name = name.trim().to_ascii_lowercase();
+                    let value = value.trim().trim_end_matches("!important").trim();
+                    names.contains(&name.as_str()).then_some(value)
+                })
+            };
+            let color = declaration(&["color"])?;
+            let background = declaration(&["background-color", "background"])?;
+            let ratio = contrast_ratio(parse_color(color)?, parse_color(background)?);
+            let line = content[..offset].matches('\n').count() + 1;
+            (ratio < MIN_RATIO).then(|| {
+                let message = format!(
+                    "color {} on {} has contrast {:.1}:1 (minimum {}:1)",
+                    color, background, ratio, MIN_RATIO
+                );
+                (line, message)
+            })
+        })
+        .collect()
+}
+
+/// Declaration blocks with their byte offsets
+fn blocks(content: &str, css: bool) -> Vec<(usize, &str)> {
+    let (open, close) = if css { ("{", '}') } else { ("style=\"", '"') };
+    let mut found = Vec::new();
+    let mut from = 0;
+    while let Some(pos) = content[from..].find(open) {
+        let start = from + pos + open.len();
+        let Some(len) = content[start..].find(close) else {
+            break;
+        };
+        found.push((start, &content[start..start + len]));
+        from = start + len + 1;
+    }
+    found
+}
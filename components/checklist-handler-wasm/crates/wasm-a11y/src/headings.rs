@@ -0,0 +1,67 @@
+//! Heading structure: one `<h1>` and no skipped levels
+
+use checklist_result::CheckResult;
+use std::path::PathBuf;
+
+/// Warn on a missing or repeated `<h1>` across the files, and at each heading
+/// more than one level deeper than the one before it in the same file
+pub fn check_headings(label: &str, files: &[(PathBuf, String)]) -> Vec<CheckResult> {
+    let mut results = Vec::new();
+    let mut h1s = Vec::new();
+    for (path, content) in files {
+        let mut previous = None;
+        for (line, level) in headings(content) {
+            if let Some(prev) = previous
+                && level > prev + 1
+            {
+                let message = format!(
+                    "<h{}> follows <h{}>; don't skip heading levels",
+                    level, prev
+                );
+                results.push(CheckResult::warn(label, message).at(path, line));
+            }
+            if level == 1 {
+                h1s.push(format!("{}:{}", path.display(), line));
+            }
+            previous = Some(level);
+        }
+    }
+    match h1s.len() {
+        0 => results.push(CheckResult::warn(
+            label,
+            "No <h1> heading for the page title",
+        )),
+        1 if results.is_empty() => {
+            results.push(CheckResult::pass(
+                label,
+                "One <h1>, heading levels sequential",
+            ));
+        }
+        1 => {}
+        n => results.push(CheckResult::warn(
+            label,
+            format!("{} <h1> headings (expected one): {}", n, h1s.join(", ")),
+        )),
+    }
+    results
+}
+
+/// `<h1>`..`<h6>` opening tags as (1-based line, level), in document order
+fn headings(content: &str) -> Vec<(usize, u8)> {
+    let mut found = Vec::new();
+    for (i, line) in content.lines().enumerate() {
+        let bytes = line.as_bytes();
+        for pos in 0..bytes.len().saturating_sub(2) {
+            if bytes[pos] == b'<'
+                && bytes[pos + 1].eq_ignore_ascii_case(&b'h')
+                && let level @ b'1'..=b'6' = bytes[pos + 2]
+                && bytes
+                    .get(pos + 3)
+                    .is_none_or(|&c| c == b'>' || c == b'/' || c.is_ascii_whitespace())
+            {
+                found.push((i + 1, level - b'0'));
+            }
+        }
+    }
+    found
+}
@@ -0,0 +1,14 @@
+//! Static accessibility checks for Web UI crates
+//!
+//! Reads index.html, `html!`/`view!` templates under src/, and stylesheets for
+//! heading structure (a single `<h1>`, no skipped levels) and text colors with
+//! obviously insufficient contrast against their background.
+
+mod check;
+mod color;
+mod contrast;
+mod headings;
+
+pub use check::check_a11y;
+pub use color::{contrast_ratio, parse_color};
+pub use headings::check_headings;
@@ -0,0 +1,82 @@
+use checklist_result::CheckStatus;
+use std::path::PathBuf;
+use wasm_a11y::{check_headings, contrast_ratio, parse_color};
+
+#[test]
+fn colors_parse_from_hex_rgb_and_names() {
+    assert_eq!(parse_color("#fff"), Some([255, 255, 255]));
+    assert_eq!(parse_color("#1A2b3C"), Some([26, 43, 60]));
+    assert_eq!(parse_color("rgb(10, 20, 30)"), Some([10, 20, 30]));
+    assert_eq!(parse_color(" Gray !important"), Some([128, 128, 128]));
+    for value in [
+        "#ffff",
+        "rgba(0, 0, 0, 0.5)",
+        "rgb(1, 2)",
+        "currentColor",
+        "#zzz",
+    ] {
+        assert_eq!(parse_color(value), None, "{}", value);
+    }
+}
+
+#[test]
+fn contrast_ratios_follow_wcag() {
+    let (black, white) = ([0, 0, 0], [255, 255, 255]);
+    assert!((contrast_ratio(black, white) - 21.0).abs() < 1e-9);
+    assert!((contrast_ratio(white, black) - 21.0).abs() < 1e-9);
+    assert!((contrast_ratio(white, white) - 1.0).abs() < 1e-9);
+    let gray = contrast_ratio([119, 119, 119], white);
+    assert!((gray - 4.48).abs() < 0.01, "{}", gray);
+}
+
+fn headings(files: &[(&str, &str)]) -> Vec<(CheckStatus, String, Option<usize>)> {
+    let files: Vec<(PathBuf, String)> = files
+        .iter()
+        .map(|(path, content)| (PathBuf::from(path), content.to_string()))
+        .collect();
+    check_headings("Headings [web]", &files)
+        .into_iter()
+        .map(|r| (r.status, r.message, r.location.map(|l| l.line)))
+        .collect()
+}
+
+#[test]
+fn one_h1_and_sequential_levels_pass() {
+    let html = "<H1 class=\"title\">App</H1>\n<h2>Intro</h2>\n<h3/>\n<h2>More</h2>\n";
+    assert_eq!(
+        headings(&[("index.html", html)]),
+        [(
+            CheckStatus::Pass,
+            "One <h1>, heading levels sequential".to_string(),
+            None
+        )]
+    );
+}
+
+#[test]
+fn skipped_levels_are_reported_at_their_line() {
+    let html = "<h1>App</h1>\n<header><hr><h4>Deep</h4>\n";
+    assert_eq!(
+        headings(&[("index.html", html)]),
+        [(
+            CheckStatus::Warn,
+            "<h4> follows <h1>; don't skip heading levels".to_string(),
+            Some(2)
+        )]
+    );
+}
+
+#[test]
+fn the_h1_is_counted_across_files() {
+    let missing = headings(&[("index.html", "<h2>Intro</h2>\n")]);
+    assert_eq!(missing[0].1, "No <h1> heading for the page title");
+
+    let repeated = headings(&[
+        ("index.html", "<h1>App</h1>\n"),
+        ("src/app.rs", "html! { <h1>{ \"Title\" }</h1> }\n"),
+    ]);
+    assert_eq!(
+        repeated[0].1,
+        "2 <h1> headings (expected one): index.html:1, src/app.rs:1"
+    );
+}
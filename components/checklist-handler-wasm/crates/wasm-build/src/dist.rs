@@ -0,0 +1,76 @@
+//! Built dist/ output checks
+
+use checklist_result::CheckResult;
+use std::fs;
+use std::path::Path;
+
+/// Check a crate's built dist/, if any (crates without one have nothing to check)
+pub fn check_dist(crate_dir: &Path, crate_name: &str) -> Vec<CheckResult> {
+    let dist = crate_dir.join("dist");
+    if !dist.is_dir() {
+        return Vec::new();
+    }
+    check_dist_dir(&dist, crate_name)
+}
+
+/// Check a dist directory contains the favicon and every asset its index.html references
+pub fn check_dist_dir(dist: &Path, crate_name: &str) -> Vec<CheckResult> {
+    let mut results = vec![dist_favicon(dist, crate_name)];
+    let index = dist.join("index.html");
+    match fs::read_to_string(&index) {
+        Ok(html) => results.push(dist_references(dist, &html, crate_name).at(&index, 1)),
+        Err(_) => results.push(CheckResult::fail(
+            format!("Dist References [{}]", crate_name),
+            "dist/ has no index.html; rebuild with trunk build",
+        )),
+    }
+    results
+}
+
+fn dist_favicon(dist: &Path, crate_name: &str) -> CheckResult {
+    let label = format!("Dist Favicon [{}]", crate_name);
+    if dist.join("favicon.ico").exists() {
+        CheckResult::pass(label, "dist/ contains favicon.ico")
+    } else {
+        CheckResult::fail(
+            label,
+            "dist/ has no favicon.ico; add a <link data-trunk rel=\"icon\"> or copy-file asset",
+        )
+    }
+}
+
+fn dist_references(dist: &Path, html: &str, crate_name: &str) -> CheckResult {
+    let label = format!("Dist References [{}]", crate_name);
+    let refs = local_refs(html);
+    let missing: Vec<&str> = refs
+        .iter()
+        .filter(|r| !dist.join(r.trim_start_matches('/')).exists())
+        .map(String::as_str)
+        .collect();
+    if missing.is_empty() {
+        CheckResult::pass(
+            label,
+            format!("All {} dist/index.html references resolve", refs.len()),
+        )
+    } else {
+        CheckResult::fail(
+            label,
+            format!(
+                "dist/index.html references missing files: {}",
+                missing.join(", ")
+            ),
+        )
+    }
+}
+
+/// Local `href`/`src` targets in the HTML, without query strings or fragments
+pub fn local_refs(html: &str) -> Vec<String> {
+    html.split(['\'', '"'])
+        .collect::<Vec<_>>()
+        .windows(2)
+        .filter(|w| w[0].ends_with("href=") || w[0].ends_with("src="))
+        .map(|w| w[1].split(['?', '#']).next().unwrap_or_default())
+        .filter(|r| !r.is_empty() && !r.contains(':') && !r.starts_with("//"))
+        .map(str::to_string)
+        .collect()
+}
@@ -0,0 +1,69 @@
+//! Compile-time injection of footer build metadata
+
+use checklist_result::CheckResult;
+use std::fs;
+use std::path::Path;
+use wasm_html::collect_source_content;
+
+/// Footer build fields, with the env var name fragments that identify each
+const BUILD_FIELDS: &[(&str, &[&str])] = &[
+    ("Commit", &["COMMIT", "SHA"]),
+    ("Time", &["TIME", "DATE"]),
+    ("Host", &["HOST"]),
+];
+
+/// Check the footer's commit/time/host values are read with `env!`/`option_env!`
+/// from `BUILD_*` vars that build.rs or a Trunk hook actually sets
+pub fn check_build_injection(crate_dir: &Path, crate_name: &str) -> Vec<CheckResult> {
+    let (source, _) = collect_source_content(crate_dir);
+    let vars = env_vars(&source);
+    let producers = ["build.rs", "Trunk.toml"]
+        .iter()
+        .filter_map(|name| fs::read_to_string(crate_dir.join(name)).ok())
+        .collect::<Vec<_>>()
+        .join("\n");
+    BUILD_FIELDS
+        .iter()
+        .map(|(field, fragments)| {
+            let var = vars
+                .iter()
+                .find(|var| fragments.iter().any(|f| var.contains(f)));
+            field_result(field, var.map(String::as_str), &producers, crate_name)
+        })
+        .collect()
+}
+
+/// `BUILD_*` env var names read with `env!` or `option_env!`
+fn env_vars(source: &str) -> Vec<String> {
+    source
+        .split("env!(\"")
+        .skip(1)
+        .filter_map(|rest| rest.split('"').next())
+        .filter(|var| var.starts_with("BUILD_"))
+        .map(str::to_string)
+        .collect()
+}
+
+fn field_result(field: &str, var: Option<&str>, producers: &str, crate_name: &str) -> CheckResult {
+    let label = format!("Build {} Injection [{}]", field, crate_name);
+    let Some(var) = var else {
+        return CheckResult::warn(
+            label,
+            format!(
+                "Footer build {} is not injected at compile time (no env!(\"BUILD_*\"))",
+                field.to_lowercase()
+            ),
+        );
+    };
+    if producers.contains(var) {
+        CheckResult::pass(label, format!("{} is set at build time", var))
+    } else {
+        CheckResult::fail(
+            label,
+            format!(
+                "{} is read but neither build.rs nor a Trunk hook sets it",
+                var
+            ),
+        )
+    }
+}
@@ -0,0 +1,16 @@
+//! Build-time checks for Web UI crates
+//!
+//! Looks past the source-level footer and favicon checks at what the build
+//! actually produces.
+
+mod dist;
+mod inject;
+mod opt;
+
+pub use dist::{check_dist, check_dist_dir};
+pub use inject::check_build_injection;
+pub use opt::check_wasm_opt;
+
+/// HTML asset reference scanning, exposed for fuzz targets
+#[cfg(feature = "fuzzing")]
+pub use dist::local_refs;
@@ -0,0 +1,83 @@
+//! wasm-opt optimization pipeline checks
+
+use checklist_result::CheckResult;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Files (relative to the crate or an enclosing directory) where a wasm-opt
+/// step is usually configured
+const PIPELINE_FILES: &[&str] = &[
+    "index.html",
+    "Trunk.toml",
+    "build.sh",
+    "Makefile",
+    "justfile",
+    "package.json",
+    "scripts/build.sh",
+    "scripts/build-all.sh",
+];
+
+/// Check the release build runs wasm-opt, and that the dist .wasm is smaller
+/// than the unoptimized `target/wasm32-unknown-unknown/release` output
+pub fn check_wasm_opt(crate_dir: &Path, crate_name: &str) -> Vec<CheckResult> {
+    let label = format!("WASM Optimization [{}]", crate_name);
+    let mut results = vec![match pipeline_file(crate_dir) {
+        Some(path) => CheckResult::pass(label, "Release build runs wasm-opt").at(&path, 1),
+        None => CheckResult::warn(
+            label,
+            "No wasm-opt step found; add data-wasm-opt=\"z\" to the data-trunk rust link",
+        ),
+    }];
+    results.extend(size_result(crate_dir, crate_name));
+    results
+}
+
+/// The first pipeline file in the crate or up to three enclosing directories
+/// that enables wasm-opt (`data-wasm-opt` other than "0", or a `wasm-opt` command)
+fn pipeline_file(crate_dir: &Path) -> Option<PathBuf> {
+    crate_dir
+        .ancestors()
+        .take(4)
+        .flat_map(|dir| PIPELINE_FILES.iter().map(move |f| dir.join(f)))
+        .find(|path| {
+            fs::read_to_string(path).is_ok_and(|c| {
+                (c.contains("wasm-opt") && !c.contains("data-wasm-opt=\"0\""))
+                    || c.contains("wasm_opt")
+            })
+        })
+}
+
+/// Compare the dist .wasm against the unoptimized build, when both exist
+fn size_result(crate_dir: &Path, crate_name: &str) -> Option<CheckResult> {
+    let unoptimized = unoptimized_size(crate_dir, crate_name)?;
+    let dist = fs::read_dir(crate_dir.join("dist")).ok()?;
+    let (size, path) = dist
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| p.extension().is_some_and(|e| e == "wasm"))
+        .find_map(|p| Some((p.metadata().ok()?.len(), p)))?;
+    let label = format!("Dist WASM Size [{}]", crate_name);
+    let sizes = format!(
+        "{} KiB vs {} KiB unoptimized",
+        size / 1024,
+        unoptimized / 1024
+    );
+    Some(if size < unoptimized {
+        CheckResult::pass(label, sizes)
+    } else {
+        CheckResult::warn(label, format!("{}; wasm-opt was skipped", sizes)).at(&path, 1)
+    })
+}
+
+/// Size of the crate's `target/wasm32-unknown-unknown/release` output in the
+/// nearest enclosing target directory
+fn unoptimized_size(crate_dir: &Path, crate_name: &str) -> Option<u64> {
+    let file_name = format!("{}.wasm", crate_name.replace('-', "_"));
+    let path = crate_dir
+        .ancestors()
+        .map(|dir| {
+            dir.join("target/wasm32-unknown-unknown/release")
+                .join(&file_name)
+        })
+        .find(|path| path.is_file())?;
+    Some(path.metadata().ok()?.len())
+}
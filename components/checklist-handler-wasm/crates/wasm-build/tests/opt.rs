@@ -0,0 +1,87 @@
+use checklist_result::{CheckResult, CheckStatus};
+use checklist_tempdir::TempWorkspace;
+use std::fs;
+use std::path::{Path, PathBuf};
+use wasm_build::check_wasm_opt;
+
+/// A `web/app` crate directory two levels inside a temp workspace, so the
+/// pipeline search never leaves the workspace
+fn web_app(workspace: &TempWorkspace) -> PathBuf {
+    let dir = workspace.path().join("web/app");
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn write(path: &Path, contents: impl AsRef<[u8]>) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, contents).unwrap();
+}
+
+fn pipeline(results: &[CheckResult]) -> (CheckStatus, Option<PathBuf>) {
+    let result = &results[0];
+    assert_eq!(result.name, "WASM Optimization [web-app]");
+    (
+        result.status,
+        result.location.as_ref().map(|l| l.file.clone()),
+    )
+}
+
+#[test]
+fn release_builds_need_a_wasm_opt_step() {
+    let workspace = TempWorkspace::new("wasm-opt").unwrap();
+    let dir = web_app(&workspace);
+    let results = check_wasm_opt(&dir, "web-app");
+    assert_eq!(pipeline(&results), (CheckStatus::Warn, None));
+    assert_eq!(results.len(), 1, "{:?}", results);
+
+    let index = dir.join("index.html");
+    write(
+        &index,
+        "<link data-trunk rel=\"rust\" data-wasm-opt=\"0\" />",
+    );
+    assert_eq!(
+        pipeline(&check_wasm_opt(&dir, "web-app")).0,
+        CheckStatus::Warn
+    );
+    write(
+        &index,
+        "<link data-trunk rel=\"rust\" data-wasm-opt=\"z\" />",
+    );
+    let results = check_wasm_opt(&dir, "web-app");
+    assert_eq!(pipeline(&results), (CheckStatus::Pass, Some(index)));
+}
+
+#[test]
+fn build_scripts_in_enclosing_directories_count() {
+    let workspace = TempWorkspace::new("wasm-opt").unwrap();
+    let dir = web_app(&workspace);
+    let script = workspace.path().join("scripts/build.sh");
+    write(
+        &script,
+        "wasm-opt -Oz dist/app_bg.wasm -o dist/app_bg.wasm\n",
+    );
+    let results = check_wasm_opt(&dir, "web-app");
+    assert_eq!(pipeline(&results), (CheckStatus::Pass, Some(script)));
+}
+
+#[test]
+fn dist_wasm_must_be_smaller_than_the_unoptimized_build() {
+    let workspace = TempWorkspace::new("wasm-opt").unwrap();
+    let dir = web_app(&workspace);
+    let release = workspace
+        .path()
+        .join("target/wasm32-unknown-unknown/release");
+    write(&release.join("web_app.wasm"), vec![0u8; 4096]);
+    write(&dir.join("dist/app_bg.wasm"), vec![0u8; 2048]);
+    let results = check_wasm_opt(&dir, "web-app");
+    assert_eq!(results[1].status, CheckStatus::Pass);
+    assert_eq!(results[1].message, "2 KiB vs 4 KiB unoptimized");
+
+    write(&dir.join("dist/app_bg.wasm"), vec![0u8; 4096]);
+    let results = check_wasm_opt(&dir, "web-app");
+    assert_eq!(results[1].status, CheckStatus::Warn);
+    assert_eq!(
+        results[1].message,
+        "4 KiB vs 4 KiB unoptimized; wasm-opt was skipped"
+    );
+}
@@ -0,0 +1,75 @@
+//! Static asset reference integrity for Web UI crates
+
+use checklist_result::CheckResult;
+use std::fs;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// Directories (besides the crate root) that assets are served or copied from
+const ASSET_ROOTS: &[&str] = &["dist", "static", "public", "assets", "www"];
+
+/// Check that every local file referenced by `src=`/`href=` in index.html and
+/// in `html!`/`view!` templates under src/ exists in the crate or its dist
+///
+/// Broken index.html references fail; template references warn, since values
+/// there may be built at runtime.
+pub fn check_asset_refs(crate_dir: &Path, crate_name: &str) -> Vec<CheckResult> {
+    let label = format!("Asset References [{}]", crate_name);
+    let index = crate_dir.join("index.html");
+    let mut results = Vec::new();
+    for path in std::iter::once(index.clone()).chain(template_files(crate_dir)) {
+        let content = fs::read_to_string(&path).unwrap_or_default();
+        for (line, reference) in broken_refs(crate_dir, &content) {
+            let message = format!("{} not found in the crate or dist/", reference);
+            let result = if path == index {
+                CheckResult::fail(&label, message)
+            } else {
+                CheckResult::warn(&label, message)
+            };
+            results.push(result.at(&path, line));
+        }
+    }
+    if results.is_empty() {
+        results.push(CheckResult::pass(label, "All asset references resolve"));
+    }
+    results
+}
+
+/// Rust sources under src/ that may hold `html!`/`view!` templates
+fn template_files(crate_dir: &Path) -> impl Iterator<Item = PathBuf> {
+    WalkDir::new(crate_dir.join("src"))
+        .sort_by_file_name()
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().extension().is_some_and(|ext| ext == "rs"))
+        .map(|e| e.into_path())
+}
+
+/// Local file references (`src="..."`/`href="..."` values with a file
+/// extension) that don't resolve, with their 1-based line numbers
+fn broken_refs(crate_dir: &Path, content: &str) -> Vec<(usize, String)> {
+    let mut refs = Vec::new();
+    for (i, line) in content.lines().enumerate() {
+        for attr in ["src=\"", "href=\""] {
+            let values = line
+                .split(attr)
+                .skip(1)
+                .filter_map(|rest| rest.split('"').next());
+            let broken = values
+                .map(|v| v.split(['?', '#']).next().unwrap_or_default())
+                .filter(|v| !v.contains([':', '{', '$']) && !v.starts_with("//"))
+                .filter(|v| v.rsplit('/').next().is_some_and(|name| name.contains('.')))
+                .filter(|v| !resolves(crate_dir, v));
+            refs.extend(broken.map(|v| (i + 1, v.to_string())));
+        }
+    }
+    refs
+}
+
+/// Whether a reference exists relative to the crate root or an asset root
+fn resolves(crate_dir: &Path, reference: &str) -> bool {
+    let relative = reference.trim_start_matches("./").trim_start_matches('/');
+    std::iter::once(crate_dir.to_path_buf())
+        .chain(ASSET_ROOTS.iter().map(|dir| crate_dir.join(dir)))
+        .any(|root| root.join(relative).exists())
+}
@@ -0,0 +1,26 @@
+//! Safe remediations for missing favicons
+
+/// A 1x1 fully transparent 32-bit ICO, enough to satisfy browsers and Trunk
+pub const PLACEHOLDER_FAVICON: &[u8] = &[
+    0, 0, 1, 0, 1, 0, // ICONDIR: icon type, one image
+    1, 1, 0, 0, 1, 0, 32, 0, 48, 0, 0, 0, 22, 0, 0, 0, // 1x1, 32 bpp, 48 bytes at 22
+    40, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 1, 0, 32, 0, // BITMAPINFOHEADER (height x2)
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, // transparent pixel
+    0, 0, 0, 0, // AND mask row
+];
+
+/// Link tag inserted by `--fix`, picked up by Trunk and plain servers alike
+const FAVICON_LINK: &str = r#"<link data-trunk rel="icon" href="favicon.ico">"#;
+
+/// `html` with a favicon link inserted before `</head>`, or `None` without a head
+pub fn with_favicon_link(html: &str) -> Option<String> {
+    let end = html.to_ascii_lowercase().find("</head>")?;
+    let indent = html[..end]
+        .rsplit('\n')
+        .next()
+        .filter(|s| s.trim().is_empty())
+        .unwrap_or("");
+    let (head, rest) = html.split_at(end - indent.len());
+    Some(format!("{}{}  {}\n{}", head, indent, FAVICON_LINK, rest))
+}
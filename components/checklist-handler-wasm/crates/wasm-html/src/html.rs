@@ -4,8 +4,10 @@ use checklist_result::CheckResult;
 use std::fs;
 use std::path::Path;
 
-/// Check for index.html and its contents
-pub fn check_html_files(crate_dir: &Path, crate_name: &str) -> Vec<CheckResult> {
+use crate::fix::{PLACEHOLDER_FAVICON, with_favicon_link};
+
+/// Check for index.html and its contents; with `fix`, add a missing favicon link
+pub fn check_html_files(crate_dir: &Path, crate_name: &str, fix: bool) -> Vec<CheckResult> {
     let label = format!("[{}]", crate_name);
     let index_html = crate_dir.join("index.html");
 
@@ -21,38 +23,39 @@ pub fn check_html_files(crate_dir: &Path, crate_name: &str) -> Vec<CheckResult>
         "Found index.html",
     )];
     if let Ok(html) = fs::read_to_string(&index_html) {
-        results.push(check_favicon_ref(&label, &html));
+        results.push(check_favicon_ref(&label, &index_html, &html, fix));
     }
     results
 }
 
-/// Check for favicon.ico file
-pub fn check_favicon(crate_dir: &Path, crate_name: &str) -> Vec<CheckResult> {
-    let label = format!("[{}]", crate_name);
-    if crate_dir.join("favicon.ico").exists() {
-        vec![CheckResult::pass(
-            format!("favicon.ico {}", label),
-            "Found favicon.ico",
-        )]
+/// Check for favicon.ico file; with `fix`, write a placeholder icon
+pub fn check_favicon(crate_dir: &Path, crate_name: &str, fix: bool) -> Vec<CheckResult> {
+    let label = format!("favicon.ico [{}]", crate_name);
+    let favicon = crate_dir.join("favicon.ico");
+    if favicon.exists() {
+        vec![CheckResult::pass(label, "Found favicon.ico")]
+    } else if fix && fs::write(&favicon, PLACEHOLDER_FAVICON).is_ok() {
+        let message = "Fixed favicon.ico: wrote a blank placeholder (replace with the real icon)";
+        vec![CheckResult::info(label, message)]
     } else {
         vec![CheckResult::fail(
-            format!("favicon.ico {}", label),
+            label,
             "WASM projects should have a favicon.ico file",
         )]
     }
 }
 
-fn check_favicon_ref(label: &str, html: &str) -> CheckResult {
+fn check_favicon_ref(label: &str, index_html: &Path, html: &str, fix: bool) -> CheckResult {
+    let name = format!("Favicon Reference {}", label);
     let lower = html.to_lowercase();
     if lower.contains("favicon.ico") || lower.contains("rel=\"icon\"") {
-        CheckResult::pass(
-            format!("Favicon Reference {}", label),
-            "index.html references favicon",
-        )
+        CheckResult::pass(name, "index.html references favicon")
+    } else if fix
+        && let Some(fixed) = with_favicon_link(html)
+        && fs::write(index_html, fixed).is_ok()
+    {
+        CheckResult::info(name, "Fixed index.html: added a favicon.ico link to <head>")
     } else {
-        CheckResult::fail(
-            format!("Favicon Reference {}", label),
-            "index.html should reference favicon.ico",
-        )
+        CheckResult::fail(name, "index.html should reference favicon.ico")
     }
 }
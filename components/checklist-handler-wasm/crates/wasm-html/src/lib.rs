@@ -1,7 +1,11 @@
-//! HTML/favicon checks for Web UI crates
+//! HTML/favicon and asset reference checks for Web UI crates
 
+mod assets;
+mod fix;
 mod html;
 mod source;
 
+pub use assets::check_asset_refs;
+pub use fix::with_favicon_link;
 pub use html::{check_favicon, check_html_files};
 pub use source::collect_source_content;
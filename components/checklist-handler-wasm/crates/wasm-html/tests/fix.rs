@@ -0,0 +1,37 @@
+use wasm_html::with_favicon_link;
+
+const LINK: &str = r#"<link data-trunk rel="icon" href="favicon.ico">"#;
+
+#[test]
+fn the_link_goes_before_the_head_end_at_its_indent() {
+    let html = "<html>\n  <head>\n    <title>App</title>\n  </head>\n</html>\n";
+    let expected = format!(
+        "<html>\n  <head>\n    <title>App</title>\n    {}\n  </head>\n</html>\n",
+        LINK
+    );
+    assert_eq!(with_favicon_link(html).as_deref(), Some(expected.as_str()));
+}
+
+#[test]
+fn the_head_end_is_found_in_any_case_on_a_shared_line() {
+    let html = "<HEAD><title>App</title></HEAD><body></body>";
+    let expected = format!("<HEAD><title>App</title>  {}\n</HEAD><body></body>", LINK);
+    assert_eq!(with_favicon_link(html).as_deref(), Some(expected.as_str()));
+}
+
+#[test]
+fn non_ascii_text_before_the_head_end_is_kept() {
+    let html = "<head><title>\u{130}stanbul \u{212A}</title>\n</head>";
+    let fixed = with_favicon_link(html).unwrap();
+    assert!(fixed.starts_with("<head><title>\u{130}stanbul \u{212A}</title>\n"));
+    assert!(
+        fixed.ends_with(&format!("  {}\n</head>", LINK)),
+        "{}",
+        fixed
+    );
+}
+
+#[test]
+fn documents_without_a_head_are_left_alone() {
+    assert_eq!(with_favicon_link("<body>App</body>"), None);
+}
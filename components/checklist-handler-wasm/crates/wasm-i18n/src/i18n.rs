@@ -0,0 +1,99 @@
+//! i18n crate detection and locale file checks
+
+use checklist_config::Config;
+use checklist_result::CheckResult;
+use discovery_source::rust_sources;
+use std::path::Path;
+
+use crate::strings::hardcoded_strings;
+
+/// Dependencies that mark a crate as localized
+const I18N_CRATES: &[&str] = &[
+    "fluent",
+    "fluent-bundle",
+    "fluent-templates",
+    "rust-i18n",
+    "i18n-embed",
+    "leptos_i18n",
+];
+
+/// Directories searched for translation files
+const LOCALE_DIRS: &[&str] = &["locales", "i18n", "translations"];
+
+/// Translation file extensions (a `<locale>/` directory also counts)
+const LOCALE_EXTENSIONS: &[&str] = &["ftl", "yml", "yaml", "json", "toml"];
+
+/// Check hard-coded markup strings and locale files; empty unless
+/// `[i18n] check` is enabled and the crate depends on an i18n crate
+pub fn check_i18n(
+    crate_dir: &Path,
+    crate_name: &str,
+    cargo_toml: &str,
+    config: &Config,
+) -> Vec<CheckResult> {
+    let policy = &config.file().i18n;
+    if !policy.check || !uses_i18n(cargo_toml) {
+        return Vec::new();
+    }
+    let mut results = check_strings(crate_dir, crate_name, config);
+    results.push(check_locales(crate_dir, crate_name, &policy.locales));
+    results
+}
+
+fn uses_i18n(cargo_toml: &str) -> bool {
+    let Ok(manifest) = cargo_toml.parse::<toml::Value>() else {
+        return false;
+    };
+    let deps = manifest.get("dependencies").and_then(|d| d.as_table());
+    deps.is_some_and(|deps| I18N_CRATES.iter().any(|name| deps.contains_key(*name)))
+}
+
+/// Hard-coded markup strings in src/ (honoring `exclude` and generated-code settings)
+fn check_strings(crate_dir: &Path, crate_name: &str, config: &Config) -> Vec<CheckResult> {
+    let label = format!("I18n Strings [{}]", crate_name);
+    let sources = rust_sources(&crate_dir.join("src"), config).unwrap_or_default();
+    let results: Vec<CheckResult> = sources
+        .iter()
+        .filter_map(|source| {
+            let found = hardcoded_strings(&source.content);
+            let (line, text) = found.first()?;
+            let message = format!(
+                "{} hard-coded strings in markup, e.g. \"{}\"",
+                found.len(),
+                text
+            );
+            Some(CheckResult::warn(&label, message).at(&source.path, *line))
+        })
+        .collect();
+    if results.is_empty() {
+        return vec![CheckResult::pass(label, "No hard-coded markup strings")];
+    }
+    results
+}
+
+fn check_locales(crate_dir: &Path, crate_name: &str, locales: &[String]) -> CheckResult {
+    let label = format!("I18n Locales [{}]", crate_name);
+    let missing: Vec<&str> = locales
+        .iter()
+        .filter(|locale| !has_translation(crate_dir, locale))
+        .map(|locale| locale.as_str())
+        .collect();
+    if locales.is_empty() {
+        CheckResult::info(label, "No [i18n] locales configured")
+    } else if missing.is_empty() {
+        CheckResult::pass(label, format!("Translations for {}", locales.join(", ")))
+    } else {
+        CheckResult::fail(label, format!("No translations for {}", missing.join(", ")))
+    }
+}
+
+/// A `<locale>/` directory or `<locale>.<ext>` file in any locale directory
+fn has_translation(crate_dir: &Path, locale: &str) -> bool {
+    LOCALE_DIRS
+        .iter()
+        .map(|dir| crate_dir.join(dir))
+        .any(|dir| {
+            let file = |ext: &&str| dir.join(format!("{}.{}", locale, ext)).is_file();
+            dir.join(locale).is_dir() || LOCALE_EXTENSIONS.iter().any(file)
+        })
+}
@@ -0,0 +1,11 @@
+//! Locale/i18n readiness checks for Web UI crates
+//!
+//! Once a crate adopts an i18n crate, user-facing text in Yew `html!` and
+//! Leptos `view!` markup should come from translations, and every configured
+//! locale needs a translation file.
+
+mod i18n;
+mod strings;
+
+pub use i18n::check_i18n;
+pub use strings::hardcoded_strings;
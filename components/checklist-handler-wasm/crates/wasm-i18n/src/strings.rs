@@ -0,0 +1,63 @@
+//! Hard-coded user-facing string detection in html!/view! markup
+
+/// Markup macros whose bodies render user-facing text
+const MARKUP_MACROS: &[&str] = &["html!", "view!"];
+
+/// 1-based line and text of each string literal rendered as a text node in
+/// markup (`{ "..." }` or `>"..."`), skipping attribute values and
+/// literals without words
+pub fn hardcoded_strings(content: &str) -> Vec<(usize, &str)> {
+    macro_bodies(content)
+        .into_iter()
+        .flat_map(|(start, end)| text_literals(content, start, end))
+        .map(|(offset, text)| (content[..offset].matches('\n').count() + 1, text))
+        .collect()
+}
+
+/// Byte ranges of the bracketed bodies of markup macro invocations
+fn macro_bodies(content: &str) -> Vec<(usize, usize)> {
+    let invocations = MARKUP_MACROS
+        .iter()
+        .flat_map(|m| content.match_indices(m).map(|(i, m)| i + m.len()));
+    invocations
+        .filter_map(|after| {
+            let open = after + content[after..].find(['{', '('])?;
+            let mut depth = 0usize;
+            content[open..].char_indices().find_map(|(i, c)| {
+                match c {
+                    '{' | '(' => depth += 1,
+                    '}' | ')' => depth -= 1,
+                    _ => {}
+                }
+                (depth == 0).then_some((open, open + i))
+            })
+        })
+        .collect()
+}
+
+/// String literals in `content[start..end]` that sit in text-node position
+fn text_literals(content: &str, start: usize, end: usize) -> Vec<(usize, &str)> {
+    let mut literals = Vec::new();
+    let mut quote = None;
+    for (i, c) in content[start..end]
+        .char_indices()
+        .map(|(i, c)| (start + i, c))
+    {
+        match (quote, c) {
+            (None, '"') => quote = Some(i),
+            (Some(open), '"') if !content[..i].ends_with('\\') => {
+                let before = content[..open].trim_end();
+                let text = &content[open + 1..i];
+                let wordy = text
+                    .split_whitespace()
+                    .any(|w| w.chars().filter(|c| c.is_alphabetic()).count() > 1);
+                if wordy && (before.ends_with('{') || before.ends_with('>')) {
+                    literals.push((open, text));
+                }
+                quote = None;
+            }
+            _ => {}
+        }
+    }
+    literals
+}
@@ -0,0 +1,40 @@
+use wasm_i18n::hardcoded_strings;
+
+const VIEW: &str = r#"use yew::prelude::*;
+
+const TITLE: &str = "Not markup";
+
+#[function_component]
+fn App() -> Html {
+    html! {
+        <main class="page wide">
+            <h1>{ "Welcome back" }</h1>
+            <p>"Say \"hi\" to everyone"</p>
+            <span>{ "42" }</span>
+            <button onclick={on_click}>{ t("save") }</button>
+        </main>
+    }
+}
+
+fn footer() -> impl IntoView {
+    view! { <footer>"Built with care"</footer> }
+}
+"#;
+
+#[test]
+fn text_nodes_are_reported_at_their_lines() {
+    assert_eq!(
+        hardcoded_strings(VIEW),
+        [
+            (9, "Welcome back"),
+            (10, r#"Say \"hi\" to everyone"#),
+            (18, "Built with care"),
+        ]
+    );
+}
+
+#[test]
+fn code_outside_markup_is_ignored() {
+    assert!(hardcoded_strings("fn f() { println!(\"Hello there\"); }\n").is_empty());
+    assert!(hardcoded_strings("html! { <img alt=\"A photo\" /> }").is_empty());
+}
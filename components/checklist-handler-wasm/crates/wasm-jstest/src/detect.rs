@@ -0,0 +1,40 @@
+//! JS test setup detection from package.json
+
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+
+/// Test runners recognized from package.json dependencies
+const RUNNERS: &[&str] = &["vitest", "jest", "mocha", "@web/test-runner", "karma"];
+
+/// The script `npm init` writes when a package has no tests
+const NO_TESTS_SCRIPT: &str = "no test specified";
+
+/// A package.json `test` script and the runner it uses, if recognized
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsTestSetup {
+    /// The `scripts.test` command
+    pub script: String,
+    /// The first of jest, vitest, ... found in the dependencies
+    pub runner: Option<String>,
+}
+
+/// The crate's JS test setup: `None` without a package.json, or when its
+/// `test` script is missing or the `npm init` placeholder
+pub fn detect_js_tests(crate_dir: &Path) -> Option<JsTestSetup> {
+    let content = fs::read_to_string(crate_dir.join("package.json")).ok()?;
+    let package: Value = serde_json::from_str(&content).ok()?;
+    let script = package["scripts"]["test"].as_str()?.trim();
+    if script.is_empty() || script.contains(NO_TESTS_SCRIPT) {
+        return None;
+    }
+    let runner = RUNNERS.iter().find(|runner| {
+        ["dependencies", "devDependencies"]
+            .iter()
+            .any(|section| package[section].get(**runner).is_some())
+    });
+    Some(JsTestSetup {
+        script: script.to_string(),
+        runner: runner.map(|r| r.to_string()),
+    })
+}
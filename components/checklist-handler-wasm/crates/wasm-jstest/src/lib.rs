@@ -0,0 +1,11 @@
+//! JS test suites for Web UIs
+//!
+//! Detects a JS test setup in a Web UI crate's `package.json` (a real `test`
+//! script, typically Jest or Vitest) and, when `[js_tests] check` is enabled,
+//! runs `npm test` with a timeout and folds the outcome into the checklist.
+
+mod detect;
+mod run;
+
+pub use detect::{JsTestSetup, detect_js_tests};
+pub use run::check_js_tests;
@@ -0,0 +1,69 @@
+//! Running `npm test`
+
+use checklist_config::JsTestsConfig;
+use checklist_exec::{Exec, ExecError, ExecOutput};
+use checklist_result::CheckResult;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::detect::{JsTestSetup, detect_js_tests};
+
+/// Seconds before `npm test` is killed unless `[js_tests] timeout_secs` is set
+const DEFAULT_TIMEOUT_SECS: u64 = 300;
+
+/// Output lines kept from a failing run
+const TAIL_LINES: usize = 5;
+
+/// Run `npm test` (with `CI=true` so Jest and Vitest don't watch) for a crate
+/// with a JS test setup; nothing unless `[js_tests] check` is enabled
+pub fn check_js_tests(crate_dir: &Path, crate_name: &str, js: &JsTestsConfig) -> Vec<CheckResult> {
+    if !js.check {
+        return Vec::new();
+    }
+    let label = format!("JS Tests [{}]", crate_name);
+    let Some(setup) = detect_js_tests(crate_dir) else {
+        return vec![CheckResult::info(label, "No package.json test script")];
+    };
+    if !crate_dir.join("node_modules").is_dir() {
+        return vec![CheckResult::warn(
+            label,
+            "node_modules/ missing; run npm install (or npm ci) first",
+        )];
+    }
+    let timeout = Duration::from_secs(js.timeout_secs.unwrap_or(DEFAULT_TIMEOUT_SECS));
+    let outcome = Exec::new("npm")
+        .args(["test"])
+        .env("CI", "true")
+        .current_dir(crate_dir)
+        .timeout(timeout)
+        .run();
+    vec![outcome_result(label, &setup, outcome)]
+}
+
+/// Pass with the runner and time, fail with the output tail or timeout, and
+/// warn when npm itself can't be run
+fn outcome_result(
+    label: String,
+    setup: &JsTestSetup,
+    outcome: Result<ExecOutput, ExecError>,
+) -> CheckResult {
+    let runner = setup.runner.as_deref().unwrap_or(&setup.script);
+    match outcome {
+        Ok(output) if output.success() => CheckResult::pass(
+            label,
+            format!(
+                "npm test passed ({}) in {:.1}s",
+                runner,
+                output.elapsed.as_secs_f64()
+            ),
+        ),
+        Ok(output) => {
+            let combined = format!("{}\n{}", output.stdout, output.stderr);
+            let lines: Vec<&str> = combined.lines().filter(|l| !l.trim().is_empty()).collect();
+            let tail = lines[lines.len().saturating_sub(TAIL_LINES)..].join(" | ");
+            CheckResult::fail(label, format!("npm test failed ({}): {}", runner, tail))
+        }
+        Err(e @ ExecError::Timeout { .. }) => CheckResult::fail(label, e.to_string()),
+        Err(e) => CheckResult::warn(label, e.to_string()),
+    }
+}
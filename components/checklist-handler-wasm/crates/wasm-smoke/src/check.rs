@@ -0,0 +1,104 @@
+//! Smoke test orchestration and results
+
+use checklist_config::HttpSmokeConfig;
+use checklist_result::CheckResult;
+use std::fs;
+use std::net::SocketAddr;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::console::check_console;
+use crate::fetch::get;
+use crate::serve::serve;
+
+/// How long the server waits for the smoke test's requests
+const SERVE_FOR: Duration = Duration::from_secs(30);
+
+/// A URL path to fetch, the content type it must be served as, and a check
+/// that the body really is that kind of file
+type Target = (String, &'static str, fn(&[u8]) -> bool);
+
+/// Serve dist/ locally and GET `/`, `/favicon.ico`, and the .wasm bundle,
+/// failing on non-200 responses, wrong content types, or bodies that aren't
+/// HTML, an ICO image, or WebAssembly respectively; with `browser`, also
+/// loads `/` headless and fails on console errors
+pub fn check_http_smoke(
+    crate_dir: &Path,
+    crate_name: &str,
+    smoke: &HttpSmokeConfig,
+) -> Vec<CheckResult> {
+    let label = format!("HTTP Smoke [{}]", crate_name);
+    let dist = crate_dir.join("dist");
+    if !dist.is_dir() {
+        return vec![CheckResult::info(
+            label,
+            "No dist/ to serve (run trunk build)",
+        )];
+    }
+    let Some(targets) = targets(&dist) else {
+        return vec![CheckResult::fail(label, "dist/ has no .wasm bundle")];
+    };
+    let mut results = fetch_all(&label, &dist, &targets);
+    if smoke.browser {
+        let label = format!("Browser Console [{}]", crate_name);
+        results.push(check_console(&label, &dist));
+    }
+    results
+}
+
+/// Serve dist/ for the targets and GET each one
+fn fetch_all(label: &str, dist: &Path, targets: &[Target]) -> Vec<CheckResult> {
+    match serve(dist, targets.len(), SERVE_FOR) {
+        Ok(addr) => targets
+            .iter()
+            .map(|t| fetch_result(label, addr, t))
+            .collect(),
+        Err(e) => vec![CheckResult::warn(
+            label,
+            format!("Could not serve dist/: {}", e),
+        )],
+    }
+}
+
+/// The index, favicon, and first .wasm bundle in dist/ (None without a bundle)
+fn targets(dist: &Path) -> Option<Vec<Target>> {
+    let wasm = fs::read_dir(dist)
+        .ok()?
+        .filter_map(|e| e.ok())
+        .map(|e| e.file_name().to_string_lossy().into_owned())
+        .find(|name| name.ends_with(".wasm"))?;
+    Some(vec![
+        ("/".to_string(), "text/html", |b| {
+            let start = String::from_utf8_lossy(&b[..b.len().min(512)]).to_lowercase();
+            start.trim_start().starts_with("<!doctype html") || start.contains("<html")
+        }),
+        ("/favicon.ico".to_string(), "image/x-icon", |b| {
+            b.starts_with(&[0, 0, 1, 0])
+        }),
+        (format!("/{}", wasm), "application/wasm", |b| {
+            b.starts_with(b"\0asm")
+        }),
+    ])
+}
+
+/// GET one target and judge its status, content type, and body
+fn fetch_result(label: &str, addr: SocketAddr, target: &Target) -> CheckResult {
+    let (path, expected, is_kind) = target;
+    let response = match get(addr, path) {
+        Ok(response) => response,
+        Err(e) => return CheckResult::fail(label, format!("GET {} failed: {}", path, e)),
+    };
+    let problem = if response.status != 200 {
+        format!("returned {}", response.status)
+    } else if !response.content_type.starts_with(expected) {
+        format!(
+            "served as '{}' (expected {})",
+            response.content_type, expected
+        )
+    } else if !is_kind(&response.body) {
+        format!("body is not valid {}", expected)
+    } else {
+        return CheckResult::pass(label, format!("GET {}: 200 {}", path, expected));
+    };
+    CheckResult::fail(label, format!("GET {} {}", path, problem))
+}
@@ -0,0 +1,95 @@
+//! Headless browser load with console error capture
+
+use checklist_exec::{Exec, ExecError};
+use checklist_result::CheckResult;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::serve::serve;
+
+/// Chrome/Chromium executables tried in order
+const BROWSERS: &[&str] = &[
+    "chromium",
+    "chromium-browser",
+    "google-chrome",
+    "google-chrome-stable",
+];
+
+/// Virtual time the page gets to fetch and start its WASM before the DOM is
+/// dumped and the browser exits
+const LOAD_BUDGET_MS: u64 = 5000;
+
+/// Wall-clock limit for the browser run (and the server behind it)
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Console text that marks an error even when logged below ERROR level:
+/// uncaught exceptions, Rust panics, WASM traps, and failed loads
+const ERROR_MARKERS: &[&str] = &[
+    "uncaught",
+    "panicked at",
+    "runtimeerror: unreachable",
+    "failed to load resource",
+];
+
+/// Load `/` from dist/ in headless Chrome/Chromium and fail on any console
+/// error during the initial load; INFO when no browser is installed
+pub(crate) fn check_console(label: &str, dist: &Path) -> CheckResult {
+    let addr = match serve(dist, usize::MAX, TIMEOUT) {
+        Ok(addr) => addr,
+        Err(e) => return CheckResult::warn(label, format!("Could not serve dist/: {}", e)),
+    };
+    let budget = format!("--virtual-time-budget={}", LOAD_BUDGET_MS);
+    let url = format!("http://{}/", addr);
+    let args = [
+        "--headless",
+        "--disable-gpu",
+        "--enable-logging=stderr",
+        "--v=0",
+        &budget,
+        "--dump-dom",
+        &url,
+    ];
+    let outcome = BROWSERS.iter().find_map(|browser| {
+        match Exec::new(browser).args(args).timeout(TIMEOUT).run() {
+            Err(ExecError::Spawn { .. }) => None,
+            outcome => Some((browser, outcome)),
+        }
+    });
+    let Some((browser, outcome)) = outcome else {
+        return CheckResult::info(label, "No Chrome/Chromium on PATH; console check skipped");
+    };
+    let errors = match outcome {
+        Ok(output) => console_errors(&output.stderr),
+        Err(e) => return CheckResult::warn(label, e.to_string()),
+    };
+    match errors.first() {
+        None => CheckResult::pass(
+            label,
+            format!("Loaded / in headless {} with no console errors", browser),
+        ),
+        Some(first) => CheckResult::fail(
+            label,
+            format!(
+                "{} console errors loading /, first: {}",
+                errors.len(),
+                first
+            ),
+        ),
+    }
+}
+
+/// Console messages from Chrome's stderr log (`[...:LEVEL:CONSOLE(n)] "text",
+/// source: url (n)`) logged at ERROR level or matching an error marker
+pub fn console_errors(log: &str) -> Vec<String> {
+    log.lines()
+        .filter(|line| line.contains(":CONSOLE"))
+        .filter_map(|line| {
+            let message = line.split_once("] ").map_or(line, |(_, m)| m);
+            let text = message.rsplit_once(", source:").map_or(message, |(t, _)| t);
+            let lower = text.to_lowercase();
+            let error = line.contains(":ERROR:CONSOLE")
+                || ERROR_MARKERS.iter().any(|marker| lower.contains(marker));
+            error.then(|| message.to_string())
+        })
+        .collect()
+}
@@ -0,0 +1,14 @@
+//! HTTP smoke test for built Web UIs
+//!
+//! Serves a crate's dist/ on an ephemeral localhost port and fetches its entry
+//! points over real HTTP, checking status codes, content types, and that each
+//! body is what its content type claims. Optionally loads the page in
+//! headless Chrome/Chromium and fails on console errors during startup.
+
+mod check;
+mod console;
+mod fetch;
+mod serve;
+
+pub use check::check_http_smoke;
+pub use console::console_errors;
@@ -0,0 +1,85 @@
+//! Minimal static file server for dist/
+
+use std::fs;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How often the listener is polled for a new connection
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Serve files from `dist` on an ephemeral localhost port on a background
+/// thread, stopping after `requests` connections or once `lifetime` passes
+pub(crate) fn serve(dist: &Path, requests: usize, lifetime: Duration) -> io::Result<SocketAddr> {
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    listener.set_nonblocking(true)?;
+    let addr = listener.local_addr()?;
+    let dist = dist.to_path_buf();
+    let deadline = Instant::now() + lifetime;
+    thread::spawn(move || {
+        let mut served = 0;
+        while served < requests && Instant::now() < deadline {
+            match listener.accept() {
+                Ok((stream, _)) => {
+                    served += 1;
+                    let _ = stream
+                        .set_nonblocking(false)
+                        .and_then(|_| respond(&dist, stream));
+                }
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => thread::sleep(POLL_INTERVAL),
+                Err(_) => break,
+            }
+        }
+    });
+    Ok(addr)
+}
+
+/// Answer one GET with the file (index.html for `/`) or a 404
+fn respond(dist: &Path, mut stream: TcpStream) -> io::Result<()> {
+    let path = request_path(&stream)?;
+    let relative = match path.trim_start_matches('/') {
+        "" => "index.html",
+        relative => relative,
+    };
+    let body = (!relative.contains("..")).then(|| fs::read(dist.join(relative)).ok());
+    let Some(Some(body)) = body else {
+        return write!(
+            stream,
+            "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+        );
+    };
+    write!(
+        stream,
+        "HTTP/1.1 200 OK\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        content_type(relative),
+        body.len()
+    )?;
+    stream.write_all(&body)
+}
+
+/// The request target, after reading the whole request head
+fn request_path(stream: &TcpStream) -> io::Result<String> {
+    let mut reader = BufReader::new(stream);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut header = String::new();
+    while reader.read_line(&mut header)? > 2 {
+        header.clear();
+    }
+    let path = request_line.split_whitespace().nth(1).unwrap_or("/");
+    Ok(path.to_string())
+}
+
+/// The Content-Type a static host would send for a file, by extension
+fn content_type(path: &str) -> &'static str {
+    match path.rsplit('.').next().unwrap_or_default() {
+        "html" => "text/html; charset=utf-8",
+        "ico" => "image/x-icon",
+        "wasm" => "application/wasm",
+        "js" => "text/javascript",
+        "css" => "text/css",
+        _ => "application/octet-stream",
+    }
+}
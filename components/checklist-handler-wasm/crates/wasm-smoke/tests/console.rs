@@ -0,0 +1,42 @@
+//! Console error detection in Chrome's stderr log
+
+use wasm_smoke::console_errors;
+
+const SOURCE: &str = ", source: http://127.0.0.1:8080/app.js (12)";
+
+fn line(level: &str, text: &str) -> String {
+    format!(
+        "[1016/120000.123:{}:CONSOLE(12)] \"{}\"{}",
+        level, text, SOURCE
+    )
+}
+
+#[test]
+fn error_level_is_reported() {
+    let log = line("ERROR", "TypeError: x is undefined");
+    let errors = console_errors(&log);
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].starts_with("\"TypeError: x is undefined\", source:"));
+}
+
+#[test]
+fn benign_info_mentioning_error_is_ignored() {
+    let log = [
+        line("INFO", "No error handlers registered"),
+        line("INFO", "error boundary mounted"),
+        line("INFO", "unreachable code path guarded"),
+    ]
+    .join("\n");
+    assert!(console_errors(&log).is_empty());
+}
+
+#[test]
+fn markers_below_error_level_are_reported() {
+    let log = [
+        line("INFO", "panicked at src/lib.rs:10:5: oops"),
+        line("WARNING", "RuntimeError: unreachable executed"),
+        "[1016/120000.123:INFO:gpu_init.cc(1)] unreachable".to_string(),
+    ]
+    .join("\n");
+    assert_eq!(console_errors(&log).len(), 2);
+}
@@ -0,0 +1,85 @@
+//! Modularity findings as LSP diagnostics
+
+use checklist_config::{Config, exempt_legacy, suppress};
+use checklist_result::{CheckResult, CheckStatus};
+use discovery_source::{RustSource, is_generated};
+use handler_modularity::check_module_function_count;
+use modularity_loc::{check_file_loc, check_source_function_locs};
+use serde_json::{Value, json};
+use std::path::Path;
+
+use crate::project::{DocumentCrate, document_config, document_crate};
+use crate::scans::scan_results;
+
+const ERROR: u8 = 1;
+const WARNING: u8 = 2;
+
+/// Compute diagnostics for the text of the Rust source file at `path` (none for
+/// generated code), using the limits, `[suppress]`, and `[[allow_legacy]]` of
+/// the project's `.sw-checklist.toml` and the allow comments in `content`
+pub fn diagnostics(path: &Path, content: &str) -> Vec<Value> {
+    if is_generated(content) {
+        return Vec::new();
+    }
+    let config = document_config(path);
+    let krate = document_crate(path);
+    let mut results = modularity_results(path, content, krate.as_ref(), &config);
+    if let Some(krate) = &krate {
+        results.extend(scan_results(path, content, krate, config.file()));
+    }
+    results
+        .into_iter()
+        .map(|r| suppress(&config, exempt_legacy(&config, r), Some(content)))
+        .filter_map(|r| finding(&r))
+        .collect()
+}
+
+/// The modularity handler's function LOC, file LOC, and module function
+/// count findings for this one file (outside a crate, labelled with its
+/// directory name)
+fn modularity_results(
+    path: &Path,
+    content: &str,
+    krate: Option<&DocumentCrate>,
+    config: &Config,
+) -> Vec<CheckResult> {
+    let source = RustSource {
+        path: path.to_path_buf(),
+        content: content.to_string(),
+    };
+    let dir = path.parent().and_then(|dir| dir.file_name());
+    let crate_name = match krate {
+        Some(krate) => krate.name.clone(),
+        None => dir
+            .map(|d| d.to_string_lossy().into_owned())
+            .unwrap_or_default(),
+    };
+    let limits = &config.file().modularity;
+    let mut results = check_source_function_locs(&source, &crate_name, limits.function_loc);
+    results.extend(check_file_loc(&source, &crate_name, limits.file_loc));
+    results.extend(check_module_function_count(
+        &source,
+        &crate_name,
+        limits.module_functions,
+    ));
+    results
+}
+
+/// A located warning or failure as a diagnostic on its line
+fn finding(result: &CheckResult) -> Option<Value> {
+    let line = result.location.as_ref()?.line.saturating_sub(1);
+    let severity = match result.status {
+        CheckStatus::Fail => ERROR,
+        CheckStatus::Warn => WARNING,
+        _ => return None,
+    };
+    Some(json!({
+        "range": {
+            "start": { "line": line, "character": 0 },
+            "end": { "line": line + 1, "character": 0 }
+        },
+        "severity": severity,
+        "source": "sw-checklist",
+        "message": result.message
+    }))
+}
@@ -0,0 +1,14 @@
+//! Minimal language server for sw-checklist
+//!
+//! Speaks LSP over stdio and publishes modularity findings (function, file,
+//! and module size, with the project's `.sw-checklist.toml` limits) and
+//! source-scan findings (no-print, process-exit, blocking-in-async) as
+//! diagnostics for open Rust files.
+
+mod diagnostics;
+mod project;
+mod protocol;
+mod scans;
+mod server;
+
+pub use server::{run, run_stdio};
@@ -0,0 +1,48 @@
+//! Locating the crate and `.sw-checklist.toml` of an open document
+
+use checklist_config::{CONFIG_FILE_NAME, Config, ConfigBuilder, load_file_config};
+use discovery_crate::{CrateType, detect_crate_type, extract_crate_name};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The crate an open document belongs to
+pub struct DocumentCrate {
+    /// Directory holding the crate's Cargo.toml
+    pub dir: PathBuf,
+    /// Package name
+    pub name: String,
+    /// Cargo.toml content
+    pub cargo_toml: String,
+    /// Detected crate type
+    pub crate_type: CrateType,
+}
+
+/// The crate containing `file`: its nearest ancestor with a `[package]` Cargo.toml
+pub fn document_crate(file: &Path) -> Option<DocumentCrate> {
+    file.ancestors().skip(1).find_map(|dir| {
+        let cargo_toml = fs::read_to_string(dir.join("Cargo.toml")).ok()?;
+        let manifest: toml::Value = cargo_toml.parse().ok()?;
+        manifest.get("package")?;
+        Some(DocumentCrate {
+            dir: dir.to_path_buf(),
+            name: extract_crate_name(&manifest).to_string(),
+            crate_type: detect_crate_type(&cargo_toml, dir),
+            cargo_toml,
+        })
+    })
+}
+
+/// Config rooted at the nearest ancestor of `file` with a `.sw-checklist.toml`;
+/// defaults when none has one or it fails to load
+pub fn document_config(file: &Path) -> Config {
+    let root = file
+        .ancestors()
+        .find(|dir| dir.join(CONFIG_FILE_NAME).is_file());
+    let file_config = root
+        .and_then(|root| load_file_config(root).ok())
+        .unwrap_or_default();
+    ConfigBuilder::new()
+        .project_path(root.unwrap_or(file).to_path_buf())
+        .file_config(file_config)
+        .build()
+}
@@ -0,0 +1,33 @@
+//! LSP base protocol framing (Content-Length headers + JSON body)
+
+use anyhow::{Context, Result};
+use serde_json::Value;
+use std::io::{BufRead, Write};
+
+/// Read the next message, or None at end of input
+pub fn read_message(reader: &mut impl BufRead) -> Result<Option<Value>> {
+    let mut content_length = None;
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 {
+            return Ok(None);
+        }
+        let header = header.trim();
+        if header.is_empty() {
+            break;
+        }
+        if let Some(value) = header.strip_prefix("Content-Length:") {
+            content_length = Some(value.trim().parse::<usize>()?);
+        }
+    }
+    let mut body = vec![0; content_length.context("Message without Content-Length")?];
+    reader.read_exact(&mut body)?;
+    Ok(Some(serde_json::from_slice(&body)?))
+}
+
+/// Write a message with its Content-Length header
+pub fn write_message(writer: &mut impl Write, message: &Value) -> Result<()> {
+    let body = message.to_string();
+    write!(writer, "Content-Length: {}\r\n\r\n{}", body.len(), body)?;
+    Ok(writer.flush()?)
+}
@@ -0,0 +1,36 @@
+//! Source-scan findings (no-print, process-exit, lint) for an open document
+
+use checklist_config::FileConfig;
+use checklist_result::CheckResult;
+use discovery_crate::{CrateType, has_dependency};
+use discovery_source::RustSource;
+use handler_lint::{check_blocking_in_async, check_process_exit};
+use handler_logging::check_no_print;
+use std::path::Path;
+
+use crate::project::DocumentCrate;
+
+/// What the logging and lint handlers report for this one file of `krate`
+pub fn scan_results(
+    path: &Path,
+    content: &str,
+    krate: &DocumentCrate,
+    config: &FileConfig,
+) -> Vec<CheckResult> {
+    let sources = [RustSource {
+        path: path.to_path_buf(),
+        content: content.to_string(),
+    }];
+    let mut results = Vec::new();
+    let library = krate.crate_type == CrateType::Library;
+    if config.selects("logging") && library && !config.crates.is_output(&krate.name, &krate.dir) {
+        results.extend(check_no_print(&sources, &krate.dir, &krate.name));
+    }
+    if config.selects("lint") {
+        results.extend(check_process_exit(&sources, &krate.dir, &krate.name));
+        if has_dependency(&krate.cargo_toml, "tokio") {
+            results.extend(check_blocking_in_async(&sources, &krate.name));
+        }
+    }
+    results
+}
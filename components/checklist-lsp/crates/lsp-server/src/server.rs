@@ -0,0 +1,118 @@
+//! Language server message loop
+
+use anyhow::Result;
+use serde_json::{Value, json};
+use std::io::{self, BufRead, Write};
+use std::path::PathBuf;
+
+use crate::diagnostics::diagnostics;
+use crate::protocol::{read_message, write_message};
+
+/// Serve LSP over stdin/stdout until the client sends `exit`
+pub fn run_stdio() -> Result<()> {
+    run(&mut io::stdin().lock(), &mut io::stdout().lock())
+}
+
+/// Serve LSP over `input` and `output` until `exit` or end of input, logging
+/// and skipping messages that aren't valid JSON-RPC
+pub fn run(input: &mut impl BufRead, output: &mut impl Write) -> Result<()> {
+    loop {
+        let message = match read_message(input) {
+            Ok(Some(message)) => message,
+            Ok(None) => break,
+            Err(e) if e.is::<io::Error>() => return Err(e),
+            Err(e) => {
+                log::warn!("Skipping malformed message: {:#}", e);
+                continue;
+            }
+        };
+        let method = message
+            .get("method")
+            .and_then(|m| m.as_str())
+            .unwrap_or_default();
+        if method == "exit" {
+            break;
+        }
+        for reply in handle(method, &message) {
+            write_message(output, &reply)?;
+        }
+    }
+    Ok(())
+}
+
+fn handle(method: &str, message: &Value) -> Vec<Value> {
+    let id = message.get("id").cloned();
+    match method {
+        "initialize" => vec![response(
+            id,
+            json!({
+                "capabilities": { "textDocumentSync": 1 },
+                "serverInfo": { "name": "sw-checklist", "version": env!("CARGO_PKG_VERSION") }
+            }),
+        )],
+        "shutdown" => vec![response(id, Value::Null)],
+        "textDocument/didOpen" | "textDocument/didChange" | "textDocument/didClose" => {
+            publish(message)
+        }
+        _ if id.is_some() => vec![json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "error": { "code": -32601, "message": format!("Method not found: {}", method) }
+        })],
+        _ => Vec::new(),
+    }
+}
+
+/// Publish diagnostics for a Rust file's full text (clearing them on close)
+fn publish(message: &Value) -> Vec<Value> {
+    let uri = message
+        .pointer("/params/textDocument/uri")
+        .and_then(|u| u.as_str());
+    let Some(uri) = uri.filter(|u| u.ends_with(".rs")) else {
+        return Vec::new();
+    };
+    let changes = message
+        .pointer("/params/contentChanges")
+        .and_then(|c| c.as_array());
+    let text = message
+        .pointer("/params/textDocument/text")
+        .or_else(|| changes.and_then(|c| c.last()).and_then(|c| c.get("text")))
+        .and_then(|t| t.as_str());
+    let path = uri_path(uri);
+    let found = text
+        .map(|text| diagnostics(&path, text))
+        .unwrap_or_default();
+    vec![json!({
+        "jsonrpc": "2.0",
+        "method": "textDocument/publishDiagnostics",
+        "params": { "uri": uri, "diagnostics": found }
+    })]
+}
+
+fn response(id: Option<Value>, result: Value) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "result": result })
+}
+
+/// The filesystem path of a `file://` URI, percent-decoded (`%20` is a space)
+fn uri_path(uri: &str) -> PathBuf {
+    let encoded = uri.strip_prefix("file://").unwrap_or(uri).as_bytes();
+    let mut bytes = Vec::with_capacity(encoded.len());
+    let mut i = 0;
+    while i < encoded.len() {
+        let escaped = encoded
+            .get(i + 1..i + 3)
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (encoded[i], escaped) {
+            (b'%', Some(byte)) => {
+                bytes.push(byte);
+                i += 3;
+            }
+            (byte, _) => {
+                bytes.push(byte);
+                i += 1;
+            }
+        }
+    }
+    PathBuf::from(String::from_utf8_lossy(&bytes).into_owned())
+}
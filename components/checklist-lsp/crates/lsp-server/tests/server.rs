@@ -0,0 +1,125 @@
+use checklist_tempdir::TempWorkspace;
+use serde_json::{Value, json};
+use std::fs;
+use std::io::Cursor;
+use std::path::Path;
+
+/// Frame `body` with a Content-Length header
+fn frame(body: &str) -> String {
+    format!("Content-Length: {}\r\n\r\n{}", body.len(), body)
+}
+
+/// Run the server over `input` and return the messages it wrote
+fn exchange(input: &str) -> Vec<Value> {
+    let mut output = Vec::new();
+    lsp_server::run(&mut Cursor::new(input.as_bytes()), &mut output).unwrap();
+    let output = String::from_utf8(output).unwrap();
+    output
+        .split("Content-Length: ")
+        .filter_map(|m| m.split_once("\r\n\r\n"))
+        .map(|(_, body)| serde_json::from_str(body).unwrap())
+        .collect()
+}
+
+/// A 32-line function that prints, optionally with its length allowed inline
+fn long_function(allow: bool) -> String {
+    let body = "    println!(\"hi\");\n".repeat(30);
+    let allow = if allow {
+        "// sw-checklist: allow(function-loc)\n"
+    } else {
+        ""
+    };
+    format!("{}pub fn long() {{\n{}}}\n", allow, body)
+}
+
+fn open(path: &Path, text: &str) -> String {
+    let uri = format!("file://{}", path.display()).replace(' ', "%20");
+    let params = json!({ "textDocument": { "uri": uri, "text": text } });
+    frame(
+        &json!({ "jsonrpc": "2.0", "method": "textDocument/didOpen", "params": params })
+            .to_string(),
+    )
+}
+
+fn crate_dir(workspace: &TempWorkspace, config: &str) -> std::path::PathBuf {
+    let dir = workspace.path().join("my crate");
+    fs::create_dir_all(dir.join("src")).unwrap();
+    fs::write(
+        dir.join("Cargo.toml"),
+        "[package]\nname = \"demo\"\nversion = \"0.1.0\"\nedition = \"2024\"\n",
+    )
+    .unwrap();
+    fs::write(dir.join(".sw-checklist.toml"), config).unwrap();
+    dir
+}
+
+fn diagnostics(messages: &[Value]) -> Vec<String> {
+    messages[0]["params"]["diagnostics"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|d| d["message"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[test]
+fn answers_requests_and_skips_malformed_messages() {
+    let input = [
+        frame(r#"{"jsonrpc":"2.0","id":1,"method":"initialize","params":{}}"#),
+        frame("{not json"),
+        "X-Other: 1\r\n\r\n".to_string(),
+        frame(r#"{"jsonrpc":"2.0","id":2,"method":"shutdown"}"#),
+        frame(r#"{"jsonrpc":"2.0","method":"exit"}"#),
+        frame(r#"{"jsonrpc":"2.0","id":3,"method":"shutdown"}"#),
+    ]
+    .concat();
+    let replies = exchange(&input);
+    assert_eq!(replies.len(), 2, "{:?}", replies);
+    assert_eq!(replies[0]["id"], 1);
+    assert_eq!(replies[0]["result"]["serverInfo"]["name"], "sw-checklist");
+    assert_eq!(replies[1]["id"], 2);
+}
+
+#[test]
+fn decodes_file_uris() {
+    let workspace = TempWorkspace::new("lsp").unwrap();
+    let dir = crate_dir(&workspace, "");
+    let replies = exchange(&open(&dir.join("src/lib.rs"), &long_function(false)));
+    let found = diagnostics(&replies);
+    assert_eq!(found.len(), 2, "{:?}", found);
+    assert_eq!(found[0], "'long' in lib.rs has 32 lines (warning >25)");
+    assert!(found[1].contains("println!/eprintln!"), "{}", found[1]);
+}
+
+#[test]
+fn honors_allow_comments_in_the_buffer() {
+    let workspace = TempWorkspace::new("lsp").unwrap();
+    let dir = crate_dir(&workspace, "");
+    let replies = exchange(&open(&dir.join("src/lib.rs"), &long_function(true)));
+    let found = diagnostics(&replies);
+    assert_eq!(found.len(), 1, "{:?}", found);
+    assert!(found[0].contains("println!/eprintln!"), "{}", found[0]);
+}
+
+#[test]
+fn honors_suppress_entries() {
+    let workspace = TempWorkspace::new("lsp").unwrap();
+    let dir = crate_dir(&workspace, "[suppress]\n\"Function LOC\" = [\"demo\"]\n");
+    let replies = exchange(&open(&dir.join("src/lib.rs"), &long_function(false)));
+    let found = diagnostics(&replies);
+    assert_eq!(found.len(), 1, "{:?}", found);
+    assert!(found[0].contains("println!/eprintln!"), "{}", found[0]);
+}
+
+#[test]
+fn module_function_counts_include_nested_functions() {
+    let workspace = TempWorkspace::new("lsp").unwrap();
+    let dir = crate_dir(&workspace, "");
+    let nested = "    fn inner() {}\n".repeat(7);
+    let text = format!("pub fn outer() {{\n{}}}\n", nested);
+    let replies = exchange(&open(&dir.join("src/lib.rs"), &text));
+    assert_eq!(
+        diagnostics(&replies),
+        ["Module lib.rs has 8 functions (max 7)"]
+    );
+}
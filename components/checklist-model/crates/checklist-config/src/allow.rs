@@ -0,0 +1,131 @@
+//! Grandfathered (allow_legacy) exemptions and suppressions
+
+use anyhow::{Result, bail};
+use checklist_result::{CheckResult, CheckStatus, Disposition, slugify};
+use serde::Deserialize;
+use std::borrow::Cow;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::config::Config;
+
+/// An `[[allow_legacy]]` entry: checks permanently exempted for a crate or file
+#[derive(Debug, Clone, Deserialize)]
+pub struct AllowLegacy {
+    /// Crate name the exemption applies to
+    #[serde(rename = "crate")]
+    pub crate_name: Option<String>,
+    /// File (relative to the project root) the exemption applies to
+    pub file: Option<PathBuf>,
+    /// Check IDs (e.g. "function-loc") or titles exempted
+    pub checks: Vec<String>,
+    /// Why the exemption exists (required, shown with exempted results)
+    pub justification: String,
+}
+
+impl AllowLegacy {
+    /// Ensure the entry names a crate or file and has a justification
+    pub fn validate(&self) -> Result<()> {
+        if self.crate_name.is_none() && self.file.is_none() {
+            bail!(
+                "allow_legacy entry for {:?} needs a `crate` or `file`",
+                self.checks
+            );
+        }
+        if self.justification.trim().is_empty() {
+            bail!(
+                "allow_legacy entry for {:?} needs a justification",
+                self.checks
+            );
+        }
+        Ok(())
+    }
+
+    fn covers(&self, result: &CheckResult) -> bool {
+        let check_id = result.check_id();
+        if !self.checks.iter().any(|c| slugify(c) == check_id) {
+            return false;
+        }
+        let crate_match = self
+            .crate_name
+            .as_deref()
+            .is_some_and(|c| result.subject() == Some(c));
+        let file_match = match (&self.file, &result.location) {
+            (Some(file), Some(loc)) => loc.file.ends_with(file),
+            _ => false,
+        };
+        crate_match || file_match
+    }
+}
+
+/// Turn an issue covered by an allow_legacy entry into INFO carrying the justification
+pub fn exempt_legacy(config: &Config, result: CheckResult) -> CheckResult {
+    if !matches!(result.status, CheckStatus::Fail | CheckStatus::Warn) {
+        return result;
+    }
+    match config.file.allow_legacy.iter().find(|a| a.covers(&result)) {
+        Some(allow) => CheckResult {
+            status: CheckStatus::Info,
+            message: format!(
+                "{} (allowed legacy: {})",
+                result.message, allow.justification
+            ),
+            ..result
+        },
+        None => result,
+    }
+}
+
+/// Turn an issue acknowledged by a `// sw-checklist: allow(<check-id>)`
+/// comment (on or just above its line) or a `[suppress]` entry into INFO;
+/// allow comments are read from `source` when given (e.g. an editor's unsaved
+/// buffer), else from the result's file
+pub fn suppress(config: &Config, result: CheckResult, source: Option<&str>) -> CheckResult {
+    if !matches!(result.status, CheckStatus::Fail | CheckStatus::Warn) {
+        return result;
+    }
+    let check_id = result.check_id();
+    if !allowed_by_config(config, &result, &check_id) && !allowed_inline(&result, &check_id, source)
+    {
+        return result;
+    }
+    result.disposed(Disposition::Suppressed)
+}
+
+/// Whether a result was suppressed (counted separately in the summary)
+pub fn is_suppressed(result: &CheckResult) -> bool {
+    result.disposition == Some(Disposition::Suppressed)
+}
+
+fn allowed_by_config(config: &Config, result: &CheckResult, check_id: &str) -> bool {
+    config
+        .file
+        .suppress
+        .iter()
+        .filter(|(check, _)| slugify(check) == check_id)
+        .flat_map(|(_, targets)| targets)
+        .any(|target| {
+            result.subject() == Some(target.as_str())
+                || result
+                    .location
+                    .as_ref()
+                    .is_some_and(|l| l.file.ends_with(target))
+        })
+}
+
+fn allowed_inline(result: &CheckResult, check_id: &str, source: Option<&str>) -> bool {
+    let Some(loc) = &result.location else {
+        return false;
+    };
+    let content = match source {
+        Some(source) => Cow::Borrowed(source),
+        None => Cow::Owned(fs::read_to_string(&loc.file).unwrap_or_default()),
+    };
+    let lines: Vec<&str> = content.lines().collect();
+    let first = loc.line.saturating_sub(2);
+    lines.iter().skip(first).take(loc.line - first).any(|line| {
+        line.split_once("sw-checklist: allow(")
+            .and_then(|(_, rest)| rest.split_once(')'))
+            .is_some_and(|(ids, _)| ids.split(',').any(|id| slugify(id.trim()) == check_id))
+    })
+}
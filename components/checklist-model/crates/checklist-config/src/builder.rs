@@ -1,13 +1,24 @@
 //! Configuration builder
 
 use crate::config::Config;
+use crate::file::FileConfig;
+use crate::output::OutputFormat;
 use std::path::PathBuf;
 
+/// Issues shown per check outside verbose mode unless overridden
+const DEFAULT_MAX_PER_CHECK: usize = 5;
+
 /// Builder for Config
 #[derive(Debug, Default)]
 pub struct ConfigBuilder {
     project_path: Option<PathBuf>,
-    verbose: bool,
+    verbose: u8,
+    pub(crate) format: OutputFormat,
+    pub(crate) rollup: Option<usize>,
+    pub(crate) max_per_check: Option<usize>,
+    ratchet: bool,
+    fix: bool,
+    file: FileConfig,
 }
 
 impl ConfigBuilder {
@@ -22,15 +33,41 @@ impl ConfigBuilder {
         self
     }
 
-    /// Set verbose mode
-    pub fn verbose(mut self, verbose: bool) -> Self {
-        self.verbose = verbose;
+    /// Set the verbosity level (1: all results, 2: also skipped handlers)
+    pub fn verbose(mut self, level: u8) -> Self {
+        self.verbose = level;
+        self
+    }
+
+    /// Enable ratchet mode
+    pub fn ratchet(mut self, ratchet: bool) -> Self {
+        self.ratchet = ratchet;
+        self
+    }
+
+    /// Enable fix mode (checks that support it repair what they find)
+    pub fn fix(mut self, fix: bool) -> Self {
+        self.fix = fix;
+        self
+    }
+
+    /// Apply settings loaded from .sw-checklist.toml
+    pub fn file_config(mut self, file: FileConfig) -> Self {
+        self.file = file;
         self
     }
 
     /// Build the Config
     pub fn build(self) -> Config {
-        let path = self.project_path.unwrap_or_else(|| PathBuf::from("."));
-        crate::config::new(path, self.verbose)
+        Config {
+            project_path: self.project_path.unwrap_or_else(|| PathBuf::from(".")),
+            verbose: self.verbose,
+            format: self.format,
+            rollup: self.rollup,
+            max_per_check: self.max_per_check.unwrap_or(DEFAULT_MAX_PER_CHECK),
+            ratchet: self.ratchet,
+            fix: self.fix,
+            file: self.file,
+        }
     }
 }
@@ -1,20 +1,20 @@
 //! Configuration struct
 
+use crate::file::FileConfig;
+use crate::output::OutputFormat;
 use std::path::{Path, PathBuf};
 
 /// Configuration for sw-checklist run
 #[derive(Debug, Clone)]
 pub struct Config {
-    project_path: PathBuf,
-    verbose: bool,
-}
-
-/// Create a new Config
-pub fn new(project_path: PathBuf, verbose: bool) -> Config {
-    Config {
-        project_path,
-        verbose,
-    }
+    pub(crate) project_path: PathBuf,
+    pub(crate) verbose: u8,
+    pub(crate) format: OutputFormat,
+    pub(crate) rollup: Option<usize>,
+    pub(crate) max_per_check: usize,
+    pub(crate) ratchet: bool,
+    pub(crate) fix: bool,
+    pub(crate) file: FileConfig,
 }
 
 impl Config {
@@ -25,6 +25,26 @@ impl Config {
 
     /// Check if verbose mode is enabled
     pub fn verbose(&self) -> bool {
-        self.verbose
+        self.verbose > 0
+    }
+
+    /// Check if skipped handler/crate combinations should be listed (-vv)
+    pub fn show_skipped(&self) -> bool {
+        self.verbose > 1
+    }
+
+    /// Check if ratchet mode is enabled (fail only on metric regressions)
+    pub fn ratchet(&self) -> bool {
+        self.ratchet
+    }
+
+    /// Check if fix mode is enabled (checks that support it repair what they find)
+    pub fn fix(&self) -> bool {
+        self.fix
+    }
+
+    /// Get the settings loaded from .sw-checklist.toml
+    pub fn file(&self) -> &FileConfig {
+        &self.file
     }
 }
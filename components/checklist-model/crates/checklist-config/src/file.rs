@@ -0,0 +1,141 @@
+//! Project configuration file (.sw-checklist.toml)
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::allow::AllowLegacy;
+use crate::sections::{
+    A11yConfig, BudgetsConfig, DepsConfig, GeneratedConfig, HeaderConfig, HttpSmokeConfig,
+    HygieneConfig, I18nConfig, JsTestsConfig, LintsConfig, ModularityConfig, NamingConfig,
+    PatchesConfig, ReleaseProfileConfig, SemverConfig, StartupConfig, TextConfig, ToolchainConfig,
+    UnusedDepsConfig,
+};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Name of the project configuration file
+pub const CONFIG_FILE_NAME: &str = ".sw-checklist.toml";
+
+/// Settings loaded from .sw-checklist.toml
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct FileConfig {
+    /// Crate classification settings
+    pub crates: CratesConfig,
+    /// Checks permanently exempted for specific crates or files
+    pub allow_legacy: Vec<AllowLegacy>,
+    /// Known violations acknowledged per check ID, by crate name or file path
+    pub suppress: BTreeMap<String, Vec<String>>,
+    /// Modularity thresholds (function, file, module, crate, and workspace sizes)
+    pub modularity: ModularityConfig,
+    /// Size budgets
+    pub budgets: BudgetsConfig,
+    /// Generated-code handling
+    pub generated: GeneratedConfig,
+    /// Toolchain pinning policy
+    pub toolchain: ToolchainConfig,
+    /// Repository hygiene policy
+    pub hygiene: HygieneConfig,
+    /// Text file encoding and line ending policy
+    pub text: TextConfig,
+    /// Copyright header policy for source files
+    pub header: HeaderConfig,
+    /// Web UI localization readiness
+    pub i18n: I18nConfig,
+    /// Semver compatibility checking
+    pub semver: SemverConfig,
+    /// Baseline lint policy
+    pub lints: LintsConfig,
+    /// CLI startup time budget
+    pub startup: StartupConfig,
+    /// CLI binary naming policy
+    pub naming: NamingConfig,
+    /// Unused dependency detection
+    pub unused_deps: UnusedDepsConfig,
+    /// Internal dependency graph limits
+    pub deps: DepsConfig,
+    /// HTTP smoke test of built Web UIs
+    pub http_smoke: HttpSmokeConfig,
+    /// `npm test` runs for Web UIs with JS test suites
+    pub js_tests: JsTestsConfig,
+    /// Accessibility checks for Web UIs
+    pub a11y: A11yConfig,
+    /// Allowlisted `[patch]` / `[replace]` entries
+    pub patches: PatchesConfig,
+    /// Panic strategy and overflow-check policy for release builds
+    pub release_profile: ReleaseProfileConfig,
+    /// Language for report text (`--lang`); check IDs and machine-readable
+    /// output stay English
+    pub lang: Option<String>,
+    /// Crate types forced by crate name or path suffix, overriding detection
+    pub crate_type_overrides: BTreeMap<String, String>,
+    /// Run only these handlers (`--only`; empty: all)
+    pub only: Vec<String>,
+    /// Never run these handlers (`--skip`)
+    pub skip: Vec<String>,
+    /// Warnings fail the exit code too (`--strict`), for zero-warning CI runs
+    pub strict: bool,
+    /// Path globs relative to the project root (vendored code, examples,
+    /// generated directories) left out of crate discovery and source walks
+    pub exclude: Vec<String>,
+}
+
+impl FileConfig {
+    /// The configured crate type name for a crate (by name or path suffix), if any
+    pub fn crate_type_override(&self, crate_name: &str, crate_dir: &Path) -> Option<&str> {
+        self.crate_type_overrides
+            .iter()
+            .find(|(key, _)| key.as_str() == crate_name || crate_dir.ends_with(key))
+            .map(|(_, crate_type)| crate_type.as_str())
+    }
+
+    /// Whether the handler named `handler` is selected by `only` and `skip`
+    pub fn selects(&self, handler: &str) -> bool {
+        let only = self.only.is_empty() || self.only.iter().any(|h| h == handler);
+        only && !self.skip.iter().any(|h| h == handler)
+    }
+}
+
+/// The `[crates]` section
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct CratesConfig {
+    /// Crate names or paths that are build tooling (xtask pattern)
+    pub tooling: Vec<String>,
+    /// Crate names or paths whose purpose is terminal output (may use println!)
+    pub output: Vec<String>,
+}
+
+impl CratesConfig {
+    /// Check if a crate is configured as build tooling (by name or path suffix)
+    pub fn is_tooling(&self, crate_name: &str, crate_dir: &Path) -> bool {
+        self.tooling
+            .iter()
+            .any(|t| t == crate_name || crate_dir.ends_with(t))
+    }
+
+    /// Check if a crate is configured as a terminal output crate (by name or path suffix)
+    pub fn is_output(&self, crate_name: &str, crate_dir: &Path) -> bool {
+        self.output
+            .iter()
+            .any(|t| t == crate_name || crate_dir.ends_with(t))
+    }
+}
+
+/// Load .sw-checklist.toml from the project root (defaults if absent)
+pub fn load_file_config(project_root: &Path) -> Result<FileConfig> {
+    let path = project_root.join(CONFIG_FILE_NAME);
+    if !path.exists() {
+        return Ok(FileConfig::default());
+    }
+    let content = std::fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    let file: FileConfig =
+        toml::from_str(&content).with_context(|| format!("Failed to parse {}", path.display()))?;
+    for allow in &file.allow_legacy {
+        allow
+            .validate()
+            .with_context(|| format!("Invalid {}", path.display()))?;
+    }
+    Ok(file)
+}
@@ -1,9 +1,23 @@
 //! Configuration types for sw-checklist
 //!
-//! This crate provides configuration structures built from CLI arguments.
+//! This crate provides configuration structures built from CLI arguments
+//! and the project's .sw-checklist.toml file.
 
+mod allow;
 mod builder;
 mod config;
+mod file;
+mod output;
+mod sections;
 
+pub use allow::{AllowLegacy, exempt_legacy, is_suppressed, suppress};
 pub use builder::ConfigBuilder;
 pub use config::Config;
+pub use file::{CONFIG_FILE_NAME, CratesConfig, FileConfig, load_file_config};
+pub use output::{DOCUMENT_FORMATS, FORMAT_NAMES, OutputFormat};
+pub use sections::{
+    A11yConfig, BudgetsConfig, DepsConfig, GeneratedConfig, HeaderConfig, HttpSmokeConfig,
+    HygieneConfig, I18nConfig, JsTestsConfig, Limit, LintsConfig, ModularityConfig, NamingConfig,
+    PanicStrategy, PatchesConfig, ReleaseProfileConfig, SemverConfig, StartupConfig, TextConfig,
+    ToolchainConfig, ToolchainPolicy, UnusedDepsConfig,
+};
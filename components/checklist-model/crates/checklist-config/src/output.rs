@@ -0,0 +1,98 @@
+//! Output format selection
+
+use crate::builder::ConfigBuilder;
+use crate::config::Config;
+use std::str::FromStr;
+
+/// How check results are printed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human-readable results and summary
+    #[default]
+    Text,
+    /// `file:line:col: severity: message` lines for editor quickfix lists
+    Quickfix,
+    /// Text output with rustc-style code frames for located findings
+    Annotated,
+    /// One JSON document with every result and the summary counts
+    Json,
+    /// A self-contained HTML report with collapsible per-crate sections
+    Html,
+    /// JUnit XML with a testcase per result, for CI test report UIs
+    Junit,
+    /// A SARIF 2.1.0 log of failures and warnings, for code scanning UIs
+    Sarif,
+    /// The Markdown report posted as a PR comment
+    Markdown,
+}
+
+/// Each format's `--format` name
+pub const FORMAT_NAMES: &[(&str, OutputFormat)] = &[
+    ("text", OutputFormat::Text),
+    ("quickfix", OutputFormat::Quickfix),
+    ("annotated", OutputFormat::Annotated),
+    ("json", OutputFormat::Json),
+    ("html", OutputFormat::Html),
+    ("junit", OutputFormat::Junit),
+    ("sarif", OutputFormat::Sarif),
+    ("markdown", OutputFormat::Markdown),
+];
+
+/// Formats that render the whole run as one document (and can go to `--output`)
+pub const DOCUMENT_FORMATS: &[OutputFormat] = &[
+    OutputFormat::Json,
+    OutputFormat::Html,
+    OutputFormat::Junit,
+    OutputFormat::Sarif,
+    OutputFormat::Markdown,
+];
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let names: Vec<&str> = FORMAT_NAMES.iter().map(|(name, _)| *name).collect();
+        FORMAT_NAMES
+            .iter()
+            .find(|(name, _)| *name == s)
+            .map(|(_, format)| *format)
+            .ok_or_else(|| format!("unknown format '{}' (expected {})", s, names.join(", ")))
+    }
+}
+
+impl Config {
+    /// Get the output format
+    pub fn format(&self) -> OutputFormat {
+        self.format
+    }
+
+    /// Maximum similar findings to show before rolling them up (None: no roll-up)
+    pub fn rollup(&self) -> Option<usize> {
+        self.rollup
+    }
+
+    /// Maximum issues shown per check in the default (non-verbose) output
+    pub fn max_per_check(&self) -> usize {
+        self.max_per_check
+    }
+}
+
+impl ConfigBuilder {
+    /// Set the output format
+    pub fn format(mut self, format: OutputFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Roll up more than `max_similar` findings with the same check and status
+    pub fn rollup(mut self, max_similar: Option<usize>) -> Self {
+        self.rollup = max_similar;
+        self
+    }
+
+    /// Set the maximum issues shown per check outside verbose mode
+    pub fn max_per_check(mut self, max: Option<usize>) -> Self {
+        self.max_per_check = max;
+        self
+    }
+}
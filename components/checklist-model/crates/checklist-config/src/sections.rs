@@ -0,0 +1,325 @@
+//! Policy sections of .sw-checklist.toml
+
+use serde::Deserialize;
+use std::path::Path;
+
+/// The `[budgets]` section
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct BudgetsConfig {
+    /// Total Rust LOC across all crates before warning
+    pub project_loc_warn: usize,
+    /// Total Rust LOC across all crates before failing
+    pub project_loc_fail: usize,
+    /// Rust LOC in a single crate before warning
+    pub crate_loc_warn: usize,
+    /// Rust LOC in a single crate before failing
+    pub crate_loc_fail: usize,
+    /// Rust LOC across one component's crates (components/<name>/crates/*) before warning
+    pub component_loc_warn: usize,
+    /// Rust LOC across one component's crates before failing
+    pub component_loc_fail: usize,
+    /// Function LOC allowed in a binary entry point (main.rs, src/bin/*.rs)
+    pub main_loc: usize,
+    /// Release binary size in MiB before warning
+    pub binary_mb_warn: u64,
+    /// Release binary size in MiB before failing
+    pub binary_mb_fail: u64,
+}
+
+impl Default for BudgetsConfig {
+    fn default() -> Self {
+        Self {
+            project_loc_warn: 20_000,
+            project_loc_fail: 50_000,
+            crate_loc_warn: 2_000,
+            crate_loc_fail: 5_000,
+            component_loc_warn: 8_000,
+            component_loc_fail: 20_000,
+            main_loc: 80,
+            binary_mb_warn: 50,
+            binary_mb_fail: 200,
+        }
+    }
+}
+
+/// Warn and fail thresholds for a size metric
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct Limit {
+    /// Values above this warn
+    pub warn: usize,
+    /// Values above this fail
+    pub fail: usize,
+}
+
+/// The `[modularity]` section
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct ModularityConfig {
+    /// Lines per function
+    pub function_loc: Limit,
+    /// Lines per source file
+    pub file_loc: Limit,
+    /// Functions per module (source file)
+    pub module_functions: Limit,
+    /// Modules (source files) per crate
+    pub crate_modules: Limit,
+    /// Crates per workspace
+    pub workspace_crates: Limit,
+    /// Directories whose crates don't count toward `workspace_crates`
+    /// (names at any depth, like "xtask", or paths relative to the project root)
+    pub crate_count_exclude: Vec<String>,
+}
+
+impl Default for ModularityConfig {
+    fn default() -> Self {
+        Self {
+            function_loc: Limit { warn: 25, fail: 50 },
+            file_loc: Limit {
+                warn: 350,
+                fail: 500,
+            },
+            module_functions: Limit { warn: 4, fail: 7 },
+            crate_modules: Limit { warn: 4, fail: 7 },
+            workspace_crates: Limit { warn: 4, fail: 7 },
+            crate_count_exclude: Vec::new(),
+        }
+    }
+}
+
+/// The `[generated]` section
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct GeneratedConfig {
+    /// Check generated files like hand-written code instead of skipping them
+    pub check: bool,
+    /// Extra file paths (or path suffixes) to treat as generated
+    pub paths: Vec<String>,
+}
+
+impl GeneratedConfig {
+    /// Check if a file is configured as generated
+    pub fn matches_path(&self, path: &Path) -> bool {
+        self.paths.iter().any(|p| path.ends_with(p))
+    }
+}
+
+/// The `[toolchain]` section
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ToolchainConfig {
+    /// Whether a rust-toolchain.toml pin is required, forbidden, or optional
+    pub policy: ToolchainPolicy,
+}
+
+/// Policy for pinning the toolchain with rust-toolchain.toml
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ToolchainPolicy {
+    /// A pin must exist
+    Required,
+    /// No pin may exist (track the latest stable)
+    Forbidden,
+    /// A pin is optional, but must be compatible when present
+    #[default]
+    Any,
+}
+
+/// The `[hygiene]` section
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct HygieneConfig {
+    /// Required name of the default git branch
+    pub default_branch: String,
+    /// Largest tracked file allowed, in KiB
+    pub max_file_kb: u64,
+    /// Directories (at any depth) where tracked binary files are allowed
+    pub asset_dirs: Vec<String>,
+    /// Days an untagged version bump may sit on unreleased changes
+    pub release_stale_days: u64,
+}
+
+impl Default for HygieneConfig {
+    fn default() -> Self {
+        Self {
+            default_branch: "main".to_string(),
+            max_file_kb: 1024,
+            asset_dirs: vec!["assets".to_string(), "static".to_string()],
+            release_stale_days: 30,
+        }
+    }
+}
+
+/// The `[text]` section
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct TextConfig {
+    /// File extensions checked for encoding and line endings (empty: built-in list)
+    pub extensions: Vec<String>,
+    /// File extensions that must use CRLF line endings instead of LF (e.g. "bat")
+    pub crlf_extensions: Vec<String>,
+    /// Lines with trailing whitespace allowed per file before warning
+    pub max_trailing_whitespace: usize,
+}
+
+/// The `[header]` section
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct HeaderConfig {
+    /// Require the header at the top of every .rs file (opt-in)
+    pub check: bool,
+    /// Header lines, with `{year}` matching any year or year range
+    /// (default: the org's copyright and SPDX license lines)
+    pub template: Option<String>,
+}
+
+/// The `[i18n]` section
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct I18nConfig {
+    /// Check localized Web UI crates for hard-coded strings and locale files (opt-in)
+    pub check: bool,
+    /// Locales that need translation files (e.g. "en", "de")
+    pub locales: Vec<String>,
+}
+
+/// The `[semver]` section
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct SemverConfig {
+    /// Run cargo-semver-checks for library crates (opt-in; slow)
+    pub check: bool,
+}
+
+/// The `[startup]` section
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct StartupConfig {
+    /// Time `--version` for CLI binaries (opt-in; runs each binary repeatedly)
+    pub check: bool,
+    /// Median startup time in milliseconds before warning
+    pub budget_ms: u64,
+    /// Runs to take the median of
+    pub runs: usize,
+}
+
+impl Default for StartupConfig {
+    fn default() -> Self {
+        Self {
+            check: false,
+            budget_ms: 200,
+            runs: 5,
+        }
+    }
+}
+
+/// The `[naming]` section
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct NamingConfig {
+    /// Prefix every CLI binary name must start with, e.g. "sw-" (opt-in)
+    pub binary_prefix: Option<String>,
+}
+
+/// The `[http_smoke]` section
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct HttpSmokeConfig {
+    /// Serve each Web UI's dist/ locally and fetch its entry points (opt-in)
+    pub check: bool,
+    /// Also load `/` in headless Chrome/Chromium and fail on console errors
+    pub browser: bool,
+}
+
+/// The `[a11y]` section
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct A11yConfig {
+    /// Check Web UI heading structure and color contrast (opt-in)
+    pub check: bool,
+}
+
+/// The `[js_tests]` section
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct JsTestsConfig {
+    /// Run `npm test` for Web UIs with a JS test setup (opt-in; slow)
+    pub check: bool,
+    /// Seconds before `npm test` is killed (default 300)
+    pub timeout_secs: Option<u64>,
+}
+
+/// The `[deps]` section
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct DepsConfig {
+    /// Crates allowed in an internal dependency chain before warning (default 7)
+    pub max_chain_depth: Option<usize>,
+}
+
+/// The `[unused_deps]` section
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct UnusedDepsConfig {
+    /// Warn on dependencies never referenced in source (opt-in; heuristic)
+    pub check: bool,
+    /// Dependencies to never report (e.g. used only through macros or features)
+    pub ignore: Vec<String>,
+}
+
+/// The `[patches]` section
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct PatchesConfig {
+    /// `[patch]` / `[replace]` entries that are deliberate: a package name,
+    /// `<source>.<name>` (e.g. "crates-io.serde"), or a `name:version` replace key
+    pub allow: Vec<String>,
+}
+
+/// The `[release_profile]` section: org policy for native release builds
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct ReleaseProfileConfig {
+    /// Required `panic` strategy in `[profile.release]` (unset: either)
+    pub panic: Option<PanicStrategy>,
+    /// Require `overflow-checks = true` so release arithmetic doesn't silently wrap
+    pub overflow_checks: bool,
+}
+
+impl Default for ReleaseProfileConfig {
+    fn default() -> Self {
+        Self {
+            panic: None,
+            overflow_checks: true,
+        }
+    }
+}
+
+/// Panic strategy for `[profile.release] panic`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PanicStrategy {
+    /// Unwind the stack (Cargo's default)
+    Unwind,
+    /// Abort the process immediately
+    Abort,
+}
+
+/// The `[lints]` section
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct LintsConfig {
+    /// Lints every crate must declare (rust lints, or `tool::lint`)
+    pub baseline: Vec<String>,
+}
+
+impl Default for LintsConfig {
+    fn default() -> Self {
+        Self {
+            baseline: vec![
+                "missing_docs".to_string(),
+                "clippy::unwrap_used".to_string(),
+            ],
+        }
+    }
+}
@@ -0,0 +1,31 @@
+//! .sw-checklist.toml parsing
+
+use checklist_config::{FileConfig, Limit, ModularityConfig};
+
+fn parse(toml: &str) -> FileConfig {
+    toml::from_str(toml).unwrap()
+}
+
+#[test]
+fn an_empty_file_keeps_the_default_limits() {
+    let defaults = ModularityConfig::default();
+    let modularity = parse("").modularity;
+    assert_eq!(modularity.function_loc, Limit { warn: 25, fail: 50 });
+    assert_eq!(modularity.file_loc, defaults.file_loc);
+    assert_eq!(modularity.crate_modules, defaults.crate_modules);
+}
+
+#[test]
+fn limits_are_overridden_one_at_a_time() {
+    let file = parse("[modularity]\nfunction_loc = { warn = 40, fail = 80 }\n");
+    let defaults = ModularityConfig::default();
+    assert_eq!(file.modularity.function_loc, Limit { warn: 40, fail: 80 });
+    assert_eq!(file.modularity.module_functions, defaults.module_functions);
+    assert_eq!(file.modularity.workspace_crates, defaults.workspace_crates);
+}
+
+#[test]
+fn limits_need_both_bounds() {
+    let partial = "[modularity]\nfunction_loc = { warn = 40 }\n";
+    assert!(toml::from_str::<FileConfig>(partial).is_err());
+}
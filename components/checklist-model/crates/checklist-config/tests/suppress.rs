@@ -0,0 +1,76 @@
+//! Inline allow comments and `[suppress]` entries
+
+use checklist_config::{Config, ConfigBuilder, FileConfig, is_suppressed, suppress};
+use checklist_result::{CheckResult, CheckStatus};
+
+const SOURCE: &str = "\
+fn short() {}
+
+// sw-checklist: allow(function-loc, no-print)
+fn long() {
+}
+
+fn other() {} // sw-checklist: allow(Function LOC)
+";
+
+fn config(toml: &str) -> Config {
+    let file: FileConfig = toml::from_str(toml).unwrap();
+    ConfigBuilder::new().file_config(file).build()
+}
+
+fn long_function(line: usize) -> CheckResult {
+    CheckResult::fail("Function LOC [demo]", "Function has 60 lines").at("src/lib.rs", line)
+}
+
+#[test]
+fn allow_comments_cover_their_line_and_the_next() {
+    let config = config("");
+    for line in [4, 7] {
+        let result = suppress(&config, long_function(line), Some(SOURCE));
+        assert_eq!(result.status, CheckStatus::Info, "line {}", line);
+        assert!(is_suppressed(&result));
+    }
+    for line in [1, 5] {
+        let result = suppress(&config, long_function(line), Some(SOURCE));
+        assert_eq!(result.status, CheckStatus::Fail, "line {}", line);
+    }
+}
+
+#[test]
+fn allow_comments_name_the_check() {
+    let result = CheckResult::warn("Process Exit [demo]", "exit").at("src/lib.rs", 4);
+    let result = suppress(&config(""), result, Some(SOURCE));
+    assert_eq!(result.status, CheckStatus::Warn);
+    assert!(!is_suppressed(&result));
+}
+
+#[test]
+fn suppress_entries_match_crates_and_files() {
+    let by_crate = config("[suppress]\n\"function-loc\" = [\"demo\"]\n");
+    let result = suppress(&by_crate, long_function(1), Some(""));
+    assert_eq!(result.message, "Function has 60 lines");
+    assert!(is_suppressed(&result));
+
+    let by_file = config("[suppress]\n\"Function LOC\" = [\"lib.rs\"]\n");
+    assert!(is_suppressed(&suppress(
+        &by_file,
+        long_function(1),
+        Some("")
+    )));
+
+    let other = config("[suppress]\n\"function-loc\" = [\"other\"]\n");
+    let result = suppress(&other, long_function(1), Some(""));
+    assert_eq!(result.status, CheckStatus::Fail);
+}
+
+#[test]
+fn only_issues_are_suppressed() {
+    let config = config("[suppress]\n\"function-loc\" = [\"demo\"]\n");
+    let passed = CheckResult::pass("Function LOC [demo]", "ok");
+    let result = suppress(&config, passed, None);
+    assert_eq!(result.status, CheckStatus::Pass);
+    assert_eq!(result.message, "ok");
+
+    let info = CheckResult::info("Function LOC [demo]", "note");
+    assert!(!is_suppressed(&suppress(&config, info, None)));
+}
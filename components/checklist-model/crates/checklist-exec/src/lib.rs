@@ -0,0 +1,15 @@
+//! External command execution for sw-checklist
+//!
+//! Checks run project binaries and tools that may hang, prompt, or print
+//! non-UTF-8 output. [`Exec`] runs them with a timeout, a controlled
+//! environment, and both output streams captured, reporting failures as
+//! [`ExecError`]. [`git`] and [`run_command`] cover the common one-shot calls.
+
+mod command;
+mod outcome;
+mod run;
+mod tools;
+
+pub use command::{DEFAULT_TIMEOUT, Exec};
+pub use outcome::{ExecError, ExecOutput};
+pub use tools::{GIT_TIMEOUT, git, run_command};
@@ -0,0 +1,62 @@
+//! Command output and errors
+
+use std::fmt;
+use std::io;
+use std::process::ExitStatus;
+use std::time::Duration;
+
+/// Captured result of a command that ran to completion
+#[derive(Debug, Clone)]
+pub struct ExecOutput {
+    /// Exit status
+    pub status: ExitStatus,
+    /// Standard output (invalid UTF-8 replaced)
+    pub stdout: String,
+    /// Standard error (invalid UTF-8 replaced)
+    pub stderr: String,
+    /// Wall-clock time from spawn to exit
+    pub elapsed: Duration,
+}
+
+impl ExecOutput {
+    /// Whether the command exited with status 0
+    pub fn success(&self) -> bool {
+        self.status.success()
+    }
+}
+
+/// Why a command did not run to completion
+#[derive(Debug)]
+pub enum ExecError {
+    /// The program could not be started (missing, not executable, ...)
+    Spawn { program: String, source: io::Error },
+    /// Waiting on the running program or reading its output failed
+    Io { program: String, source: io::Error },
+    /// The program ran longer than its timeout and was killed
+    Timeout { program: String, timeout: Duration },
+    /// The program exited unsuccessfully (used by helpers such as [`git`](crate::git)
+    /// that only want output from a successful run); `command` is the command line
+    Failed { command: String, stderr: String },
+}
+
+impl fmt::Display for ExecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Spawn { program, source } => write!(f, "could not run {}: {}", program, source),
+            Self::Io { program, source } => write!(f, "error running {}: {}", program, source),
+            Self::Timeout { program, timeout } => {
+                write!(f, "{} timed out after {}s", program, timeout.as_secs_f64())
+            }
+            Self::Failed { command, stderr } => write!(f, "{}: {}", command, stderr),
+        }
+    }
+}
+
+impl std::error::Error for ExecError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Spawn { source, .. } | Self::Io { source, .. } => Some(source),
+            Self::Timeout { .. } | Self::Failed { .. } => None,
+        }
+    }
+}
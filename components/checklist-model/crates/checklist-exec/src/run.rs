@@ -0,0 +1,127 @@
+//! Running a command with a timeout
+
+use std::io::{self, Read, Write};
+use std::process::{Child, Command, Stdio};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use crate::command::Exec;
+use crate::outcome::{ExecError, ExecOutput};
+
+/// How often a running command is polled for exit
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+impl Exec {
+    /// Run the command to completion, capturing stdout and stderr, and kill it
+    /// if it outlives the timeout (stdin is closed unless [`Exec::stdin`] gave
+    /// input, so prompts fail fast)
+    ///
+    /// On Unix the command leads its own process group, and a timeout kills the
+    /// whole group, so tools that start workers (`npm test` running node and
+    /// jest) leave nothing running. The group is also killed once the command
+    /// exits, so a background process still holding its output can't keep the
+    /// run waiting past the timeout.
+    pub fn run(&self) -> Result<ExecOutput, ExecError> {
+        let program = self.program.to_string_lossy().into_owned();
+        let start = Instant::now();
+        let mut child = self.command().spawn().map_err(|source| ExecError::Spawn {
+            program: program.clone(),
+            source,
+        })?;
+        if let (Some(input), Some(mut pipe)) = (self.stdin.clone(), child.stdin.take()) {
+            thread::spawn(move || pipe.write_all(&input));
+        }
+        let stdout = read_pipe(child.stdout.take());
+        let stderr = read_pipe(child.stderr.take());
+        let io_error = |source| ExecError::Io {
+            program: program.clone(),
+            source,
+        };
+        let Some(status) = wait(&mut child, self.timeout).map_err(io_error)? else {
+            let timeout = self.timeout;
+            return Err(ExecError::Timeout { program, timeout });
+        };
+        #[cfg(unix)]
+        kill_group(&child);
+        Ok(ExecOutput {
+            status,
+            stdout: join_pipe(stdout).map_err(io_error)?,
+            stderr: join_pipe(stderr).map_err(io_error)?,
+            elapsed: start.elapsed(),
+        })
+    }
+
+    /// The std command with arguments, environment, and piped output applied
+    fn command(&self) -> Command {
+        let mut command = Command::new(&self.program);
+        command
+            .args(&self.args)
+            .stdin(if self.stdin.is_some() {
+                Stdio::piped()
+            } else {
+                Stdio::null()
+            })
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        if self.env_clear {
+            command.env_clear();
+        }
+        command.envs(self.envs.iter().map(|(k, v)| (k, v)));
+        if let Some(dir) = &self.dir {
+            command.current_dir(dir);
+        }
+        #[cfg(unix)]
+        std::os::unix::process::CommandExt::process_group(&mut command, 0);
+        command
+    }
+}
+
+/// Wait for exit, killing the child's process group (or, off Unix, the
+/// child) and returning `None` after `timeout`
+fn wait(child: &mut Child, timeout: Duration) -> io::Result<Option<std::process::ExitStatus>> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(Some(status));
+        }
+        if Instant::now() >= deadline {
+            #[cfg(unix)]
+            if !kill_group(child) {
+                child.kill()?;
+            }
+            #[cfg(not(unix))]
+            child.kill()?;
+            child.wait()?;
+            return Ok(None);
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// SIGKILL the process group the child leads, returning whether any process
+/// was signalled (none are left once the child and all its workers exited)
+#[cfg(unix)]
+fn kill_group(child: &Child) -> bool {
+    let group = -(child.id() as libc::pid_t);
+    // SAFETY: kill(2) only sends a signal; a negative pid targets the
+    // process group the child leads (see `process_group(0)` above)
+    unsafe { libc::kill(group, libc::SIGKILL) == 0 }
+}
+
+/// Drain a pipe on its own thread so a full pipe buffer can't stall the child
+fn read_pipe(pipe: Option<impl Read + Send + 'static>) -> JoinHandle<io::Result<Vec<u8>>> {
+    thread::spawn(move || {
+        let mut bytes = Vec::new();
+        if let Some(mut pipe) = pipe {
+            pipe.read_to_end(&mut bytes)?;
+        }
+        Ok(bytes)
+    })
+}
+
+fn join_pipe(handle: JoinHandle<io::Result<Vec<u8>>>) -> io::Result<String> {
+    let bytes = handle
+        .join()
+        .map_err(|_| io::Error::other("output reader panicked"))??;
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
@@ -0,0 +1,37 @@
+//! One-shot runs of common tools
+
+use std::ffi::OsStr;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::command::Exec;
+use crate::outcome::ExecError;
+
+/// Timeout for local git commands (rev-parse, ls-files, worktree add, ...)
+pub const GIT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Run `program` with `args` and the default timeout, returning its stdout
+/// whatever the exit status
+pub fn run_command(program: impl AsRef<OsStr>, args: &[&str]) -> Result<String, ExecError> {
+    Exec::new(program)
+        .args(args)
+        .run()
+        .map(|output| output.stdout)
+}
+
+/// Run `git -C dir <args>` with [`GIT_TIMEOUT`], returning trimmed stdout; a
+/// non-zero exit is [`ExecError::Failed`] with git's stderr
+pub fn git(dir: &Path, args: &[&str]) -> Result<String, ExecError> {
+    let output = Exec::new("git")
+        .args([OsStr::new("-C"), dir.as_os_str()])
+        .args(args)
+        .timeout(GIT_TIMEOUT)
+        .run()?;
+    if !output.success() {
+        return Err(ExecError::Failed {
+            command: format!("git {}", args.join(" ")),
+            stderr: output.stderr.trim().to_string(),
+        });
+    }
+    Ok(output.stdout.trim().to_string())
+}
@@ -0,0 +1,77 @@
+//! Timeouts, process groups, and stdin
+
+use checklist_exec::{Exec, ExecError, git};
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process;
+use std::time::Duration;
+
+#[test]
+fn stdin_reaches_the_command() {
+    let output = Exec::new("cat").stdin("token\n").run().unwrap();
+    assert_eq!(output.stdout, "token\n");
+}
+
+#[test]
+fn timeout_is_reported() {
+    let outcome = Exec::new("sleep")
+        .args(["5"])
+        .timeout(Duration::from_millis(100))
+        .run();
+    assert!(matches!(outcome, Err(ExecError::Timeout { .. })));
+}
+
+/// A worker started by the command (like node under `npm test`) dies with it
+#[cfg(target_os = "linux")]
+#[test]
+fn timeout_kills_the_whole_process_group() {
+    let pid_file = env::temp_dir().join(format!("sw-checklist-exec-{}.pid", process::id()));
+    let script = format!("sleep 30 & echo $! > {}; wait", pid_file.display());
+    let outcome = Exec::new("sh")
+        .args(["-c", &script])
+        .timeout(Duration::from_millis(300))
+        .run();
+    assert!(matches!(outcome, Err(ExecError::Timeout { .. })));
+    let pid = fs::read_to_string(&pid_file).unwrap();
+    fs::remove_file(&pid_file).unwrap();
+    std::thread::sleep(Duration::from_millis(100));
+    let stat = fs::read_to_string(format!("/proc/{}/stat", pid.trim())).unwrap_or_default();
+    let running = stat
+        .rsplit(')')
+        .next()
+        .is_some_and(|rest| !rest.trim_start().starts_with('Z'));
+    assert!(!running, "worker {} still running: {}", pid.trim(), stat);
+}
+
+/// A background process left holding stdout can't stall the run once the command exits
+#[cfg(unix)]
+#[test]
+fn leftover_workers_do_not_outlast_the_command() {
+    let output = Exec::new("sh")
+        .args(["-c", "sleep 8 & echo hi"])
+        .timeout(Duration::from_secs(1))
+        .run()
+        .unwrap();
+    assert_eq!(output.stdout, "hi\n");
+    assert!(
+        output.elapsed < Duration::from_secs(2),
+        "{:?}",
+        output.elapsed
+    );
+}
+
+#[test]
+fn git_failure_carries_stderr() {
+    let err = git(&env::temp_dir(), &["rev-parse", "--show-toplevel"]).unwrap_err();
+    assert!(matches!(err, ExecError::Failed { .. }));
+    assert!(
+        err.to_string()
+            .starts_with("git rev-parse --show-toplevel: ")
+    );
+    assert!(
+        git(Path::new("."), &["--version"])
+            .unwrap()
+            .starts_with("git version")
+    );
+}
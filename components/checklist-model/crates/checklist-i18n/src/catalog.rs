@@ -0,0 +1,79 @@
+//! Catalog loading from built-in and project files
+
+use anyhow::{Context, Result, bail};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// Languages with a catalog compiled in ("en" needs none)
+pub const BUILTIN_LANGS: &[&str] = &["de"];
+
+const BUILTIN: &[(&str, &str)] = &[("de", include_str!("../catalogs/de.toml"))];
+
+/// Translations for one language
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Catalog {
+    /// Report text (status words, summary, hints) by key
+    pub(crate) report: BTreeMap<String, String>,
+    /// Titles and static messages by check ID
+    pub(crate) checks: BTreeMap<String, CheckText>,
+}
+
+/// Translations for one check
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub(crate) struct CheckText {
+    pub(crate) title: Option<String>,
+    /// Translations of exact English messages
+    pub(crate) messages: BTreeMap<String, String>,
+}
+
+impl Catalog {
+    /// The catalog for `lang` (English when `None` or "en"): the built-in
+    /// catalog overlaid with `.sw-checklist-<lang>.toml` from the project root
+    pub fn load(lang: Option<&str>, project_root: &Path) -> Result<Self> {
+        let Some(lang) = lang.filter(|l| *l != "en") else {
+            return Ok(Self::default());
+        };
+        let builtin = BUILTIN
+            .iter()
+            .find(|(l, _)| *l == lang)
+            .map(|(_, text)| *text);
+        let project = project_root.join(format!(".sw-checklist-{}.toml", lang));
+        let overlay = project_catalog(&project)?;
+        if builtin.is_none() && overlay.is_none() {
+            let hint = format!(
+                "built in: en, {}; or add {}",
+                BUILTIN_LANGS.join(", "),
+                project.display()
+            );
+            bail!("No catalog for language '{}' ({})", lang, hint);
+        }
+        let mut catalog: Self = toml::from_str(builtin.unwrap_or_default())?;
+        if let Some(overlay) = overlay {
+            catalog.overlay(overlay);
+        }
+        Ok(catalog)
+    }
+
+    fn overlay(&mut self, other: Self) {
+        self.report.extend(other.report);
+        for (id, text) in other.checks {
+            let entry = self.checks.entry(id).or_default();
+            entry.title = text.title.or(entry.title.take());
+            entry.messages.extend(text.messages);
+        }
+    }
+}
+
+/// A project catalog file, if present
+fn project_catalog(path: &Path) -> Result<Option<Catalog>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let text = fs::read_to_string(path)?;
+    let catalog = toml::from_str(&text).with_context(|| format!("Invalid {}", path.display()))?;
+    Ok(Some(catalog))
+}
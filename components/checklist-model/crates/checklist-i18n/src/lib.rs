@@ -0,0 +1,10 @@
+//! Report message catalogs for sw-checklist
+//!
+//! Translates check titles, static messages, and report text for human
+//! readers. Catalogs are keyed by check ID, and only what is displayed is
+//! localized, so IDs and machine-readable output stay stable for tooling.
+
+mod catalog;
+mod localize;
+
+pub use catalog::{BUILTIN_LANGS, Catalog};
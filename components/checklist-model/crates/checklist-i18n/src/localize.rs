@@ -0,0 +1,48 @@
+//! Applying a catalog to results and report text
+
+use checklist_result::{CheckResult, CheckStatus};
+
+use crate::catalog::Catalog;
+
+impl Catalog {
+    /// Report text for `key` with `{name}` placeholders filled, or `english`
+    /// (with the same placeholders) when the catalog has none
+    pub fn text(&self, key: &str, english: &str, args: &[(&str, String)]) -> String {
+        let template = self.report.get(key).map_or(english, String::as_str);
+        args.iter()
+            .fold(template.to_string(), |text, (name, value)| {
+                text.replace(&format!("{{{}}}", name), value)
+            })
+    }
+
+    /// The status word shown in `[STATUS]` tags
+    pub fn status(&self, status: CheckStatus) -> String {
+        let (key, english) = match status {
+            CheckStatus::Pass => ("pass", "PASS"),
+            CheckStatus::Fail => ("fail", "FAIL"),
+            CheckStatus::Warn => ("warn", "WARN"),
+            CheckStatus::Info => ("info", "INFO"),
+        };
+        self.text(key, english, &[])
+    }
+
+    /// A translated check title, or the English one
+    pub fn title<'a>(&'a self, result: &'a CheckResult) -> &'a str {
+        let text = self.checks.get(&result.check_id());
+        text.and_then(|t| t.title.as_deref())
+            .unwrap_or(result.check_title())
+    }
+
+    /// The result with its title and (static) message translated; the
+    /// `[crate]` label, status, and location are kept
+    pub fn localize(&self, result: &CheckResult) -> CheckResult {
+        let text = self.checks.get(&result.check_id());
+        let message = text.and_then(|t| t.messages.get(&result.message));
+        let label = &result.name[result.check_title().len()..];
+        CheckResult {
+            name: format!("{}{}", self.title(result), label),
+            message: message.unwrap_or(&result.message).clone(),
+            ..result.clone()
+        }
+    }
+}
@@ -0,0 +1,40 @@
+//! De-duplication and roll-up of check results
+
+use crate::result::CheckResult;
+
+/// Remove exact duplicate results, keeping the first occurrence
+pub fn dedup_results(results: Vec<CheckResult>) -> Vec<CheckResult> {
+    let mut unique: Vec<CheckResult> = Vec::with_capacity(results.len());
+    for result in results {
+        if !unique.contains(&result) {
+            unique.push(result);
+        }
+    }
+    unique
+}
+
+/// Collapse groups of more than `max_similar` results with the same name and
+/// status into a single entry carrying the count
+pub fn roll_up(results: &[CheckResult], max_similar: usize) -> Vec<CheckResult> {
+    let mut rolled = Vec::new();
+    for (i, result) in results.iter().enumerate() {
+        let similar = |r: &&CheckResult| r.name == result.name && r.status == result.status;
+        if results[..i].iter().any(|r| similar(&r)) {
+            continue;
+        }
+        let count = results[i..].iter().filter(similar).count();
+        if count > max_similar {
+            rolled.push(CheckResult {
+                message: format!(
+                    "{} similar findings, e.g. {} (run --verbose for all)",
+                    count, result.message
+                ),
+                location: None,
+                ..result.clone()
+            });
+        } else {
+            rolled.extend(results[i..].iter().filter(similar).cloned());
+        }
+    }
+    rolled
+}
@@ -0,0 +1,42 @@
+//! Issues set aside by an allow comment, `[suppress]`, or the baseline
+
+use crate::result::CheckResult;
+use crate::status::CheckStatus;
+
+/// Why a failure or warning is reported as INFO instead
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Disposition {
+    /// Acknowledged by an allow comment or a `[suppress]` entry
+    Suppressed,
+    /// Recorded in the project's baseline file
+    Baselined,
+}
+
+impl Disposition {
+    /// Lowercase label shown after the message ("suppressed", "baseline")
+    pub fn label(self) -> &'static str {
+        match self {
+            Disposition::Suppressed => "suppressed",
+            Disposition::Baselined => "baseline",
+        }
+    }
+}
+
+impl CheckResult {
+    /// Report this result as INFO, set aside for `disposition`
+    pub fn disposed(self, disposition: Disposition) -> Self {
+        Self {
+            status: CheckStatus::Info,
+            disposition: Some(disposition),
+            ..self
+        }
+    }
+
+    /// The message with the disposition, if any, in parentheses after it
+    pub fn display_message(&self) -> String {
+        match self.disposition {
+            Some(disposition) => format!("{} ({})", self.message, disposition.label()),
+            None => self.message.clone(),
+        }
+    }
+}
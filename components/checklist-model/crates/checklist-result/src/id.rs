@@ -0,0 +1,35 @@
+//! Check identity derived from result names
+
+use crate::result::CheckResult;
+
+impl CheckResult {
+    /// Check title without the `[crate]` / `[crate/bin]` label, e.g. "Function LOC"
+    pub fn check_title(&self) -> &str {
+        match self.name.find(" [") {
+            Some(idx) if self.name.ends_with(']') => &self.name[..idx],
+            _ => &self.name,
+        }
+    }
+
+    /// Stable check ID, e.g. "function-loc" for "Function LOC [crate]"
+    pub fn check_id(&self) -> String {
+        slugify(self.check_title())
+    }
+
+    /// Crate (or "project") the result is about, from its `[crate/bin]` label
+    pub fn subject(&self) -> Option<&str> {
+        let label = self.name.strip_suffix(']')?;
+        let label = &label[label.rfind('[')? + 1..];
+        label.split('/').next()
+    }
+}
+
+/// Lowercase a check title into a dash-separated ID
+pub fn slugify(title: &str) -> String {
+    title
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(|part| part.to_ascii_lowercase())
+        .collect::<Vec<_>>()
+        .join("-")
+}
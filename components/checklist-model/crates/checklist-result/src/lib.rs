@@ -2,8 +2,16 @@
 //!
 //! This crate provides the core result types used throughout sw-checklist.
 
+mod collapse;
+mod disposition;
+mod id;
+mod location;
 mod result;
 mod status;
 
+pub use collapse::{dedup_results, roll_up};
+pub use disposition::Disposition;
+pub use id::slugify;
+pub use location::Location;
 pub use result::CheckResult;
-pub use status::CheckStatus;
+pub use status::{CheckStatus, EXIT_ERROR, exit_code};
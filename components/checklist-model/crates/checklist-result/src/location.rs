@@ -0,0 +1,24 @@
+//! Source locations attached to check results
+
+use crate::result::CheckResult;
+use std::path::PathBuf;
+
+/// Location in a source file that a check result refers to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    /// Path to the file
+    pub file: PathBuf,
+    /// One-based line number
+    pub line: usize,
+}
+
+impl CheckResult {
+    /// Attach a file and one-based line number to this result
+    pub fn at(mut self, file: impl Into<PathBuf>, line: usize) -> Self {
+        self.location = Some(Location {
+            file: file.into(),
+            line,
+        });
+        self
+    }
+}
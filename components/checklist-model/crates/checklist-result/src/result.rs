@@ -1,9 +1,11 @@
 //! Check result type
 
+use crate::disposition::Disposition;
+use crate::location::Location;
 use crate::status::CheckStatus;
 
 /// Result of a validation check
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CheckResult {
     /// Name of the check
     pub name: String,
@@ -11,6 +13,10 @@ pub struct CheckResult {
     pub status: CheckStatus,
     /// Message describing the result
     pub message: String,
+    /// Source location the result refers to, if any
+    pub location: Option<Location>,
+    /// Why a failure or warning is reported as INFO, if it was set aside
+    pub disposition: Option<Disposition>,
 }
 
 impl CheckResult {
@@ -20,6 +26,8 @@ impl CheckResult {
             name: name.into(),
             status: CheckStatus::Pass,
             message: message.into(),
+            location: None,
+            disposition: None,
         }
     }
     /// Create a failing check result
@@ -28,6 +36,8 @@ impl CheckResult {
             name: name.into(),
             status: CheckStatus::Fail,
             message: message.into(),
+            location: None,
+            disposition: None,
         }
     }
     /// Create a warning check result
@@ -36,6 +46,8 @@ impl CheckResult {
             name: name.into(),
             status: CheckStatus::Warn,
             message: message.into(),
+            location: None,
+            disposition: None,
         }
     }
     /// Create an informational check result
@@ -44,6 +56,8 @@ impl CheckResult {
             name: name.into(),
             status: CheckStatus::Info,
             message: message.into(),
+            location: None,
+            disposition: None,
         }
     }
 }
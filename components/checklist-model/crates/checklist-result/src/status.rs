@@ -22,6 +22,12 @@ impl CheckStatus {
         )
     }
 
+    /// Returns true if this status fails the run's exit code (Fail, plus Warn
+    /// when `strict`)
+    pub fn fails(self, strict: bool) -> bool {
+        !self.passed() || (strict && self.is_warning())
+    }
+
     /// Returns true if this is a warning
     pub fn is_warning(self) -> bool {
         matches!(self, CheckStatus::Warn)
@@ -32,3 +38,19 @@ impl CheckStatus {
         matches!(self, CheckStatus::Info)
     }
 }
+
+/// Exit code for internal errors (bad arguments or config, nothing to check)
+pub const EXIT_ERROR: i32 = 3;
+
+/// Process exit code for a run's statuses: 0 when everything passed, 1 on
+/// failures (including warnings when `strict`), 2 when there are only warnings
+pub fn exit_code(statuses: impl IntoIterator<Item = CheckStatus>, strict: bool) -> i32 {
+    let statuses: Vec<CheckStatus> = statuses.into_iter().collect();
+    if statuses.iter().any(|s| s.fails(strict)) {
+        1
+    } else if statuses.iter().any(|s| s.is_warning()) {
+        2
+    } else {
+        0
+    }
+}
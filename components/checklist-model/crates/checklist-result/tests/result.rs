@@ -0,0 +1,46 @@
+use checklist_result::{CheckResult, CheckStatus, Disposition, exit_code};
+
+#[test]
+fn constructors_set_status_name_and_message() {
+    let pass = CheckResult::pass("Test", "This passed");
+    assert_eq!(pass.status, CheckStatus::Pass);
+    assert_eq!(pass.name, "Test");
+    assert_eq!(pass.message, "This passed");
+
+    let fail = CheckResult::fail("Test", "This failed");
+    assert_eq!(fail.status, CheckStatus::Fail);
+    assert!(!fail.status.passed());
+
+    let warn = CheckResult::warn("Test Warning", "This is a warning");
+    assert!(warn.status.is_warning());
+    assert!(warn.status.passed());
+    assert_eq!(warn.message, "This is a warning");
+}
+
+#[test]
+fn ids_and_subjects_come_from_the_label() {
+    let result = CheckResult::fail("Function LOC [demo/demo-cli]", "too long").at("src/main.rs", 3);
+    assert_eq!(result.check_title(), "Function LOC");
+    assert_eq!(result.check_id(), "function-loc");
+    assert_eq!(result.subject(), Some("demo"));
+    assert_eq!(result.location.unwrap().line, 3);
+}
+
+#[test]
+fn exit_codes_rank_failures_over_warnings() {
+    use CheckStatus::*;
+    assert_eq!(exit_code([Pass, Info], false), 0);
+    assert_eq!(exit_code([Pass, Warn], false), 2);
+    assert_eq!(exit_code([Pass, Warn], true), 1);
+    assert_eq!(exit_code([Warn, Fail], false), 1);
+}
+
+#[test]
+fn disposed_results_keep_their_message() {
+    let result =
+        CheckResult::fail("Function LOC [demo]", "too long").disposed(Disposition::Baselined);
+    assert_eq!(result.status, CheckStatus::Info);
+    assert_eq!(result.message, "too long");
+    assert_eq!(result.display_message(), "too long (baseline)");
+    assert_eq!(CheckResult::pass("Test", "ok").display_message(), "ok");
+}
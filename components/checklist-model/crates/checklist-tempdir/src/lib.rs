@@ -0,0 +1,11 @@
+//! Managed temporary workspaces for sw-checklist
+//!
+//! Remote clones, base-branch worktrees, and unpacked artifacts each get a
+//! directory unique to the run, removed on drop (including while unwinding
+//! from a panic), so parallel CI invocations never collide or leak state.
+
+mod unpack;
+mod workspace;
+
+pub use unpack::{executables, is_archive, unpack};
+pub use workspace::TempWorkspace;
@@ -0,0 +1,72 @@
+//! Release archive unpacking
+
+use anyhow::{Result, bail};
+use checklist_exec::Exec;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::workspace::TempWorkspace;
+
+/// Archive extensions `tar -xf` unpacks
+const ARCHIVE_EXTENSIONS: &[&str] = &[".tar", ".tar.gz", ".tgz", ".tar.xz"];
+
+/// Longest time `tar` may take to unpack one archive
+const UNPACK_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Whether a path names a tar archive
+pub fn is_archive(path: &Path) -> bool {
+    let name = path.to_string_lossy();
+    ARCHIVE_EXTENSIONS.iter().any(|ext| name.ends_with(ext))
+}
+
+/// Unpack an archive into a fresh workspace, returning it with the content root
+/// (descending through a single top-level directory like `tool-v1.0/`)
+pub fn unpack(archive: &Path) -> Result<(TempWorkspace, PathBuf)> {
+    let workspace = TempWorkspace::new("artifact")?;
+    let output = Exec::new("tar")
+        .args([Path::new("-xf"), archive, Path::new("-C"), workspace.path()])
+        .timeout(UNPACK_TIMEOUT)
+        .run()?;
+    if !output.success() {
+        bail!(
+            "Failed to unpack {}: {}",
+            archive.display(),
+            output.stderr.trim()
+        );
+    }
+    let mut root = workspace.path().to_path_buf();
+    while let Ok(entries) = fs::read_dir(&root)
+        && let [only] = entries.flatten().collect::<Vec<_>>().as_slice()
+        && only.path().is_dir()
+    {
+        root = only.path();
+    }
+    Ok((workspace, root))
+}
+
+/// Executable files directly inside `dir`, sorted by name
+pub fn executables(dir: &Path) -> Vec<PathBuf> {
+    let mut found: Vec<PathBuf> = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| is_executable(path))
+        .collect();
+    found.sort();
+    found
+}
+
+fn is_executable(path: &Path) -> bool {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        path.metadata()
+            .is_ok_and(|m| m.is_file() && m.permissions().mode() & 0o111 != 0)
+    }
+    #[cfg(not(unix))]
+    {
+        path.is_file() && path.extension().is_some_and(|e| e == "exe")
+    }
+}
@@ -0,0 +1,57 @@
+//! Temporary workspace directory
+
+use anyhow::{Context, Result};
+use std::env;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Workspaces created by this process, so concurrent runs in one server never share one
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+/// A directory under the system temp dir, unique to this run and removed on drop
+#[derive(Debug)]
+pub struct TempWorkspace {
+    path: PathBuf,
+}
+
+impl TempWorkspace {
+    /// Create an empty workspace named `sw-checklist-<purpose>-<pid>-<id>-<nanos>`
+    ///
+    /// Creation fails rather than reusing a directory that already exists.
+    pub fn new(purpose: &str) -> Result<Self> {
+        loop {
+            let nanos = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.subsec_nanos());
+            let name = format!(
+                "sw-checklist-{}-{}-{}-{}",
+                purpose,
+                std::process::id(),
+                NEXT_ID.fetch_add(1, Ordering::Relaxed),
+                nanos
+            );
+            let path = env::temp_dir().join(name);
+            match fs::create_dir(&path) {
+                Ok(()) => return Ok(Self { path }),
+                Err(e) if e.kind() == ErrorKind::AlreadyExists => continue,
+                Err(e) => {
+                    return Err(e).with_context(|| format!("Failed to create {}", path.display()));
+                }
+            }
+        }
+    }
+
+    /// Root directory of the workspace
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for TempWorkspace {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
@@ -0,0 +1,56 @@
+//! Minimal HTTP/1.1 response writing
+
+use anyhow::Result;
+use std::io::Write;
+use std::net::TcpStream;
+
+/// HTTP response
+pub struct Response {
+    pub status: u16,
+    pub content_type: &'static str,
+    pub body: String,
+}
+
+impl Response {
+    /// JSON response
+    pub fn json(status: u16, value: serde_json::Value) -> Self {
+        Self {
+            status,
+            content_type: "application/json",
+            body: value.to_string(),
+        }
+    }
+
+    /// HTML response
+    pub fn html(body: String) -> Self {
+        Self {
+            status: 200,
+            content_type: "text/html; charset=utf-8",
+            body,
+        }
+    }
+
+    /// Write the response and close the connection
+    pub fn write_to(&self, mut stream: &TcpStream) -> Result<()> {
+        let reason = match self.status {
+            200 => "OK",
+            202 => "Accepted",
+            400 => "Bad Request",
+            403 => "Forbidden",
+            404 => "Not Found",
+            413 => "Payload Too Large",
+            503 => "Service Unavailable",
+            _ => "Error",
+        };
+        write!(
+            stream,
+            "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            self.status,
+            reason,
+            self.content_type,
+            self.body.len(),
+            self.body
+        )?;
+        Ok(stream.flush()?)
+    }
+}
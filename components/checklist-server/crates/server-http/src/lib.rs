@@ -0,0 +1,12 @@
+//! HTTP API for triggering and reading sw-checklist runs
+//!
+//! - `POST /check` with `{"path": "..."}` or `{"git": "<url>"}` starts a run
+//!   (503 while the maximum number of runs are in progress)
+//! - `GET /runs/<id>` returns the run as JSON
+//! - `GET /runs/<id>/report` returns the run as an HTML report
+
+mod http;
+mod request;
+mod server;
+
+pub use server::{bind, serve};
@@ -0,0 +1,69 @@
+//! Minimal HTTP/1.1 request parsing
+
+use anyhow::{Context, Result};
+use std::fmt;
+use std::io::{BufRead, BufReader, Read};
+use std::net::TcpStream;
+
+/// Largest request body accepted
+const MAX_BODY_LEN: usize = 64 * 1024;
+
+/// A Content-Length over [`MAX_BODY_LEN`], answered with 413
+#[derive(Debug)]
+pub struct BodyTooLarge(usize);
+
+impl fmt::Display for BodyTooLarge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Request body of {} bytes exceeds the {} byte limit",
+            self.0, MAX_BODY_LEN
+        )
+    }
+}
+
+impl std::error::Error for BodyTooLarge {}
+
+/// Parsed HTTP request
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub body: String,
+}
+
+/// Read a request (line, headers, and Content-Length body) from the stream
+pub fn read_request(stream: &TcpStream) -> Result<Request> {
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    let mut parts = line.split_whitespace();
+    let method = parts.next().context("Empty request")?.to_string();
+    let path = parts.next().context("Missing request path")?.to_string();
+
+    let mut body = vec![0; read_content_length(&mut reader)?];
+    reader.read_exact(&mut body)?;
+    Ok(Request {
+        method,
+        path,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    })
+}
+
+/// Consume the headers, returning the Content-Length ([`BodyTooLarge`] over the cap)
+fn read_content_length(reader: &mut impl BufRead) -> Result<usize> {
+    let mut content_length = 0;
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 || header.trim().is_empty() {
+            return Ok(content_length);
+        }
+        if let Some((name, value)) = header.split_once(':')
+            && name.trim().eq_ignore_ascii_case("content-length")
+        {
+            content_length = value.trim().parse::<usize>().unwrap_or(0);
+            if content_length > MAX_BODY_LEN {
+                return Err(BodyTooLarge(content_length).into());
+            }
+        }
+    }
+}
@@ -0,0 +1,113 @@
+//! HTTP server and routing
+
+use anyhow::{Context, Result};
+use serde_json::{Value, json};
+use server_runs::{Busy, Checker, RunStore, is_git_url, run_html, run_json};
+use std::net::{TcpListener, TcpStream};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use crate::http::Response;
+use crate::request::{BodyTooLarge, Request, read_request};
+
+/// How long a client may stall while sending its request or reading the reply
+const IO_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Bind the check API's listener on `addr`
+pub fn bind(addr: &str) -> Result<TcpListener> {
+    TcpListener::bind(addr).with_context(|| format!("Failed to bind {}", addr))
+}
+
+/// Serve the check API on `listener` until the process is stopped; git URL
+/// targets are refused unless `allow_remote` is set, since checking a clone
+/// runs code from it (built binaries, `npm test`)
+pub fn serve(listener: TcpListener, checker: Arc<Checker>, allow_remote: bool) -> Result<()> {
+    let store = Arc::new(RunStore::default());
+    for stream in listener.incoming().flatten() {
+        let store = Arc::clone(&store);
+        let checker = Arc::clone(&checker);
+        thread::spawn(move || handle_connection(&stream, &store, checker, allow_remote));
+    }
+    Ok(())
+}
+
+fn handle_connection(
+    stream: &TcpStream,
+    store: &Arc<RunStore>,
+    checker: Arc<Checker>,
+    allow_remote: bool,
+) {
+    let timeouts = stream
+        .set_read_timeout(Some(IO_TIMEOUT))
+        .and_then(|_| stream.set_write_timeout(Some(IO_TIMEOUT)));
+    if let Err(e) = timeouts {
+        log::warn!("Failed to set socket timeouts: {}", e);
+        return;
+    }
+    let response = match read_request(stream) {
+        Ok(request) => {
+            log::info!("{} {}", request.method, request.path);
+            route(&request, store, checker, allow_remote)
+        }
+        Err(e) if e.is::<BodyTooLarge>() => Response::json(413, json!({ "error": e.to_string() })),
+        Err(e) => Response::json(400, json!({ "error": e.to_string() })),
+    };
+    if let Err(e) = response.write_to(stream) {
+        log::warn!("Failed to write response: {}", e);
+    }
+}
+
+fn route(
+    request: &Request,
+    store: &Arc<RunStore>,
+    checker: Arc<Checker>,
+    allow_remote: bool,
+) -> Response {
+    let segments: Vec<&str> = request.path.trim_matches('/').split('/').collect();
+    let run = |id: &str| id.parse::<u64>().ok().and_then(|id| store.get(id));
+    match (request.method.as_str(), segments.as_slice()) {
+        ("POST", ["check"]) => post_check(request, store, checker, allow_remote),
+        ("GET", ["runs", id]) => match run(id) {
+            Some(run) => Response::json(200, run_json(&run)),
+            None => Response::json(404, json!({ "error": "run not found" })),
+        },
+        ("GET", ["runs", id, "report"]) => match run(id) {
+            Some(run) => Response::html(run_html(&run)),
+            None => Response::json(404, json!({ "error": "run not found" })),
+        },
+        _ => Response::json(404, json!({ "error": "not found" })),
+    }
+}
+
+fn post_check(
+    request: &Request,
+    store: &Arc<RunStore>,
+    checker: Arc<Checker>,
+    allow_remote: bool,
+) -> Response {
+    let body: Value = serde_json::from_str(&request.body).unwrap_or_default();
+    let target = ["path", "git"]
+        .iter()
+        .find_map(|key| body.get(key).and_then(|v| v.as_str()));
+    match target {
+        Some(target) if is_git_url(target) && !allow_remote => Response::json(
+            403,
+            json!({ "error": "git targets are disabled; start the server with --allow-remote" }),
+        ),
+        Some(target) => match store.start(target.to_string(), checker) {
+            Ok(id) => {
+                let location = format!("/runs/{}", id);
+                Response::json(
+                    202,
+                    json!({ "id": id, "status": "running", "location": location }),
+                )
+            }
+            Err(e @ Busy) => Response::json(503, json!({ "error": e.to_string() })),
+        },
+        None => Response::json(
+            400,
+            json!({ "error": "body must contain \"path\" or \"git\"" }),
+        ),
+    }
+}
@@ -0,0 +1,77 @@
+use checklist_result::CheckResult;
+use server_runs::Checker;
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+/// Serve on an ephemeral port (git targets refused) with a checker that passes every project
+fn start_server() -> SocketAddr {
+    let listener = server_http::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let checker: Arc<Checker> = Arc::new(|_| Ok(vec![CheckResult::pass("Sample", "ok")]));
+    thread::spawn(move || server_http::serve(listener, checker, false));
+    addr
+}
+
+/// Send a request and return the status code and body
+fn request(addr: SocketAddr, method: &str, path: &str, body: &str) -> (u16, String) {
+    let mut stream = TcpStream::connect(addr).unwrap();
+    write!(
+        stream,
+        "{} {} HTTP/1.1\r\nContent-Length: {}\r\n\r\n{}",
+        method,
+        path,
+        body.len(),
+        body
+    )
+    .unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    let status = response[9..12].parse().unwrap();
+    let body = response.split_once("\r\n\r\n").unwrap().1.to_string();
+    (status, body)
+}
+
+#[test]
+fn check_then_poll_run() {
+    let addr = start_server();
+    let (status, body) = request(addr, "POST", "/check", r#"{"path": "."}"#);
+    assert_eq!(status, 202);
+    assert!(body.contains(r#""location":"/runs/1""#), "{}", body);
+
+    let mut run = String::new();
+    for _ in 0..400 {
+        run = request(addr, "GET", "/runs/1", "").1;
+        if !run.contains(r#""status":"running""#) {
+            break;
+        }
+        thread::sleep(Duration::from_millis(5));
+    }
+    assert!(run.contains(r#""status":"done""#), "{}", run);
+    assert!(run.contains(r#""status":"pass""#), "{}", run);
+
+    let (status, report) = request(addr, "GET", "/runs/1/report", "");
+    assert_eq!(status, 200);
+    assert!(report.contains("<td>Sample</td>"), "{}", report);
+}
+
+#[test]
+fn unknown_routes_and_bad_bodies() {
+    let addr = start_server();
+    assert_eq!(request(addr, "GET", "/runs/99", "").0, 404);
+    assert_eq!(request(addr, "GET", "/nope", "").0, 404);
+    assert_eq!(request(addr, "POST", "/check", "{}").0, 400);
+}
+
+#[test]
+fn git_targets_need_allow_remote() {
+    let addr = start_server();
+    let git = r#"{"git": "https://example.com/owner/repo"}"#;
+    let (status, body) = request(addr, "POST", "/check", git);
+    assert_eq!(status, 403);
+    assert!(body.contains("--allow-remote"), "{}", body);
+    let disguised = r#"{"path": "git@example.com:owner/repo"}"#;
+    assert_eq!(request(addr, "POST", "/check", disguised).0, 403);
+}
@@ -0,0 +1,10 @@
+//! Check run tracking for the sw-checklist server
+//!
+//! Runs execute in the background against a local path or a cloned git URL;
+//! their results are rendered as JSON or an HTML report.
+
+mod render;
+mod store;
+
+pub use render::{run_html, run_json};
+pub use store::{Busy, Checker, MAX_RUNNING, Run, RunStatus, RunStore, is_git_url};
@@ -0,0 +1,83 @@
+//! JSON and HTML rendering of runs
+
+use checklist_result::{CheckResult, CheckStatus, Disposition};
+use serde_json::Value;
+
+use crate::store::{Run, RunStatus};
+
+/// Render a run as a JSON object for the API
+pub fn run_json(run: &Run) -> Value {
+    let (status, results, error) = match &run.status {
+        RunStatus::Running => ("running", Vec::new(), None),
+        RunStatus::Done(results) => ("done", results.clone(), None),
+        RunStatus::Failed(e) => ("error", Vec::new(), Some(e.clone())),
+    };
+    let results: Vec<Value> = results
+        .iter()
+        .map(|r| {
+            serde_json::json!({
+                "name": r.name,
+                "status": status_name(r.status),
+                "message": r.message,
+                "disposition": r.disposition.map(Disposition::label),
+            })
+        })
+        .collect();
+    serde_json::json!({
+        "id": run.id,
+        "target": run.target,
+        "status": status,
+        "error": error,
+        "results": results,
+    })
+}
+
+/// Render a run as a standalone HTML report
+pub fn run_html(run: &Run) -> String {
+    let body = match &run.status {
+        RunStatus::Running => "<p>Run in progress; refresh to update.</p>".to_string(),
+        RunStatus::Failed(e) => format!("<p>Run failed: {}</p>", escape_html(e)),
+        RunStatus::Done(results) => results_table(results),
+    };
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head><meta charset=\"utf-8\"><title>sw-checklist run {id}</title></head>\n<body>\n<h1>sw-checklist run {id}</h1>\n<p>Target: <code>{target}</code></p>\n{body}\n</body>\n</html>\n",
+        id = run.id,
+        target = escape_html(&run.target),
+        body = body,
+    )
+}
+
+fn results_table(results: &[CheckResult]) -> String {
+    let rows: String = results
+        .iter()
+        .map(|r| {
+            format!(
+                "<tr class=\"{status}\"><td>{status}</td><td>{}</td><td>{}</td></tr>\n",
+                escape_html(&r.name),
+                escape_html(&r.display_message()),
+                status = status_name(r.status),
+            )
+        })
+        .collect();
+    format!(
+        "<table>\n<tr><th>Status</th><th>Check</th><th>Message</th></tr>\n{}</table>",
+        rows
+    )
+}
+
+/// Lowercase status name used in the JSON API and as the HTML row class
+fn status_name(status: CheckStatus) -> &'static str {
+    match status {
+        CheckStatus::Pass => "pass",
+        CheckStatus::Fail => "fail",
+        CheckStatus::Warn => "warn",
+        CheckStatus::Info => "info",
+    }
+}
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
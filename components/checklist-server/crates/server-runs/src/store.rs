@@ -0,0 +1,140 @@
+//! In-memory run store
+
+use anyhow::{Result, bail};
+use checklist_exec::Exec;
+use checklist_result::CheckResult;
+use checklist_tempdir::TempWorkspace;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+/// Runs kept in memory; beyond this the oldest finished runs are dropped
+const MAX_RUNS: usize = 100;
+
+/// Runs checked at once; further requests are refused with [`Busy`]
+pub const MAX_RUNNING: usize = 4;
+
+/// Limit for shallow-cloning a git target
+const CLONE_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Function that checks the project at a path
+pub type Checker = dyn Fn(&Path) -> Result<Vec<CheckResult>> + Send + Sync;
+
+/// State of a check run
+#[derive(Debug, Clone)]
+pub enum RunStatus {
+    Running,
+    Done(Vec<CheckResult>),
+    Failed(String),
+}
+
+/// [`MAX_RUNNING`] runs are already in progress, answered with 503
+#[derive(Debug)]
+pub struct Busy;
+
+impl fmt::Display for Busy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} runs already in progress; retry later", MAX_RUNNING)
+    }
+}
+
+impl std::error::Error for Busy {}
+
+/// A check run triggered through the API
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub id: u64,
+    pub target: String,
+    pub status: RunStatus,
+}
+
+/// Recent runs, keyed by id (at most [`MAX_RUNS`] plus any still running)
+#[derive(Default)]
+pub struct RunStore {
+    runs: Mutex<BTreeMap<u64, Run>>,
+    next_id: AtomicU64,
+}
+
+impl RunStore {
+    /// Start checking a path or git URL in the background and return the run
+    /// id ([`Busy`] when [`MAX_RUNNING`] runs are in progress)
+    pub fn start(self: &Arc<Self>, target: String, checker: Arc<Checker>) -> Result<u64, Busy> {
+        let mut runs = self.runs.lock().unwrap();
+        let running = runs
+            .values()
+            .filter(|run| matches!(run.status, RunStatus::Running))
+            .count();
+        if running >= MAX_RUNNING {
+            return Err(Busy);
+        }
+        let id = self.next_id.fetch_add(1, Ordering::SeqCst) + 1;
+        let run = Run {
+            id,
+            target: target.clone(),
+            status: RunStatus::Running,
+        };
+        runs.insert(id, run);
+        evict_finished(&mut runs);
+        drop(runs);
+
+        let store = Arc::clone(self);
+        thread::spawn(move || {
+            let status = match run_target(&target, checker.as_ref()) {
+                Ok(results) => RunStatus::Done(results),
+                Err(e) => RunStatus::Failed(format!("{:#}", e)),
+            };
+            if let Some(run) = store.runs.lock().unwrap().get_mut(&id) {
+                run.status = status;
+            }
+        });
+        Ok(id)
+    }
+
+    /// Look up a run by id
+    pub fn get(&self, id: u64) -> Option<Run> {
+        self.runs.lock().unwrap().get(&id).cloned()
+    }
+}
+
+/// Drop the oldest finished runs until at most [`MAX_RUNS`] remain
+fn evict_finished(runs: &mut BTreeMap<u64, Run>) {
+    let excess = runs.len().saturating_sub(MAX_RUNS);
+    let stale: Vec<u64> = runs
+        .values()
+        .filter(|run| !matches!(run.status, RunStatus::Running))
+        .map(|run| run.id)
+        .take(excess)
+        .collect();
+    for id in stale {
+        runs.remove(&id);
+    }
+}
+
+/// Whether a run target is a git URL to clone rather than a local path
+pub fn is_git_url(target: &str) -> bool {
+    ["https://", "http://", "git@", "ssh://"]
+        .iter()
+        .any(|p| target.starts_with(p))
+}
+
+/// Check a local path directly, or shallow-clone a git URL into a temp workspace first
+fn run_target(target: &str, checker: &Checker) -> Result<Vec<CheckResult>> {
+    if !is_git_url(target) {
+        return checker(Path::new(target));
+    }
+    let workspace = TempWorkspace::new("run")?;
+    let dir = workspace.path().join("repo");
+    let output = Exec::new("git")
+        .args(["clone", "--depth", "1", "--quiet", target])
+        .args([&dir])
+        .timeout(CLONE_TIMEOUT)
+        .run()?;
+    if !output.status.success() {
+        bail!("git clone failed: {}", output.stderr.trim());
+    }
+    checker(&dir)
+}
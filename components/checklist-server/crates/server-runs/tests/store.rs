@@ -0,0 +1,56 @@
+use checklist_result::CheckResult;
+use server_runs::{Checker, MAX_RUNNING, RunStatus, RunStore, run_json};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::Duration;
+
+/// A checker that blocks until `release` is set, then reports one warning
+fn gated_checker(release: Arc<AtomicBool>) -> Arc<Checker> {
+    Arc::new(move |_| {
+        while !release.load(Ordering::SeqCst) {
+            thread::sleep(Duration::from_millis(5));
+        }
+        Ok(vec![CheckResult::warn("Sample", "sample warning")])
+    })
+}
+
+fn wait_done(store: &RunStore, id: u64) -> RunStatus {
+    for _ in 0..400 {
+        let status = store.get(id).unwrap().status;
+        if !matches!(status, RunStatus::Running) {
+            return status;
+        }
+        thread::sleep(Duration::from_millis(5));
+    }
+    panic!("run {} never finished", id);
+}
+
+#[test]
+fn refuses_runs_beyond_the_cap() {
+    let store = Arc::new(RunStore::default());
+    let release = Arc::new(AtomicBool::new(false));
+    let checker = gated_checker(Arc::clone(&release));
+    let ids: Vec<u64> = (0..MAX_RUNNING)
+        .map(|_| store.start(".".into(), Arc::clone(&checker)).unwrap())
+        .collect();
+    assert!(store.start(".".into(), Arc::clone(&checker)).is_err());
+
+    release.store(true, Ordering::SeqCst);
+    for id in ids {
+        assert!(matches!(wait_done(&store, id), RunStatus::Done(_)));
+    }
+    assert!(store.start(".".into(), checker).is_ok());
+}
+
+#[test]
+fn run_json_names_statuses() {
+    let store = Arc::new(RunStore::default());
+    let checker = gated_checker(Arc::new(AtomicBool::new(true)));
+    let id = store.start(".".into(), checker).unwrap();
+    wait_done(&store, id);
+    let json = run_json(&store.get(id).unwrap());
+    assert_eq!(json["status"], "done");
+    assert_eq!(json["results"][0]["status"], "warn");
+    assert_eq!(json["results"][0]["name"], "Sample");
+}
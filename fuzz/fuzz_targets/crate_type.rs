@@ -0,0 +1,16 @@
+//! Crate classification and target discovery on arbitrary Cargo.toml text
+
+#![no_main]
+
+use discovery_crate::{binary_targets, detect_crate_type, has_dependency};
+use libfuzzer_sys::fuzz_target;
+use std::path::Path;
+
+fuzz_target!(|data: &[u8]| {
+    let manifest = String::from_utf8_lossy(data);
+    // A directory that never exists, so only the manifest text matters
+    let dir = Path::new("/nonexistent/sw-checklist-fuzz");
+    detect_crate_type(&manifest, dir);
+    has_dependency(&manifest, "clap");
+    binary_targets(&manifest, "fuzz", dir);
+});
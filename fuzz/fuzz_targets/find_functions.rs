@@ -0,0 +1,20 @@
+//! Function span detection on arbitrary source text
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use modularity_loc::find_functions;
+
+fuzz_target!(|data: &[u8]| {
+    let source = String::from_utf8_lossy(data);
+    let lines = source.lines().count();
+    let mut next_free = 0;
+    for span in find_functions(&source) {
+        assert!(span.line >= next_free, "overlapping spans");
+        assert!(
+            span.loc >= 1 && span.line + span.loc <= lines,
+            "span out of range"
+        );
+        next_free = span.line + span.loc;
+    }
+});
@@ -0,0 +1,21 @@
+//! Asset reference scanning and favicon link insertion on arbitrary HTML
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use wasm_build::local_refs;
+use wasm_html::with_favicon_link;
+
+fuzz_target!(|data: &[u8]| {
+    let html = String::from_utf8_lossy(data);
+    for asset in local_refs(&html) {
+        assert!(
+            !asset.is_empty() && !asset.contains(':'),
+            "non-local ref {:?}",
+            asset
+        );
+    }
+    if let Some(fixed) = with_favicon_link(&html) {
+        assert!(fixed.len() > html.len(), "link not inserted");
+    }
+});
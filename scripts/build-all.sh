@@ -33,6 +33,11 @@ echo "=== Building checklist-handler-cargo ==="
 cd "$REPO_ROOT/components/checklist-handler-cargo"
 cargo build --release
 
+echo ""
+echo "=== Building checklist-handler-modularity ==="
+cd "$REPO_ROOT/components/checklist-handler-modularity"
+cargo build --release
+
 echo ""
 echo "=== Building checklist-handler-clap ==="
 cd "$REPO_ROOT/components/checklist-handler-clap"
@@ -44,8 +49,88 @@ cd "$REPO_ROOT/components/checklist-handler-wasm"
 cargo build --release
 
 echo ""
-echo "=== Building checklist-handler-modularity ==="
-cd "$REPO_ROOT/components/checklist-handler-modularity"
+echo "=== Building checklist-handler-plugin ==="
+cd "$REPO_ROOT/components/checklist-handler-plugin"
+cargo build --release
+
+echo ""
+echo "=== Building checklist-handler-embedded ==="
+cd "$REPO_ROOT/components/checklist-handler-embedded"
+cargo build --release
+
+echo ""
+echo "=== Building checklist-handler-grpc ==="
+cd "$REPO_ROOT/components/checklist-handler-grpc"
+cargo build --release
+
+echo ""
+echo "=== Building checklist-handler-db ==="
+cd "$REPO_ROOT/components/checklist-handler-db"
+cargo build --release
+
+echo ""
+echo "=== Building checklist-handler-tauri ==="
+cd "$REPO_ROOT/components/checklist-handler-tauri"
+cargo build --release
+
+echo ""
+echo "=== Building checklist-handler-egui ==="
+cd "$REPO_ROOT/components/checklist-handler-egui"
+cargo build --release
+
+echo ""
+echo "=== Building checklist-handler-logging ==="
+cd "$REPO_ROOT/components/checklist-handler-logging"
+cargo build --release
+
+echo ""
+echo "=== Building checklist-handler-lint ==="
+cd "$REPO_ROOT/components/checklist-handler-lint"
+cargo build --release
+
+echo ""
+echo "=== Building checklist-handler-hygiene ==="
+cd "$REPO_ROOT/components/checklist-handler-hygiene"
+cargo build --release
+
+echo ""
+echo "=== Building checklist-handler-layout ==="
+cd "$REPO_ROOT/components/checklist-handler-layout"
+cargo build --release
+
+echo ""
+echo "=== Building checklist-handler-license ==="
+cd "$REPO_ROOT/components/checklist-handler-license"
+cargo build --release
+
+echo ""
+echo "=== Building checklist-handler-deps ==="
+cd "$REPO_ROOT/components/checklist-handler-deps"
+cargo build --release
+
+echo ""
+echo "=== Building checklist-handler-ratchet ==="
+cd "$REPO_ROOT/components/checklist-handler-ratchet"
+cargo build --release
+
+echo ""
+echo "=== Building checklist-engine ==="
+cd "$REPO_ROOT/components/checklist-engine"
+cargo build --release
+
+echo ""
+echo "=== Building checklist-server ==="
+cd "$REPO_ROOT/components/checklist-server"
+cargo build --release
+
+echo ""
+echo "=== Building checklist-lsp ==="
+cd "$REPO_ROOT/components/checklist-lsp"
+cargo build --release
+
+echo ""
+echo "=== Building checklist-api ==="
+cd "$REPO_ROOT/components/checklist-api"
 cargo build --release
 
 echo ""
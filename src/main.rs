@@ -338,725 +338,3 @@ fn print_results(results: &[CheckResult]) {
         println!();
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_find_cargo_tomls() {
-        use tempfile::tempdir;
-
-        let temp = tempdir().unwrap();
-        let cargo_toml = temp.path().join("Cargo.toml");
-        fs::write(&cargo_toml, "[package]\nname = \"test\"\n").unwrap();
-
-        let found = discovery::find_cargo_tomls(temp.path());
-        assert_eq!(found.len(), 1);
-        assert!(found[0].ends_with("Cargo.toml"));
-    }
-
-    #[test]
-    fn test_find_no_cargo_tomls() {
-        use tempfile::tempdir;
-
-        let temp = tempdir().unwrap();
-        let found = discovery::find_cargo_tomls(temp.path());
-        assert_eq!(found.len(), 0);
-    }
-
-    #[test]
-    fn test_check_result_creation() {
-        let pass = CheckResult::pass("Test", "This passed");
-        assert!(pass.passed);
-        assert_eq!(pass.name, "Test");
-        assert_eq!(pass.message, "This passed");
-
-        let fail = CheckResult::fail("Test", "This failed");
-        assert!(!fail.passed);
-        assert_eq!(fail.name, "Test");
-        assert_eq!(fail.message, "This failed");
-    }
-
-    #[test]
-    fn test_workspace_structure() {
-        use tempfile::tempdir;
-
-        // Create a workspace-like structure
-        let temp = tempdir().unwrap();
-
-        // Root workspace Cargo.toml
-        let workspace_toml = temp.path().join("Cargo.toml");
-        fs::write(
-            &workspace_toml,
-            r#"
-[workspace]
-members = ["crate1", "crate2"]
-"#,
-        )
-        .unwrap();
-
-        // Crate 1
-        fs::create_dir_all(temp.path().join("crate1")).unwrap();
-        let crate1_toml = temp.path().join("crate1/Cargo.toml");
-        fs::write(
-            &crate1_toml,
-            r#"
-[package]
-name = "crate1"
-version = "0.1.0"
-"#,
-        )
-        .unwrap();
-
-        // Crate 2
-        fs::create_dir_all(temp.path().join("crate2")).unwrap();
-        let crate2_toml = temp.path().join("crate2/Cargo.toml");
-        fs::write(
-            &crate2_toml,
-            r#"
-[package]
-name = "crate2"
-version = "0.1.0"
-"#,
-        )
-        .unwrap();
-
-        // Should find all 3 Cargo.toml files
-        let found = discovery::find_cargo_tomls(temp.path());
-        assert_eq!(found.len(), 3);
-    }
-
-    #[test]
-    fn test_nested_crates() {
-        use tempfile::tempdir;
-
-        let temp = tempdir().unwrap();
-
-        // Root Cargo.toml
-        let root_toml = temp.path().join("Cargo.toml");
-        fs::write(&root_toml, "[package]\nname = \"root\"\n").unwrap();
-
-        // Nested crate
-        fs::create_dir_all(temp.path().join("nested/deep")).unwrap();
-        let nested_toml = temp.path().join("nested/deep/Cargo.toml");
-        fs::write(&nested_toml, "[package]\nname = \"nested\"\n").unwrap();
-
-        // Should find both
-        let found = discovery::find_cargo_tomls(temp.path());
-        assert_eq!(found.len(), 2);
-    }
-
-    #[test]
-    fn test_crate_without_clap() {
-        use tempfile::tempdir;
-
-        let temp = tempdir().unwrap();
-        let cargo_toml = temp.path().join("Cargo.toml");
-        fs::write(
-            &cargo_toml,
-            r#"
-[package]
-name = "my-library"
-
-[dependencies]
-serde = "1.0"
-tokio = "1.0"
-"#,
-        )
-        .unwrap();
-
-        let found = discovery::find_cargo_tomls(temp.path());
-        assert_eq!(found.len(), 1);
-
-        // Should skip clap/WASM checks but still run modularity checks
-        let results = run_checks(temp.path(), &found, false).unwrap();
-
-        // Should have modularity check results (at least one)
-        assert!(!results.is_empty());
-        // All modularity checks should pass for an empty library
-        assert!(results.iter().all(|r| r.passed || r.is_warning));
-    }
-
-    #[test]
-    fn test_empty_project() {
-        use tempfile::tempdir;
-
-        let temp = tempdir().unwrap();
-        let found = discovery::find_cargo_tomls(temp.path());
-        assert_eq!(found.len(), 0);
-    }
-
-    #[test]
-    fn test_check_result_warn() {
-        let warn = CheckResult::warn("Test Warning", "This is a warning");
-        assert!(warn.is_warning);
-        assert_eq!(warn.name, "Test Warning");
-        assert_eq!(warn.message, "This is a warning");
-    }
-
-    #[test]
-    fn test_check_sw_install_present() {
-        use tempfile::tempdir;
-
-        let temp = tempdir().unwrap();
-
-        // Test when sw-install does not exist
-        let result = checks::install::check_sw_install_presence_impl(Some(temp.path()));
-        assert!(result.is_warning);
-        assert!(result.message.contains("sw-install"));
-        assert!(result.message.contains("not installed"));
-
-        // Test when sw-install exists
-        let sw_install_path = temp.path().join("sw-install");
-        fs::write(&sw_install_path, "fake sw-install").unwrap();
-
-        let result = checks::install::check_sw_install_presence_impl(Some(temp.path()));
-        assert!(result.passed);
-        assert!(!result.is_warning);
-        assert_eq!(result.message, "sw-install is installed");
-    }
-
-    #[test]
-    fn test_check_binary_freshness_no_installed_binary() {
-        use tempfile::tempdir;
-
-        let temp = tempdir().unwrap();
-
-        // Create a fake binary in target/release
-        let target_dir = temp.path().join("target/release");
-        fs::create_dir_all(&target_dir).unwrap();
-        let local_binary = target_dir.join("test-binary");
-        fs::write(&local_binary, "test").unwrap();
-
-        // Non-existent installed binary
-        let installed_binary = PathBuf::from("/nonexistent/path/test-binary");
-
-        let result = checks::install::check_binary_freshness(
-            "test-binary",
-            &local_binary,
-            &installed_binary,
-        );
-
-        // Should not warn if installed binary doesn't exist
-        assert!(!result.is_warning);
-        assert!(result.passed);
-    }
-
-    #[test]
-    fn test_check_binary_freshness_local_newer() {
-        use std::thread;
-        use std::time::Duration;
-        use tempfile::tempdir;
-
-        let temp = tempdir().unwrap();
-
-        // Create installed binary first
-        let installed_dir = temp.path().join("installed");
-        fs::create_dir_all(&installed_dir).unwrap();
-        let installed_binary = installed_dir.join("test-binary");
-        fs::write(&installed_binary, "old").unwrap();
-
-        // Wait a bit to ensure different timestamps
-        thread::sleep(Duration::from_millis(10));
-
-        // Create newer local binary
-        let local_dir = temp.path().join("target/release");
-        fs::create_dir_all(&local_dir).unwrap();
-        let local_binary = local_dir.join("test-binary");
-        fs::write(&local_binary, "new").unwrap();
-
-        let result = checks::install::check_binary_freshness(
-            "test-binary",
-            &local_binary,
-            &installed_binary,
-        );
-
-        // Should warn because local is newer
-        assert!(result.is_warning);
-        assert!(result.message.contains("newer"));
-        assert!(result.message.contains("acceptance test"));
-        assert!(result.message.contains("sw-install"));
-    }
-
-    #[test]
-    fn test_check_binary_freshness_installed_newer() {
-        use std::thread;
-        use std::time::Duration;
-        use tempfile::tempdir;
-
-        let temp = tempdir().unwrap();
-
-        // Create local binary first
-        let local_dir = temp.path().join("target/release");
-        fs::create_dir_all(&local_dir).unwrap();
-        let local_binary = local_dir.join("test-binary");
-        fs::write(&local_binary, "old").unwrap();
-
-        // Wait a bit to ensure different timestamps
-        thread::sleep(Duration::from_millis(10));
-
-        // Create newer installed binary
-        let installed_dir = temp.path().join("installed");
-        fs::create_dir_all(&installed_dir).unwrap();
-        let installed_binary = installed_dir.join("test-binary");
-        fs::write(&installed_binary, "new").unwrap();
-
-        let result = checks::install::check_binary_freshness(
-            "test-binary",
-            &local_binary,
-            &installed_binary,
-        );
-
-        // Should not warn because installed is newer or equal
-        assert!(!result.is_warning);
-        assert!(result.passed);
-    }
-
-    // Modularity tests
-
-    #[test]
-    fn test_function_loc_under_25_pass() {
-        use tempfile::tempdir;
-
-        let temp = tempdir().unwrap();
-        let crate_dir = temp.path().join("test-crate");
-        fs::create_dir_all(&crate_dir).unwrap();
-
-        // Create Cargo.toml
-        fs::write(
-            crate_dir.join("Cargo.toml"),
-            r#"
-[package]
-name = "test-crate"
-version = "0.1.0"
-"#,
-        )
-        .unwrap();
-
-        // Create lib.rs with small function (10 lines)
-        let src_dir = crate_dir.join("src");
-        fs::create_dir_all(&src_dir).unwrap();
-        fs::write(
-            src_dir.join("lib.rs"),
-            r#"
-pub fn small_function() {
-    let x = 1;
-    let y = 2;
-    let z = x + y;
-    println!("{}", z);
-}
-"#,
-        )
-        .unwrap();
-
-        let results = checks::modularity::check_modularity(&crate_dir, "test-crate").unwrap();
-
-        // Should pass with no warnings
-        let function_results: Vec<_> = results
-            .iter()
-            .filter(|r| r.name.contains("Function LOC"))
-            .collect();
-
-        assert!(!function_results.is_empty());
-        assert!(function_results.iter().all(|r| r.passed));
-        assert!(function_results.iter().all(|r| !r.is_warning));
-    }
-
-    #[test]
-    fn test_function_loc_26_to_50_warn() {
-        use tempfile::tempdir;
-
-        let temp = tempdir().unwrap();
-        let crate_dir = temp.path().join("test-crate");
-        fs::create_dir_all(&crate_dir).unwrap();
-
-        fs::write(
-            crate_dir.join("Cargo.toml"),
-            r#"
-[package]
-name = "test-crate"
-version = "0.1.0"
-"#,
-        )
-        .unwrap();
-
-        // Create function with 30 lines (warning)
-        let src_dir = crate_dir.join("src");
-        fs::create_dir_all(&src_dir).unwrap();
-        let mut content = String::from("pub fn medium_function() {\n");
-        for i in 0..28 {
-            content.push_str(&format!("    let x{} = {};\n", i, i));
-        }
-        content.push_str("}\n");
-
-        fs::write(src_dir.join("lib.rs"), content).unwrap();
-
-        let results = checks::modularity::check_modularity(&crate_dir, "test-crate").unwrap();
-
-        let function_results: Vec<_> = results
-            .iter()
-            .filter(|r| r.name.contains("Function LOC"))
-            .collect();
-
-        assert!(!function_results.is_empty());
-        // Should have a warning for the large function
-        assert!(function_results.iter().any(|r| r.is_warning));
-    }
-
-    #[test]
-    fn test_function_loc_over_50_fail() {
-        use tempfile::tempdir;
-
-        let temp = tempdir().unwrap();
-        let crate_dir = temp.path().join("test-crate");
-        fs::create_dir_all(&crate_dir).unwrap();
-
-        fs::write(
-            crate_dir.join("Cargo.toml"),
-            r#"
-[package]
-name = "test-crate"
-version = "0.1.0"
-"#,
-        )
-        .unwrap();
-
-        // Create function with 60 lines (fail)
-        let src_dir = crate_dir.join("src");
-        fs::create_dir_all(&src_dir).unwrap();
-        let mut content = String::from("pub fn huge_function() {\n");
-        for i in 0..58 {
-            content.push_str(&format!("    let x{} = {};\n", i, i));
-        }
-        content.push_str("}\n");
-
-        fs::write(src_dir.join("lib.rs"), content).unwrap();
-
-        let results = checks::modularity::check_modularity(&crate_dir, "test-crate").unwrap();
-
-        let function_results: Vec<_> = results
-            .iter()
-            .filter(|r| r.name.contains("Function LOC"))
-            .collect();
-
-        assert!(!function_results.is_empty());
-        // Should fail for the huge function
-        assert!(function_results.iter().any(|r| !r.passed));
-    }
-
-    #[test]
-    fn test_module_function_count_under_4_pass() {
-        use tempfile::tempdir;
-
-        let temp = tempdir().unwrap();
-        let crate_dir = temp.path().join("test-crate");
-        fs::create_dir_all(&crate_dir).unwrap();
-
-        fs::write(
-            crate_dir.join("Cargo.toml"),
-            r#"
-[package]
-name = "test-crate"
-version = "0.1.0"
-"#,
-        )
-        .unwrap();
-
-        // Create module with 3 functions
-        let src_dir = crate_dir.join("src");
-        fs::create_dir_all(&src_dir).unwrap();
-        fs::write(
-            src_dir.join("lib.rs"),
-            r#"
-pub fn func1() {}
-pub fn func2() {}
-pub fn func3() {}
-"#,
-        )
-        .unwrap();
-
-        let results = checks::modularity::check_modularity(&crate_dir, "test-crate").unwrap();
-
-        let module_results: Vec<_> = results
-            .iter()
-            .filter(|r| r.name.contains("Module Function Count"))
-            .collect();
-
-        assert!(!module_results.is_empty());
-        assert!(module_results.iter().all(|r| r.passed));
-        assert!(module_results.iter().all(|r| !r.is_warning));
-    }
-
-    #[test]
-    fn test_module_function_count_5_to_7_warn() {
-        use tempfile::tempdir;
-
-        let temp = tempdir().unwrap();
-        let crate_dir = temp.path().join("test-crate");
-        fs::create_dir_all(&crate_dir).unwrap();
-
-        fs::write(
-            crate_dir.join("Cargo.toml"),
-            r#"
-[package]
-name = "test-crate"
-version = "0.1.0"
-"#,
-        )
-        .unwrap();
-
-        // Create module with 5 functions (warning)
-        let src_dir = crate_dir.join("src");
-        fs::create_dir_all(&src_dir).unwrap();
-        let mut content = String::new();
-        for i in 1..=5 {
-            content.push_str(&format!("pub fn func{}() {{}}\n", i));
-        }
-        fs::write(src_dir.join("lib.rs"), content).unwrap();
-
-        let results = checks::modularity::check_modularity(&crate_dir, "test-crate").unwrap();
-
-        let module_results: Vec<_> = results
-            .iter()
-            .filter(|r| r.name.contains("Module Function Count"))
-            .collect();
-
-        assert!(!module_results.is_empty());
-        assert!(module_results.iter().any(|r| r.is_warning));
-    }
-
-    #[test]
-    fn test_module_function_count_over_7_fail() {
-        use tempfile::tempdir;
-
-        let temp = tempdir().unwrap();
-        let crate_dir = temp.path().join("test-crate");
-        fs::create_dir_all(&crate_dir).unwrap();
-
-        fs::write(
-            crate_dir.join("Cargo.toml"),
-            r#"
-[package]
-name = "test-crate"
-version = "0.1.0"
-"#,
-        )
-        .unwrap();
-
-        // Create module with 8 functions (fail)
-        let src_dir = crate_dir.join("src");
-        fs::create_dir_all(&src_dir).unwrap();
-        let mut content = String::new();
-        for i in 1..=8 {
-            content.push_str(&format!("pub fn func{}() {{}}\n", i));
-        }
-        fs::write(src_dir.join("lib.rs"), content).unwrap();
-
-        let results = checks::modularity::check_modularity(&crate_dir, "test-crate").unwrap();
-
-        let module_results: Vec<_> = results
-            .iter()
-            .filter(|r| r.name.contains("Module Function Count"))
-            .collect();
-
-        assert!(!module_results.is_empty());
-        assert!(module_results.iter().any(|r| !r.passed));
-    }
-
-    #[test]
-    fn test_crate_module_count_under_4_pass() {
-        use tempfile::tempdir;
-
-        let temp = tempdir().unwrap();
-        let crate_dir = temp.path().join("test-crate");
-        fs::create_dir_all(&crate_dir).unwrap();
-
-        fs::write(
-            crate_dir.join("Cargo.toml"),
-            r#"
-[package]
-name = "test-crate"
-version = "0.1.0"
-"#,
-        )
-        .unwrap();
-
-        // Create 3 module files
-        let src_dir = crate_dir.join("src");
-        fs::create_dir_all(&src_dir).unwrap();
-        fs::write(src_dir.join("lib.rs"), "pub mod module1;\npub mod module2;").unwrap();
-        fs::write(src_dir.join("module1.rs"), "pub fn func() {}").unwrap();
-        fs::write(src_dir.join("module2.rs"), "pub fn func() {}").unwrap();
-
-        let results = checks::modularity::check_modularity(&crate_dir, "test-crate").unwrap();
-
-        let crate_results: Vec<_> = results
-            .iter()
-            .filter(|r| r.name.contains("Crate Module Count"))
-            .collect();
-
-        assert!(!crate_results.is_empty());
-        assert!(crate_results.iter().all(|r| r.passed));
-        assert!(crate_results.iter().all(|r| !r.is_warning));
-    }
-
-    #[test]
-    fn test_crate_module_count_over_7_fail() {
-        use tempfile::tempdir;
-
-        let temp = tempdir().unwrap();
-        let crate_dir = temp.path().join("test-crate");
-        fs::create_dir_all(&crate_dir).unwrap();
-
-        fs::write(
-            crate_dir.join("Cargo.toml"),
-            r#"
-[package]
-name = "test-crate"
-version = "0.1.0"
-"#,
-        )
-        .unwrap();
-
-        // Create 8 module files (fail)
-        let src_dir = crate_dir.join("src");
-        fs::create_dir_all(&src_dir).unwrap();
-        let mut lib_content = String::new();
-        for i in 1..=8 {
-            lib_content.push_str(&format!("pub mod module{};\n", i));
-            fs::write(src_dir.join(format!("module{}.rs", i)), "pub fn func() {}").unwrap();
-        }
-        fs::write(src_dir.join("lib.rs"), lib_content).unwrap();
-
-        let results = checks::modularity::check_modularity(&crate_dir, "test-crate").unwrap();
-
-        let crate_results: Vec<_> = results
-            .iter()
-            .filter(|r| r.name.contains("Crate Module Count"))
-            .collect();
-
-        assert!(!crate_results.is_empty());
-        assert!(crate_results.iter().any(|r| !r.passed));
-    }
-
-    // File LOC tests
-
-    #[test]
-    fn test_file_loc_under_350_pass() {
-        use tempfile::tempdir;
-
-        let temp = tempdir().unwrap();
-        let crate_dir = temp.path().join("test-crate");
-        fs::create_dir_all(&crate_dir).unwrap();
-
-        fs::write(
-            crate_dir.join("Cargo.toml"),
-            r#"
-[package]
-name = "test-crate"
-version = "0.1.0"
-"#,
-        )
-        .unwrap();
-
-        // Create file with 100 lines (pass)
-        let src_dir = crate_dir.join("src");
-        fs::create_dir_all(&src_dir).unwrap();
-        let mut content = String::new();
-        for i in 0..100 {
-            content.push_str(&format!("// Line {}\n", i));
-        }
-        fs::write(src_dir.join("lib.rs"), content).unwrap();
-
-        let results = checks::modularity::check_modularity(&crate_dir, "test-crate").unwrap();
-
-        let file_results: Vec<_> = results
-            .iter()
-            .filter(|r| r.name.contains("File LOC"))
-            .collect();
-
-        assert!(!file_results.is_empty());
-        assert!(file_results.iter().all(|r| r.passed));
-        assert!(file_results.iter().all(|r| !r.is_warning));
-    }
-
-    #[test]
-    fn test_file_loc_351_to_500_warn() {
-        use tempfile::tempdir;
-
-        let temp = tempdir().unwrap();
-        let crate_dir = temp.path().join("test-crate");
-        fs::create_dir_all(&crate_dir).unwrap();
-
-        fs::write(
-            crate_dir.join("Cargo.toml"),
-            r#"
-[package]
-name = "test-crate"
-version = "0.1.0"
-"#,
-        )
-        .unwrap();
-
-        // Create file with 400 lines (warning)
-        let src_dir = crate_dir.join("src");
-        fs::create_dir_all(&src_dir).unwrap();
-        let mut content = String::new();
-        for i in 0..400 {
-            content.push_str(&format!("// Line {}\n", i));
-        }
-        fs::write(src_dir.join("lib.rs"), content).unwrap();
-
-        let results = checks::modularity::check_modularity(&crate_dir, "test-crate").unwrap();
-
-        let file_results: Vec<_> = results
-            .iter()
-            .filter(|r| r.name.contains("File LOC"))
-            .collect();
-
-        assert!(!file_results.is_empty());
-        assert!(file_results.iter().any(|r| r.is_warning));
-        assert!(file_results[0].message.contains("400 lines"));
-    }
-
-    #[test]
-    fn test_file_loc_over_500_fail() {
-        use tempfile::tempdir;
-
-        let temp = tempdir().unwrap();
-        let crate_dir = temp.path().join("test-crate");
-        fs::create_dir_all(&crate_dir).unwrap();
-
-        fs::write(
-            crate_dir.join("Cargo.toml"),
-            r#"
-[package]
-name = "test-crate"
-version = "0.1.0"
-"#,
-        )
-        .unwrap();
-
-        // Create file with 600 lines (fail)
-        let src_dir = crate_dir.join("src");
-        fs::create_dir_all(&src_dir).unwrap();
-        let mut content = String::new();
-        for i in 0..600 {
-            content.push_str(&format!("// Line {}\n", i));
-        }
-        fs::write(src_dir.join("lib.rs"), content).unwrap();
-
-        let results = checks::modularity::check_modularity(&crate_dir, "test-crate").unwrap();
-
-        let file_results: Vec<_> = results
-            .iter()
-            .filter(|r| r.name.contains("File LOC"))
-            .collect();
-
-        assert!(!file_results.is_empty());
-        assert!(file_results.iter().any(|r| !r.passed));
-        assert!(file_results[0].message.contains("600 lines"));
-        assert!(file_results[0].message.contains("Extract modules"));
-    }
-}